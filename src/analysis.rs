@@ -0,0 +1,399 @@
+//! One-shot analysis of a manifest without the LSP layer
+//!
+//! Composes the parsers, registries and checkers of the [`PackageResolver`]s so
+//! the version checks can be embedded in other tools: packages are parsed from
+//! the content, missing or stale versions are fetched into the storer, and each
+//! package is compared against the latest version. The `check` subcommand runs
+//! the same pipeline.
+
+use std::collections::HashMap;
+
+use crate::config::LspConfig;
+use crate::lsp::diagnostics::{create_diagnostic, go_release_package};
+use crate::lsp::refresh::{fetch_missing_packages, revalidate_stale_packages};
+use crate::lsp::resolver::{Matchers, PackageResolver};
+use crate::parser::types::{PackageInfo, RegistryType};
+use crate::version::checker::{VersionCompareResult, VersionStorer, compare_package};
+use tracing::warn;
+
+/// Result of checking one package of an analyzed manifest
+#[derive(Debug, Clone, PartialEq)]
+pub struct AnalysisResult {
+    pub package: PackageInfo,
+    pub result: VersionCompareResult,
+    /// Diagnostic message, `None` when there is nothing to report
+    pub message: Option<String>,
+}
+
+impl AnalysisResult {
+    /// The latest version the package was compared against
+    pub fn latest_version(&self) -> Option<&str> {
+        self.result.latest_version.as_deref()
+    }
+}
+
+/// Analyze the dependencies of a `registry_type` manifest in `content`
+///
+/// `uri` is the manifest's URI or path; its file name picks the format where a
+/// registry reads several (`.tool-versions` and mise.toml).
+///
+/// Unlike the language server, this waits for missing and stale packages to be
+/// fetched, so every package is compared on the first call. Packages of other
+/// registries (e.g. `npm:` imports in deno.json) are fetched from the registry
+/// and compared with the matcher of their own resolver. Disabled registries, ignored packages and digest pins are skipped,
+/// and messages use the severities of `config`.
+pub async fn analyze<S: VersionStorer>(
+    storer: &S,
+    resolvers: &HashMap<RegistryType, PackageResolver>,
+    config: &LspConfig,
+    registry_type: RegistryType,
+    uri: &str,
+    content: &str,
+) -> Vec<AnalysisResult> {
+    compare_packages(storer, resolvers, config, registry_type, uri, content)
+        .await
+        .into_iter()
+        .map(|(package, result)| {
+            let message = create_diagnostic(&package, &result, &config.severity).map(|d| d.message);
+            AnalysisResult {
+                package,
+                result,
+                message,
+            }
+        })
+        .collect()
+}
+
+/// Parse `content`, fetch what its packages need, then compare each reported one
+pub(crate) async fn compare_packages<S: VersionStorer>(
+    storer: &S,
+    resolvers: &HashMap<RegistryType, PackageResolver>,
+    config: &LspConfig,
+    registry_type: RegistryType,
+    uri: &str,
+    content: &str,
+) -> Vec<(PackageInfo, VersionCompareResult)> {
+    let Some(matchers) = Matchers::from_resolvers(resolvers, registry_type) else {
+        return Vec::new();
+    };
+    let options = config.compare_options();
+
+    fetch_packages(storer, resolvers, config, registry_type, uri, content)
+        .await
+        .into_iter()
+        .filter(|package| {
+            config.registries.is_enabled(package.registry_type)
+                && config.is_package_reported(package)
+                && !package.is_digest_pinned()
+                && !package.is_unversioned_commit_pin()
+        })
+        .filter_map(|package| {
+            let result =
+                compare_package(storer, matchers.get(&package), &package, &options).ok()?;
+            Some((package, result))
+        })
+        .collect()
+}
+
+/// Parse `content` and fetch the missing and stale versions of its packages
+///
+/// Each package is fetched from the registry of its own resolver. Returns the
+/// parsed packages, or nothing when the document's registry is disabled.
+pub(crate) async fn fetch_packages<S: VersionStorer>(
+    storer: &S,
+    resolvers: &HashMap<RegistryType, PackageResolver>,
+    config: &LspConfig,
+    registry_type: RegistryType,
+    uri: &str,
+    content: &str,
+) -> Vec<PackageInfo> {
+    if !config.registries.is_enabled(registry_type) {
+        return Vec::new();
+    }
+    let Some(resolver) = resolvers.get(&registry_type) else {
+        return Vec::new();
+    };
+    let packages = resolver
+        .parser_for(uri)
+        .parse(content)
+        .inspect_err(|e| warn!("Failed to parse document: {}", e))
+        .unwrap_or_default();
+
+    // A document may mix registries (e.g. `npm:` imports in deno.json)
+    let mut by_registry: HashMap<RegistryType, Vec<PackageInfo>> = HashMap::new();
+    let release = (registry_type == RegistryType::GoProxy && config.go.check_toolchain)
+        .then(|| go_release_package(content))
+        .flatten();
+    for package in packages.iter().cloned().chain(release) {
+        by_registry
+            .entry(package.registry_type)
+            .or_default()
+            .push(package);
+    }
+    for (package_registry, packages) in &by_registry {
+        if !config.registries.is_enabled(*package_registry) {
+            continue;
+        }
+        let Some(registry) = resolvers.get(package_registry).map(|r| r.registry()) else {
+            continue;
+        };
+        fetch_missing_packages(storer, &**registry, packages).await;
+        revalidate_stale_packages(storer, &**registry, packages).await;
+    }
+
+    packages
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use tempfile::TempDir;
+
+    use super::*;
+    use crate::parser::types::RegistryType;
+    use crate::parser::{CargoTomlParser, DenoJsonParser, PackageJsonParser};
+    use crate::version::cache::Cache;
+    use crate::version::checker::VersionStatus;
+    use crate::version::matcher::VersionMatcher;
+    use crate::version::matchers::{CratesVersionMatcher, JsrVersionMatcher, NpmVersionMatcher};
+    use crate::version::registry::MockRegistry;
+    use crate::version::semver::CompareResult;
+    use crate::version::types::PackageVersions;
+
+    /// Matcher that finds no version, to tell which matcher compared a package
+    struct RejectingMatcher(RegistryType);
+
+    impl VersionMatcher for RejectingMatcher {
+        fn registry_type(&self) -> RegistryType {
+            self.0
+        }
+
+        fn version_exists(&self, _version_spec: &str, _available_versions: &[String]) -> bool {
+            false
+        }
+
+        fn compare_to_latest(
+            &self,
+            _current_version: &str,
+            _latest_version: &str,
+        ) -> CompareResult {
+            CompareResult::Invalid
+        }
+    }
+
+    fn mock_registry(
+        registry_type: RegistryType,
+        versions: fn(&str) -> Vec<&'static str>,
+    ) -> MockRegistry {
+        let mut registry = MockRegistry::new();
+        registry
+            .expect_registry_type()
+            .returning(move || registry_type);
+        registry.expect_fetch_all_versions().returning(move |name| {
+            Ok(PackageVersions::new(
+                versions(name).into_iter().map(str::to_string).collect(),
+            ))
+        });
+        registry
+    }
+
+    fn crates_resolvers() -> HashMap<RegistryType, PackageResolver> {
+        let registry = mock_registry(RegistryType::CratesIo, |name| match name {
+            "serde" => vec!["1.0.0", "2.0.0"],
+            _ => vec!["1.40.0"],
+        });
+        HashMap::from([(
+            RegistryType::CratesIo,
+            PackageResolver::new(
+                Arc::new(CargoTomlParser::new()),
+                Arc::new(CratesVersionMatcher),
+                Arc::new(registry),
+            ),
+        )])
+    }
+
+    const CARGO_TOML: &str = r#"[package]
+name = "example"
+
+[dependencies]
+serde = "1.0"
+tokio = "1.40.0"
+"#;
+
+    #[tokio::test]
+    async fn analyze_reports_outdated_dependencies_of_cargo_toml() {
+        let temp_dir = TempDir::new().unwrap();
+        let cache = Cache::new(&temp_dir.path().join("test.db"), 86400000, false).unwrap();
+
+        let results = analyze(
+            &cache,
+            &crates_resolvers(),
+            &LspConfig::default(),
+            RegistryType::CratesIo,
+            "Cargo.toml",
+            CARGO_TOML,
+        )
+        .await;
+
+        let summary: Vec<_> = results
+            .iter()
+            .map(|r| (r.package.name.as_str(), r.result.status, r.latest_version()))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("serde", VersionStatus::Outdated, Some("2.0.0")),
+                ("tokio", VersionStatus::Latest, Some("1.40.0")),
+            ]
+        );
+        assert_eq!(
+            results[0].message.as_deref(),
+            Some("Update available: 1.0 -> 2.0.0")
+        );
+        assert_eq!(results[1].message, None);
+    }
+
+    #[tokio::test]
+    async fn analyze_skips_ignored_packages_and_disabled_registries() {
+        let temp_dir = TempDir::new().unwrap();
+        let cache = Cache::new(&temp_dir.path().join("test.db"), 86400000, false).unwrap();
+        let resolvers = crates_resolvers();
+
+        let mut config = LspConfig {
+            ignore: vec!["serde".to_string()],
+            ..Default::default()
+        };
+        let results = analyze(
+            &cache,
+            &resolvers,
+            &config,
+            RegistryType::CratesIo,
+            "Cargo.toml",
+            CARGO_TOML,
+        )
+        .await;
+        let names: Vec<_> = results.iter().map(|r| r.package.name.as_str()).collect();
+        assert_eq!(names, vec!["tokio"]);
+
+        config.registries.crates.enabled = false;
+        let results = analyze(
+            &cache,
+            &resolvers,
+            &config,
+            RegistryType::CratesIo,
+            "Cargo.toml",
+            CARGO_TOML,
+        )
+        .await;
+        assert!(results.is_empty());
+    }
+
+    #[tokio::test]
+    async fn analyze_checks_npm_imports_of_deno_json_against_npm() {
+        let temp_dir = TempDir::new().unwrap();
+        let cache = Cache::new(&temp_dir.path().join("test.db"), 86400000, false).unwrap();
+
+        let jsr = mock_registry(RegistryType::Jsr, |_| vec!["1.0.0", "1.1.0"]);
+        let npm = mock_registry(RegistryType::Npm, |_| vec!["4.17.21", "5.0.0"]);
+        let resolvers = HashMap::from([
+            (
+                RegistryType::Jsr,
+                PackageResolver::new(
+                    Arc::new(DenoJsonParser::new()),
+                    Arc::new(JsrVersionMatcher),
+                    Arc::new(jsr),
+                ),
+            ),
+            (
+                RegistryType::Npm,
+                PackageResolver::new(
+                    Arc::new(PackageJsonParser::new()),
+                    Arc::new(NpmVersionMatcher),
+                    Arc::new(npm),
+                ),
+            ),
+        ]);
+        let content = r#"{
+  "imports": {
+    "@std/path": "jsr:@std/path@1.0.0",
+    "lodash": "npm:lodash@4.17.21"
+  }
+}"#;
+
+        let results = analyze(
+            &cache,
+            &resolvers,
+            &LspConfig::default(),
+            RegistryType::Jsr,
+            "deno.json",
+            content,
+        )
+        .await;
+
+        let summary: Vec<_> = results
+            .iter()
+            .map(|r| (r.package.name.as_str(), r.latest_version()))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![("@std/path", Some("1.1.0")), ("lodash", Some("5.0.0"))]
+        );
+    }
+
+    #[tokio::test]
+    async fn analyze_compares_npm_imports_of_deno_json_with_the_npm_matcher() {
+        let temp_dir = TempDir::new().unwrap();
+        let cache = Cache::new(&temp_dir.path().join("test.db"), 86400000, false).unwrap();
+
+        let jsr = mock_registry(RegistryType::Jsr, |_| vec!["1.0.0", "1.1.0"]);
+        let npm = mock_registry(RegistryType::Npm, |_| vec!["4.17.21", "5.0.0"]);
+        let resolvers = HashMap::from([
+            (
+                RegistryType::Jsr,
+                PackageResolver::new(
+                    Arc::new(DenoJsonParser::new()),
+                    Arc::new(JsrVersionMatcher),
+                    Arc::new(jsr),
+                ),
+            ),
+            (
+                RegistryType::Npm,
+                PackageResolver::new(
+                    Arc::new(PackageJsonParser::new()),
+                    Arc::new(RejectingMatcher(RegistryType::Npm)),
+                    Arc::new(npm),
+                ),
+            ),
+        ]);
+        let content = r#"{
+  "imports": {
+    "@std/path": "jsr:@std/path@1.0.0",
+    "lodash": "npm:lodash@4.17.21"
+  }
+}"#;
+
+        let results = analyze(
+            &cache,
+            &resolvers,
+            &LspConfig::default(),
+            RegistryType::Jsr,
+            "deno.json",
+            content,
+        )
+        .await;
+
+        // The JSR matcher would find lodash 4.17.21 and report it outdated
+        let statuses: Vec<_> = results
+            .iter()
+            .map(|r| (r.package.name.as_str(), r.result.status))
+            .collect();
+        assert_eq!(
+            statuses,
+            vec![
+                ("@std/path", VersionStatus::Outdated),
+                ("lodash", VersionStatus::Invalid)
+            ]
+        );
+    }
+}
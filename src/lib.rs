@@ -1,6 +1,12 @@
 // Library crate for version-lsp
+pub mod analysis;
 pub mod config;
 pub(crate) mod log;
 pub mod lsp;
+pub mod netrc;
+pub mod npmrc;
 pub mod parser;
+pub mod renovate;
 pub mod version;
+
+pub use analysis::{AnalysisResult, analyze};
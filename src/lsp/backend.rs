@@ -1,5 +1,8 @@
-use std::collections::HashMap;
-use std::sync::{Arc, RwLock};
+use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, OnceLock, RwLock};
+use std::time::Duration;
 
 use tower_lsp::jsonrpc::Result;
 use tower_lsp::lsp_types::*;
@@ -8,62 +11,150 @@ use tracing::{debug, error, info, warn};
 
 use crate::config::{LspConfig, data_dir, db_path};
 use crate::lsp::code_action::{
-    PackageIndex, generate_constraint_code_actions, generate_pypi_constraint_code_actions,
-    generate_upgrade_code_actions, generate_upgrade_code_actions_with_sha,
+    PackageIndex, generate_constraint_code_actions, generate_pin_to_latest_action,
+    generate_pypi_constraint_code_actions, generate_update_all_edit,
+    generate_update_to_latest_action, generate_upgrade_code_actions,
+    generate_upgrade_code_actions_with_sha,
 };
-use crate::lsp::diagnostics::generate_diagnostics;
-use crate::lsp::refresh::{fetch_missing_packages, refresh_packages};
-use crate::lsp::resolver::{PackageResolver, create_resolvers};
-use crate::parser::types::{PackageInfo, RegistryType, detect_parser_type};
+use crate::lsp::code_lens::{generate_code_lenses, resolve_code_lens};
+use crate::lsp::completion::{TRIGGER_CHARACTERS, generate_completions};
+use crate::lsp::diagnostics::{assemble_diagnostics, go_release_package};
+use crate::lsp::document::apply_content_change;
+use crate::lsp::hover::generate_hover;
+use crate::lsp::inlay_hint::generate_inlay_hints;
+use crate::lsp::progress::{FetchProgress, ProgressRegistry, pending_fetches};
+use crate::lsp::refresh::{fetch_missing_packages, refresh_packages, revalidate_stale_packages};
+use crate::lsp::resolver::{Matchers, PackageResolver, create_resolvers};
+use crate::npmrc::{NPMRC_FILE, Npmrc};
+use crate::parser::types::{PackageInfo, RegistryType, detect_document_type, detect_parser_type};
+use crate::renovate::RenovatePolicy;
 use crate::version::cache::Cache;
-use crate::version::checker::VersionStorer;
+use crate::version::checker::{CompareOptions, VersionStorer};
 use crate::version::registry::Registry;
 
-/// Cached parsed packages for a document
+/// Command bumping every outdated dependency of a document to its latest version
+///
+/// Takes the document URI as its only argument.
+pub const UPDATE_ALL_COMMAND: &str = "version-lsp.updateAll";
+
+/// Command bumping a single outdated dependency to its latest version
+///
+/// Takes the document URI, and the line and column of the version string.
+pub const UPDATE_PACKAGE_COMMAND: &str = "version-lsp.updatePackage";
+
+/// Command dropping the cached versions of a document's packages and fetching
+/// them again
+///
+/// Takes the document URI, and optionally the name of a single package.
+pub const REFRESH_COMMAND: &str = "version-lsp.refresh";
+
+/// Delay before republishing diagnostics after a change, so typing doesn't
+/// trigger a check per keystroke
+const DIAGNOSTICS_DEBOUNCE: Duration = Duration::from_millis(300);
+
+/// Section of the client settings holding the server configuration
+const CONFIG_SECTION: &str = "version-lsp";
+
+/// Parse the document URI passed as the first command argument
+fn uri_argument(arguments: &[serde_json::Value]) -> Result<Url> {
+    arguments
+        .first()
+        .and_then(|arg| arg.as_str())
+        .and_then(|s| Url::parse(s).ok())
+        .ok_or_else(|| {
+            tower_lsp::jsonrpc::Error::invalid_params(
+                "Expected the document URI as the first argument",
+            )
+        })
+}
+
+/// Current text and parsed packages of an open document
 struct DocumentCache {
+    /// Parser type detected when the document was opened
+    registry_type: Option<RegistryType>,
+    /// Document version reported by the client
+    version: i32,
+    text: String,
+    packages: Vec<PackageInfo>,
+}
+
+/// What the request handlers need to answer for an open document
+struct DocumentContext<S> {
+    storer: Arc<S>,
+    /// Packages parsed on the last open/change
     packages: Vec<PackageInfo>,
+    matchers: Matchers,
+    /// Comparison settings of the current configuration
+    options: CompareOptions,
 }
 
+/// Opens the storer from the initial configuration, if it can be opened
+type OpenStorer<S> = fn(&LspConfig) -> Option<Arc<S>>;
+
 pub struct Backend<S: VersionStorer> {
     client: Client,
-    storer: Option<Arc<S>>,
+    /// Set on construction, or on initialization by `open_storer`
+    storer: Arc<OnceLock<Arc<S>>>,
+    /// Opens the storer once the initial configuration is known
+    open_storer: Option<OpenStorer<S>>,
     config: Arc<RwLock<LspConfig>>,
     resolvers: Arc<RwLock<HashMap<RegistryType, PackageResolver>>>,
     documents: Arc<RwLock<HashMap<Url, DocumentCache>>>,
+    /// Renovate policy loaded from the workspace root, if any
+    renovate: Arc<RwLock<Option<RenovatePolicy>>>,
+    /// npm registry settings from `.npmrc`, merged into every configuration
+    npmrc: Arc<RwLock<Npmrc>>,
+    /// User-level `.npmrc` read alongside the project one
+    user_npmrc: Option<PathBuf>,
+    /// Whether the client accepts server-initiated work done progress
+    work_done_progress: Arc<AtomicBool>,
 }
 
 impl Backend<Cache> {
     pub fn new(client: Client) -> Self {
         let config = LspConfig::default();
-        let storer = Self::initialize_storer(&config);
         let resolvers = create_resolvers(&config);
         Self {
             client,
-            storer,
+            // `cachePath` may point the cache elsewhere, so it is opened on initialize
+            storer: Arc::new(OnceLock::new()),
+            open_storer: Some(Self::initialize_storer),
             config: Arc::new(RwLock::new(config)),
             resolvers: Arc::new(RwLock::new(resolvers)),
             documents: Arc::new(RwLock::new(HashMap::new())),
+            renovate: Arc::new(RwLock::new(None)),
+            npmrc: Arc::new(RwLock::new(Npmrc::default())),
+            user_npmrc: dirs::home_dir().map(|home| home.join(NPMRC_FILE)),
+            work_done_progress: Arc::new(AtomicBool::new(false)),
         }
     }
 
     fn initialize_storer(config: &LspConfig) -> Option<Arc<Cache>> {
-        let data_dir = data_dir();
-        let db_path = db_path();
-
-        // Create data directory if it doesn't exist
-        if let Err(e) = std::fs::create_dir_all(&data_dir) {
-            error!("Failed to create data directory {:?}: {}", data_dir, e);
-            return None;
-        }
+        let db_path = match &config.cache_path {
+            Some(path) => PathBuf::from(path),
+            None => {
+                // Create data directory if it doesn't exist
+                let data_dir = data_dir();
+                if let Err(e) = std::fs::create_dir_all(&data_dir) {
+                    error!("Failed to create data directory {:?}: {}", data_dir, e);
+                    return None;
+                }
+                db_path()
+            }
+        };
 
         match Cache::new(
             &db_path,
             config.cache.refresh_interval,
-            config.ignore_prerelease,
+            config.drops_prereleases(),
         ) {
             Ok(cache) => {
                 info!("Cache initialized at {:?}", db_path);
-                Some(Arc::new(cache))
+                Some(Arc::new(
+                    cache
+                        .with_max_stale_age(config.cache.max_stale_age)
+                        .with_not_found_ttl(config.cache.not_found_ttl),
+                ))
             }
             Err(e) => {
                 error!("Failed to initialize cache: {}", e);
@@ -73,6 +164,23 @@ impl Backend<Cache> {
     }
 }
 
+impl<S: VersionStorer> Clone for Backend<S> {
+    fn clone(&self) -> Self {
+        Self {
+            client: self.client.clone(),
+            storer: self.storer.clone(),
+            open_storer: self.open_storer,
+            config: self.config.clone(),
+            resolvers: self.resolvers.clone(),
+            documents: self.documents.clone(),
+            renovate: self.renovate.clone(),
+            npmrc: self.npmrc.clone(),
+            user_npmrc: self.user_npmrc.clone(),
+            work_done_progress: self.work_done_progress.clone(),
+        }
+    }
+}
+
 impl<S: VersionStorer> Backend<S> {
     /// Build a Backend with custom storer and resolvers
     pub fn build(
@@ -82,22 +190,53 @@ impl<S: VersionStorer> Backend<S> {
     ) -> Self {
         Self {
             client,
-            storer: Some(storer),
+            storer: Arc::new(OnceLock::from(storer)),
+            open_storer: None,
             config: Arc::new(RwLock::new(LspConfig::default())),
             resolvers: Arc::new(RwLock::new(resolvers)),
             documents: Arc::new(RwLock::new(HashMap::new())),
+            renovate: Arc::new(RwLock::new(None)),
+            npmrc: Arc::new(RwLock::new(Npmrc::default())),
+            // Only a project .npmrc may replace the custom resolvers
+            user_npmrc: None,
+            work_done_progress: Arc::new(AtomicBool::new(false)),
         }
     }
 
-    /// Parse document and cache packages
-    fn cache_document(&self, uri: &Url, content: &str) {
+    /// Parser type of a document, preferring the one detected on open
+    ///
+    /// Falls back to URI-based detection for documents that were never opened.
+    fn document_registry_type(&self, uri: &Url) -> Option<RegistryType> {
+        let docs = self.documents.read().expect("documents lock poisoned");
+        match docs.get(uri) {
+            Some(cache) => cache.registry_type,
+            None => detect_parser_type(uri.as_str()),
+        }
+    }
+
+    /// Text of a document if it is still at `version`
+    fn document_text(&self, uri: &Url, version: i32) -> Option<String> {
+        let docs = self.documents.read().expect("documents lock poisoned");
+        docs.get(uri)
+            .filter(|cache| cache.version == version)
+            .map(|cache| cache.text.clone())
+    }
+
+    /// Parse document and cache its text and packages
+    fn cache_document(
+        &self,
+        uri: &Url,
+        registry_type: Option<RegistryType>,
+        version: i32,
+        content: &str,
+    ) {
         let uri_str = uri.as_str();
         let resolvers = self.resolvers.read().expect("resolvers lock poisoned");
-        let packages = detect_parser_type(uri_str)
+        let packages = registry_type
             .and_then(|registry_type| resolvers.get(&registry_type))
             .map(|resolver| {
                 resolver
-                    .parser()
+                    .parser_for(uri_str)
                     .parse(content)
                     .inspect_err(|e| warn!("Failed to parse {}: {}", uri_str, e))
                     .unwrap_or_default()
@@ -106,66 +245,99 @@ impl<S: VersionStorer> Backend<S> {
         drop(resolvers);
 
         let mut docs = self.documents.write().expect("documents lock poisoned");
-        docs.insert(uri.clone(), DocumentCache { packages });
+        docs.insert(
+            uri.clone(),
+            DocumentCache {
+                registry_type,
+                version,
+                text: content.to_string(),
+                packages,
+            },
+        );
     }
 
     /// Check if a registry is enabled in the configuration
     fn is_registry_enabled(&self, registry_type: RegistryType) -> bool {
-        let config = self.config.read().expect("config lock poisoned");
-        match registry_type {
-            RegistryType::Npm => config.registries.npm.enabled,
-            RegistryType::CratesIo => config.registries.crates.enabled,
-            RegistryType::GoProxy => config.registries.go_proxy.enabled,
-            RegistryType::GitHubActions => config.registries.github.enabled,
-            RegistryType::PnpmCatalog => config.registries.pnpm_catalog.enabled,
-            RegistryType::Jsr => config.registries.jsr.enabled,
-            RegistryType::PyPI => config.registries.pypi.enabled,
-            RegistryType::Docker => config.registries.docker.enabled,
+        self.config
+            .read()
+            .expect("config lock poisoned")
+            .registries
+            .is_enabled(registry_type)
+    }
+
+    /// Storer, cached packages, matchers and comparison settings of an open document
+    ///
+    /// Returns None when the document is not a supported manifest, its
+    /// registry is disabled, the cache is not available or the document is
+    /// not open.
+    fn document_context(&self, uri: &Url) -> Option<DocumentContext<S>> {
+        let uri_str = uri.as_str();
+
+        let Some(registry_type) = self.document_registry_type(uri) else {
+            debug!("No parser type detected for URI: {}", uri_str);
+            return None;
+        };
+
+        if !self.is_registry_enabled(registry_type) {
+            debug!("Registry {:?} is disabled, skipping request", registry_type);
+            return None;
         }
+
+        let Some(storer) = self.storer.get() else {
+            debug!("Storer not available");
+            return None;
+        };
+
+        let packages = {
+            let docs = self.documents.read().expect("documents lock poisoned");
+            let Some(cache) = docs.get(uri) else {
+                debug!("Document not found in cache: {}", uri_str);
+                return None;
+            };
+            cache.packages.clone()
+        };
+
+        let matchers = {
+            let resolvers = self.resolvers.read().expect("resolvers lock poisoned");
+            let Some(matchers) = Matchers::from_resolvers(&resolvers, registry_type) else {
+                debug!("No resolver for registry type {:?}", registry_type);
+                return None;
+            };
+            matchers
+        };
+
+        Some(DocumentContext {
+            storer: storer.clone(),
+            packages,
+            matchers,
+            options: self
+                .config
+                .read()
+                .expect("config lock poisoned")
+                .compare_options(),
+        })
     }
 
     /// Spawn background task to fetch configuration from client
+    ///
+    /// Open documents are checked again under the fetched configuration.
     fn spawn_fetch_configuration(&self) {
-        let client = self.client.clone();
-        let config = self.config.clone();
-        let resolvers = self.resolvers.clone();
+        let backend = self.clone();
 
         tokio::spawn(async move {
             let items = vec![ConfigurationItem {
                 scope_uri: None,
-                section: Some("version-lsp".to_string()),
+                section: Some(CONFIG_SECTION.to_string()),
             }];
 
-            match client.configuration(items).await {
+            match backend.client.configuration(items).await {
                 Ok(configs) => {
-                    if let Some(config_value) = configs.into_iter().next() {
-                        // Handle null/empty configuration by using defaults
-                        let new_config = if config_value.is_null() {
-                            LspConfig::default()
-                        } else {
-                            match serde_json::from_value::<LspConfig>(config_value) {
-                                Ok(c) => c,
-                                Err(e) => {
-                                    let msg = format!("Failed to parse configuration: {}", e);
-                                    warn!("{}", msg);
-                                    client.show_message(MessageType::ERROR, msg).await;
-                                    return;
-                                }
-                            }
-                        };
-                        info!("Configuration updated: {:?}", new_config);
-
-                        // Rebuild resolvers from the new config so URL
-                        // overrides take effect on subsequent fetches.
-                        let new_resolvers = create_resolvers(&new_config);
-
-                        let mut cfg = config.write().expect("config lock poisoned");
-                        *cfg = new_config;
-                        drop(cfg);
-
-                        let mut res = resolvers.write().expect("resolvers lock poisoned");
-                        *res = new_resolvers;
-                        debug!("Resolvers rebuilt with new configuration");
+                    // A null section means the client has no settings for us;
+                    // keep the configuration from initializationOptions.
+                    if let Some(config_value) = configs.into_iter().next().filter(|v| !v.is_null())
+                    {
+                        backend.update_config(config_value).await;
+                        backend.republish_open_documents().await;
                     }
                 }
                 Err(e) => {
@@ -176,28 +348,149 @@ impl<S: VersionStorer> Backend<S> {
         });
     }
 
+    /// Parse a configuration object sent by the client and apply it
+    ///
+    /// Invalid configuration is reported to the user and leaves the current
+    /// configuration in place.
+    async fn update_config(&self, value: serde_json::Value) {
+        let new_config = match serde_json::from_value::<LspConfig>(value) {
+            Ok(c) => c,
+            Err(e) => {
+                let msg = format!("Failed to parse configuration: {}", e);
+                warn!("{}", msg);
+                self.client.show_message(MessageType::ERROR, msg).await;
+                return;
+            }
+        };
+        let new_config = match new_config.resolve_env_vars() {
+            Ok(c) => c,
+            Err(e) => {
+                let msg = format!("Failed to resolve configuration: {}", e);
+                warn!("{}", msg);
+                self.client.show_message(MessageType::ERROR, msg).await;
+                return;
+            }
+        };
+        self.apply_config(new_config);
+    }
+
+    /// Check every open document again, e.g. after the configuration changed
+    ///
+    /// Documents of a registry that is now disabled have their diagnostics
+    /// cleared.
+    async fn republish_open_documents(&self) {
+        let documents: Vec<(Url, Option<RegistryType>, String)> = {
+            let docs = self.documents.read().expect("documents lock poisoned");
+            docs.iter()
+                .map(|(uri, cache)| (uri.clone(), cache.registry_type, cache.text.clone()))
+                .collect()
+        };
+
+        for (uri, registry_type, content) in documents {
+            match registry_type {
+                None => {}
+                Some(registry_type) if !self.is_registry_enabled(registry_type) => {
+                    self.client.publish_diagnostics(uri, Vec::new(), None).await;
+                }
+                Some(_) => self.check_and_publish_diagnostics(uri, content).await,
+            }
+        }
+    }
+
+    /// Open the storer with `config` unless it is already open
+    ///
+    /// Only the first configuration decides where the cache lives; a later
+    /// `cachePath` has no effect.
+    fn ensure_storer(&self, config: &LspConfig) {
+        if let Some(open_storer) = self.open_storer
+            && self.storer.get().is_none()
+            && let Some(storer) = open_storer(config)
+        {
+            let _ = self.storer.set(storer);
+        }
+    }
+
+    /// Store a new configuration, merged with `.npmrc` settings
+    ///
+    /// Resolvers are rebuilt only when registry or proxy settings changed, so
+    /// URL overrides take effect on subsequent fetches.
+    fn apply_config(&self, mut new_config: LspConfig) {
+        self.npmrc
+            .read()
+            .expect("npmrc lock poisoned")
+            .apply_to(&mut new_config.registries.npm);
+        info!("Configuration updated: {:?}", new_config);
+
+        self.ensure_storer(&new_config);
+
+        let mut cfg = self.config.write().expect("config lock poisoned");
+        // Only a changed interval is pushed, so a storer built with its own
+        // interval keeps it until the client configures otherwise
+        if let Some(storer) = self.storer.get()
+            && cfg.cache.refresh_interval != new_config.cache.refresh_interval
+        {
+            storer.set_refresh_interval(new_config.cache.refresh_interval);
+        }
+
+        let registries_changed = cfg.registries != new_config.registries
+            || cfg.http_settings() != new_config.http_settings()
+            || cfg.requests_per_second != new_config.requests_per_second;
+        *cfg = new_config;
+
+        if registries_changed {
+            let new_resolvers = create_resolvers(&cfg);
+            drop(cfg);
+
+            let mut res = self.resolvers.write().expect("resolvers lock poisoned");
+            *res = new_resolvers;
+            debug!("Resolvers rebuilt with new configuration");
+        }
+    }
+
     pub fn server_capabilities() -> ServerCapabilities {
         ServerCapabilities {
             text_document_sync: Some(TextDocumentSyncCapability::Options(
                 TextDocumentSyncOptions {
                     open_close: Some(true),
-                    change: Some(TextDocumentSyncKind::FULL),
+                    change: Some(TextDocumentSyncKind::INCREMENTAL),
+                    // The saved text is already known from didOpen/didChange
+                    save: Some(TextDocumentSyncSaveOptions::Supported(true)),
                     ..Default::default()
                 },
             )),
             code_action_provider: Some(CodeActionProviderCapability::Simple(true)),
+            hover_provider: Some(HoverProviderCapability::Simple(true)),
+            completion_provider: Some(CompletionOptions {
+                trigger_characters: Some(
+                    TRIGGER_CHARACTERS.iter().map(|c| c.to_string()).collect(),
+                ),
+                ..Default::default()
+            }),
+            inlay_hint_provider: Some(OneOf::Left(true)),
+            code_lens_provider: Some(CodeLensOptions {
+                resolve_provider: Some(true),
+            }),
+            execute_command_provider: Some(ExecuteCommandOptions {
+                commands: vec![
+                    UPDATE_ALL_COMMAND.to_string(),
+                    UPDATE_PACKAGE_COMMAND.to_string(),
+                    REFRESH_COMMAND.to_string(),
+                ],
+                ..Default::default()
+            }),
             ..Default::default()
         }
     }
 
     fn spawn_background_refresh(&self) {
-        let Some(storer) = self.storer.clone() else {
+        let Some(storer) = self.storer.get().cloned() else {
             warn!("Storer not available, skipping background refresh");
             return;
         };
 
         // Snapshot registries from the current resolvers so the spawned task
-        // doesn't need to hold the lock or share `self`.
+        // doesn't need to hold the resolvers lock.
+        let backend = self.clone();
         let registries: HashMap<RegistryType, Arc<dyn Registry>> = self
             .resolvers
             .read()
@@ -222,7 +515,7 @@ impl<S: VersionStorer> Backend<S> {
 
             info!("{} packages need refresh", packages.len());
 
-            // Group packages by registry type
+            // Group packages by registry type, keeping oldest-first order within each
             let mut packages_by_registry: HashMap<RegistryType, Vec<_>> = HashMap::new();
             for package in packages {
                 packages_by_registry
@@ -237,14 +530,97 @@ impl<S: VersionStorer> Backend<S> {
                     refresh_packages(&*storer, &**registry, packages).await;
                 }
             }
+
+            // Documents opened meanwhile were checked against the stale entries,
+            // and their own revalidation skips packages refreshed here
+            backend.republish_open_documents().await;
         });
     }
 
+    /// Edit updating the outdated packages of a document to their latest versions
+    ///
+    /// With a `position`, only the package whose version is there is updated.
+    fn update_edit(&self, uri: &Url, position: Option<Position>) -> Option<WorkspaceEdit> {
+        let DocumentContext {
+            storer,
+            packages,
+            matchers,
+            options,
+        } = self.document_context(uri)?;
+
+        let renovate = self
+            .renovate
+            .read()
+            .expect("renovate lock poisoned")
+            .clone()
+            .unwrap_or_default();
+        let packages: Vec<PackageInfo> = packages
+            .into_iter()
+            .filter(|package| !renovate.is_package_ignored(&package.name))
+            .collect();
+        let packages: Vec<PackageInfo> = match position {
+            Some(position) => PackageIndex::new(&packages)
+                .find_at_position(position)
+                .into_iter()
+                .cloned()
+                .collect(),
+            None => packages,
+        };
+
+        let edit = generate_update_all_edit(&*storer, &packages, uri, &matchers, &options);
+        if edit.is_none() {
+            debug!("No outdated packages in {}", uri.as_str());
+        }
+        edit
+    }
+
+    /// Invalidate the cached packages of a document (or only `package_name`)
+    /// and check it again, which fetches them from the registries
+    async fn refresh_document(&self, uri: Url, package_name: Option<&str>) {
+        let Some(storer) = self.storer.get() else {
+            debug!("Storer not available");
+            return;
+        };
+
+        let Some((content, packages)) = ({
+            let docs = self.documents.read().expect("documents lock poisoned");
+            docs.get(&uri)
+                .map(|cache| (cache.text.clone(), cache.packages.clone()))
+        }) else {
+            debug!("Document not found in cache: {}", uri.as_str());
+            return;
+        };
+
+        let mut invalidated = HashSet::new();
+        for package in &packages {
+            if package_name.is_some_and(|name| name != package.name)
+                || !invalidated.insert((package.registry_type, package.name.as_str()))
+            {
+                continue;
+            }
+            if let Err(e) = storer.invalidate(package.registry_type, &package.name) {
+                warn!(
+                    "Failed to invalidate {}/{}: {}",
+                    package.registry_type.as_str(),
+                    package.name,
+                    e
+                );
+            }
+        }
+        info!(
+            "Invalidated {} packages of {}, fetching them again",
+            invalidated.len(),
+            uri.as_str()
+        );
+
+        self.check_and_publish_diagnostics(uri, content).await;
+    }
+
     async fn check_and_publish_diagnostics(&self, uri: Url, content: String) {
         let uri_str = uri.as_str();
         debug!("Checking diagnostics for URI: {}", uri_str);
 
-        let Some(registry_type) = detect_parser_type(uri_str) else {
+        let Some(registry_type) = self.document_registry_type(&uri) else {
             debug!("No parser type detected for URI: {}", uri_str);
             return;
         };
@@ -259,22 +635,45 @@ impl<S: VersionStorer> Backend<S> {
             return;
         }
 
-        // Snapshot parser/matcher/registry from the resolver under a brief
+        // Snapshot parser/matchers/registries from the resolvers under a brief
         // read lock so we don't hold the lock across awaits or `tokio::spawn`.
-        let (parser, matcher, registry) = {
+        // All matchers and registries are kept since a document may mix
+        // registries (e.g. `npm:` imports in deno.json).
+        let (parser, matchers, registries) = {
             let resolvers = self.resolvers.read().expect("resolvers lock poisoned");
-            let Some(resolver) = resolvers.get(&registry_type) else {
+            let (Some(resolver), Some(matchers)) = (
+                resolvers.get(&registry_type),
+                Matchers::from_resolvers(&resolvers, registry_type),
+            ) else {
                 debug!("No resolver found for registry type: {:?}", registry_type);
                 return;
             };
-            (
-                resolver.parser().clone(),
-                resolver.matcher().clone(),
-                resolver.registry().clone(),
-            )
+            let registries: HashMap<RegistryType, Arc<dyn Registry>> = resolvers
+                .iter()
+                .map(|(k, v)| (*k, v.registry().clone()))
+                .collect();
+            (resolver.parser_for(uri_str).clone(), matchers, registries)
         };
 
-        let Some(storer) = &self.storer else {
+        let renovate = self
+            .renovate
+            .read()
+            .expect("renovate lock poisoned")
+            .clone()
+            .unwrap_or_default();
+        if uri
+            .to_file_path()
+            .is_ok_and(|path| renovate.is_path_ignored(&path))
+        {
+            debug!("{} is excluded by Renovate ignorePaths", uri_str);
+            self.client.publish_diagnostics(uri, Vec::new(), None).await;
+            return;
+        }
+
+        let config = Arc::new(self.config.read().expect("config lock poisoned").clone());
+        let diagnostics_enabled = config.diagnostics_enabled;
+
+        let Some(storer) = self.storer.get() else {
             self.client
                 .show_message(
                     MessageType::WARNING,
@@ -285,13 +684,39 @@ impl<S: VersionStorer> Backend<S> {
         };
 
         // Parse document to get packages (needed for on-demand fetch)
-        let packages = parser
+        let mut packages: Vec<PackageInfo> = parser
             .parse(&content)
             .inspect_err(|e| warn!("Failed to parse {}: {}", uri_str, e))
-            .unwrap_or_default();
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|package| !renovate.is_package_ignored(&package.name))
+            .collect();
+        if registry_type == RegistryType::GoProxy && config.go.check_toolchain {
+            packages.extend(go_release_package(&content));
+        }
         debug!("Parsed {} packages: {:?}", packages.len(), packages);
 
-        let diagnostics = generate_diagnostics(&*parser, &*matcher, &**storer, &content);
+        // The `ignore` and `go.checkIndirect` settings only silence diagnostics
+        let reporting = config.clone();
+        let is_reported = move |package: &PackageInfo| {
+            !renovate.is_package_ignored(&package.name) && reporting.is_package_reported(package)
+        };
+
+        // Packages are still fetched below so hover and inlay hints have data
+        let diagnostics = if diagnostics_enabled {
+            assemble_diagnostics(
+                &*parser,
+                &matchers,
+                &**storer,
+                registry_type,
+                &content,
+                &config,
+                &is_reported,
+            )
+        } else {
+            debug!("Diagnostics are disabled, clearing diagnostics");
+            Vec::new()
+        };
 
         self.client
             .log_message(
@@ -316,27 +741,88 @@ impl<S: VersionStorer> Backend<S> {
             );
             let storer = storer.clone();
             let client = self.client.clone();
+            let work_done_progress = self.work_done_progress.load(Ordering::Relaxed);
+
+            // Group packages by registry type, skipping disabled registries
+            let mut packages_by_registry: HashMap<RegistryType, Vec<PackageInfo>> = HashMap::new();
+            for package in packages {
+                if self.is_registry_enabled(package.registry_type) {
+                    packages_by_registry
+                        .entry(package.registry_type)
+                        .or_default()
+                        .push(package);
+                }
+            }
 
             tokio::spawn(async move {
                 debug!("Background task started for fetching packages");
-                let fetched = fetch_missing_packages(&*storer, &*registry, &packages).await;
-                debug!("fetch_missing_packages returned {} packages", fetched.len());
+                let pending = if work_done_progress {
+                    pending_fetches(&*storer, &packages_by_registry)
+                } else {
+                    0
+                };
+                let progress = match pending {
+                    0 => None,
+                    total => FetchProgress::begin(client.clone(), total)
+                        .await
+                        .map(Arc::new),
+                };
+
+                let mut fetched = Vec::new();
+                let mut revalidated = Vec::new();
+                for (package_registry_type, packages) in &packages_by_registry {
+                    let Some(registry) = registries.get(package_registry_type) else {
+                        continue;
+                    };
+                    let registry: Arc<dyn Registry> = match &progress {
+                        Some(progress) => {
+                            Arc::new(ProgressRegistry::new(registry.clone(), progress.clone()))
+                        }
+                        None => registry.clone(),
+                    };
+                    // Missing packages are fetched while stale ones (already
+                    // served above) are revalidated; both trigger a republish.
+                    let (missing, stale) = tokio::join!(
+                        fetch_missing_packages(&*storer, &*registry, packages),
+                        revalidate_stale_packages(&*storer, &*registry, packages)
+                    );
+                    fetched.extend(missing);
+                    revalidated.extend(stale);
+                }
+                debug!(
+                    "Fetched {} missing and revalidated {} stale packages",
+                    fetched.len(),
+                    revalidated.len()
+                );
 
-                if !fetched.is_empty() {
+                if diagnostics_enabled && (!fetched.is_empty() || !revalidated.is_empty()) {
                     client
                         .log_message(
                             MessageType::LOG,
                             format!(
-                                "Fetched {} missing packages, republishing diagnostics",
-                                fetched.len()
+                                "Fetched {} missing and {} stale packages, republishing diagnostics",
+                                fetched.len(),
+                                revalidated.len()
                             ),
                         )
                         .await;
 
-                    let diagnostics = generate_diagnostics(&*parser, &*matcher, &*storer, &content);
+                    let diagnostics = assemble_diagnostics(
+                        &*parser,
+                        &matchers,
+                        &*storer,
+                        registry_type,
+                        &content,
+                        &config,
+                        &is_reported,
+                    );
 
                     client.publish_diagnostics(uri, diagnostics, None).await;
                 }
+
+                if let Some(progress) = progress {
+                    progress.end().await;
+                }
             });
         }
     }
@@ -344,10 +830,46 @@ impl<S: VersionStorer> Backend<S> {
 
 #[tower_lsp::async_trait]
 impl<S: VersionStorer> LanguageServer for Backend<S> {
-    async fn initialize(&self, _params: InitializeParams) -> Result<InitializeResult> {
+    async fn initialize(&self, params: InitializeParams) -> Result<InitializeResult> {
         self.client
             .log_message(MessageType::INFO, "LSP server initializing")
             .await;
+
+        // Align diagnostics with the workspace's Renovate policy, if present
+        #[allow(deprecated)] // root_uri is the fallback for clients without workspace folders
+        let workspace_root = params
+            .workspace_folders
+            .and_then(|folders| folders.into_iter().next().map(|f| f.uri))
+            .or(params.root_uri)
+            .and_then(|uri| uri.to_file_path().ok());
+        if let Some(root) = &workspace_root {
+            let policy = RenovatePolicy::load(root);
+            *self.renovate.write().expect("renovate lock poisoned") = policy;
+        }
+
+        let npmrc = Npmrc::load(workspace_root.as_deref(), self.user_npmrc.as_deref());
+        *self.npmrc.write().expect("npmrc lock poisoned") = npmrc;
+
+        let work_done_progress = params
+            .capabilities
+            .window
+            .and_then(|window| window.work_done_progress)
+            .unwrap_or(false);
+        self.work_done_progress
+            .store(work_done_progress, Ordering::Relaxed);
+
+        // Clients without workspace/configuration pass settings here instead
+        match params.initialization_options {
+            Some(options) => self.update_config(options).await,
+            None => {
+                let config = self.config.read().expect("config lock poisoned").clone();
+                self.apply_config(config);
+            }
+        }
+        // Rejected options leave the default configuration in place
+        let config = self.config.read().expect("config lock poisoned").clone();
+        self.ensure_storer(&config);
+
         Ok(InitializeResult {
             capabilities: Self::server_capabilities(),
             server_info: Some(ServerInfo {
@@ -368,6 +890,34 @@ impl<S: VersionStorer> LanguageServer for Backend<S> {
         self.spawn_background_refresh();
     }
 
+    async fn did_change_configuration(&self, params: DidChangeConfigurationParams) {
+        self.client
+            .log_message(MessageType::LOG, "Configuration changed")
+            .await;
+
+        // Clients push their whole settings object, our section alone, or
+        // nothing, asking the server to pull it via workspace/configuration
+        let settings = match params.settings {
+            serde_json::Value::Object(mut settings) if settings.contains_key(CONFIG_SECTION) => {
+                // A null section means the client has no settings for us
+                let Some(section) = settings.remove(CONFIG_SECTION).filter(|v| !v.is_null()) else {
+                    return;
+                };
+                section
+            }
+            serde_json::Value::Object(settings) if !settings.is_empty() => {
+                serde_json::Value::Object(settings)
+            }
+            _ => {
+                self.spawn_fetch_configuration();
+                return;
+            }
+        };
+
+        self.update_config(settings).await;
+        self.republish_open_documents().await;
+    }
+
     async fn shutdown(&self) -> Result<()> {
         self.client
             .log_message(MessageType::INFO, "LSP server shutting down")
@@ -383,31 +933,114 @@ impl<S: VersionStorer> LanguageServer for Backend<S> {
             )
             .await;
 
+        // The languageId lets editors surface manifests with unusual names
+        let registry_type = detect_document_type(
+            params.text_document.uri.as_str(),
+            &params.text_document.language_id,
+            &params.text_document.text,
+        );
+
         // Parse and cache packages
-        self.cache_document(&params.text_document.uri, &params.text_document.text);
+        self.cache_document(
+            &params.text_document.uri,
+            registry_type,
+            params.text_document.version,
+            &params.text_document.text,
+        );
 
         self.check_and_publish_diagnostics(params.text_document.uri, params.text_document.text)
             .await;
     }
 
     async fn did_change(&self, params: DidChangeTextDocumentParams) {
-        // With FULL sync mode, the last content change contains the full document text
-        let Some(content) = params.content_changes.into_iter().last().map(|c| c.text) else {
-            return;
-        };
+        let uri = params.text_document.uri;
+        let version = params.text_document.version;
+
+        let registry_type = self.document_registry_type(&uri);
+        let parser = registry_type.and_then(|registry_type| {
+            let resolvers = self.resolvers.read().expect("resolvers lock poisoned");
+            resolvers
+                .get(&registry_type)
+                .map(|resolver| resolver.parser_for(uri.as_str()).clone())
+        });
+
+        // Rebuild the full text from the incremental changes and store it
+        // under one lock: notifications run concurrently, so reading and
+        // storing separately could apply two changes to the same base text.
+        // Re-parse right away so hover and code actions see the new positions.
+        {
+            let mut docs = self.documents.write().expect("documents lock poisoned");
+            let cache = docs.entry(uri.clone()).or_insert_with(|| DocumentCache {
+                registry_type,
+                version,
+                text: String::new(),
+                packages: Vec::new(),
+            });
+            for change in params.content_changes {
+                apply_content_change(&mut cache.text, change);
+            }
+            cache.version = version;
+            cache.packages = parser
+                .map(|parser| {
+                    parser
+                        .parse(&cache.text)
+                        .inspect_err(|e| warn!("Failed to parse {}: {}", uri.as_str(), e))
+                        .unwrap_or_default()
+                })
+                .unwrap_or_default();
+        }
 
         self.client
-            .log_message(
-                MessageType::LOG,
-                format!("Document changed: {}", params.text_document.uri),
-            )
+            .log_message(MessageType::LOG, format!("Document changed: {}", uri))
             .await;
 
-        // Re-parse and cache packages
-        self.cache_document(&params.text_document.uri, &content);
+        // Republish once the document has been quiet for the debounce delay
+        let backend = self.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(DIAGNOSTICS_DEBOUNCE).await;
+            // A newer change supersedes this one
+            let Some(content) = backend.document_text(&uri, version) else {
+                return;
+            };
+            backend.check_and_publish_diagnostics(uri, content).await;
+        });
+    }
 
-        self.check_and_publish_diagnostics(params.text_document.uri, content)
+    async fn did_save(&self, params: DidSaveTextDocumentParams) {
+        let uri = params.text_document.uri;
+
+        self.client
+            .log_message(MessageType::LOG, format!("Document saved: {}", uri))
             .await;
+
+        // The in-memory text reflects every change sent before the save
+        let Some(content) = ({
+            let docs = self.documents.read().expect("documents lock poisoned");
+            docs.get(&uri).map(|cache| cache.text.clone())
+        }) else {
+            debug!("Document not found in cache: {}", uri.as_str());
+            return;
+        };
+
+        let update_on_save = self
+            .config
+            .read()
+            .expect("config lock poisoned")
+            .update_on_save;
+        if update_on_save && let Some(edit) = self.update_edit(&uri, None) {
+            // The client applies the edit and sends a didChange, which
+            // republishes the diagnostics of the updated text
+            let client = self.client.clone();
+            tokio::spawn(async move {
+                if let Err(e) = client.apply_edit(edit).await {
+                    warn!("Failed to apply update edit: {}", e);
+                }
+            });
+            return;
+        }
+
+        // The cache may have been refreshed since the document was opened
+        self.check_and_publish_diagnostics(uri, content).await;
     }
 
     async fn did_close(&self, params: DidCloseTextDocumentParams) {
@@ -425,37 +1058,211 @@ impl<S: VersionStorer> LanguageServer for Backend<S> {
         }
     }
 
-    async fn code_action(&self, params: CodeActionParams) -> Result<Option<CodeActionResponse>> {
-        let uri = &params.text_document.uri;
-        let uri_str = uri.as_str();
-        debug!("Code action requested for URI: {}", uri_str);
+    async fn hover(&self, params: HoverParams) -> Result<Option<Hover>> {
+        let uri = &params.text_document_position_params.text_document.uri;
+        let position = params.text_document_position_params.position;
+        debug!("Hover requested for URI: {}", uri.as_str());
 
-        let Some(registry_type) = detect_parser_type(uri_str) else {
-            debug!("No parser type detected for URI: {}", uri_str);
+        let Some(DocumentContext {
+            storer,
+            packages,
+            matchers,
+            options,
+        }) = self.document_context(uri)
+        else {
             return Ok(None);
         };
 
-        if !self.is_registry_enabled(registry_type) {
-            debug!(
-                "Registry {:?} is disabled, skipping code actions",
-                registry_type
-            );
+        let index = PackageIndex::new(&packages);
+        let Some(package) = index.find_at_position(position) else {
+            debug!("No package found at position {:?}", position);
+            return Ok(None);
+        };
+
+        Ok(Some(generate_hover(
+            &*storer,
+            matchers.get(package),
+            &options,
+            package,
+        )))
+    }
+
+    async fn completion(&self, params: CompletionParams) -> Result<Option<CompletionResponse>> {
+        let uri = &params.text_document_position.text_document.uri;
+        let position = params.text_document_position.position;
+        debug!("Completion requested for URI: {}", uri.as_str());
+
+        // Packages are re-parsed on every change, so they include what was just typed
+        let Some(DocumentContext {
+            storer, packages, ..
+        }) = self.document_context(uri)
+        else {
+            return Ok(None);
+        };
+
+        let index = PackageIndex::new(&packages);
+        let Some(package) = index.find_touching_position(position) else {
+            debug!("No package version at position {:?}", position);
+            return Ok(None);
+        };
+
+        let items = generate_completions(&*storer, package);
+        if items.is_empty() {
             return Ok(None);
         }
+        Ok(Some(CompletionResponse::Array(items)))
+    }
 
-        let Some(storer) = &self.storer else {
-            debug!("Storer not available");
+    async fn inlay_hint(&self, params: InlayHintParams) -> Result<Option<Vec<InlayHint>>> {
+        let uri = &params.text_document.uri;
+        debug!("Inlay hints requested for URI: {}", uri.as_str());
+
+        let Some(DocumentContext {
+            storer,
+            packages,
+            matchers,
+            options,
+        }) = self.document_context(uri)
+        else {
             return Ok(None);
         };
 
-        // Get cached packages
-        let packages = {
-            let docs = self.documents.read().expect("documents lock poisoned");
-            let Some(cache) = docs.get(uri) else {
-                debug!("Document not found in cache: {}", uri_str);
+        Ok(Some(generate_inlay_hints(
+            &*storer,
+            &matchers,
+            &options,
+            &packages,
+            params.range,
+        )))
+    }
+
+    async fn code_lens(&self, params: CodeLensParams) -> Result<Option<Vec<CodeLens>>> {
+        let uri = &params.text_document.uri;
+        debug!("Code lens requested for URI: {}", uri.as_str());
+
+        let Some(DocumentContext {
+            storer,
+            packages,
+            matchers,
+            options,
+        }) = self.document_context(uri)
+        else {
+            return Ok(None);
+        };
+
+        Ok(Some(generate_code_lenses(
+            &*storer, &matchers, &options, &packages, uri,
+        )))
+    }
+
+    async fn code_lens_resolve(&self, lens: CodeLens) -> Result<CodeLens> {
+        // The lens data carries the document URI; the range locates the package
+        let Some(uri) = lens
+            .data
+            .as_ref()
+            .and_then(|data| data.as_str())
+            .and_then(|s| Url::parse(s).ok())
+        else {
+            debug!("Code lens without document URI: {:?}", lens);
+            return Ok(lens);
+        };
+
+        let Some(DocumentContext {
+            storer,
+            packages,
+            matchers,
+            options,
+        }) = self.document_context(&uri)
+        else {
+            return Ok(lens);
+        };
+
+        let Some(package) = PackageIndex::new(&packages).find_at_position(lens.range.start) else {
+            debug!("No package found at position {:?}", lens.range.start);
+            return Ok(lens);
+        };
+
+        Ok(resolve_code_lens(
+            &*storer,
+            matchers.get(package),
+            &options,
+            package,
+            &uri,
+            lens,
+        ))
+    }
+
+    async fn execute_command(
+        &self,
+        params: ExecuteCommandParams,
+    ) -> Result<Option<serde_json::Value>> {
+        let update_all = match params.command.as_str() {
+            UPDATE_ALL_COMMAND => true,
+            UPDATE_PACKAGE_COMMAND => false,
+            REFRESH_COMMAND => {
+                let uri = uri_argument(&params.arguments)?;
+                let package_name = params.arguments.get(1).and_then(|arg| arg.as_str());
+                debug!("{} requested for URI: {}", params.command, uri.as_str());
+                self.refresh_document(uri, package_name).await;
                 return Ok(None);
+            }
+            _ => {
+                debug!("Unknown command: {}", params.command);
+                return Err(tower_lsp::jsonrpc::Error::method_not_found());
+            }
+        };
+
+        let uri = uri_argument(&params.arguments)?;
+        let uri_str = uri.as_str();
+        debug!("{} requested for URI: {}", params.command, uri_str);
+
+        // updatePackage also takes the position of the version string
+        let position = if update_all {
+            None
+        } else {
+            let coordinate = |index: usize| {
+                params
+                    .arguments
+                    .get(index)
+                    .and_then(|arg| arg.as_u64())
+                    .and_then(|n| u32::try_from(n).ok())
             };
-            cache.packages.clone()
+            let (Some(line), Some(character)) = (coordinate(1), coordinate(2)) else {
+                return Err(tower_lsp::jsonrpc::Error::invalid_params(
+                    "Expected the line and column of the version as the second and third arguments",
+                ));
+            };
+            Some(Position { line, character })
+        };
+
+        let Some(edit) = self.update_edit(&uri, position) else {
+            return Ok(None);
+        };
+
+        // Apply in the background so the command does not wait on the client.
+        // The edit is not returned as well, or it would be applied twice.
+        let client = self.client.clone();
+        tokio::spawn(async move {
+            if let Err(e) = client.apply_edit(edit).await {
+                warn!("Failed to apply update edit: {}", e);
+            }
+        });
+
+        Ok(None)
+    }
+
+    async fn code_action(&self, params: CodeActionParams) -> Result<Option<CodeActionResponse>> {
+        let uri = &params.text_document.uri;
+        debug!("Code action requested for URI: {}", uri.as_str());
+
+        let Some(DocumentContext {
+            storer,
+            packages,
+            matchers,
+            options,
+        }) = self.document_context(uri)
+        else {
+            return Ok(None);
         };
 
         if packages.is_empty() {
@@ -475,41 +1282,71 @@ impl<S: VersionStorer> LanguageServer for Backend<S> {
             package.name, package.version
         );
 
-        let (matcher, sha_fetcher) = {
-            let resolvers = self.resolvers.read().expect("resolvers lock poisoned");
-            let Some(resolver) = resolvers.get(&registry_type) else {
-                debug!("No resolver for registry type {:?}", registry_type);
-                return Ok(None);
-            };
-            (resolver.matcher().clone(), resolver.sha_fetcher().cloned())
-        };
+        if package.is_digest_pinned() {
+            debug!("Skipping image pinned by digest: {}", package.name);
+            return Ok(None);
+        }
+
+        // flake.lock is written by `nix flake update`, not edited by hand
+        if package.registry_type == RegistryType::NixFlake {
+            debug!("Skipping flake input: {}", package.name);
+            return Ok(None);
+        }
+
+        let matcher = matchers.get(package);
+        let sha_fetcher = self
+            .resolvers
+            .read()
+            .expect("resolvers lock poisoned")
+            .get(&package.registry_type)
+            .and_then(|resolver| resolver.sha_fetcher().cloned());
 
         // For GitHub Actions with commit hash, use async function to fetch SHA
         let mut actions = if package.registry_type == RegistryType::GitHubActions
             && package.commit_hash.is_some()
         {
             let Some(sha_fetcher) = sha_fetcher else {
-                debug!("No SHA fetcher for registry type {:?}", registry_type);
+                debug!(
+                    "No SHA fetcher for registry type {:?}",
+                    package.registry_type
+                );
                 return Ok(None);
             };
-            generate_upgrade_code_actions_with_sha(
-                &**storer,
-                package,
-                uri,
-                &*sha_fetcher,
-                &*matcher,
-            )
-            .await
+            generate_upgrade_code_actions_with_sha(&*storer, package, uri, &*sha_fetcher, matcher)
+                .await
         } else {
-            generate_upgrade_code_actions(&**storer, package, uri, &*matcher)
+            generate_upgrade_code_actions(&*storer, package, uri, matcher)
         };
 
+        // The quick fixes for an outdated diagnostic come first
+        if let Some(action) = generate_pin_to_latest_action(
+            &*storer,
+            package,
+            uri,
+            matcher,
+            &options,
+            &params.context.diagnostics,
+        ) {
+            actions.insert(0, action);
+        }
+        if let Some(action) = generate_update_to_latest_action(
+            &*storer,
+            package,
+            uri,
+            matcher,
+            &options,
+            &params.context.diagnostics,
+        ) {
+            actions.insert(0, action);
+        }
+
         // Append constraint actions based on registry type
         match package.registry_type {
             RegistryType::Npm
             | RegistryType::CratesIo
             | RegistryType::Jsr
-            | RegistryType::PnpmCatalog => {
+            | RegistryType::PnpmCatalog
+            | RegistryType::Composer => {
                 actions.extend(generate_constraint_code_actions(package, uri));
             }
             RegistryType::PyPI => {
@@ -0,0 +1,231 @@
+//! Standalone checking of dependency files without an editor
+//!
+//! Runs the same parse → fetch → diagnose pipeline as the LSP backend, but
+//! waits for missing or stale packages to be fetched before generating
+//! diagnostics, so the result is complete on the first run (e.g. in CI).
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use anyhow::Context;
+use serde::Serialize;
+use tower_lsp::lsp_types::{Diagnostic, DiagnosticSeverity, NumberOrString};
+use tracing::info;
+
+use crate::analysis::fetch_packages;
+use crate::config::{self, LspConfig};
+use crate::log::init;
+use crate::lsp::diagnostics::assemble_diagnostics;
+use crate::lsp::resolver::{Matchers, PackageResolver, create_resolvers};
+use crate::parser::types::{RegistryType, detect_document_type};
+use crate::version::cache::Cache;
+use crate::version::checker::VersionStorer;
+
+/// Diagnostics found in one checked file
+#[derive(Debug, Clone, PartialEq)]
+pub struct FileReport {
+    pub path: PathBuf,
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+/// One diagnostic in `--json` output, with 1-based line and column
+#[derive(Debug, Serialize)]
+struct JsonDiagnostic<'a> {
+    path: String,
+    line: u32,
+    column: u32,
+    severity: &'static str,
+    code: Option<&'a str>,
+    message: &'a str,
+}
+
+/// Check `paths` against the cache, fetching missing and stale packages first
+///
+/// Fails if a file cannot be read or is not a supported manifest.
+pub async fn check_files<S: VersionStorer>(
+    storer: &S,
+    resolvers: &HashMap<RegistryType, PackageResolver>,
+    config: &LspConfig,
+    paths: &[PathBuf],
+) -> anyhow::Result<Vec<FileReport>> {
+    let mut reports = Vec::with_capacity(paths.len());
+
+    for path in paths {
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        let absolute = std::path::absolute(path)?;
+        let uri = absolute.to_string_lossy();
+        // Without an editor there is no languageId; the name and content decide
+        let registry_type = detect_document_type(&uri, "", &content)
+            .with_context(|| format!("Unsupported file: {}", path.display()))?;
+
+        let diagnostics =
+            check_content(storer, resolvers, config, registry_type, &uri, &content).await;
+
+        reports.push(FileReport {
+            path: path.clone(),
+            diagnostics,
+        });
+    }
+
+    Ok(reports)
+}
+
+/// Fetch what the document needs, then generate its diagnostics
+///
+/// The diagnostics are assembled as the language server publishes them.
+async fn check_content<S: VersionStorer>(
+    storer: &S,
+    resolvers: &HashMap<RegistryType, PackageResolver>,
+    config: &LspConfig,
+    registry_type: RegistryType,
+    uri: &str,
+    content: &str,
+) -> Vec<Diagnostic> {
+    if !config.registries.is_enabled(registry_type) {
+        return Vec::new();
+    }
+    let (Some(resolver), Some(matchers)) = (
+        resolvers.get(&registry_type),
+        Matchers::from_resolvers(resolvers, registry_type),
+    ) else {
+        return Vec::new();
+    };
+
+    fetch_packages(storer, resolvers, config, registry_type, uri, content).await;
+    assemble_diagnostics(
+        &**resolver.parser_for(uri),
+        &matchers,
+        storer,
+        registry_type,
+        content,
+        config,
+        |package| {
+            config.registries.is_enabled(package.registry_type)
+                && config.is_package_reported(package)
+        },
+    )
+}
+
+/// Whether any diagnostic is at least as severe as `threshold`
+///
+/// `None` never fails.
+pub fn exceeds_threshold(reports: &[FileReport], threshold: Option<DiagnosticSeverity>) -> bool {
+    let Some(threshold) = threshold else {
+        return false;
+    };
+    reports
+        .iter()
+        .flat_map(|report| &report.diagnostics)
+        .any(|diagnostic| diagnostic.severity.is_some_and(|s| s <= threshold))
+}
+
+/// Render reports as `path:line:column: severity: message` lines
+pub fn render_text(reports: &[FileReport]) -> String {
+    reports
+        .iter()
+        .flat_map(|report| {
+            report.diagnostics.iter().map(|diagnostic| {
+                format!(
+                    "{}:{}:{}: {}: {}\n",
+                    report.path.display(),
+                    diagnostic.range.start.line + 1,
+                    diagnostic.range.start.character + 1,
+                    severity_label(diagnostic.severity),
+                    diagnostic.message
+                )
+            })
+        })
+        .collect()
+}
+
+/// Render reports as a JSON array of diagnostics
+pub fn render_json(reports: &[FileReport]) -> serde_json::Result<String> {
+    let diagnostics: Vec<JsonDiagnostic> = reports
+        .iter()
+        .flat_map(|report| {
+            report.diagnostics.iter().map(|diagnostic| JsonDiagnostic {
+                path: report.path.display().to_string(),
+                line: diagnostic.range.start.line + 1,
+                column: diagnostic.range.start.character + 1,
+                severity: severity_label(diagnostic.severity),
+                code: match &diagnostic.code {
+                    Some(NumberOrString::String(code)) => Some(code.as_str()),
+                    _ => None,
+                },
+                message: &diagnostic.message,
+            })
+        })
+        .collect();
+    serde_json::to_string_pretty(&diagnostics)
+}
+
+fn severity_label(severity: Option<DiagnosticSeverity>) -> &'static str {
+    match severity {
+        Some(DiagnosticSeverity::ERROR) => "error",
+        Some(DiagnosticSeverity::WARNING) => "warning",
+        Some(DiagnosticSeverity::INFORMATION) => "information",
+        _ => "hint",
+    }
+}
+
+/// Load the settings of the `check` subcommand
+///
+/// `path` is a JSON file with the same settings as the language server's
+/// `version-lsp` section; without it the defaults are used.
+pub fn load_config(path: Option<&Path>) -> anyhow::Result<LspConfig> {
+    let Some(path) = path else {
+        return Ok(LspConfig::default());
+    };
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read {}", path.display()))?;
+    let config: LspConfig = serde_json::from_str(&content)
+        .with_context(|| format!("Failed to parse configuration {}", path.display()))?;
+    Ok(config.resolve_env_vars()?)
+}
+
+/// Entry point of the `check` subcommand
+///
+/// Prints the diagnostics of `paths` to stdout and returns whether any
+/// reached `fail_on`.
+pub async fn run_check(
+    paths: &[PathBuf],
+    config: Option<&Path>,
+    json: bool,
+    fail_on: Option<DiagnosticSeverity>,
+) -> anyhow::Result<bool> {
+    init()?;
+
+    let config = load_config(config)?;
+    let cache = open_cache(&config)?;
+    let resolvers = create_resolvers(&config);
+
+    info!("Checking {} files", paths.len());
+    let reports = check_files(&*cache, &resolvers, &config, paths).await?;
+
+    if json {
+        println!("{}", render_json(&reports)?);
+    } else {
+        print!("{}", render_text(&reports));
+    }
+
+    Ok(exceeds_threshold(&reports, fail_on))
+}
+
+/// Open the cache shared with the language server
+fn open_cache(config: &LspConfig) -> anyhow::Result<Arc<Cache>> {
+    let data_dir = config::data_dir();
+    std::fs::create_dir_all(&data_dir)
+        .with_context(|| format!("Failed to create data directory {:?}", data_dir))?;
+    let cache = Cache::new(
+        &config::db_path(),
+        config.cache.refresh_interval,
+        config.drops_prereleases(),
+    )?;
+    Ok(Arc::new(
+        cache
+            .with_max_stale_age(config.cache.max_stale_age)
+            .with_not_found_ttl(config.cache.not_found_ttl),
+    ))
+}
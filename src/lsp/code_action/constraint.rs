@@ -20,7 +20,8 @@ fn parse_pypi_version(version: &str) -> Option<(&str, &str)> {
     }
 }
 
-/// Generate constraint code actions for semver registries (npm, crates, jsr, pnpm catalogs)
+/// Generate constraint code actions for semver registries (npm, crates, jsr, pnpm catalogs,
+/// Composer)
 ///
 /// Changes only the version prefix, not the version itself.
 /// - `^X.Y.Z` → offers exact pin and `~`
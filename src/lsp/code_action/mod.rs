@@ -4,7 +4,10 @@ mod constraint;
 mod upgrade;
 
 pub use constraint::{generate_constraint_code_actions, generate_pypi_constraint_code_actions};
-pub use upgrade::{generate_upgrade_code_actions, generate_upgrade_code_actions_with_sha};
+pub use upgrade::{
+    generate_pin_to_latest_action, generate_update_all_edit, generate_update_to_latest_action,
+    generate_upgrade_code_actions, generate_upgrade_code_actions_with_sha,
+};
 
 use crate::parser::types::PackageInfo;
 use std::collections::HashMap;
@@ -44,11 +47,30 @@ impl<'a> PackageIndex<'a> {
             }
         })
     }
+
+    /// Find the package whose version the cursor is in or right after
+    ///
+    /// Unlike [`Self::find_at_position`], a cursor at the end of the version
+    /// matches too, which is where it sits while typing.
+    pub fn find_touching_position(&self, position: Position) -> Option<&'a PackageInfo> {
+        let packages_on_line = self.by_line.get(&position.line)?;
+
+        packages_on_line.iter().copied().find(|pkg| {
+            let start_col = pkg.column as u32;
+            let end_col = start_col + pkg.version.len() as u32;
+            (start_col..=end_col).contains(&position.character)
+        })
+    }
 }
 
-/// Extract version prefix (^, ~, ~=, ==, !=, >=, <=, >, <, =, v) from a version string
-fn extract_version_prefix(version: &str) -> &str {
-    if version.starts_with("~=") {
+/// Extract version prefix (^, ~, ~>, ~=, ==, !=, >=, <=, >, <, =, v) from a version string
+pub(crate) fn extract_version_prefix(version: &str) -> &str {
+    if version.starts_with("~> ") {
+        // RubyGems pessimistic operator is conventionally followed by a space
+        "~> "
+    } else if version.starts_with("~>") {
+        "~>"
+    } else if version.starts_with("~=") {
         "~="
     } else if version.starts_with(">=") {
         ">="
@@ -76,7 +98,7 @@ fn extract_version_prefix(version: &str) -> &str {
 }
 
 /// Strip version prefix, returning the bare version string
-fn strip_version_prefix(version: &str) -> &str {
+pub(crate) fn strip_version_prefix(version: &str) -> &str {
     let prefix = extract_version_prefix(version);
     &version[prefix.len()..]
 }
@@ -174,4 +196,19 @@ mod tests {
         let result = index.find_at_position(position);
         assert_eq!(result.map(|p| p.name.as_str()), expected_name);
     }
+
+    #[rstest]
+    #[case(15, Some("lodash"))] // start of version
+    #[case(22, Some("lodash"))] // right after version, while typing
+    #[case(14, None)] // before opening quote
+    #[case(23, None)] // after closing quote
+    fn test_package_index_find_touching_position(
+        #[case] character: u32,
+        #[case] expected_name: Option<&str>,
+    ) {
+        let packages = vec![make_package("lodash", "4.17.21", 3, 15, 7)];
+        let index = PackageIndex::new(&packages);
+        let result = index.find_touching_position(Position { line: 3, character });
+        assert_eq!(result.map(|p| p.name.as_str()), expected_name);
+    }
 }
@@ -1,15 +1,18 @@
 //! Upgrade code actions — version bumping across all registries
 
-use crate::parser::types::{ExtraInfo, PackageInfo};
-use crate::version::checker::VersionStorer;
+use crate::config::DiagnosticKind;
+use crate::lsp::diagnostics::diagnostic_kind;
+use crate::lsp::resolver::Matchers;
+use crate::parser::types::{ExtraInfo, PackageInfo, RegistryType};
+use crate::version::checker::{CompareOptions, VersionStatus, VersionStorer, compare_package};
 use crate::version::matcher::VersionMatcher;
 use crate::version::registries::github::TagShaFetcher;
 use std::collections::HashMap;
 use tower_lsp::lsp_types::{
-    CodeAction, CodeActionKind, Position, Range, TextEdit, Url, WorkspaceEdit,
+    CodeAction, CodeActionKind, Diagnostic, Position, Range, TextEdit, Url, WorkspaceEdit,
 };
 
-use super::{create_bump_action, extract_version_prefix};
+use super::{create_bump_action, extract_version_prefix, strip_version_prefix};
 
 /// Compute deduplicated bump targets from smallest to largest jump.
 ///
@@ -90,6 +93,172 @@ pub fn generate_upgrade_code_actions<S: VersionStorer>(
         .collect()
 }
 
+/// Outdated diagnostics on the package's version among `diagnostics`, with
+/// the bare latest version, if the package is outdated
+///
+/// Packages pinned by commit hash are skipped since they need a SHA lookup.
+fn outdated_latest<S: VersionStorer>(
+    storer: &S,
+    package: &PackageInfo,
+    matcher: &dyn VersionMatcher,
+    options: &CompareOptions,
+    diagnostics: &[Diagnostic],
+) -> Option<(Vec<Diagnostic>, String)> {
+    if package.commit_hash.is_some() {
+        return None;
+    }
+
+    let outdated: Vec<Diagnostic> = diagnostics
+        .iter()
+        .filter(|d| diagnostic_kind(d) == Some(DiagnosticKind::Outdated))
+        .filter(|d| {
+            let line = package.line as u32;
+            let start = package.column as u32;
+            let end = start + package.version.len() as u32;
+            d.range.start.line == line
+                && d.range.start.character < end
+                && d.range.end.character > start
+        })
+        .cloned()
+        .collect();
+    if outdated.is_empty() {
+        return None;
+    }
+
+    // The diagnostic already establishes the package is outdated; the comparison
+    // is only used to resolve the effective latest version
+    let latest = compare_package(storer, matcher, package, options)
+        .ok()?
+        .latest_version?;
+
+    Some((outdated, strip_version_prefix(&latest).to_string()))
+}
+
+/// Generate the "Update to latest" quick fix for an outdated-version diagnostic
+///
+/// Only offered when one of `diagnostics` (from the code action context) is an
+/// outdated diagnostic on the package's version; that diagnostic is attached to
+/// the action. The current version prefix is preserved (`^4.17.0` -> `^4.17.21`).
+/// Packages pinned by commit hash are skipped since they need a SHA lookup.
+pub fn generate_update_to_latest_action<S: VersionStorer>(
+    storer: &S,
+    package: &PackageInfo,
+    uri: &Url,
+    matcher: &dyn VersionMatcher,
+    options: &CompareOptions,
+    diagnostics: &[Diagnostic],
+) -> Option<CodeAction> {
+    let (outdated, latest) = outdated_latest(storer, package, matcher, options, diagnostics)?;
+
+    let new_version = format!("{}{}", extract_version_prefix(&package.version), latest);
+    if new_version == package.version {
+        return None;
+    }
+    let action = create_bump_action(
+        &format!("Update to latest: {new_version}"),
+        &new_version,
+        package,
+        uri,
+    );
+
+    Some(CodeAction {
+        diagnostics: Some(outdated),
+        is_preferred: Some(true),
+        ..action
+    })
+}
+
+/// Generate the "Pin to latest exact version" quick fix offered next to
+/// [`generate_update_to_latest_action`]
+///
+/// The range operator is dropped in favor of the registry's exact form
+/// (`^4.17.0` -> `4.17.21` for npm, `=4.17.21` for Cargo, `==` for PyPI).
+/// Not offered when it would produce the same edit as "Update to latest",
+/// e.g. for versions that are already exact.
+pub fn generate_pin_to_latest_action<S: VersionStorer>(
+    storer: &S,
+    package: &PackageInfo,
+    uri: &Url,
+    matcher: &dyn VersionMatcher,
+    options: &CompareOptions,
+    diagnostics: &[Diagnostic],
+) -> Option<CodeAction> {
+    let (outdated, latest) = outdated_latest(storer, package, matcher, options, diagnostics)?;
+
+    let pinned = match package.registry_type {
+        RegistryType::CratesIo => format!("={latest}"),
+        RegistryType::PyPI => format!("=={latest}"),
+        _ => latest.clone(),
+    };
+    let updated = format!("{}{}", extract_version_prefix(&package.version), latest);
+    if pinned == package.version || pinned == updated {
+        return None;
+    }
+    let action = create_bump_action(
+        &format!("Pin to latest exact version: {pinned}"),
+        &pinned,
+        package,
+        uri,
+    );
+
+    Some(CodeAction {
+        diagnostics: Some(outdated),
+        ..action
+    })
+}
+
+/// Generate a single edit bumping every outdated package to its latest version
+///
+/// Each package's version prefix is preserved. Packages that are already latest,
+/// not found, not cached or pinned by commit hash are left untouched.
+/// Returns None when nothing is outdated.
+pub fn generate_update_all_edit<S: VersionStorer>(
+    storer: &S,
+    packages: &[PackageInfo],
+    uri: &Url,
+    matchers: &Matchers,
+    options: &CompareOptions,
+) -> Option<WorkspaceEdit> {
+    let edits: Vec<TextEdit> = packages
+        .iter()
+        .filter(|package| package.commit_hash.is_none())
+        .filter_map(|package| {
+            let result = compare_package(storer, matchers.get(package), package, options).ok()?;
+            if result.status != VersionStatus::Outdated {
+                return None;
+            }
+            let latest = result.latest_version?;
+            let new_text = format!(
+                "{}{}",
+                extract_version_prefix(&package.version),
+                strip_version_prefix(&latest)
+            );
+            Some(TextEdit {
+                range: Range {
+                    start: Position {
+                        line: package.line as u32,
+                        character: package.column as u32,
+                    },
+                    end: Position {
+                        line: package.line as u32,
+                        character: (package.column + package.version.len()) as u32,
+                    },
+                },
+                new_text,
+            })
+        })
+        .collect();
+
+    if edits.is_empty() {
+        return None;
+    }
+
+    Some(WorkspaceEdit {
+        changes: Some(HashMap::from([(uri.clone(), edits)])),
+        ..Default::default()
+    })
+}
+
 /// Generate upgrade code actions with SHA fetching for GitHub Actions
 ///
 /// When the package has a commit hash (GitHub Actions), this function will fetch
@@ -112,9 +281,7 @@ pub async fn generate_upgrade_code_actions_with_sha<S: VersionStorer, F: TagShaF
 
     // For hash-only packages (no comment version), we need special handling
     // because the version field contains the hash itself, not a semver version
-    let is_hash_only = package.commit_hash.is_some() && package.extra_info.is_none();
-
-    if is_hash_only {
+    if package.is_unversioned_commit_pin() {
         // Pattern 1: Hash only - just offer the latest version
         return generate_hash_only_actions(storer, package, uri, sha_fetcher).await;
     }
@@ -212,7 +379,7 @@ fn create_hash_bump_action(
             let end_col = package.column + (comment_end_offset - hash_start_offset);
             (end_col as u32, format!("{new_sha} # {new_version}"))
         }
-        None => {
+        None | Some(ExtraInfo::GoMod { .. } | ExtraInfo::FlakeLock { .. }) => {
             // Pattern 1: Hash only
             // Replace just the hash (40 characters)
             let hash_len = package.commit_hash.as_ref().map(|h| h.len()).unwrap_or(40);
@@ -247,11 +414,11 @@ fn create_hash_bump_action(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::parser::types::RegistryType;
     use crate::version::cache::PackageId;
     use crate::version::error::{CacheError, RegistryError};
-    use crate::version::matchers::{GitHubActionsMatcher, NpmVersionMatcher};
+    use crate::version::matchers::{CratesVersionMatcher, GitHubActionsMatcher, NpmVersionMatcher};
     use rstest::rstest;
+    use tower_lsp::lsp_types::NumberOrString;
 
     fn make_package(name: &str, version: &str, line: u32, column: u32, len: usize) -> PackageInfo {
         PackageInfo {
@@ -353,6 +520,23 @@ mod tests {
             Ok(())
         }
 
+        fn get_yanked_versions(
+            &self,
+            _registry_type: RegistryType,
+            _package_name: &str,
+        ) -> Result<Vec<String>, CacheError> {
+            Ok(vec![])
+        }
+
+        fn save_yanked_versions(
+            &self,
+            _registry_type: RegistryType,
+            _package_name: &str,
+            _yanked: &[String],
+        ) -> Result<(), CacheError> {
+            Ok(())
+        }
+
         fn filter_packages_not_in_cache(
             &self,
             _registry_type: RegistryType,
@@ -368,6 +552,22 @@ mod tests {
         ) -> Result<(), CacheError> {
             Ok(())
         }
+
+        fn filter_stale_packages(
+            &self,
+            _registry_type: RegistryType,
+            _package_names: &[String],
+        ) -> Result<Vec<String>, CacheError> {
+            Ok(vec![])
+        }
+
+        fn invalidate(
+            &self,
+            _registry_type: RegistryType,
+            _package_name: &str,
+        ) -> Result<(), CacheError> {
+            Ok(())
+        }
     }
 
     #[test]
@@ -435,6 +635,199 @@ mod tests {
         );
     }
 
+    fn make_outdated_diagnostic(line: u32, start: u32, end: u32) -> Diagnostic {
+        Diagnostic {
+            range: Range {
+                start: Position::new(line, start),
+                end: Position::new(line, end),
+            },
+            code: Some(NumberOrString::String(
+                DiagnosticKind::Outdated.as_str().to_string(),
+            )),
+            message: "Update available".to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn update_to_latest_preserves_prefix_and_attaches_diagnostic() {
+        let storer = MockStorer::new(vec!["4.17.0", "4.17.21"]);
+        let package = make_package("lodash", "^4.17.0", 3, 15, 7);
+        let uri = Url::parse("file:///test/package.json").unwrap();
+        let diagnostic = make_outdated_diagnostic(3, 15, 22);
+
+        let action = generate_update_to_latest_action(
+            &storer,
+            &package,
+            &uri,
+            &NpmVersionMatcher,
+            &CompareOptions::default(),
+            std::slice::from_ref(&diagnostic),
+        )
+        .expect("Expected update to latest action");
+
+        assert_eq!(action.title, "Update to latest: ^4.17.21");
+        assert_eq!(action.kind, Some(CodeActionKind::QUICKFIX));
+        assert_eq!(action.is_preferred, Some(true));
+        assert_eq!(action.diagnostics, Some(vec![diagnostic]));
+        let changes = action.edit.unwrap().changes.unwrap();
+        let edits = changes.get(&uri).unwrap();
+        assert_eq!(edits.len(), 1);
+        assert_eq!(edits[0].new_text, "^4.17.21");
+        assert_eq!(
+            edits[0].range,
+            Range {
+                start: Position::new(3, 15),
+                end: Position::new(3, 22),
+            }
+        );
+    }
+
+    #[rstest]
+    // No diagnostic in the context
+    #[case(vec![])]
+    // Outdated diagnostic on another line
+    #[case(vec![make_outdated_diagnostic(4, 15, 22)])]
+    // Diagnostic of a different kind on the version
+    #[case(vec![Diagnostic {
+        code: Some(NumberOrString::String(DiagnosticKind::NotFound.as_str().to_string())),
+        ..make_outdated_diagnostic(3, 15, 22)
+    }])]
+    fn update_to_latest_requires_outdated_diagnostic_on_version(
+        #[case] diagnostics: Vec<Diagnostic>,
+    ) {
+        let storer = MockStorer::new(vec!["4.17.0", "4.17.21"]);
+        let package = make_package("lodash", "^4.17.0", 3, 15, 7);
+        let uri = Url::parse("file:///test/package.json").unwrap();
+
+        let action = generate_update_to_latest_action(
+            &storer,
+            &package,
+            &uri,
+            &NpmVersionMatcher,
+            &CompareOptions::default(),
+            &diagnostics,
+        );
+
+        assert!(action.is_none());
+    }
+
+    #[rstest]
+    #[case(RegistryType::CratesIo, "=4.17.21")]
+    #[case(RegistryType::Npm, "4.17.21")]
+    fn pin_to_latest_replaces_range_with_exact_version(
+        #[case] registry_type: RegistryType,
+        #[case] expected: &str,
+    ) {
+        let storer = MockStorer::new(vec!["4.17.0", "4.17.21"]);
+        let package = PackageInfo {
+            registry_type,
+            ..make_package("lodash", "^4.17.0", 3, 15, 7)
+        };
+        let uri = Url::parse("file:///test/manifest").unwrap();
+        let diagnostic = make_outdated_diagnostic(3, 15, 22);
+        let matcher: &dyn VersionMatcher = match registry_type {
+            RegistryType::CratesIo => &CratesVersionMatcher,
+            _ => &NpmVersionMatcher,
+        };
+
+        let action = generate_pin_to_latest_action(
+            &storer,
+            &package,
+            &uri,
+            matcher,
+            &CompareOptions::default(),
+            std::slice::from_ref(&diagnostic),
+        )
+        .expect("Expected pin to latest action");
+
+        assert_eq!(
+            action.title,
+            format!("Pin to latest exact version: {expected}")
+        );
+        assert_eq!(action.diagnostics, Some(vec![diagnostic]));
+        let changes = action.edit.unwrap().changes.unwrap();
+        assert_eq!(changes.get(&uri).unwrap()[0].new_text, expected);
+    }
+
+    #[test]
+    fn pin_to_latest_is_skipped_when_it_matches_update_to_latest() {
+        let storer = MockStorer::new(vec!["4.17.0", "4.17.21"]);
+        let package = make_package("lodash", "4.17.0", 3, 15, 6);
+        let uri = Url::parse("file:///test/package.json").unwrap();
+
+        let action = generate_pin_to_latest_action(
+            &storer,
+            &package,
+            &uri,
+            &NpmVersionMatcher,
+            &CompareOptions::default(),
+            &[make_outdated_diagnostic(3, 15, 21)],
+        );
+
+        assert!(action.is_none());
+    }
+
+    #[test]
+    fn update_all_edits_only_outdated_packages() {
+        let storer = MockStorer::new(vec!["1.0.0", "1.5.0", "2.0.0"]);
+        let packages = vec![
+            make_package("outdated-caret", "^1.0.0", 1, 10, 6),
+            make_package("current", "2.0.0", 2, 10, 5),
+            make_package("outdated-tilde", "~1.5.0", 3, 10, 6),
+            make_package("missing", "9.9.9", 4, 10, 5),
+        ];
+        let uri = Url::parse("file:///test/package.json").unwrap();
+
+        let edit = generate_update_all_edit(
+            &storer,
+            &packages,
+            &uri,
+            &Matchers::from(NpmVersionMatcher),
+            &CompareOptions::default(),
+        )
+        .expect("Expected workspace edit");
+
+        let changes = edit.changes.unwrap();
+        let edits = changes.get(&uri).unwrap();
+        assert_eq!(
+            edits,
+            &vec![
+                TextEdit {
+                    range: Range {
+                        start: Position::new(1, 10),
+                        end: Position::new(1, 16),
+                    },
+                    new_text: "^2.0.0".to_string(),
+                },
+                TextEdit {
+                    range: Range {
+                        start: Position::new(3, 10),
+                        end: Position::new(3, 16),
+                    },
+                    new_text: "~2.0.0".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn update_all_returns_none_when_everything_is_latest() {
+        let storer = MockStorer::new(vec!["1.0.0", "2.0.0"]);
+        let packages = vec![make_package("current", "^2.0.0", 1, 10, 6)];
+        let uri = Url::parse("file:///test/package.json").unwrap();
+
+        let edit = generate_update_all_edit(
+            &storer,
+            &packages,
+            &uri,
+            &Matchers::from(NpmVersionMatcher),
+            &CompareOptions::default(),
+        );
+
+        assert!(edit.is_none());
+    }
+
     #[test]
     fn upgrade_preserves_caret_prefix() {
         let storer = MockStorer::new(vec!["4.17.19", "4.17.21", "4.18.0", "5.0.0"]);
@@ -0,0 +1,263 @@
+//! Code lenses showing the latest version above each dependency
+
+use tower_lsp::lsp_types::{CodeLens, Command, Position, Range, Url};
+use tracing::warn;
+
+use crate::lsp::backend::UPDATE_PACKAGE_COMMAND;
+use crate::lsp::resolver::Matchers;
+use crate::parser::types::PackageInfo;
+use crate::version::checker::{CompareOptions, VersionStatus, VersionStorer, compare_package};
+use crate::version::matcher::VersionMatcher;
+
+/// Generate one code lens per package
+///
+/// Packages with a cached latest version get a resolved `latest <version>`
+/// lens; outdated ones run [`UPDATE_PACKAGE_COMMAND`] when clicked. Packages
+/// still being fetched get an unresolved lens carrying the document URI as
+/// data, to be completed by [`resolve_code_lens`].
+pub fn generate_code_lenses<S: VersionStorer>(
+    storer: &S,
+    matchers: &Matchers,
+    options: &CompareOptions,
+    packages: &[PackageInfo],
+    uri: &Url,
+) -> Vec<CodeLens> {
+    packages
+        .iter()
+        .map(|package| CodeLens {
+            range: version_range(package),
+            command: latest_command(storer, matchers.get(package), options, package, uri),
+            data: Some(serde_json::Value::String(uri.to_string())),
+        })
+        .collect()
+}
+
+/// Resolve a lens whose package was still being fetched when it was created
+///
+/// Lenses for packages that are still not cached show `fetching…`.
+pub fn resolve_code_lens<S: VersionStorer>(
+    storer: &S,
+    matcher: &dyn VersionMatcher,
+    options: &CompareOptions,
+    package: &PackageInfo,
+    uri: &Url,
+    lens: CodeLens,
+) -> CodeLens {
+    let command =
+        latest_command(storer, matcher, options, package, uri).unwrap_or_else(|| Command {
+            title: "fetching…".to_string(),
+            command: String::new(),
+            arguments: None,
+        });
+
+    CodeLens {
+        command: Some(command),
+        ..lens
+    }
+}
+
+/// Build the `latest <version>` command, or None if the package is not cached
+fn latest_command<S: VersionStorer>(
+    storer: &S,
+    matcher: &dyn VersionMatcher,
+    options: &CompareOptions,
+    package: &PackageInfo,
+    uri: &Url,
+) -> Option<Command> {
+    let result = compare_package(storer, matcher, package, options)
+        .inspect_err(|e| warn!("Failed to compare version for {}: {}", package.name, e))
+        .ok()?;
+    let latest = result.latest_version?;
+
+    // Display-only lenses use an empty command
+    let (command, arguments) =
+        if result.status == VersionStatus::Outdated && package.commit_hash.is_none() {
+            (
+                UPDATE_PACKAGE_COMMAND.to_string(),
+                Some(vec![
+                    serde_json::json!(uri),
+                    serde_json::json!(package.line),
+                    serde_json::json!(package.column),
+                ]),
+            )
+        } else {
+            (String::new(), None)
+        };
+
+    Some(Command {
+        title: format!("latest {}", latest),
+        command,
+        arguments,
+    })
+}
+
+/// Range of the version string, so the package can be found again on resolve
+fn version_range(package: &PackageInfo) -> Range {
+    Range {
+        start: Position {
+            line: package.line as u32,
+            character: package.column as u32,
+        },
+        end: Position {
+            line: package.line as u32,
+            character: (package.column + package.version.len()) as u32,
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::types::RegistryType;
+    use crate::version::checker::MockVersionStorer;
+    use crate::version::matchers::CratesVersionMatcher;
+
+    fn make_package(name: &str, version: &str, line: usize) -> PackageInfo {
+        PackageInfo {
+            name: name.to_string(),
+            version: version.to_string(),
+            commit_hash: None,
+            registry_type: RegistryType::CratesIo,
+            start_offset: 0,
+            end_offset: version.len(),
+            line,
+            column: 9,
+            extra_info: None,
+        }
+    }
+
+    fn uri() -> Url {
+        Url::parse("file:///test/Cargo.toml").unwrap()
+    }
+
+    fn make_storer(latest: Option<&'static str>) -> MockVersionStorer {
+        let mut storer = MockVersionStorer::new();
+        storer
+            .expect_get_latest_version()
+            .returning(move |_, _| Ok(latest.map(str::to_string)));
+        storer.expect_get_dist_tag().returning(|_, _, _| Ok(None));
+        storer.expect_is_not_found().return_const(false);
+        storer.expect_is_unreachable().return_const(false);
+        storer.expect_get_versions().returning(|_, _| {
+            Ok(vec![
+                "1.0.100".to_string(),
+                "1.0.200".to_string(),
+                "1.0.210".to_string(),
+            ])
+        });
+        storer
+            .expect_get_yanked_versions()
+            .returning(|_, _| Ok(vec![]));
+        storer
+    }
+
+    #[test]
+    fn generate_code_lenses_returns_one_lens_per_package_line() {
+        let storer = make_storer(Some("1.0.210"));
+        let packages = vec![
+            make_package("serde", "1.0.100", 7),
+            make_package("serde_json", "1.0.210", 8),
+            make_package("tokio", "=1.0.200", 11),
+        ];
+
+        let lenses = generate_code_lenses(
+            &storer,
+            &Matchers::from(CratesVersionMatcher),
+            &CompareOptions::default(),
+            &packages,
+            &uri(),
+        );
+
+        assert_eq!(lenses.len(), packages.len());
+        for (lens, package) in lenses.iter().zip(&packages) {
+            assert_eq!(lens.range.start.line, package.line as u32);
+        }
+        let titles: Vec<_> = lenses
+            .iter()
+            .map(|lens| lens.command.as_ref().unwrap().title.as_str())
+            .collect();
+        assert_eq!(titles, vec!["latest 1.0.210"; 3]);
+    }
+
+    #[test]
+    fn generate_code_lenses_attaches_update_command_to_outdated_packages() {
+        let storer = make_storer(Some("1.0.210"));
+        let packages = vec![
+            make_package("serde", "=1.0.100", 7),
+            make_package("serde_json", "1.0.210", 8),
+        ];
+
+        let lenses = generate_code_lenses(
+            &storer,
+            &Matchers::from(CratesVersionMatcher),
+            &CompareOptions::default(),
+            &packages,
+            &uri(),
+        );
+
+        let outdated = lenses[0].command.as_ref().unwrap();
+        assert_eq!(outdated.command, UPDATE_PACKAGE_COMMAND);
+        assert_eq!(
+            outdated.arguments,
+            Some(vec![
+                serde_json::json!("file:///test/Cargo.toml"),
+                serde_json::json!(7),
+                serde_json::json!(9),
+            ])
+        );
+        let latest = lenses[1].command.as_ref().unwrap();
+        assert_eq!(latest.command, "");
+        assert_eq!(latest.arguments, None);
+    }
+
+    #[test]
+    fn generate_code_lenses_leaves_uncached_packages_unresolved() {
+        let storer = make_storer(None);
+        let packages = vec![make_package("serde", "1.0.100", 7)];
+
+        let lenses = generate_code_lenses(
+            &storer,
+            &Matchers::from(CratesVersionMatcher),
+            &CompareOptions::default(),
+            &packages,
+            &uri(),
+        );
+
+        assert_eq!(lenses.len(), 1);
+        assert!(lenses[0].command.is_none());
+        assert_eq!(
+            lenses[0].data,
+            Some(serde_json::json!("file:///test/Cargo.toml"))
+        );
+    }
+
+    #[test]
+    fn resolve_code_lens_fills_in_latest_version() {
+        let package = make_package("serde", "=1.0.100", 7);
+        let lens = CodeLens {
+            range: version_range(&package),
+            command: None,
+            data: Some(serde_json::json!("file:///test/Cargo.toml")),
+        };
+
+        let resolved = resolve_code_lens(
+            &make_storer(Some("1.0.210")),
+            &CratesVersionMatcher,
+            &CompareOptions::default(),
+            &package,
+            &uri(),
+            lens.clone(),
+        );
+        assert_eq!(resolved.command.unwrap().title, "latest 1.0.210");
+
+        let pending = resolve_code_lens(
+            &make_storer(None),
+            &CratesVersionMatcher,
+            &CompareOptions::default(),
+            &package,
+            &uri(),
+            lens,
+        );
+        assert_eq!(pending.command.unwrap().title, "fetching…");
+    }
+}
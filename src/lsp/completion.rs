@@ -0,0 +1,153 @@
+//! Completion of version strings from the cached registry versions
+
+use tower_lsp::lsp_types::{
+    CompletionItem, CompletionItemKind, CompletionTextEdit, Position, Range, TextEdit,
+};
+use tracing::warn;
+
+use crate::lsp::code_action::{extract_version_prefix, strip_version_prefix};
+use crate::parser::types::PackageInfo;
+use crate::version::checker::VersionStorer;
+use crate::version::semver::{is_prerelease, parse_version};
+
+/// Characters typed in version strings that trigger completion
+pub const TRIGGER_CHARACTERS: [&str; 7] = ["\"", "^", "~", "=", ">", "<", "."];
+
+/// Generate completion items for the version of a package
+///
+/// Versions are read from the cache only and listed newest first, keeping the
+/// operator already typed (e.g. `^`). Pre-releases sort after stable versions.
+pub fn generate_completions<S: VersionStorer>(
+    storer: &S,
+    package: &PackageInfo,
+) -> Vec<CompletionItem> {
+    let versions = storer
+        .get_versions(package.registry_type, &package.name)
+        .inspect_err(|e| warn!("Failed to get versions for {}: {}", package.name, e))
+        .unwrap_or_default();
+
+    // Registry order is oldest first; versions that don't parse keep it, reversed
+    let mut versions: Vec<_> = versions
+        .into_iter()
+        .rev()
+        .map(|version| {
+            let parsed = parse_version(&version);
+            (version, parsed)
+        })
+        .collect();
+    versions.sort_by(|(_, a), (_, b)| b.cmp(a));
+
+    let prefix = extract_version_prefix(&package.version);
+    let range = Range {
+        start: Position {
+            line: package.line as u32,
+            character: package.column as u32,
+        },
+        end: Position {
+            line: package.line as u32,
+            character: (package.column + package.version.len()) as u32,
+        },
+    };
+
+    let (stable, prereleases): (Vec<_>, Vec<_>) = versions
+        .into_iter()
+        .map(|(version, _)| version)
+        .partition(|version| !is_prerelease(version));
+
+    stable
+        .iter()
+        .map(|version| (version, "0"))
+        .chain(prereleases.iter().map(|version| (version, "1")))
+        .enumerate()
+        .map(|(index, (version, rank))| {
+            let label = format!("{}{}", prefix, strip_version_prefix(version));
+            CompletionItem {
+                label: label.clone(),
+                kind: Some(CompletionItemKind::VALUE),
+                detail: (index == 0).then(|| "latest".to_string()),
+                sort_text: Some(format!("{}{:05}", rank, index)),
+                filter_text: Some(label.clone()),
+                text_edit: Some(CompletionTextEdit::Edit(TextEdit {
+                    range,
+                    new_text: label,
+                })),
+                ..Default::default()
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::types::RegistryType;
+    use crate::version::checker::MockVersionStorer;
+
+    fn make_package(version: &str) -> PackageInfo {
+        PackageInfo {
+            name: "lodash".to_string(),
+            version: version.to_string(),
+            commit_hash: None,
+            registry_type: RegistryType::Npm,
+            start_offset: 40,
+            end_offset: 40 + version.len(),
+            line: 3,
+            column: 15,
+            extra_info: None,
+        }
+    }
+
+    fn make_storer(versions: &'static [&'static str]) -> MockVersionStorer {
+        let mut storer = MockVersionStorer::new();
+        storer
+            .expect_get_versions()
+            .returning(move |_, _| Ok(versions.iter().map(|v| v.to_string()).collect()));
+        storer
+    }
+
+    fn labels(items: &[CompletionItem]) -> Vec<&str> {
+        items.iter().map(|item| item.label.as_str()).collect()
+    }
+
+    #[test]
+    fn generate_completions_lists_newest_first_with_prefix() {
+        let storer = make_storer(&["4.17.0", "4.17.21", "3.10.1", "4.17.20"]);
+
+        let items = generate_completions(&storer, &make_package("^4.17"));
+
+        assert_eq!(
+            labels(&items),
+            vec!["^4.17.21", "^4.17.20", "^4.17.0", "^3.10.1"]
+        );
+        assert_eq!(items[0].detail.as_deref(), Some("latest"));
+        assert_eq!(
+            items[0].text_edit,
+            Some(CompletionTextEdit::Edit(TextEdit {
+                range: Range {
+                    start: Position::new(3, 15),
+                    end: Position::new(3, 20),
+                },
+                new_text: "^4.17.21".to_string(),
+            }))
+        );
+    }
+
+    #[test]
+    fn generate_completions_ranks_prereleases_after_stable_versions() {
+        let storer = make_storer(&["4.0.0", "5.0.0-beta.1", "4.1.0"]);
+
+        let mut items = generate_completions(&storer, &make_package(""));
+        items.sort_by(|a, b| a.sort_text.cmp(&b.sort_text));
+
+        assert_eq!(labels(&items), vec!["4.1.0", "4.0.0", "5.0.0-beta.1"]);
+    }
+
+    #[test]
+    fn generate_completions_keeps_v_prefix_of_registry_tags() {
+        let storer = make_storer(&["v4.1.0", "v4.2.0"]);
+
+        let items = generate_completions(&storer, &make_package("v4"));
+
+        assert_eq!(labels(&items), vec!["v4.2.0", "v4.1.0"]);
+    }
+}
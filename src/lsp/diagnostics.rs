@@ -1,61 +1,259 @@
 //! Diagnostics generation for version checking results
 
-use tower_lsp::lsp_types::{Diagnostic, DiagnosticSeverity, Position, Range};
+use chrono::DateTime;
+use tower_lsp::lsp_types::{Diagnostic, DiagnosticSeverity, NumberOrString, Position, Range};
 use tracing::warn;
 
+use crate::config::{DiagnosticKind, DiagnosticSummary, LspConfig, SeverityConfig};
+use crate::lsp::resolver::Matchers;
+use crate::parser::github_actions::GitHubActionsParser;
+use crate::parser::go_mod::{GO_RELEASES_PACKAGE, GoDirectiveInfo, GoModParser};
 use crate::parser::traits::Parser;
-use crate::parser::types::PackageInfo;
+use crate::parser::types::{ExtraInfo, PackageInfo, RegistryType};
 use crate::version::checker::{
-    VersionCompareResult, VersionStatus, VersionStorer, compare_version,
+    CompareOptions, VersionCompareResult, VersionStatus, VersionStorer, compare_package,
+    is_dist_tag_name, newer_go_release, publishes_dist_tags,
 };
-use crate::version::matcher::VersionMatcher;
 
 const PACKAGE_NAME: &str = env!("CARGO_PKG_NAME");
 
 /// Generate diagnostics for a document by parsing and checking versions
 pub fn generate_diagnostics<S: VersionStorer>(
     parser: &dyn Parser,
-    matcher: &dyn VersionMatcher,
+    matchers: &Matchers,
+    options: &CompareOptions,
     storer: &S,
     content: &str,
+    severity: &SeverityConfig,
+) -> Vec<Diagnostic> {
+    generate_diagnostics_filtered(parser, matchers, options, storer, content, severity, |_| {
+        true
+    })
+}
+
+/// Generate diagnostics only for packages accepted by `include`
+///
+/// When the document has syntax errors, an informational notice at the top of
+/// the file says that dependencies may have been skipped.
+pub fn generate_diagnostics_filtered<S: VersionStorer>(
+    parser: &dyn Parser,
+    matchers: &Matchers,
+    options: &CompareOptions,
+    storer: &S,
+    content: &str,
+    severity: &SeverityConfig,
+    include: impl Fn(&PackageInfo) -> bool,
 ) -> Vec<Diagnostic> {
     let packages = parser
         .parse(content)
         .inspect_err(|e| warn!("Failed to parse document: {}", e))
         .unwrap_or_default();
 
-    packages
+    let mut diagnostics: Vec<Diagnostic> = packages
         .iter()
+        .filter(|package| {
+            include(package) && !package.is_digest_pinned() && !package.is_unversioned_commit_pin()
+        })
         .filter_map(|package| {
-            let result = compare_version(storer, matcher, &package.name, &package.version).ok()?;
-            create_diagnostic(package, &result)
+            let result = compare_package(storer, matchers.get(package), package, options).ok()?;
+            create_diagnostic(package, &result, severity)
+        })
+        .collect();
+
+    if parser.has_syntax_errors(content) {
+        diagnostics.push(syntax_error_notice());
+    }
+
+    diagnostics
+}
+
+/// Diagnostics published for a document
+///
+/// Adds the end-of-life runtime check of GitHub Actions and, with
+/// `go.checkToolchain`, the Go toolchain check of go.mod to the package
+/// diagnostics. Updates are then summarized and the rest capped by priority
+/// as `config` says.
+pub fn assemble_diagnostics<S: VersionStorer>(
+    parser: &dyn Parser,
+    matchers: &Matchers,
+    storer: &S,
+    registry_type: RegistryType,
+    content: &str,
+    config: &LspConfig,
+    include: impl Fn(&PackageInfo) -> bool,
+) -> Vec<Diagnostic> {
+    let mut diagnostics = generate_diagnostics_filtered(
+        parser,
+        matchers,
+        &config.compare_options(),
+        storer,
+        content,
+        &config.severity,
+        include,
+    );
+    if registry_type == RegistryType::GitHubActions {
+        diagnostics.extend(generate_node_runtime_diagnostics(content));
+    }
+    if registry_type == RegistryType::GoProxy && config.go.check_toolchain {
+        diagnostics.extend(generate_go_toolchain_diagnostics(
+            storer,
+            content,
+            &config.severity,
+        ));
+    }
+    // The summary counts every update, including ones the cap drops
+    let summary = summarize_diagnostics(&mut diagnostics, config.summarize_diagnostics);
+    let mut diagnostics = prioritize_diagnostics(
+        diagnostics,
+        &config.diagnostic_priority,
+        config.max_diagnostics_per_document,
+    );
+    diagnostics.splice(0..0, summary);
+    diagnostics
+}
+
+/// Notice that a document could only be parsed partially
+fn syntax_error_notice() -> Diagnostic {
+    Diagnostic {
+        range: Range::default(),
+        severity: Some(DiagnosticSeverity::INFORMATION),
+        message: format!("{}: could not fully parse this file", PACKAGE_NAME),
+        source: Some(PACKAGE_NAME.to_string()),
+        ..Default::default()
+    }
+}
+
+/// Generate diagnostics for an end-of-life Node.js runtime in `runs.using` of an action.yml
+pub fn generate_node_runtime_diagnostics(content: &str) -> Vec<Diagnostic> {
+    GitHubActionsParser::new()
+        .find_node_runtime(content)
+        .filter(|runtime| runtime.is_eol())
+        .map(|runtime| Diagnostic {
+            range: Range {
+                start: Position {
+                    line: runtime.line as u32,
+                    character: runtime.column as u32,
+                },
+                end: Position {
+                    line: runtime.line as u32,
+                    character: (runtime.column + runtime.end_offset - runtime.start_offset) as u32,
+                },
+            },
+            severity: Some(DiagnosticSeverity::WARNING),
+            code: Some(NumberOrString::String(
+                DiagnosticKind::Deprecated.as_str().to_string(),
+            )),
+            message: format!(
+                "Runtime {} is end-of-life; use node20 or later",
+                runtime.runtime
+            ),
+            source: Some(PACKAGE_NAME.to_string()),
+            ..Default::default()
+        })
+        .into_iter()
+        .collect()
+}
+
+/// Package that fetches the Go release list, if a go.mod declares a Go version
+pub fn go_release_package(content: &str) -> Option<PackageInfo> {
+    GoModParser::new()
+        .find_go_directives(content)
+        .first()
+        .map(GoDirectiveInfo::release_package)
+}
+
+/// Generate diagnostics for `go` and `toolchain` directives of a go.mod that
+/// are older than the latest stable Go release
+///
+/// Releases are read from the cache only; nothing is reported until the
+/// release list has been fetched.
+pub fn generate_go_toolchain_diagnostics<S: VersionStorer>(
+    storer: &S,
+    content: &str,
+    severities: &SeverityConfig,
+) -> Vec<Diagnostic> {
+    let releases = storer
+        .get_versions(RegistryType::GoRelease, GO_RELEASES_PACKAGE)
+        .inspect_err(|e| warn!("Failed to get Go releases: {}", e))
+        .unwrap_or_default();
+
+    GoModParser::new()
+        .find_go_directives(content)
+        .into_iter()
+        .filter_map(|directive| {
+            let latest = newer_go_release(&directive.version, &releases)?;
+            Some(Diagnostic {
+                range: Range {
+                    start: Position {
+                        line: directive.line as u32,
+                        character: directive.column as u32,
+                    },
+                    end: Position {
+                        line: directive.line as u32,
+                        character: (directive.column + directive.end_offset
+                            - directive.start_offset) as u32,
+                    },
+                },
+                severity: Some(severities.outdated.into()),
+                code: Some(NumberOrString::String(
+                    DiagnosticKind::Outdated.as_str().to_string(),
+                )),
+                message: format!("Go update available: {} -> {}", directive.version, latest),
+                source: Some(PACKAGE_NAME.to_string()),
+                ..Default::default()
+            })
         })
         .collect()
 }
 
 /// Create a diagnostic from package info and version check result
 /// Returns None if no diagnostic should be shown (e.g., NotInCache)
-fn create_diagnostic(package: &PackageInfo, result: &VersionCompareResult) -> Option<Diagnostic> {
-    let (severity, message) = match result.status {
+pub(crate) fn create_diagnostic(
+    package: &PackageInfo,
+    result: &VersionCompareResult,
+    severities: &SeverityConfig,
+) -> Option<Diagnostic> {
+    let (kind, severity, message) = match result.status {
         // No diagnostic for: not cached, latest version, or newer than latest
         // Newer: version exists but is newer than dist-tags.latest (valid scenario)
         VersionStatus::NotInCache | VersionStatus::Latest | VersionStatus::Newer => return None,
         VersionStatus::Outdated => (
-            DiagnosticSeverity::WARNING,
-            format!(
-                "Update available: {} -> {}",
-                result.current_version,
-                result.latest_version.as_deref().unwrap_or("unknown")
-            ),
+            DiagnosticKind::Outdated,
+            severities.outdated.into(),
+            match locked_date(package) {
+                Some(date) => format!("{} (locked {})", outdated_message(result), date),
+                None => outdated_message(result),
+            },
         ),
         VersionStatus::NotFound => (
+            DiagnosticKind::NotFound,
+            severities.not_found.into(),
+            match result.latest_version {
+                Some(_)
+                    if publishes_dist_tags(package.registry_type)
+                        && is_dist_tag_name(&result.current_version) =>
+                {
+                    format!("Dist tag {} not found in registry", result.current_version)
+                }
+                Some(_) => format!("Version {} not found in registry", result.current_version),
+                None => format!("Package {} not found in registry", package.name),
+            },
+        ),
+        VersionStatus::Yanked => (
+            DiagnosticKind::Yanked,
             DiagnosticSeverity::ERROR,
-            format!("Version {} not found in registry", result.current_version),
+            format!("Version {} has been yanked", result.current_version),
         ),
         VersionStatus::Invalid => (
+            DiagnosticKind::Invalid,
             DiagnosticSeverity::ERROR,
             format!("Invalid version format: {}", result.current_version),
         ),
+        VersionStatus::Unreachable => (
+            DiagnosticKind::Unreachable,
+            DiagnosticSeverity::INFORMATION,
+            "Could not verify latest version (network error)".to_string(),
+        ),
     };
 
     let range = Range {
@@ -72,19 +270,120 @@ fn create_diagnostic(package: &PackageInfo, result: &VersionCompareResult) -> Op
     Some(Diagnostic {
         range,
         severity: Some(severity),
+        code: Some(NumberOrString::String(kind.as_str().to_string())),
         message,
         source: Some(PACKAGE_NAME.to_string()),
         ..Default::default()
     })
 }
 
+/// Message for an outdated package, mentioning the newest version the range
+/// allows (range-aware updates) or a newer patch in the current minor when
+/// there is one
+fn outdated_message(result: &VersionCompareResult) -> String {
+    let latest = result.latest_version.as_deref().unwrap_or("unknown");
+    if let Some(in_range) = &result.latest_in_range {
+        return format!(
+            "Update available within range: {} -> {} (latest: {})",
+            result.current_version, in_range, latest
+        );
+    }
+    match &result.latest_patch {
+        Some(patch) => format!(
+            "Update available: {} -> {} (latest in minor), {} (latest)",
+            result.current_version, patch, latest
+        ),
+        None => format!("Update available: {} -> {}", result.current_version, latest),
+    }
+}
+
+/// Date a flake input was locked at, from its `lastModified`
+fn locked_date(package: &PackageInfo) -> Option<String> {
+    let Some(ExtraInfo::FlakeLock { last_modified }) = package.extra_info else {
+        return None;
+    };
+    DateTime::from_timestamp(last_modified, 0).map(|date| date.format("%Y-%m-%d").to_string())
+}
+
+/// Order diagnostics by kind priority and apply the per-document cap
+///
+/// Kinds earlier in `priority` come first; kinds not listed (and diagnostics
+/// without a kind) come last. The sort is stable, so document order is kept
+/// within a kind. When `max` is set, lower-priority diagnostics are dropped first.
+pub fn prioritize_diagnostics(
+    mut diagnostics: Vec<Diagnostic>,
+    priority: &[DiagnosticKind],
+    max: Option<usize>,
+) -> Vec<Diagnostic> {
+    diagnostics.sort_by_key(|diagnostic| {
+        diagnostic_kind(diagnostic)
+            .and_then(|kind| priority.iter().position(|p| *p == kind))
+            .unwrap_or(priority.len())
+    });
+    if let Some(max) = max {
+        diagnostics.truncate(max);
+    }
+    diagnostics
+}
+
+/// Summary of the outdated diagnostics of a document, per `summarizeDiagnostics`
+///
+/// Returns an INFORMATION diagnostic at the top of the document counting the
+/// outdated diagnostics, which should be published before the (possibly
+/// capped) rest. In [`DiagnosticSummary::SummaryOnly`] mode the outdated
+/// diagnostics are removed from `diagnostics`. Ignored packages have no
+/// diagnostics, so they are never counted.
+pub fn summarize_diagnostics(
+    diagnostics: &mut Vec<Diagnostic>,
+    mode: DiagnosticSummary,
+) -> Option<Diagnostic> {
+    if mode == DiagnosticSummary::Off {
+        return None;
+    }
+
+    let is_outdated = |d: &Diagnostic| diagnostic_kind(d) == Some(DiagnosticKind::Outdated);
+    let outdated = diagnostics.iter().filter(|d| is_outdated(d)).count();
+    if mode == DiagnosticSummary::SummaryOnly {
+        diagnostics.retain(|d| !is_outdated(d));
+    }
+    if outdated == 0 {
+        return None;
+    }
+
+    let message = if outdated == 1 {
+        "1 dependency has an update available".to_string()
+    } else {
+        format!("{} dependencies have updates available", outdated)
+    };
+    Some(Diagnostic {
+        range: Range::default(),
+        severity: Some(DiagnosticSeverity::INFORMATION),
+        source: Some(PACKAGE_NAME.to_string()),
+        message,
+        ..Default::default()
+    })
+}
+
+/// Read the kind back from a diagnostic's code
+pub(crate) fn diagnostic_kind(diagnostic: &Diagnostic) -> Option<DiagnosticKind> {
+    match diagnostic.code.as_ref()? {
+        NumberOrString::String(code) => code.parse().ok(),
+        NumberOrString::Number(_) => None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config::Severity;
+    use crate::parser::cargo_toml::CargoTomlParser;
     use crate::parser::traits::MockParser;
-    use crate::parser::types::RegistryType;
+    use crate::parser::types::{ExtraInfo, RegistryType};
     use crate::version::checker::MockVersionStorer;
-    use crate::version::matchers::GitHubActionsMatcher;
+    use crate::version::matchers::{
+        CratesVersionMatcher, DockerVersionMatcher, GitHubActionsMatcher, GoVersionMatcher,
+        NpmVersionMatcher,
+    };
     use rstest::rstest;
 
     fn make_package_info(name: &str, version: &str, line: usize, column: usize) -> PackageInfo {
@@ -128,6 +427,7 @@ mod tests {
     ) {
         let version = current_version.to_string();
         let mut parser = MockParser::new();
+        parser.expect_has_syntax_errors().return_const(false);
         parser.expect_parse().returning(move |_| {
             Ok(vec![make_package_info(
                 "actions/checkout",
@@ -153,18 +453,304 @@ mod tests {
                 Ok(vec!["4.0.0".to_string()])
             }
         });
-        let matcher = GitHubActionsMatcher;
+        storer
+            .expect_get_yanked_versions()
+            .returning(|_, _| Ok(vec![]));
+        let matchers = Matchers::from(GitHubActionsMatcher);
 
-        let diagnostics = generate_diagnostics(&parser, &matcher, &storer, "content");
+        let diagnostics = generate_diagnostics(
+            &parser,
+            &matchers,
+            &CompareOptions::default(),
+            &storer,
+            "content",
+            &SeverityConfig::default(),
+        );
 
         assert_eq!(diagnostics.len(), 1);
         assert_eq!(diagnostics[0].severity, Some(expected_severity));
         assert_eq!(diagnostics[0].message, expected_message);
     }
 
+    #[test]
+    fn generate_diagnostics_reports_yanked_version() {
+        let mut parser = MockParser::new();
+        parser.expect_has_syntax_errors().return_const(false);
+        parser
+            .expect_parse()
+            .returning(|_| Ok(vec![make_package_info("actions/checkout", "3.0.1", 5, 14)]));
+
+        let mut storer = MockVersionStorer::new();
+        storer
+            .expect_get_latest_version()
+            .returning(|_, _| Ok(Some("4.0.0".to_string())));
+        storer.expect_get_dist_tag().returning(|_, _, _| Ok(None));
+        storer
+            .expect_get_versions()
+            .returning(|_, _| Ok(vec!["3.0.0".to_string(), "4.0.0".to_string()]));
+        storer
+            .expect_get_yanked_versions()
+            .returning(|_, _| Ok(vec!["3.0.1".to_string()]));
+
+        let diagnostics = generate_diagnostics(
+            &parser,
+            &Matchers::from(GitHubActionsMatcher),
+            &CompareOptions::default(),
+            &storer,
+            "content",
+            &SeverityConfig::default(),
+        );
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].severity, Some(DiagnosticSeverity::ERROR));
+        assert_eq!(
+            diagnostics[0].code,
+            Some(NumberOrString::String("yanked".to_string()))
+        );
+        assert_eq!(diagnostics[0].message, "Version 3.0.1 has been yanked");
+    }
+
+    #[test]
+    fn generate_diagnostics_uses_configured_severity() {
+        let mut parser = MockParser::new();
+        parser.expect_has_syntax_errors().return_const(false);
+        parser
+            .expect_parse()
+            .returning(|_| Ok(vec![make_package_info("actions/checkout", "3.0.0", 5, 14)]));
+
+        let mut storer = MockVersionStorer::new();
+        storer
+            .expect_get_latest_version()
+            .returning(|_, _| Ok(Some("4.0.0".to_string())));
+        storer.expect_get_dist_tag().returning(|_, _, _| Ok(None));
+        storer
+            .expect_get_versions()
+            .returning(|_, _| Ok(vec!["3.0.0".to_string(), "4.0.0".to_string()]));
+        let severity = SeverityConfig {
+            outdated: Severity::Information,
+            ..SeverityConfig::default()
+        };
+
+        let diagnostics = generate_diagnostics(
+            &parser,
+            &Matchers::from(GitHubActionsMatcher),
+            &CompareOptions::default(),
+            &storer,
+            "content",
+            &severity,
+        );
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(
+            diagnostics[0].severity,
+            Some(DiagnosticSeverity::INFORMATION)
+        );
+        assert_eq!(diagnostics[0].message, "Update available: 3.0.0 -> 4.0.0");
+    }
+
+    #[test]
+    fn generate_diagnostics_keeps_go_v_prefix_in_message() {
+        let mut parser = MockParser::new();
+        parser.expect_has_syntax_errors().return_const(false);
+        parser.expect_parse().returning(|_| {
+            Ok(vec![PackageInfo {
+                registry_type: RegistryType::GoProxy,
+                ..make_package_info("golang.org/x/text", "v0.12.0", 3, 22)
+            }])
+        });
+
+        let mut storer = MockVersionStorer::new();
+        storer
+            .expect_get_latest_version()
+            .returning(|_, _| Ok(Some("v0.14.0".to_string())));
+        storer.expect_get_dist_tag().returning(|_, _, _| Ok(None));
+        storer
+            .expect_get_versions()
+            .returning(|_, _| Ok(vec!["v0.12.0".to_string(), "v0.14.0".to_string()]));
+
+        let diagnostics = generate_diagnostics(
+            &parser,
+            &Matchers::from(GoVersionMatcher),
+            &CompareOptions::default(),
+            &storer,
+            "content",
+            &SeverityConfig::default(),
+        );
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(
+            diagnostics[0].message,
+            "Update available: v0.12.0 -> v0.14.0"
+        );
+    }
+
+    #[rstest]
+    #[case(None, None, "Update available: ~1.2.0 -> 1.3.4")]
+    #[case(
+        Some("1.2.9"),
+        None,
+        "Update available: ~1.2.0 -> 1.2.9 (latest in minor), 1.3.4 (latest)"
+    )]
+    #[case(
+        None,
+        Some("1.2.9"),
+        "Update available within range: ~1.2.0 -> 1.2.9 (latest: 1.3.4)"
+    )]
+    fn outdated_message_mentions_closer_updates(
+        #[case] latest_patch: Option<&str>,
+        #[case] latest_in_range: Option<&str>,
+        #[case] expected: &str,
+    ) {
+        let result = VersionCompareResult {
+            current_version: "~1.2.0".to_string(),
+            latest_version: Some("1.3.4".to_string()),
+            latest_patch: latest_patch.map(str::to_string),
+            latest_in_range: latest_in_range.map(str::to_string),
+            status: VersionStatus::Outdated,
+        };
+
+        assert_eq!(outdated_message(&result), expected);
+    }
+
+    #[test]
+    fn create_diagnostic_mentions_when_flake_input_was_locked() {
+        let package = PackageInfo {
+            commit_hash: Some("805a384895c696f802a9bf5bf4720f37385df547".to_string()),
+            registry_type: RegistryType::NixFlake,
+            end_offset: 56,
+            extra_info: Some(ExtraInfo::FlakeLock {
+                last_modified: 1717144377,
+            }),
+            ..make_package_info("NixOS/nixpkgs/nixos-24.05", "805a384", 22, 16)
+        };
+        let result = VersionCompareResult {
+            current_version: "805a384".to_string(),
+            latest_version: Some("9f4128e".to_string()),
+            latest_patch: None,
+            latest_in_range: None,
+            status: VersionStatus::Outdated,
+        };
+
+        let diagnostic = create_diagnostic(&package, &result, &SeverityConfig::default()).unwrap();
+
+        assert_eq!(
+            diagnostic.message,
+            "Update available: 805a384 -> 9f4128e (locked 2024-05-31)"
+        );
+        assert_eq!(diagnostic.range.end, Position::new(22, 56));
+    }
+
+    #[test]
+    fn generate_diagnostics_skips_images_pinned_by_digest() {
+        let mut parser = MockParser::new();
+        parser.expect_has_syntax_errors().return_const(false);
+        parser.expect_parse().returning(|_| {
+            Ok(vec![PackageInfo {
+                commit_hash: Some("sha256:abc123".to_string()),
+                registry_type: RegistryType::Docker,
+                ..make_package_info("library/node", "18.0.0", 0, 10)
+            }])
+        });
+
+        // The storer is never consulted for digest-pinned images
+        let storer = MockVersionStorer::new();
+        let matchers = Matchers::from(DockerVersionMatcher);
+
+        let diagnostics = generate_diagnostics(
+            &parser,
+            &matchers,
+            &CompareOptions::default(),
+            &storer,
+            "content",
+            &SeverityConfig::default(),
+        );
+
+        assert!(diagnostics.is_empty());
+    }
+
+    fn make_commit_pinned_package(comment: Option<&str>) -> PackageInfo {
+        let hash = "8e5e7e5ab8b370d6c329ec480221332ada57f0ab";
+        PackageInfo {
+            version: comment.unwrap_or(hash).to_string(),
+            commit_hash: Some(hash.to_string()),
+            end_offset: 14 + hash.len(),
+            extra_info: comment.map(|comment| ExtraInfo::GitHubActions {
+                comment_text: comment.to_string(),
+                comment_start_offset: 14 + hash.len() + 1,
+                comment_end_offset: 14 + hash.len() + 3 + comment.len(),
+            }),
+            ..make_package_info("actions/checkout", "", 5, 14)
+        }
+    }
+
+    #[rstest]
+    #[case("v4.1.6", Some("Update available: v4.1.6 -> v4.2.0"))]
+    #[case("v4.2.0", None)]
+    fn generate_diagnostics_checks_commit_pin_by_version_comment(
+        #[case] comment: &str,
+        #[case] expected_message: Option<&str>,
+    ) {
+        let package = make_commit_pinned_package(Some(comment));
+        let mut parser = MockParser::new();
+        parser.expect_has_syntax_errors().return_const(false);
+        parser
+            .expect_parse()
+            .returning(move |_| Ok(vec![package.clone()]));
+
+        let mut storer = MockVersionStorer::new();
+        storer
+            .expect_get_latest_version()
+            .returning(|_, _| Ok(Some("v4.2.0".to_string())));
+        storer.expect_get_dist_tag().returning(|_, _, _| Ok(None));
+        storer
+            .expect_get_versions()
+            .returning(|_, _| Ok(vec!["v4.1.6".to_string(), "v4.2.0".to_string()]));
+
+        let diagnostics = generate_diagnostics(
+            &parser,
+            &Matchers::from(GitHubActionsMatcher),
+            &CompareOptions::default(),
+            &storer,
+            "content",
+            &SeverityConfig::default(),
+        );
+
+        let messages: Vec<_> = diagnostics.iter().map(|d| d.message.as_str()).collect();
+        assert_eq!(messages, expected_message.into_iter().collect::<Vec<_>>());
+        // The warning spans the pinned hash
+        if let Some(diagnostic) = diagnostics.first() {
+            assert_eq!(diagnostic.range.start.character, 14);
+            assert_eq!(diagnostic.range.end.character, 54);
+        }
+    }
+
+    #[test]
+    fn generate_diagnostics_skips_commit_pin_without_version_comment() {
+        let mut parser = MockParser::new();
+        parser.expect_has_syntax_errors().return_const(false);
+        parser
+            .expect_parse()
+            .returning(|_| Ok(vec![make_commit_pinned_package(None)]));
+
+        // The storer is never consulted for a hash with no version to compare
+        let storer = MockVersionStorer::new();
+
+        let diagnostics = generate_diagnostics(
+            &parser,
+            &Matchers::from(GitHubActionsMatcher),
+            &CompareOptions::default(),
+            &storer,
+            "content",
+            &SeverityConfig::default(),
+        );
+
+        assert!(diagnostics.is_empty());
+    }
+
     #[test]
     fn generate_diagnostics_returns_empty_for_latest_package() {
         let mut parser = MockParser::new();
+        parser.expect_has_syntax_errors().return_const(false);
         parser
             .expect_parse()
             .returning(|_| Ok(vec![make_package_info("actions/checkout", "4.0.0", 5, 14)]));
@@ -177,9 +763,16 @@ mod tests {
         storer
             .expect_get_versions()
             .returning(|_, _| Ok(vec!["4.0.0".to_string()]));
-        let matcher = GitHubActionsMatcher;
+        let matchers = Matchers::from(GitHubActionsMatcher);
 
-        let diagnostics = generate_diagnostics(&parser, &matcher, &storer, "content");
+        let diagnostics = generate_diagnostics(
+            &parser,
+            &matchers,
+            &CompareOptions::default(),
+            &storer,
+            "content",
+            &SeverityConfig::default(),
+        );
 
         assert!(diagnostics.is_empty());
     }
@@ -187,6 +780,7 @@ mod tests {
     #[test]
     fn generate_diagnostics_skips_packages_not_in_cache() {
         let mut parser = MockParser::new();
+        parser.expect_has_syntax_errors().return_const(false);
         parser
             .expect_parse()
             .returning(|_| Ok(vec![make_package_info("actions/checkout", "4.0.0", 5, 14)]));
@@ -195,19 +789,205 @@ mod tests {
         storer
             .expect_get_latest_version()
             .returning(|_, _| Ok(None));
-        let matcher = GitHubActionsMatcher;
+        storer.expect_is_not_found().return_const(false);
+        storer.expect_is_unreachable().return_const(false);
+        let matchers = Matchers::from(GitHubActionsMatcher);
 
-        let diagnostics = generate_diagnostics(&parser, &matcher, &storer, "content");
+        let diagnostics = generate_diagnostics(
+            &parser,
+            &matchers,
+            &CompareOptions::default(),
+            &storer,
+            "content",
+            &SeverityConfig::default(),
+        );
 
         assert!(diagnostics.is_empty());
     }
 
+    #[test]
+    fn generate_diagnostics_reports_unreachable_registry_as_information() {
+        let mut parser = MockParser::new();
+        parser.expect_has_syntax_errors().return_const(false);
+        parser
+            .expect_parse()
+            .returning(|_| Ok(vec![make_package_info("actions/checkout", "4.0.0", 5, 14)]));
+
+        let mut storer = MockVersionStorer::new();
+        storer
+            .expect_get_latest_version()
+            .returning(|_, _| Ok(None));
+        storer.expect_is_not_found().return_const(false);
+        storer.expect_is_unreachable().return_const(true);
+        let matchers = Matchers::from(GitHubActionsMatcher);
+
+        let diagnostics = generate_diagnostics(
+            &parser,
+            &matchers,
+            &CompareOptions::default(),
+            &storer,
+            "content",
+            &SeverityConfig::default(),
+        );
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(
+            diagnostics[0].severity,
+            Some(DiagnosticSeverity::INFORMATION)
+        );
+        assert_eq!(
+            diagnostics[0].code,
+            Some(NumberOrString::String("unreachable".to_string()))
+        );
+        assert_eq!(
+            diagnostics[0].message,
+            "Could not verify latest version (network error)"
+        );
+    }
+
+    #[test]
+    fn generate_diagnostics_reports_package_known_not_found() {
+        let mut parser = MockParser::new();
+        parser.expect_has_syntax_errors().return_const(false);
+        parser
+            .expect_parse()
+            .returning(|_| Ok(vec![make_package_info("actions/chekout", "4.0.0", 5, 14)]));
+
+        let mut storer = MockVersionStorer::new();
+        storer
+            .expect_get_latest_version()
+            .returning(|_, _| Ok(None));
+        storer.expect_is_not_found().return_const(true);
+        let matchers = Matchers::from(GitHubActionsMatcher);
+
+        let diagnostics = generate_diagnostics(
+            &parser,
+            &matchers,
+            &CompareOptions::default(),
+            &storer,
+            "content",
+            &SeverityConfig::default(),
+        );
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(
+            diagnostics[0].code,
+            Some(NumberOrString::String("not-found".to_string()))
+        );
+        assert_eq!(
+            diagnostics[0].message,
+            "Package actions/chekout not found in registry"
+        );
+    }
+
+    #[test]
+    fn generate_diagnostics_reports_unpublished_npm_dist_tag() {
+        let mut parser = MockParser::new();
+        parser.expect_has_syntax_errors().return_const(false);
+        parser.expect_parse().returning(|_| {
+            Ok(vec![PackageInfo {
+                registry_type: RegistryType::Npm,
+                ..make_package_info("lodash", "nightly", 3, 15)
+            }])
+        });
+
+        let mut storer = MockVersionStorer::new();
+        storer
+            .expect_get_latest_version()
+            .returning(|_, _| Ok(Some("4.17.21".to_string())));
+        storer
+            .expect_get_dist_tag()
+            .returning(|_, _, tag| Ok((tag == "latest").then(|| "4.17.21".to_string())));
+
+        let diagnostics = generate_diagnostics(
+            &parser,
+            &Matchers::from(NpmVersionMatcher),
+            &CompareOptions::default(),
+            &storer,
+            "content",
+            &SeverityConfig::default(),
+        );
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(
+            diagnostics[0].code,
+            Some(NumberOrString::String("not-found".to_string()))
+        );
+        assert_eq!(
+            diagnostics[0].message,
+            "Dist tag nightly not found in registry"
+        );
+    }
+
+    #[tokio::test]
+    async fn generate_diagnostics_reports_failed_fetch_only_for_that_package() {
+        use crate::lsp::refresh::fetch_missing_packages;
+        use crate::version::cache::Cache;
+        use crate::version::error::RegistryError;
+        use crate::version::registry::MockRegistry;
+        use crate::version::types::PackageVersions;
+
+        let packages = vec![
+            make_package_info("actions/checkout", "v4.0.0", 5, 14),
+            make_package_info("actions/setup-node", "v3.0.0", 6, 16),
+        ];
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let cache = Cache::new(&temp_dir.path().join("test.db"), 86400000, false).unwrap();
+        let mut registry = MockRegistry::new();
+        registry
+            .expect_registry_type()
+            .returning(|| RegistryType::GitHubActions);
+        registry
+            .expect_fetch_all_versions()
+            .returning(|name| match name {
+                "actions/checkout" => Err(RegistryError::InvalidResponse("500".to_string())),
+                _ => Ok(PackageVersions::new(vec![
+                    "v3.0.0".to_string(),
+                    "v4.0.0".to_string(),
+                ])),
+            });
+        fetch_missing_packages(&cache, &registry, &packages).await;
+
+        let mut parser = MockParser::new();
+
+        parser.expect_has_syntax_errors().return_const(false);
+        parser.expect_parse().return_once(move |_| Ok(packages));
+        let diagnostics = generate_diagnostics(
+            &parser,
+            &Matchers::from(GitHubActionsMatcher),
+            &CompareOptions::default(),
+            &cache,
+            "content",
+            &SeverityConfig::default(),
+        );
+
+        assert_eq!(
+            diagnostics
+                .iter()
+                .map(|d| (d.range.start.line, d.severity, d.message.as_str()))
+                .collect::<Vec<_>>(),
+            vec![
+                (
+                    5,
+                    Some(DiagnosticSeverity::INFORMATION),
+                    "Could not verify latest version (network error)"
+                ),
+                (
+                    6,
+                    Some(DiagnosticSeverity::WARNING),
+                    "Update available: v3.0.0 -> v4.0.0"
+                ),
+            ]
+        );
+    }
+
     #[test]
     fn generate_diagnostics_skips_version_newer_than_latest() {
         // When a version exists but is newer than the "latest" dist-tag
         // (e.g., ag-grid 33.0.3 exists but dist-tags.latest is 32.3.9)
         // we should NOT show any diagnostic
         let mut parser = MockParser::new();
+        parser.expect_has_syntax_errors().return_const(false);
         parser
             .expect_parse()
             .returning(|_| Ok(vec![make_package_info("actions/checkout", "5.0.0", 5, 14)]));
@@ -220,9 +1000,16 @@ mod tests {
         storer
             .expect_get_versions()
             .returning(|_, _| Ok(vec!["5.0.0".to_string(), "4.0.0".to_string()]));
-        let matcher = GitHubActionsMatcher;
+        let matchers = Matchers::from(GitHubActionsMatcher);
 
-        let diagnostics = generate_diagnostics(&parser, &matcher, &storer, "content");
+        let diagnostics = generate_diagnostics(
+            &parser,
+            &matchers,
+            &CompareOptions::default(),
+            &storer,
+            "content",
+            &SeverityConfig::default(),
+        );
 
         // Version 5.0.0 exists and is newer than latest (4.0.0) - no diagnostic
         assert!(diagnostics.is_empty());
@@ -231,6 +1018,7 @@ mod tests {
     #[test]
     fn generate_diagnostics_calculates_correct_range() {
         let mut parser = MockParser::new();
+        parser.expect_has_syntax_errors().return_const(false);
         parser.expect_parse().returning(|_| {
             Ok(vec![PackageInfo {
                 name: "actions/checkout".to_string(),
@@ -253,9 +1041,16 @@ mod tests {
         storer
             .expect_get_versions()
             .returning(|_, _| Ok(vec!["3.0.0".to_string(), "4.0.0".to_string()]));
-        let matcher = GitHubActionsMatcher;
+        let matchers = Matchers::from(GitHubActionsMatcher);
 
-        let diagnostics = generate_diagnostics(&parser, &matcher, &storer, "content");
+        let diagnostics = generate_diagnostics(
+            &parser,
+            &matchers,
+            &CompareOptions::default(),
+            &storer,
+            "content",
+            &SeverityConfig::default(),
+        );
 
         assert_eq!(diagnostics.len(), 1);
         assert_eq!(
@@ -272,4 +1067,165 @@ mod tests {
             }
         );
     }
+
+    #[rstest]
+    #[case("node16", 1)]
+    #[case("node12", 1)]
+    #[case("node20", 0)]
+    fn generate_node_runtime_diagnostics_warns_on_eol_runtime(
+        #[case] runtime: &str,
+        #[case] expected_count: usize,
+    ) {
+        let content = format!("runs:\n  using: {}\n  main: dist/index.js\n", runtime);
+
+        let diagnostics = generate_node_runtime_diagnostics(&content);
+
+        assert_eq!(diagnostics.len(), expected_count);
+        if let Some(diagnostic) = diagnostics.first() {
+            assert_eq!(diagnostic.severity, Some(DiagnosticSeverity::WARNING));
+            assert_eq!(
+                diagnostic.message,
+                format!("Runtime {} is end-of-life; use node20 or later", runtime)
+            );
+            assert_eq!(
+                diagnostic.range,
+                Range {
+                    start: Position {
+                        line: 1,
+                        character: 9
+                    },
+                    end: Position {
+                        line: 1,
+                        character: 15
+                    },
+                }
+            );
+        }
+    }
+
+    fn make_diagnostic(kind: DiagnosticKind, line: u32) -> Diagnostic {
+        Diagnostic {
+            range: Range {
+                start: Position { line, character: 0 },
+                end: Position { line, character: 1 },
+            },
+            code: Some(NumberOrString::String(kind.as_str().to_string())),
+            ..Default::default()
+        }
+    }
+
+    #[rstest]
+    #[case(DiagnosticSummary::Summary, 4)]
+    #[case(DiagnosticSummary::SummaryOnly, 1)]
+    fn summarize_diagnostics_counts_outdated_dependencies(
+        #[case] mode: DiagnosticSummary,
+        #[case] expected_remaining: usize,
+    ) {
+        let mut diagnostics = vec![
+            make_diagnostic(DiagnosticKind::Outdated, 2),
+            make_diagnostic(DiagnosticKind::NotFound, 3),
+            make_diagnostic(DiagnosticKind::Outdated, 4),
+            make_diagnostic(DiagnosticKind::Outdated, 5),
+        ];
+
+        let summary = summarize_diagnostics(&mut diagnostics, mode).expect("Expected a summary");
+
+        assert_eq!(summary.message, "3 dependencies have updates available");
+        assert_eq!(summary.severity, Some(DiagnosticSeverity::INFORMATION));
+        assert_eq!(summary.range.start.line, 0);
+        assert_eq!(diagnostics.len(), expected_remaining);
+    }
+
+    #[rstest]
+    #[case(DiagnosticSummary::Off, vec![DiagnosticKind::Outdated])]
+    #[case(DiagnosticSummary::Summary, vec![DiagnosticKind::NotFound])]
+    fn summarize_diagnostics_skips_summary_without_updates_or_when_off(
+        #[case] mode: DiagnosticSummary,
+        #[case] kinds: Vec<DiagnosticKind>,
+    ) {
+        let mut diagnostics: Vec<Diagnostic> = kinds
+            .into_iter()
+            .map(|kind| make_diagnostic(kind, 1))
+            .collect();
+
+        assert_eq!(summarize_diagnostics(&mut diagnostics, mode), None);
+        assert_eq!(diagnostics.len(), 1);
+    }
+
+    #[test]
+    fn prioritize_diagnostics_orders_by_priority_and_caps() {
+        let diagnostics = vec![
+            make_diagnostic(DiagnosticKind::Outdated, 0),
+            make_diagnostic(DiagnosticKind::NotFound, 1),
+            make_diagnostic(DiagnosticKind::Outdated, 2),
+            make_diagnostic(DiagnosticKind::Yanked, 3),
+            make_diagnostic(DiagnosticKind::Invalid, 4),
+            make_diagnostic(DiagnosticKind::Deprecated, 5),
+        ];
+
+        let result =
+            prioritize_diagnostics(diagnostics, &DiagnosticKind::default_priority(), Some(4));
+
+        let kinds: Vec<(Option<DiagnosticKind>, u32)> = result
+            .iter()
+            .map(|d| (diagnostic_kind(d), d.range.start.line))
+            .collect();
+        assert_eq!(
+            kinds,
+            vec![
+                (Some(DiagnosticKind::Yanked), 3),
+                (Some(DiagnosticKind::Deprecated), 5),
+                (Some(DiagnosticKind::NotFound), 1),
+                (Some(DiagnosticKind::Invalid), 4),
+            ]
+        );
+    }
+
+    #[test]
+    fn prioritize_diagnostics_places_unlisted_kinds_last_without_cap() {
+        let diagnostics = vec![
+            make_diagnostic(DiagnosticKind::Invalid, 0),
+            make_diagnostic(DiagnosticKind::Outdated, 1),
+        ];
+
+        let result = prioritize_diagnostics(diagnostics, &[DiagnosticKind::Outdated], None);
+
+        assert_eq!(result.len(), 2);
+        assert_eq!(diagnostic_kind(&result[0]), Some(DiagnosticKind::Outdated));
+        assert_eq!(diagnostic_kind(&result[1]), Some(DiagnosticKind::Invalid));
+    }
+
+    #[test]
+    fn generate_diagnostics_reports_notice_for_broken_cargo_toml() {
+        let content = r#"[dependencies]
+serde = "1.0"
+tokio = { version = "1.0"
+"#;
+        let mut storer = MockVersionStorer::new();
+        storer
+            .expect_get_latest_version()
+            .returning(|_, _| Ok(None));
+        storer.expect_is_not_found().return_const(false);
+        storer.expect_is_unreachable().return_const(false);
+
+        let diagnostics = generate_diagnostics(
+            &CargoTomlParser::new(),
+            &Matchers::from(CratesVersionMatcher),
+            &CompareOptions::default(),
+            &storer,
+            content,
+            &SeverityConfig::default(),
+        );
+
+        assert_eq!(
+            diagnostics,
+            vec![Diagnostic {
+                range: Range::default(),
+                severity: Some(DiagnosticSeverity::INFORMATION),
+                message: "version-lsp: could not fully parse this file".to_string(),
+                source: Some("version-lsp".to_string()),
+                ..Default::default()
+            }]
+        );
+    }
 }
@@ -0,0 +1,106 @@
+//! Incremental text synchronization for open documents
+
+use tower_lsp::lsp_types::{Position, TextDocumentContentChangeEvent};
+
+/// Apply a `textDocument/didChange` content change to the document text
+///
+/// Changes without a range replace the whole document.
+pub fn apply_content_change(text: &mut String, change: TextDocumentContentChangeEvent) {
+    match change.range {
+        Some(range) => {
+            let start = position_to_offset(text, range.start);
+            let end = position_to_offset(text, range.end).max(start);
+            text.replace_range(start..end, &change.text);
+        }
+        None => *text = change.text,
+    }
+}
+
+/// Convert an LSP position (UTF-16 code units) to a byte offset in `text`
+///
+/// Positions past the end of a line or of the document are clamped.
+fn position_to_offset(text: &str, position: Position) -> usize {
+    let mut line_start = 0;
+    for _ in 0..position.line {
+        match text[line_start..].find('\n') {
+            Some(i) => line_start += i + 1,
+            None => return text.len(),
+        }
+    }
+
+    let line_end = text[line_start..]
+        .find('\n')
+        .map_or(text.len(), |i| line_start + i);
+
+    let mut units = 0;
+    for (i, c) in text[line_start..line_end].char_indices() {
+        if units >= position.character as usize {
+            return line_start + i;
+        }
+        units += c.len_utf16();
+    }
+
+    line_end
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use tower_lsp::lsp_types::Range;
+
+    fn change(
+        range: Option<((u32, u32), (u32, u32))>,
+        text: &str,
+    ) -> TextDocumentContentChangeEvent {
+        TextDocumentContentChangeEvent {
+            range: range.map(|((sl, sc), (el, ec))| Range {
+                start: Position::new(sl, sc),
+                end: Position::new(el, ec),
+            }),
+            range_length: None,
+            text: text.to_string(),
+        }
+    }
+
+    #[rstest]
+    // Replace a version in place
+    #[case(
+        "{\n  \"lodash\": \"^4.17.0\"\n}",
+        Some(((1, 13), (1, 20))),
+        "^4.17.21",
+        "{\n  \"lodash\": \"^4.17.21\"\n}"
+    )]
+    // Insertion at a single position
+    #[case("ab\ncd", Some(((1, 1), (1, 1))), "X", "ab\ncXd")]
+    // Deletion across lines
+    #[case("ab\ncd\nef", Some(((0, 1), (2, 1))), "", "af")]
+    // Positions past the end are clamped
+    #[case("ab\ncd", Some(((1, 10), (5, 0))), "!", "ab\ncd!")]
+    // Columns count UTF-16 code units
+    #[case("\"😀\": \"1.0\"", Some(((0, 7), (0, 10))), "2.0", "\"😀\": \"2.0\"")]
+    // No range replaces the whole document
+    #[case("old", None, "new", "new")]
+    fn apply_content_change_returns_expected(
+        #[case] initial: &str,
+        #[case] range: Option<((u32, u32), (u32, u32))>,
+        #[case] text: &str,
+        #[case] expected: &str,
+    ) {
+        let mut document = initial.to_string();
+
+        apply_content_change(&mut document, change(range, text));
+
+        assert_eq!(document, expected);
+    }
+
+    #[test]
+    fn apply_content_change_applies_changes_in_order() {
+        let mut document = "serde = \"1.0\"\n".to_string();
+
+        apply_content_change(&mut document, change(Some(((0, 9), (0, 12))), "1.0.2"));
+        apply_content_change(&mut document, change(Some(((0, 14), (0, 14))), "10"));
+
+        assert_eq!(document, "serde = \"1.0.210\"\n");
+    }
+}
@@ -0,0 +1,308 @@
+//! Hover content showing the latest version and status of a package
+
+use tower_lsp::lsp_types::{Hover, HoverContents, MarkupContent, MarkupKind, Position, Range};
+use tracing::warn;
+
+use crate::parser::types::PackageInfo;
+use crate::version::checker::{
+    CompareOptions, VersionLag, VersionStatus, VersionStorer, compare_package, version_lag,
+};
+use crate::version::matcher::VersionMatcher;
+
+/// Generate hover content for a package
+///
+/// The hover covers the version string and reads versions from the cache only;
+/// packages not cached yet are reported as being fetched, unless their
+/// registry could not be reached. Outdated packages also show how many
+/// releases and how much time they are behind.
+pub fn generate_hover<S: VersionStorer>(
+    storer: &S,
+    matcher: &dyn VersionMatcher,
+    options: &CompareOptions,
+    package: &PackageInfo,
+) -> Hover {
+    let result = compare_package(storer, matcher, package, options)
+        .inspect_err(|e| warn!("Failed to compare version for {}: {}", package.name, e))
+        .ok();
+
+    let status = match result
+        .as_ref()
+        .map(|r| (r.latest_version.as_deref(), r.status))
+    {
+        Some((Some(latest), VersionStatus::Outdated)) => {
+            let lag = version_lag(
+                storer,
+                package.registry_type,
+                &package.name,
+                &package.version,
+                latest,
+            )
+            .inspect_err(|e| warn!("Failed to read release history of {}: {}", package.name, e))
+            .ok()
+            .flatten();
+            let status = format!(
+                "latest: {} ({})",
+                latest,
+                status_label(VersionStatus::Outdated)
+            );
+            match lag {
+                Some(lag) => format!("{} · {}", status, lag_label(lag)),
+                None => status,
+            }
+        }
+        Some((Some(latest), status)) => {
+            format!("latest: {} ({})", latest, status_label(status))
+        }
+        Some((None, VersionStatus::Unreachable)) => {
+            status_label(VersionStatus::Unreachable).to_string()
+        }
+        _ => "fetching…".to_string(),
+    };
+
+    Hover {
+        contents: HoverContents::Markup(MarkupContent {
+            kind: MarkupKind::Markdown,
+            value: format!(
+                "**{}** current: {} · {}",
+                package.name, package.version, status
+            ),
+        }),
+        range: Some(Range {
+            start: Position {
+                line: package.line as u32,
+                character: package.column as u32,
+            },
+            end: Position {
+                line: package.line as u32,
+                character: (package.column + package.version.len()) as u32,
+            },
+        }),
+    }
+}
+
+/// Human-readable distance to the latest version, e.g. "14 releases, 8 months behind"
+fn lag_label(lag: VersionLag) -> String {
+    let releases = plural(lag.releases as i64, "release");
+    match lag.elapsed_secs.and_then(elapsed_label) {
+        Some(elapsed) => format!("{}, {} behind", releases, elapsed),
+        None => format!("{} behind", releases),
+    }
+}
+
+/// Coarse duration: days under two months, months under two years, then years
+fn elapsed_label(secs: i64) -> Option<String> {
+    let days = secs / 86_400;
+    match days {
+        0 => None,
+        1..60 => Some(plural(days, "day")),
+        60..730 => Some(plural(days / 30, "month")),
+        _ => Some(plural(days / 365, "year")),
+    }
+}
+
+fn plural(count: i64, unit: &str) -> String {
+    if count == 1 {
+        format!("1 {}", unit)
+    } else {
+        format!("{} {}s", count, unit)
+    }
+}
+
+/// Human-readable label for a version status
+pub(crate) fn status_label(status: VersionStatus) -> &'static str {
+    match status {
+        VersionStatus::Latest => "up to date",
+        VersionStatus::Outdated => "outdated",
+        VersionStatus::Newer => "newer than latest",
+        VersionStatus::Invalid => "invalid version",
+        VersionStatus::NotInCache => "not cached",
+        VersionStatus::Unreachable => "registry unreachable",
+        VersionStatus::NotFound => "not found in registry",
+        VersionStatus::Yanked => "yanked",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::types::RegistryType;
+    use crate::version::checker::MockVersionStorer;
+    use crate::version::matchers::NpmVersionMatcher;
+    use rstest::rstest;
+    use std::collections::HashMap;
+
+    fn make_package(version: &str) -> PackageInfo {
+        PackageInfo {
+            name: "lodash".to_string(),
+            version: version.to_string(),
+            commit_hash: None,
+            registry_type: RegistryType::Npm,
+            start_offset: 57,
+            end_offset: 57 + version.len(),
+            line: 3,
+            column: 15,
+            extra_info: None,
+        }
+    }
+
+    fn markdown(hover: &Hover) -> &str {
+        match &hover.contents {
+            HoverContents::Markup(content) => &content.value,
+            other => panic!("Expected markup contents, got {:?}", other),
+        }
+    }
+
+    #[rstest]
+    #[case(
+        "^4.17.0",
+        "**lodash** current: ^4.17.0 · latest: 4.17.21 (up to date)"
+    )]
+    #[case(
+        "4.17.20",
+        "**lodash** current: 4.17.20 · latest: 4.17.21 (outdated) · 1 release behind"
+    )]
+    #[case(
+        "9.9.9",
+        "**lodash** current: 9.9.9 · latest: 4.17.21 (not found in registry)"
+    )]
+    fn generate_hover_shows_latest_and_status(#[case] version: &str, #[case] expected: &str) {
+        let mut storer = MockVersionStorer::new();
+        storer
+            .expect_get_latest_version()
+            .returning(|_, _| Ok(Some("4.17.21".to_string())));
+        storer.expect_get_dist_tag().returning(|_, _, _| Ok(None));
+        storer.expect_get_versions().returning(|_, _| {
+            Ok(vec![
+                "4.17.0".to_string(),
+                "4.17.20".to_string(),
+                "4.17.21".to_string(),
+            ])
+        });
+        storer
+            .expect_get_yanked_versions()
+            .returning(|_, _| Ok(vec![]));
+        storer
+            .expect_get_published_at()
+            .returning(|_, _| Ok(HashMap::new()));
+
+        let hover = generate_hover(
+            &storer,
+            &NpmVersionMatcher,
+            &CompareOptions::default(),
+            &make_package(version),
+        );
+
+        assert_eq!(markdown(&hover), expected);
+    }
+
+    #[test]
+    fn generate_hover_reports_time_behind_latest() {
+        let mut storer = MockVersionStorer::new();
+        storer
+            .expect_get_latest_version()
+            .returning(|_, _| Ok(Some("4.17.21".to_string())));
+        storer.expect_get_dist_tag().returning(|_, _, _| Ok(None));
+        storer.expect_get_versions().returning(|_, _| {
+            Ok(vec![
+                "4.17.0".to_string(),
+                "4.17.20".to_string(),
+                "4.17.21".to_string(),
+            ])
+        });
+        storer.expect_get_published_at().returning(|_, _| {
+            Ok(HashMap::from([
+                ("4.17.0".to_string(), 1_600_000_000),
+                // 8 months (240 days) apart
+                ("4.17.21".to_string(), 1_600_000_000 + 240 * 86_400),
+            ]))
+        });
+
+        let hover = generate_hover(
+            &storer,
+            &NpmVersionMatcher,
+            &CompareOptions::default(),
+            &make_package("4.17.0"),
+        );
+
+        assert_eq!(
+            markdown(&hover),
+            "**lodash** current: 4.17.0 · latest: 4.17.21 (outdated) · 2 releases, 8 months behind"
+        );
+    }
+
+    #[rstest]
+    #[case(0, None)]
+    #[case(86_400, Some("1 day"))]
+    #[case(45 * 86_400, Some("45 days"))]
+    #[case(400 * 86_400, Some("13 months"))]
+    #[case(800 * 86_400, Some("2 years"))]
+    fn elapsed_label_rounds_to_coarse_units(#[case] secs: i64, #[case] expected: Option<&str>) {
+        assert_eq!(elapsed_label(secs).as_deref(), expected);
+    }
+
+    #[test]
+    fn generate_hover_reports_fetching_when_not_cached() {
+        let mut storer = MockVersionStorer::new();
+        storer
+            .expect_get_latest_version()
+            .returning(|_, _| Ok(None));
+        storer.expect_is_not_found().return_const(false);
+        storer.expect_is_unreachable().return_const(false);
+
+        let hover = generate_hover(
+            &storer,
+            &NpmVersionMatcher,
+            &CompareOptions::default(),
+            &make_package("4.17.20"),
+        );
+
+        assert_eq!(markdown(&hover), "**lodash** current: 4.17.20 · fetching…");
+    }
+
+    #[test]
+    fn generate_hover_reports_unreachable_registry() {
+        let mut storer = MockVersionStorer::new();
+        storer
+            .expect_get_latest_version()
+            .returning(|_, _| Ok(None));
+        storer.expect_is_not_found().return_const(false);
+        storer.expect_is_unreachable().return_const(true);
+
+        let hover = generate_hover(
+            &storer,
+            &NpmVersionMatcher,
+            &CompareOptions::default(),
+            &make_package("4.17.20"),
+        );
+
+        assert_eq!(
+            markdown(&hover),
+            "**lodash** current: 4.17.20 · registry unreachable"
+        );
+    }
+
+    #[test]
+    fn generate_hover_range_covers_version_string() {
+        let mut storer = MockVersionStorer::new();
+        storer
+            .expect_get_latest_version()
+            .returning(|_, _| Ok(None));
+        storer.expect_is_not_found().return_const(false);
+        storer.expect_is_unreachable().return_const(false);
+
+        let hover = generate_hover(
+            &storer,
+            &NpmVersionMatcher,
+            &CompareOptions::default(),
+            &make_package("4.17.20"),
+        );
+
+        assert_eq!(
+            hover.range,
+            Some(Range {
+                start: Position::new(3, 15),
+                end: Position::new(3, 22),
+            })
+        );
+    }
+}
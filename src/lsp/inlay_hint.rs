@@ -0,0 +1,184 @@
+//! Inlay hints showing the latest version after outdated packages
+
+use tower_lsp::lsp_types::{InlayHint, InlayHintLabel, InlayHintTooltip, Position, Range};
+use tracing::warn;
+
+use crate::lsp::hover::status_label;
+use crate::lsp::resolver::Matchers;
+use crate::parser::types::PackageInfo;
+use crate::version::checker::{CompareOptions, VersionStatus, VersionStorer, compare_package};
+
+/// Generate inlay hints for the outdated packages within `range`
+///
+/// Each hint is placed right after the version string and reads ` → <latest>`.
+/// Up-to-date, uncached, digest-pinned and commit-pinned packages without a
+/// version comment get no hint.
+pub fn generate_inlay_hints<S: VersionStorer>(
+    storer: &S,
+    matchers: &Matchers,
+    options: &CompareOptions,
+    packages: &[PackageInfo],
+    range: Range,
+) -> Vec<InlayHint> {
+    packages
+        .iter()
+        .filter(|package| {
+            let line = package.line as u32;
+            range.start.line <= line && line <= range.end.line
+        })
+        .filter(|package| !package.is_digest_pinned() && !package.is_unversioned_commit_pin())
+        .filter_map(|package| {
+            let result = compare_package(storer, matchers.get(package), package, options)
+                .inspect_err(|e| warn!("Failed to compare version for {}: {}", package.name, e))
+                .ok()?;
+            if result.status != VersionStatus::Outdated {
+                return None;
+            }
+            let latest = result.latest_version?;
+
+            Some(InlayHint {
+                position: Position {
+                    line: package.line as u32,
+                    character: (package.column + package.version.len()) as u32,
+                },
+                label: InlayHintLabel::String(format!(" → {}", latest)),
+                kind: None,
+                text_edits: None,
+                tooltip: Some(InlayHintTooltip::String(format!(
+                    "{} {} is {} (latest: {})",
+                    package.name,
+                    package.version,
+                    status_label(result.status),
+                    latest
+                ))),
+                padding_left: None,
+                padding_right: None,
+                data: None,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::types::RegistryType;
+    use crate::version::checker::MockVersionStorer;
+    use crate::version::matchers::NpmVersionMatcher;
+
+    fn make_package(name: &str, version: &str, line: usize) -> PackageInfo {
+        PackageInfo {
+            name: name.to_string(),
+            version: version.to_string(),
+            commit_hash: None,
+            registry_type: RegistryType::Npm,
+            start_offset: 0,
+            end_offset: version.len(),
+            line,
+            column: 15,
+            extra_info: None,
+        }
+    }
+
+    fn make_storer() -> MockVersionStorer {
+        let mut storer = MockVersionStorer::new();
+        storer
+            .expect_get_latest_version()
+            .returning(|_, _| Ok(Some("4.17.21".to_string())));
+        storer.expect_get_dist_tag().returning(|_, _, _| Ok(None));
+        storer.expect_get_versions().returning(|_, _| {
+            Ok(vec![
+                "4.17.0".to_string(),
+                "4.17.20".to_string(),
+                "4.17.21".to_string(),
+            ])
+        });
+        storer
+            .expect_get_yanked_versions()
+            .returning(|_, _| Ok(vec![]));
+        storer
+    }
+
+    fn label(hint: &InlayHint) -> &str {
+        match &hint.label {
+            InlayHintLabel::String(label) => label,
+            other => panic!("Expected string label, got {:?}", other),
+        }
+    }
+
+    fn tooltip(hint: &InlayHint) -> &str {
+        match &hint.tooltip {
+            Some(InlayHintTooltip::String(tooltip)) => tooltip,
+            other => panic!("Expected string tooltip, got {:?}", other),
+        }
+    }
+
+    fn full_range() -> Range {
+        Range::new(Position::new(0, 0), Position::new(u32::MAX, 0))
+    }
+
+    #[test]
+    fn generate_inlay_hints_shows_latest_after_outdated_version() {
+        let storer = make_storer();
+        let packages = vec![
+            make_package("lodash", "4.17.20", 3),
+            make_package("lodash-es", "^4.17.0", 4),
+        ];
+
+        let hints = generate_inlay_hints(
+            &storer,
+            &Matchers::from(NpmVersionMatcher),
+            &CompareOptions::default(),
+            &packages,
+            full_range(),
+        );
+
+        assert_eq!(hints.len(), 1);
+        assert_eq!(hints[0].position, Position::new(3, 22));
+        assert_eq!(label(&hints[0]), " → 4.17.21");
+        assert_eq!(
+            tooltip(&hints[0]),
+            "lodash 4.17.20 is outdated (latest: 4.17.21)"
+        );
+    }
+
+    #[test]
+    fn generate_inlay_hints_skips_packages_outside_range() {
+        let storer = make_storer();
+        let packages = vec![
+            make_package("lodash", "4.17.20", 3),
+            make_package("lodash-es", "4.17.20", 10),
+        ];
+
+        let hints = generate_inlay_hints(
+            &storer,
+            &Matchers::from(NpmVersionMatcher),
+            &CompareOptions::default(),
+            &packages,
+            Range::new(Position::new(5, 0), Position::new(20, 0)),
+        );
+
+        assert_eq!(hints.len(), 1);
+        assert_eq!(hints[0].position.line, 10);
+    }
+
+    #[test]
+    fn generate_inlay_hints_skips_uncached_packages() {
+        let mut storer = MockVersionStorer::new();
+        storer
+            .expect_get_latest_version()
+            .returning(|_, _| Ok(None));
+        storer.expect_is_not_found().return_const(false);
+        storer.expect_is_unreachable().return_const(false);
+
+        let hints = generate_inlay_hints(
+            &storer,
+            &Matchers::from(NpmVersionMatcher),
+            &CompareOptions::default(),
+            &[make_package("lodash", "4.17.20", 3)],
+            full_range(),
+        );
+
+        assert!(hints.is_empty());
+    }
+}
@@ -6,14 +6,28 @@
 //! # Modules
 //!
 //! - [`backend`]: Main LSP backend implementing `LanguageServer` trait
+//! - [`check`]: Checks files without an editor (`version-lsp check`)
+//! - [`code_lens`]: Shows the latest version above each dependency
+//! - [`completion`]: Completes version strings from cached registry versions
 //! - [`diagnostics`]: Generates version-related diagnostics (warnings, errors)
+//! - [`document`]: Applies incremental text changes to open documents
+//! - [`hover`]: Shows the latest version and status of a package on hover
+//! - [`inlay_hint`]: Shows the latest version inline after outdated packages
+//! - [`progress`]: Reports work done progress while packages are fetched
 //! - [`refresh`]: Background refresh logic for package version cache
 //! - [`resolver`]: Groups parser, matcher, and registry per registry type
 //! - [`server`]: LSP server initialization and lifecycle
 
 pub mod backend;
+pub mod check;
 pub mod code_action;
+pub mod code_lens;
+pub mod completion;
 pub mod diagnostics;
+pub mod document;
+pub mod hover;
+pub mod inlay_hint;
+pub mod progress;
 pub mod refresh;
 pub mod resolver;
 pub mod server;
@@ -0,0 +1,152 @@
+//! Work done progress while the packages of a document are fetched
+//!
+//! Opening a large manifest fetches its packages in the background before
+//! diagnostics are republished. Clients supporting `window.workDoneProgress`
+//! are shown "Checking dependencies (3/20)" in the meantime.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+
+use tower_lsp::Client;
+use tower_lsp::lsp_types::notification::Progress;
+use tower_lsp::lsp_types::request::WorkDoneProgressCreate;
+use tower_lsp::lsp_types::*;
+use tracing::{debug, error};
+
+use crate::parser::types::{PackageInfo, RegistryType};
+use crate::version::checker::VersionStorer;
+use crate::version::error::RegistryError;
+use crate::version::registry::Registry;
+use crate::version::types::PackageVersions;
+
+/// Title of the progress, shown next to the message by most clients
+const PROGRESS_TITLE: &str = "version-lsp";
+
+/// Counter making progress tokens unique per server
+static NEXT_TOKEN: AtomicU64 = AtomicU64::new(0);
+
+/// Progress of the fetches started for one document
+pub struct FetchProgress {
+    client: Client,
+    token: NumberOrString,
+    total: usize,
+    done: AtomicUsize,
+}
+
+impl FetchProgress {
+    /// Create a progress token on the client and report the beginning of
+    /// `total` fetches
+    ///
+    /// Returns None when the client fails to create the token.
+    pub async fn begin(client: Client, total: usize) -> Option<Self> {
+        let token = NumberOrString::String(format!(
+            "version-lsp/fetch/{}",
+            NEXT_TOKEN.fetch_add(1, Ordering::Relaxed)
+        ));
+        client
+            .send_request::<WorkDoneProgressCreate>(WorkDoneProgressCreateParams {
+                token: token.clone(),
+            })
+            .await
+            .inspect_err(|e| debug!("window/workDoneProgress/create failed: {}", e))
+            .ok()?;
+
+        let progress = Self {
+            client,
+            token,
+            total,
+            done: AtomicUsize::new(0),
+        };
+        progress
+            .notify(WorkDoneProgress::Begin(WorkDoneProgressBegin {
+                title: PROGRESS_TITLE.to_string(),
+                cancellable: Some(false),
+                message: Some(progress.message(0)),
+                percentage: Some(0),
+            }))
+            .await;
+        Some(progress)
+    }
+
+    /// Report that one more package has been fetched
+    pub async fn advance(&self) {
+        let done = (self.done.fetch_add(1, Ordering::Relaxed) + 1).min(self.total);
+        self.notify(WorkDoneProgress::Report(WorkDoneProgressReport {
+            cancellable: Some(false),
+            message: Some(self.message(done)),
+            percentage: Some((done * 100 / self.total) as u32),
+        }))
+        .await;
+    }
+
+    /// Report that the fetches are over
+    pub async fn end(&self) {
+        self.notify(WorkDoneProgress::End(WorkDoneProgressEnd { message: None }))
+            .await;
+    }
+
+    fn message(&self, done: usize) -> String {
+        format!("Checking dependencies ({}/{})", done, self.total)
+    }
+
+    async fn notify(&self, value: WorkDoneProgress) {
+        self.client
+            .send_notification::<Progress>(ProgressParams {
+                token: self.token.clone(),
+                value: ProgressParamsValue::WorkDone(value),
+            })
+            .await;
+    }
+}
+
+/// Number of packages the background fetch will request: the ones missing
+/// from the cache plus the stale ones that get revalidated
+pub fn pending_fetches<S: VersionStorer>(
+    storer: &S,
+    packages_by_registry: &HashMap<RegistryType, Vec<PackageInfo>>,
+) -> usize {
+    packages_by_registry
+        .iter()
+        .map(|(registry_type, packages)| {
+            let names: Vec<_> = packages.iter().map(|p| p.name.clone()).collect();
+            let missing = storer
+                .filter_packages_not_in_cache(*registry_type, &names)
+                .inspect_err(|e| error!("Failed to filter packages not in cache: {}", e))
+                .unwrap_or_default();
+            let stale = storer
+                .filter_stale_packages(*registry_type, &names)
+                .inspect_err(|e| error!("Failed to filter stale packages: {}", e))
+                .unwrap_or_default();
+            missing.len() + stale.len()
+        })
+        .sum()
+}
+
+/// Registry advancing a [`FetchProgress`] after every fetch
+pub struct ProgressRegistry {
+    inner: Arc<dyn Registry>,
+    progress: Arc<FetchProgress>,
+}
+
+impl ProgressRegistry {
+    pub fn new(inner: Arc<dyn Registry>, progress: Arc<FetchProgress>) -> Self {
+        Self { inner, progress }
+    }
+}
+
+#[async_trait::async_trait]
+impl Registry for ProgressRegistry {
+    fn registry_type(&self) -> RegistryType {
+        self.inner.registry_type()
+    }
+
+    async fn fetch_all_versions(
+        &self,
+        package_name: &str,
+    ) -> Result<PackageVersions, RegistryError> {
+        let result = self.inner.fetch_all_versions(package_name).await;
+        self.progress.advance().await;
+        result
+    }
+}
@@ -1,24 +1,87 @@
 //! Background refresh logic for package version cache
 
-use std::time::Duration;
+use std::future::Future;
+use std::time::{Duration, Instant};
 
-use futures::future::join_all;
+use futures::stream::{self, StreamExt};
 use tokio::time::sleep;
 use tracing::{debug, error, info};
 
-use crate::config::FETCH_STAGGER_DELAY_MS;
+use crate::config::{FETCH_CONCURRENCY, FETCH_STAGGER_DELAY_MS};
 use crate::parser::types::{PackageInfo, RegistryType};
 use crate::version::cache::PackageId;
 use crate::version::checker::VersionStorer;
 use crate::version::error::RegistryError;
 use crate::version::registry::Registry;
+use crate::version::types::PackageVersions;
+
+/// Run `fetch` for every item with at most [`FETCH_CONCURRENCY`] in flight
+///
+/// The first batch starts staggered by [`FETCH_STAGGER_DELAY_MS`] to avoid
+/// rate limiting; later fetches start as soon as a slot frees up. Results are
+/// returned in completion order.
+pub(crate) async fn fetch_concurrently<T, F, Fut>(
+    items: impl IntoIterator<Item = T>,
+    fetch: F,
+) -> Vec<Fut::Output>
+where
+    F: Fn(T) -> Fut,
+    Fut: Future,
+{
+    // Build the futures up front: mapping lazily inside the stream makes the
+    // closure generic over the item lifetime, which `tokio::spawn` rejects
+    let fetches: Vec<_> = items
+        .into_iter()
+        .enumerate()
+        .map(|(i, item)| {
+            let slot = if i < FETCH_CONCURRENCY { i } else { 0 };
+            let delay = Duration::from_millis(FETCH_STAGGER_DELAY_MS * slot as u64);
+            let fetch = fetch(item);
+            async move {
+                sleep(delay).await;
+                fetch.await
+            }
+        })
+        .collect();
+
+    stream::iter(fetches)
+        .buffer_unordered(FETCH_CONCURRENCY)
+        .collect()
+        .await
+}
+
+/// Fetch a package's versions, logging the latency and outcome at DEBUG level
+///
+/// The outcome is `hit` when the registry returned versions, `miss` when the
+/// package does not exist and `error` otherwise.
+pub(crate) async fn fetch_versions_timed(
+    registry: &dyn Registry,
+    registry_type: RegistryType,
+    package_name: &str,
+) -> Result<PackageVersions, RegistryError> {
+    let started = Instant::now();
+    let result = registry.fetch_all_versions(package_name).await;
+    let outcome = match &result {
+        Ok(_) => "hit",
+        Err(RegistryError::NotFound(_)) => "miss",
+        Err(_) => "error",
+    };
+    debug!(
+        registry = registry_type.as_str(),
+        package = package_name,
+        latency_ms = started.elapsed().as_millis() as u64,
+        outcome,
+        "Registry fetch finished"
+    );
+    result
+}
 
 /// Fetch and cache a single package's versions
 ///
 /// Handles:
 /// - Acquiring fetch lock (prevents duplicate fetches)
 /// - Fetching versions from registry
-/// - Saving versions and dist tags to cache
+/// - Saving versions, dist tags, yanked versions and publish times to cache at once
 /// - Releasing fetch lock
 ///
 /// Returns true if the package was successfully fetched and cached.
@@ -49,43 +112,24 @@ async fn fetch_and_cache_package<S: VersionStorer>(
         return false;
     }
 
-    let success = match registry.fetch_all_versions(package_name).await {
-        Ok(pkg_versions) => {
-            let version_count = pkg_versions.versions.len();
-            let save_result =
-                storer.replace_versions(registry_type, package_name, pkg_versions.versions);
-
-            if save_result
-                .inspect_err(|e| {
-                    error!(
-                        "Failed to save versions for {}/{}: {}",
-                        registry_type_str, package_name, e
-                    );
-                })
-                .is_ok()
-            {
+    let success = match fetch_versions_timed(registry, registry_type, package_name).await {
+        Ok(pkg_versions) => storer
+            .save_package(registry_type, package_name, &pkg_versions)
+            .inspect(|()| {
                 info!(
                     "Saved {} versions for {}/{}",
-                    version_count, registry_type_str, package_name
+                    pkg_versions.versions.len(),
+                    registry_type_str,
+                    package_name
                 );
-
-                // Save dist tags if available
-                if !pkg_versions.dist_tags.is_empty() {
-                    let _ = storer
-                        .save_dist_tags(registry_type, package_name, &pkg_versions.dist_tags)
-                        .inspect_err(|e| {
-                            error!(
-                                "Failed to save dist tags for {}/{}: {}",
-                                registry_type_str, package_name, e
-                            );
-                        });
-                }
-
-                true
-            } else {
-                false
-            }
-        }
+            })
+            .inspect_err(|e| {
+                error!(
+                    "Failed to save versions for {}/{}: {}",
+                    registry_type_str, package_name, e
+                );
+            })
+            .is_ok(),
         Err(RegistryError::NotFound(_)) => {
             info!(
                 "Package not found: {}/{}. Marking as not found to skip future fetches.",
@@ -106,6 +150,7 @@ async fn fetch_and_cache_package<S: VersionStorer>(
                 "Failed to fetch versions for {}/{}: {}",
                 registry_type_str, package_name, e
             );
+            storer.mark_unreachable(registry_type, package_name);
             false
         }
     };
@@ -128,27 +173,22 @@ async fn fetch_and_cache_package<S: VersionStorer>(
 /// Fetches latest versions from the registry and updates the cache.
 /// Uses try_start_fetch/finish_fetch to prevent duplicate fetches across processes.
 /// Errors are logged but do not stop processing of other packages.
-/// Fetches are executed in parallel with staggered start times to avoid rate limiting.
+/// Fetches run concurrently through [`fetch_concurrently`].
 pub async fn refresh_packages<S: VersionStorer>(
     storer: &S,
     registry: &dyn Registry,
     packages: Vec<PackageId>,
 ) {
-    let futures = packages.into_iter().enumerate().map(|(i, package)| {
-        let delay = Duration::from_millis(FETCH_STAGGER_DELAY_MS * i as u64);
-        async move {
-            sleep(delay).await;
-            fetch_and_cache_package(
-                storer,
-                registry,
-                package.registry_type,
-                &package.package_name,
-            )
-            .await;
-        }
-    });
-
-    join_all(futures).await;
+    fetch_concurrently(packages, |package| async move {
+        fetch_and_cache_package(
+            storer,
+            registry,
+            package.registry_type,
+            &package.package_name,
+        )
+        .await;
+    })
+    .await;
 }
 
 /// Fetch packages that are not in the cache (on-demand fetch)
@@ -156,7 +196,7 @@ pub async fn refresh_packages<S: VersionStorer>(
 /// Identifies packages not in cache, fetches from registry, and updates cache.
 /// Uses try_start_fetch/finish_fetch to prevent duplicate fetches across processes.
 /// Returns the list of packages that were successfully fetched and cached.
-/// Fetches are executed in parallel with staggered start times to avoid rate limiting.
+/// Fetches run concurrently through [`fetch_concurrently`].
 pub async fn fetch_missing_packages<S: VersionStorer>(
     storer: &S,
     registry: &dyn Registry,
@@ -194,27 +234,57 @@ pub async fn fetch_missing_packages<S: VersionStorer>(
         return Vec::new();
     }
 
-    let futures = packages_to_fetch
-        .into_iter()
-        .enumerate()
-        .map(|(i, package)| {
-            let delay = Duration::from_millis(FETCH_STAGGER_DELAY_MS * i as u64);
-            let package_name = package.name.clone();
-            async move {
-                sleep(delay).await;
-                info!(
-                    "Fetching missing package {}/{} from registry",
-                    package.registry_type.as_str(),
-                    package.name
-                );
-                let success =
-                    fetch_and_cache_package(storer, registry, package.registry_type, &package.name)
-                        .await;
-                if success { Some(package_name) } else { None }
-            }
-        });
+    fetch_concurrently(packages_to_fetch, |package| async move {
+        info!(
+            "Fetching missing package {}/{} from registry",
+            package.registry_type.as_str(),
+            package.name
+        );
+        let success =
+            fetch_and_cache_package(storer, registry, package.registry_type, &package.name).await;
+        success.then(|| package.name.clone())
+    })
+    .await
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+/// Revalidate packages whose cached versions are stale (stale-while-revalidate)
+///
+/// Stale versions have already been served for diagnostics; this refetches
+/// packages older than the refresh interval so diagnostics can be republished.
+/// Returns the list of packages that were successfully refreshed.
+pub async fn revalidate_stale_packages<S: VersionStorer>(
+    storer: &S,
+    registry: &dyn Registry,
+    packages: &[PackageInfo],
+) -> Vec<String> {
+    if packages.is_empty() {
+        return Vec::new();
+    }
+
+    let registry_type = packages[0].registry_type;
+    let package_names: Vec<_> = packages.iter().map(|p| p.name.clone()).collect();
 
-    join_all(futures).await.into_iter().flatten().collect()
+    let stale = storer
+        .filter_stale_packages(registry_type, &package_names)
+        .inspect_err(|e| error!("Failed to filter stale packages: {}", e))
+        .unwrap_or_default();
+
+    if stale.is_empty() {
+        return Vec::new();
+    }
+    debug!("Revalidating stale packages: {:?}", stale);
+
+    fetch_concurrently(stale, |package_name| async move {
+        let success = fetch_and_cache_package(storer, registry, registry_type, &package_name).await;
+        success.then_some(package_name)
+    })
+    .await
+    .into_iter()
+    .flatten()
+    .collect()
 }
 
 #[cfg(test)]
@@ -223,9 +293,13 @@ mod tests {
     use crate::parser::types::RegistryType;
     use crate::version::cache::Cache;
     use crate::version::registry::MockRegistry;
-    use crate::version::types::PackageVersions;
-    use std::sync::Arc;
+    use rstest::rstest;
+    use std::collections::HashMap;
+    use std::sync::{Arc, Mutex};
     use tempfile::TempDir;
+    use tracing::field::{Field, Visit};
+    use tracing_subscriber::layer::{Context, SubscriberExt};
+    use tracing_subscriber::{Layer, Registry as SubscriberRegistry};
 
     fn create_test_cache() -> (TempDir, Arc<Cache>) {
         let temp_dir = TempDir::new().unwrap();
@@ -335,6 +409,25 @@ mod tests {
         assert_eq!(checkout_versions, vec!["v4.0.0"]);
     }
 
+    #[tokio::test]
+    async fn fetch_missing_packages_marks_package_unreachable_on_timeout() {
+        let (_temp_dir, cache) = create_test_cache();
+
+        let mut registry = MockRegistry::new();
+        registry
+            .expect_registry_type()
+            .returning(|| RegistryType::GitHubActions);
+        registry
+            .expect_fetch_all_versions()
+            .times(1)
+            .returning(|_| Err(RegistryError::Timeout));
+
+        let packages = vec![make_package_info("actions/checkout", "v4")];
+        fetch_missing_packages(&*cache, &registry, &packages).await;
+
+        assert!(cache.is_unreachable(RegistryType::GitHubActions, "actions/checkout"));
+    }
+
     #[tokio::test]
     async fn refresh_packages_handles_empty_package_list() {
         let (_temp_dir, cache) = create_test_cache();
@@ -451,4 +544,107 @@ mod tests {
             .unwrap();
         assert!(!setup_node_versions.is_empty());
     }
+
+    #[tokio::test]
+    async fn revalidate_stale_packages_refetches_only_stale_packages() {
+        let temp_dir = TempDir::new().unwrap();
+        // refresh_interval = 100ms
+        let cache = Cache::new(&temp_dir.path().join("test.db"), 100, false).unwrap();
+        cache
+            .replace_versions(
+                RegistryType::GitHubActions,
+                "actions/checkout",
+                vec!["v3.0.0".to_string()],
+            )
+            .unwrap();
+        std::thread::sleep(std::time::Duration::from_millis(150));
+        cache
+            .replace_versions(
+                RegistryType::GitHubActions,
+                "actions/setup-node",
+                vec!["v4.0.0".to_string()],
+            )
+            .unwrap();
+
+        let mut registry = MockRegistry::new();
+        registry
+            .expect_fetch_all_versions()
+            .withf(|name| name == "actions/checkout")
+            .times(1)
+            .returning(|_| {
+                Ok(PackageVersions::new(vec![
+                    "v3.0.0".to_string(),
+                    "v4.0.0".to_string(),
+                ]))
+            });
+
+        let packages = vec![
+            make_package_info("actions/checkout", "v3.0.0"),
+            make_package_info("actions/setup-node", "v4.0.0"),
+        ];
+
+        let revalidated = revalidate_stale_packages(&cache, &registry, &packages).await;
+
+        assert_eq!(revalidated, vec!["actions/checkout"]);
+        assert_eq!(
+            cache
+                .get_latest_version(RegistryType::GitHubActions, "actions/checkout")
+                .unwrap(),
+            Some("v4.0.0".to_string())
+        );
+    }
+
+    /// Records the fields of every event as strings
+    #[derive(Clone, Default)]
+    struct EventRecorder(Arc<Mutex<Vec<HashMap<String, String>>>>);
+
+    impl<S: tracing::Subscriber> Layer<S> for EventRecorder {
+        fn on_event(&self, event: &tracing::Event<'_>, _ctx: Context<'_, S>) {
+            struct Fields(HashMap<String, String>);
+            impl Visit for Fields {
+                fn record_str(&mut self, field: &Field, value: &str) {
+                    self.0.insert(field.name().to_string(), value.to_string());
+                }
+                fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+                    self.0
+                        .insert(field.name().to_string(), format!("{:?}", value));
+                }
+            }
+
+            let mut fields = Fields(HashMap::new());
+            event.record(&mut fields);
+            self.0.lock().unwrap().push(fields.0);
+        }
+    }
+
+    #[rstest]
+    #[case(Ok(PackageVersions::new(vec!["v4.0.0".to_string()])), "hit")]
+    #[case(Err(RegistryError::NotFound("actions/checkout".to_string())), "miss")]
+    #[case(Err(RegistryError::InvalidResponse("bad".to_string())), "error")]
+    #[tokio::test]
+    async fn fetch_versions_timed_logs_latency_and_outcome(
+        #[case] response: Result<PackageVersions, RegistryError>,
+        #[case] expected_outcome: &str,
+    ) {
+        let recorder = EventRecorder::default();
+        let _guard =
+            tracing::subscriber::set_default(SubscriberRegistry::default().with(recorder.clone()));
+
+        let mut registry = MockRegistry::new();
+        registry
+            .expect_fetch_all_versions()
+            .return_once(move |_| response);
+
+        let _ =
+            fetch_versions_timed(&registry, RegistryType::GitHubActions, "actions/checkout").await;
+
+        let events = recorder.0.lock().unwrap();
+        let event = events
+            .iter()
+            .find(|fields| fields.contains_key("latency_ms"))
+            .expect("Expected a fetch event with latency_ms");
+        assert_eq!(event["registry"], "github_actions");
+        assert_eq!(event["package"], "actions/checkout");
+        assert_eq!(event["outcome"], expected_outcome);
+    }
 }
@@ -4,32 +4,121 @@
 //! to resolve and validate package versions.
 
 use std::collections::HashMap;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 
-use crate::config::{LspConfig, RegistryConfig};
+use futures::FutureExt;
+use futures::future::{BoxFuture, Shared};
+
+use crate::config::{LspConfig, NpmRegistryConfig, RegistryConfig};
+use crate::lsp::refresh::{fetch_concurrently, fetch_versions_timed};
+use crate::netrc::Netrc;
+use crate::parser::brewfile::BrewfileParser;
 use crate::parser::cargo_toml::CargoTomlParser;
-use crate::parser::compose::ComposeParser;
+use crate::parser::composer_json::ComposerJsonParser;
 use crate::parser::deno_json::DenoJsonParser;
+use crate::parser::docker::DockerParser;
+use crate::parser::flake_lock::FlakeLockParser;
+use crate::parser::gemfile::GemfileParser;
 use crate::parser::github_actions::GitHubActionsParser;
 use crate::parser::go_mod::GoModParser;
+use crate::parser::gradle_versions_toml::GradleVersionsTomlParser;
+use crate::parser::mise_toml::MiseTomlParser;
 use crate::parser::package_json::PackageJsonParser;
 use crate::parser::pnpm_workspace::PnpmWorkspaceParser;
 use crate::parser::pyproject_toml::PyprojectTomlParser;
+use crate::parser::terraform::TerraformParser;
+use crate::parser::tool_versions::ToolVersionsParser;
 use crate::parser::traits::Parser;
-use crate::parser::types::RegistryType;
+use crate::parser::types::{PackageInfo, RegistryType, is_mise_toml_file};
+use crate::version::error::RegistryError;
 use crate::version::matcher::VersionMatcher;
 use crate::version::matchers::{
-    CratesVersionMatcher, DockerVersionMatcher, GitHubActionsMatcher, GoVersionMatcher,
-    JsrVersionMatcher, NpmVersionMatcher, PnpmCatalogMatcher, PypiVersionMatcher,
+    ComposerVersionMatcher, CratesVersionMatcher, DockerVersionMatcher, GitHubActionsMatcher,
+    GoReleaseMatcher, GoVersionMatcher, HomebrewVersionMatcher, JsrVersionMatcher,
+    MavenVersionMatcher, NixFlakeMatcher, NpmVersionMatcher, PnpmCatalogMatcher,
+    PypiVersionMatcher, RubyGemsVersionMatcher, TerraformVersionMatcher, ToolVersionsMatcher,
 };
+use crate::version::rate_limit::{RateLimitedRegistry, RateLimiter};
 use crate::version::registries::crates_io::CratesIoRegistry;
 use crate::version::registries::docker::DockerRegistry;
 use crate::version::registries::github::{GitHubRegistry, TagShaFetcher};
 use crate::version::registries::go_proxy::GoProxyRegistry;
+use crate::version::registries::go_release::GoReleaseRegistry;
+use crate::version::registries::homebrew::HomebrewRegistry;
 use crate::version::registries::jsr::JsrRegistry;
+use crate::version::registries::maven::MavenRegistry;
+use crate::version::registries::nix_flake::NixFlakeRegistry;
 use crate::version::registries::npm::NpmRegistry;
+use crate::version::registries::packagist::PackagistRegistry;
 use crate::version::registries::pypi::PypiRegistry;
+use crate::version::registries::rubygems::RubyGemsRegistry;
+use crate::version::registries::terraform::TerraformRegistry;
+use crate::version::registries::tool_versions::ToolVersionsRegistry;
 use crate::version::registry::Registry;
+use crate::version::types::PackageVersions;
+
+/// A fetch in flight, awaited by every caller asking for the same package
+type SharedFetch = Shared<BoxFuture<'static, Result<PackageVersions, RegistryError>>>;
+
+/// Registry wrapper that coalesces concurrent fetches of the same package
+///
+/// A document may list a package more than once (e.g. `serde` in both
+/// `dependencies` and `build-dependencies`); callers asking for a package
+/// that is already being fetched share that request instead of sending
+/// another. Finished fetches are forgotten, so later calls fetch again.
+struct SingleFlightRegistry {
+    registry_type: RegistryType,
+    inner: Arc<dyn Registry>,
+    in_flight: Arc<Mutex<HashMap<(RegistryType, String), SharedFetch>>>,
+}
+
+impl SingleFlightRegistry {
+    fn new(registry_type: RegistryType, inner: Arc<dyn Registry>) -> Self {
+        Self {
+            registry_type,
+            inner,
+            in_flight: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Registry for SingleFlightRegistry {
+    fn registry_type(&self) -> RegistryType {
+        self.inner.registry_type()
+    }
+
+    async fn fetch_all_versions(
+        &self,
+        package_name: &str,
+    ) -> Result<PackageVersions, RegistryError> {
+        let key = (self.registry_type, package_name.to_string());
+        let fetch = {
+            let mut in_flight = self.in_flight.lock().expect("in-flight lock poisoned");
+            in_flight
+                .entry(key.clone())
+                .or_insert_with(|| {
+                    let inner = self.inner.clone();
+                    let in_flight = self.in_flight.clone();
+                    async move {
+                        let result = inner.fetch_all_versions(&key.1).await;
+                        in_flight
+                            .lock()
+                            .expect("in-flight lock poisoned")
+                            .remove(&key);
+                        result
+                    }
+                    .boxed()
+                    .shared()
+                })
+                .clone()
+        };
+        fetch.await
+    }
+}
+
+/// Parser of another file format, with the URIs of the files it parses
+type FileParser = (fn(&str) -> bool, Arc<dyn Parser>);
 
 /// Groups all components needed to resolve and validate package versions for a specific registry.
 ///
@@ -37,9 +126,12 @@ use crate::version::registry::Registry;
 /// that coordinates:
 /// - Parsing files to extract package information
 /// - Matching version specifications against available versions
-/// - Fetching package versions from the remote registry
+/// - Fetching package versions from the remote registry, coalescing
+///   concurrent fetches of the same package
 pub struct PackageResolver {
     parser: Arc<dyn Parser>,
+    /// Parsers of other file formats of this registry
+    file_parsers: Vec<FileParser>,
     matcher: Arc<dyn VersionMatcher>,
     registry: Arc<dyn Registry>,
     sha_fetcher: Option<Arc<dyn TagShaFetcher>>,
@@ -52,8 +144,10 @@ impl PackageResolver {
         matcher: Arc<dyn VersionMatcher>,
         registry: Arc<dyn Registry>,
     ) -> Self {
+        let registry = Arc::new(SingleFlightRegistry::new(matcher.registry_type(), registry));
         Self {
             parser,
+            file_parsers: Vec::new(),
             matcher,
             registry,
             sha_fetcher: None,
@@ -68,11 +162,28 @@ impl PackageResolver {
         self
     }
 
+    /// Parse the files whose URI `matches` with `parser` instead
+    ///
+    /// Registries with several file formats (`.tool-versions` and mise.toml)
+    /// pick the format by the file name, since their contents can look alike.
+    pub fn with_file_parser(mut self, matches: fn(&str) -> bool, parser: Arc<dyn Parser>) -> Self {
+        self.file_parsers.push((matches, parser));
+        self
+    }
+
     /// Get the parser for this registry type
     pub fn parser(&self) -> &Arc<dyn Parser> {
         &self.parser
     }
 
+    /// Get the parser for the document at `uri`
+    pub fn parser_for(&self, uri: &str) -> &Arc<dyn Parser> {
+        self.file_parsers
+            .iter()
+            .find(|(matches, _)| matches(uri))
+            .map_or(&self.parser, |(_, parser)| parser)
+    }
+
     /// Get the version matcher for this registry type
     pub fn matcher(&self) -> &Arc<dyn VersionMatcher> {
         &self.matcher
@@ -87,6 +198,76 @@ impl PackageResolver {
     pub fn sha_fetcher(&self) -> Option<&Arc<dyn TagShaFetcher>> {
         self.sha_fetcher.as_ref()
     }
+
+    /// Fetch versions for several packages from the registry concurrently
+    ///
+    /// At most [`crate::config::FETCH_CONCURRENCY`] requests are in flight at
+    /// once. The cache is not consulted; callers pass the names they need.
+    pub async fn resolve_many(
+        &self,
+        names: &[String],
+    ) -> HashMap<String, Result<PackageVersions, RegistryError>> {
+        let registry_type = self.matcher.registry_type();
+        fetch_concurrently(names, |name| async move {
+            let result = fetch_versions_timed(&*self.registry, registry_type, name).await;
+            (name.clone(), result)
+        })
+        .await
+        .into_iter()
+        .collect()
+    }
+}
+
+/// Version matchers of the registries a document's packages may come from
+///
+/// A document may mix registries (`docker://` steps in a workflow, `npm:`
+/// imports in deno.json), so each package is compared with the matcher of its
+/// own registry rather than the document's.
+#[derive(Clone)]
+pub struct Matchers {
+    document: Arc<dyn VersionMatcher>,
+    by_registry: HashMap<RegistryType, Arc<dyn VersionMatcher>>,
+}
+
+impl Matchers {
+    /// Matchers of all `resolvers`, for a document of `registry_type`
+    ///
+    /// Returns None when there is no resolver for the document's registry.
+    pub fn from_resolvers(
+        resolvers: &HashMap<RegistryType, PackageResolver>,
+        registry_type: RegistryType,
+    ) -> Option<Self> {
+        let document = resolvers.get(&registry_type)?.matcher().clone();
+        let by_registry = resolvers
+            .iter()
+            .map(|(registry_type, resolver)| (*registry_type, resolver.matcher().clone()))
+            .collect();
+        Some(Self {
+            document,
+            by_registry,
+        })
+    }
+
+    /// Matcher for the registry of `package`
+    ///
+    /// Falls back to the document's matcher when the package's registry has
+    /// no resolver.
+    pub fn get(&self, package: &PackageInfo) -> &dyn VersionMatcher {
+        self.by_registry
+            .get(&package.registry_type)
+            .unwrap_or(&self.document)
+            .as_ref()
+    }
+}
+
+/// Matchers comparing every package with `matcher`
+impl<M: VersionMatcher + 'static> From<M> for Matchers {
+    fn from(matcher: M) -> Self {
+        Self {
+            document: Arc::new(matcher),
+            by_registry: HashMap::new(),
+        }
+    }
 }
 
 /// Build the set of package resolvers for all supported registry types using
@@ -94,25 +275,35 @@ impl PackageResolver {
 /// [`RegistryConfig::url`] is `None` uses its hardcoded default URL.
 pub fn create_resolvers(config: &LspConfig) -> HashMap<RegistryType, PackageResolver> {
     let registries = &config.registries;
+    let http = config.http_settings();
+    let netrc = Netrc::load().map(Arc::new);
     let mut resolvers = HashMap::new();
 
     // Single shared NpmRegistry for both Npm and PnpmCatalog. They map to
     // separate config keys so a user could override them independently, but
     // sharing the instance when both URLs match avoids duplicate HTTP clients.
     // We accept the rare case where they differ by building two clients.
-    let npm_registry = npm_registry_from(&registries.npm);
+    let npm_registry: Arc<dyn Registry> = rate_limited(
+        Arc::new(
+            npm_registry_from(registries.npm.url.as_deref(), &registries.npm)
+                .with_http_settings(&http)
+                .with_netrc(netrc.clone()),
+        ),
+        config,
+    );
 
     // One GitHubRegistry instance serves both the version fetch (Registry) and
     // the commit-hash → SHA fetch (TagShaFetcher) so the configured URL
     // override is honored on both paths.
-    let github_registry = Arc::new(github_registry_from(&registries.github));
+    let github_registry =
+        Arc::new(github_registry_from(&registries.github).with_http_settings(&http));
 
     resolvers.insert(
         RegistryType::GitHubActions,
         PackageResolver::new(
             Arc::new(GitHubActionsParser::new()),
             Arc::new(GitHubActionsMatcher),
-            github_registry.clone(),
+            rate_limited(github_registry.clone(), config),
         )
         .with_sha_fetcher(github_registry),
     );
@@ -122,7 +313,7 @@ pub fn create_resolvers(config: &LspConfig) -> HashMap<RegistryType, PackageReso
         PackageResolver::new(
             Arc::new(PackageJsonParser::new()),
             Arc::new(NpmVersionMatcher),
-            Arc::new(npm_registry.clone()),
+            npm_registry.clone(),
         ),
     );
 
@@ -131,7 +322,14 @@ pub fn create_resolvers(config: &LspConfig) -> HashMap<RegistryType, PackageReso
         PackageResolver::new(
             Arc::new(CargoTomlParser::new()),
             Arc::new(CratesVersionMatcher),
-            Arc::new(crates_registry_from(&registries.crates)),
+            rate_limited(
+                Arc::new(
+                    crates_registry_from(&registries.crates)
+                        .with_http_settings(&http)
+                        .with_netrc(netrc.clone()),
+                ),
+                config,
+            ),
         ),
     );
 
@@ -140,16 +338,43 @@ pub fn create_resolvers(config: &LspConfig) -> HashMap<RegistryType, PackageReso
         PackageResolver::new(
             Arc::new(GoModParser::new()),
             Arc::new(GoVersionMatcher),
-            Arc::new(go_proxy_registry_from(&registries.go_proxy)),
+            rate_limited(
+                Arc::new(go_proxy_registry_from(&registries.go_proxy).with_http_settings(&http)),
+                config,
+            ),
         ),
     );
 
-    // pnpm catalog reuses the npm registry. If the user overrides the
-    // pnpmCatalog URL independently of npm, build a second NpmRegistry.
+    // No document is of this type: Go releases are checked for go.mod
+    // directives and Go pins of .tool-versions and mise.toml, so the parser
+    // is never asked to parse a document.
+    resolvers.insert(
+        RegistryType::GoRelease,
+        PackageResolver::new(
+            Arc::new(GoModParser::new()),
+            Arc::new(GoReleaseMatcher),
+            rate_limited(
+                Arc::new(GoReleaseRegistry::default().with_http_settings(&http)),
+                config,
+            ),
+        ),
+    );
+
+    // pnpm catalog reuses the npm registry, and with it the npm rate limit.
+    // If the user overrides the pnpmCatalog URL independently of npm, build a
+    // second NpmRegistry; catalog entries are npm packages, so npm's scopes
+    // and tokens apply.
     let pnpm_registry = if registries.pnpm_catalog.url == registries.npm.url {
         npm_registry
     } else {
-        npm_registry_from(&registries.pnpm_catalog)
+        rate_limited(
+            Arc::new(
+                npm_registry_from(registries.pnpm_catalog.url.as_deref(), &registries.npm)
+                    .with_http_settings(&http)
+                    .with_netrc(netrc),
+            ),
+            config,
+        )
     };
 
     resolvers.insert(
@@ -157,7 +382,7 @@ pub fn create_resolvers(config: &LspConfig) -> HashMap<RegistryType, PackageReso
         PackageResolver::new(
             Arc::new(PnpmWorkspaceParser),
             Arc::new(PnpmCatalogMatcher),
-            Arc::new(pnpm_registry),
+            pnpm_registry,
         ),
     );
 
@@ -166,7 +391,10 @@ pub fn create_resolvers(config: &LspConfig) -> HashMap<RegistryType, PackageReso
         PackageResolver::new(
             Arc::new(DenoJsonParser::new()),
             Arc::new(JsrVersionMatcher),
-            Arc::new(jsr_registry_from(&registries.jsr)),
+            rate_limited(
+                Arc::new(jsr_registry_from(&registries.jsr).with_http_settings(&http)),
+                config,
+            ),
         ),
     );
 
@@ -175,21 +403,125 @@ pub fn create_resolvers(config: &LspConfig) -> HashMap<RegistryType, PackageReso
         PackageResolver::new(
             Arc::new(PyprojectTomlParser::new()),
             Arc::new(PypiVersionMatcher),
-            Arc::new(pypi_registry_from(&registries.pypi)),
+            rate_limited(
+                Arc::new(pypi_registry_from(&registries.pypi).with_http_settings(&http)),
+                config,
+            ),
+        ),
+    );
+
+    resolvers.insert(
+        RegistryType::RubyGems,
+        PackageResolver::new(
+            Arc::new(GemfileParser::new()),
+            Arc::new(RubyGemsVersionMatcher),
+            rate_limited(
+                Arc::new(rubygems_registry_from(&registries.rubygems).with_http_settings(&http)),
+                config,
+            ),
+        ),
+    );
+
+    resolvers.insert(
+        RegistryType::Composer,
+        PackageResolver::new(
+            Arc::new(ComposerJsonParser::new()),
+            Arc::new(ComposerVersionMatcher),
+            rate_limited(
+                Arc::new(packagist_registry_from(&registries.composer).with_http_settings(&http)),
+                config,
+            ),
+        ),
+    );
+
+    resolvers.insert(
+        RegistryType::Homebrew,
+        PackageResolver::new(
+            Arc::new(BrewfileParser::new()),
+            Arc::new(HomebrewVersionMatcher),
+            rate_limited(
+                Arc::new(homebrew_registry_from(&registries.homebrew).with_http_settings(&http)),
+                config,
+            ),
+        ),
+    );
+
+    resolvers.insert(
+        RegistryType::ToolVersions,
+        PackageResolver::new(
+            Arc::new(ToolVersionsParser::new()),
+            Arc::new(ToolVersionsMatcher),
+            rate_limited(
+                Arc::new(
+                    tool_versions_registry_from(&registries.tool_versions)
+                        .with_http_settings(&http),
+                ),
+                config,
+            ),
+        )
+        .with_file_parser(is_mise_toml_file, Arc::new(MiseTomlParser::new())),
+    );
+
+    resolvers.insert(
+        RegistryType::TerraformRegistry,
+        PackageResolver::new(
+            Arc::new(TerraformParser::new()),
+            Arc::new(TerraformVersionMatcher),
+            rate_limited(
+                Arc::new(terraform_registry_from(&registries.terraform).with_http_settings(&http)),
+                config,
+            ),
+        ),
+    );
+
+    resolvers.insert(
+        RegistryType::Maven,
+        PackageResolver::new(
+            Arc::new(GradleVersionsTomlParser::new()),
+            Arc::new(MavenVersionMatcher),
+            rate_limited(
+                Arc::new(maven_registry_from(&registries.maven).with_http_settings(&http)),
+                config,
+            ),
+        ),
+    );
+
+    // Flake inputs use the GitHub API URL of `github` unless overridden
+    let nix_flake_github = match registries.nix_flake.url {
+        Some(_) => github_registry_from(&registries.nix_flake),
+        None => github_registry_from(&registries.github),
+    };
+    resolvers.insert(
+        RegistryType::NixFlake,
+        PackageResolver::new(
+            Arc::new(FlakeLockParser::new()),
+            Arc::new(NixFlakeMatcher),
+            rate_limited(
+                Arc::new(NixFlakeRegistry::new(
+                    nix_flake_github.with_http_settings(&http),
+                )),
+                config,
+            ),
         ),
     );
 
     resolvers.insert(
         RegistryType::Docker,
         PackageResolver::new(
-            Arc::new(ComposeParser::new()),
+            Arc::new(DockerParser::new()),
             Arc::new(DockerVersionMatcher),
-            Arc::new(DockerRegistry::with_overrides(
-                registries.docker.docker_hub_registry_url.as_deref(),
-                registries.docker.docker_hub_auth_url.as_deref(),
-                registries.docker.ghcr_registry_url.as_deref(),
-                registries.docker.ghcr_auth_url.as_deref(),
-            )),
+            rate_limited(
+                Arc::new(
+                    DockerRegistry::with_overrides(
+                        registries.docker.docker_hub_registry_url.as_deref(),
+                        registries.docker.docker_hub_auth_url.as_deref(),
+                        registries.docker.ghcr_registry_url.as_deref(),
+                        registries.docker.ghcr_auth_url.as_deref(),
+                    )
+                    .with_http_settings(&http),
+                ),
+                config,
+            ),
         ),
     );
 
@@ -209,8 +541,23 @@ fn pypi_registry_from(cfg: &RegistryConfig) -> PypiRegistry {
         .unwrap_or_default()
 }
 
-fn npm_registry_from(cfg: &RegistryConfig) -> NpmRegistry {
-    cfg.url.as_deref().map(NpmRegistry::new).unwrap_or_default()
+/// Give a registry its own [`RateLimiter`] at the configured
+/// `requestsPerSecond`, or return it unchanged when the limit is disabled
+fn rate_limited(registry: Arc<dyn Registry>, config: &LspConfig) -> Arc<dyn Registry> {
+    match config.requests_per_second {
+        0 => registry,
+        limit => Arc::new(RateLimitedRegistry::new(
+            registry,
+            Arc::new(RateLimiter::new(limit)),
+        )),
+    }
+}
+
+fn npm_registry_from(url: Option<&str>, npm: &NpmRegistryConfig) -> NpmRegistry {
+    url.map(NpmRegistry::new)
+        .unwrap_or_default()
+        .with_scoped_registries(&npm.scoped_registries)
+        .with_auth_tokens(&npm.auth_tokens)
 }
 
 fn crates_registry_from(cfg: &RegistryConfig) -> CratesIoRegistry {
@@ -231,13 +578,55 @@ fn jsr_registry_from(cfg: &RegistryConfig) -> JsrRegistry {
     cfg.url.as_deref().map(JsrRegistry::new).unwrap_or_default()
 }
 
+fn rubygems_registry_from(cfg: &RegistryConfig) -> RubyGemsRegistry {
+    cfg.url
+        .as_deref()
+        .map(RubyGemsRegistry::new)
+        .unwrap_or_default()
+}
+
+fn packagist_registry_from(cfg: &RegistryConfig) -> PackagistRegistry {
+    cfg.url
+        .as_deref()
+        .map(PackagistRegistry::new)
+        .unwrap_or_default()
+}
+
+fn homebrew_registry_from(cfg: &RegistryConfig) -> HomebrewRegistry {
+    cfg.url
+        .as_deref()
+        .map(HomebrewRegistry::new)
+        .unwrap_or_default()
+}
+
+fn tool_versions_registry_from(cfg: &RegistryConfig) -> ToolVersionsRegistry {
+    cfg.url
+        .as_deref()
+        .map(ToolVersionsRegistry::new)
+        .unwrap_or_default()
+}
+
+fn terraform_registry_from(cfg: &RegistryConfig) -> TerraformRegistry {
+    cfg.url
+        .as_deref()
+        .map(TerraformRegistry::new)
+        .unwrap_or_default()
+}
+
+fn maven_registry_from(cfg: &RegistryConfig) -> MavenRegistry {
+    cfg.url
+        .as_deref()
+        .map(MavenRegistry::new)
+        .unwrap_or_default()
+}
+
 /// Build a `GitHubRegistry`. LSP config takes precedence over the
 /// `GITHUB_API_BASE_URL` environment variable (which is preserved as a
 /// fallback for backward compatibility), which in turn takes precedence over
 /// the hardcoded default.
 fn github_registry_from(cfg: &RegistryConfig) -> GitHubRegistry {
     if let Some(url) = cfg.url.as_deref() {
-        GitHubRegistry::new(url)
+        GitHubRegistry::new(url).with_token(GitHubRegistry::token_from_env())
     } else {
         GitHubRegistry::default()
     }
@@ -246,7 +635,114 @@ fn github_registry_from(cfg: &RegistryConfig) -> GitHubRegistry {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::config::{DockerRegistryConfig, RegistriesConfig};
+    use crate::config::{DockerRegistryConfig, FETCH_CONCURRENCY, RegistriesConfig};
+    use rstest::rstest;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+
+    /// Registry that records how many fetches run at the same time
+    #[derive(Default)]
+    struct InFlightRegistry {
+        in_flight: AtomicUsize,
+        max_in_flight: AtomicUsize,
+        fetches: AtomicUsize,
+    }
+
+    #[async_trait::async_trait]
+    impl Registry for InFlightRegistry {
+        fn registry_type(&self) -> RegistryType {
+            RegistryType::Npm
+        }
+
+        async fn fetch_all_versions(
+            &self,
+            package_name: &str,
+        ) -> Result<PackageVersions, RegistryError> {
+            self.fetches.fetch_add(1, Ordering::SeqCst);
+            let current = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+            self.max_in_flight.fetch_max(current, Ordering::SeqCst);
+            tokio::time::sleep(Duration::from_millis(100)).await;
+            self.in_flight.fetch_sub(1, Ordering::SeqCst);
+
+            if package_name == "missing" {
+                return Err(RegistryError::NotFound(package_name.to_string()));
+            }
+            Ok(PackageVersions::new(vec!["1.0.0".to_string()]))
+        }
+    }
+
+    #[tokio::test]
+    async fn resolve_many_fetches_packages_concurrently_with_bounded_concurrency() {
+        let registry = Arc::new(InFlightRegistry::default());
+        let resolver = PackageResolver::new(
+            Arc::new(PackageJsonParser::new()),
+            Arc::new(NpmVersionMatcher),
+            registry.clone(),
+        );
+        let mut names: Vec<String> = (0..9).map(|i| format!("package-{}", i)).collect();
+        names.push("missing".to_string());
+
+        let results = resolver.resolve_many(&names).await;
+
+        assert_eq!(results.len(), 10);
+        assert_eq!(
+            results["package-0"].as_ref().unwrap().versions,
+            vec!["1.0.0"]
+        );
+        assert!(matches!(
+            results["missing"],
+            Err(RegistryError::NotFound(_))
+        ));
+        let max_in_flight = registry.max_in_flight.load(Ordering::SeqCst);
+        assert!(max_in_flight > 1, "max in flight was {}", max_in_flight);
+        assert!(max_in_flight <= FETCH_CONCURRENCY);
+    }
+
+    #[tokio::test]
+    async fn concurrent_fetches_of_the_same_package_share_one_request() {
+        let registry = Arc::new(InFlightRegistry::default());
+        let resolver = PackageResolver::new(
+            Arc::new(CargoTomlParser::new()),
+            Arc::new(CratesVersionMatcher),
+            registry.clone(),
+        );
+
+        let (first, second) = tokio::join!(
+            resolver.registry().fetch_all_versions("serde"),
+            resolver.registry().fetch_all_versions("serde"),
+        );
+
+        assert_eq!(first.unwrap().versions, vec!["1.0.0"]);
+        assert_eq!(second.unwrap().versions, vec!["1.0.0"]);
+        assert_eq!(registry.fetches.load(Ordering::SeqCst), 1);
+
+        // A finished fetch is not reused
+        resolver
+            .registry()
+            .fetch_all_versions("serde")
+            .await
+            .unwrap();
+        assert_eq!(registry.fetches.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn coalesced_fetches_share_the_error() {
+        let registry = Arc::new(InFlightRegistry::default());
+        let resolver = PackageResolver::new(
+            Arc::new(CargoTomlParser::new()),
+            Arc::new(CratesVersionMatcher),
+            registry.clone(),
+        );
+
+        let (first, second) = tokio::join!(
+            resolver.registry().fetch_all_versions("missing"),
+            resolver.registry().fetch_all_versions("missing"),
+        );
+
+        assert!(matches!(first, Err(RegistryError::NotFound(_))));
+        assert!(matches!(second, Err(RegistryError::NotFound(_))));
+        assert_eq!(registry.fetches.load(Ordering::SeqCst), 1);
+    }
 
     #[test]
     fn create_resolvers_with_default_config_includes_all_registry_types() {
@@ -256,11 +752,19 @@ mod tests {
             RegistryType::Npm,
             RegistryType::CratesIo,
             RegistryType::GoProxy,
+            RegistryType::GoRelease,
             RegistryType::GitHubActions,
             RegistryType::PnpmCatalog,
             RegistryType::Jsr,
             RegistryType::PyPI,
+            RegistryType::RubyGems,
+            RegistryType::Composer,
+            RegistryType::Homebrew,
+            RegistryType::ToolVersions,
+            RegistryType::NixFlake,
             RegistryType::Docker,
+            RegistryType::TerraformRegistry,
+            RegistryType::Maven,
         ] {
             assert!(
                 resolvers.contains_key(&registry_type),
@@ -296,6 +800,36 @@ mod tests {
         assert_eq!(result.versions, vec!["1.0.0"]);
     }
 
+    #[tokio::test]
+    async fn create_resolvers_paces_batch_fetches_at_requests_per_second() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", mockito::Matcher::Regex(r"^/crate-\d$".to_string()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"versions":[{"num":"1.0.0","yanked":false,"created_at":"2024-01-01T00:00:00.000Z"}]}"#)
+            .expect(4)
+            .create_async()
+            .await;
+
+        let mut config = LspConfig::default();
+        config.registries.crates.url = Some(server.url());
+        config.requests_per_second = 2;
+
+        let resolvers = create_resolvers(&config);
+        let names: Vec<String> = (0..4).map(|i| format!("crate-{}", i)).collect();
+        let start = std::time::Instant::now();
+
+        let results = resolvers[&RegistryType::CratesIo]
+            .resolve_many(&names)
+            .await;
+
+        mock.assert_async().await;
+        assert!(results.values().all(Result::is_ok));
+        // Two requests use up the bucket; the other two wait half a second each
+        assert!(start.elapsed() >= Duration::from_millis(950));
+    }
+
     #[tokio::test]
     async fn create_resolvers_routes_github_sha_fetches_to_overridden_url() {
         let mut server = mockito::Server::new_async().await;
@@ -352,6 +886,36 @@ mod tests {
         assert_eq!(result.versions, vec!["1.0.0"]);
     }
 
+    #[tokio::test]
+    async fn create_resolvers_routes_scoped_npm_packages_to_scoped_registry() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/@mycompany%2Fpkg")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"versions":{"2.0.0":{}},"dist-tags":{"latest":"2.0.0"},"time":{}}"#)
+            .create_async()
+            .await;
+
+        let config = serde_json::from_value::<LspConfig>(serde_json::json!({
+            "registries": {
+                "npm": { "scopedRegistries": { "@mycompany": server.url() } }
+            }
+        }))
+        .unwrap();
+
+        let resolvers = create_resolvers(&config);
+        let registry = resolvers
+            .get(&RegistryType::Npm)
+            .expect("Npm resolver missing")
+            .registry();
+
+        let result = registry.fetch_all_versions("@mycompany/pkg").await.unwrap();
+
+        mock.assert_async().await;
+        assert_eq!(result.versions, vec!["2.0.0"]);
+    }
+
     #[tokio::test]
     async fn create_resolvers_uses_independent_npm_and_pnpm_urls_when_different() {
         let mut npm_server = mockito::Server::new_async().await;
@@ -374,9 +938,9 @@ mod tests {
 
         let config = LspConfig {
             registries: RegistriesConfig {
-                npm: RegistryConfig {
-                    enabled: true,
+                npm: NpmRegistryConfig {
                     url: Some(npm_server.url()),
+                    ..NpmRegistryConfig::default()
                 },
                 pnpm_catalog: RegistryConfig {
                     enabled: true,
@@ -426,4 +990,72 @@ mod tests {
         let resolvers = create_resolvers(&config);
         assert!(resolvers.contains_key(&RegistryType::Docker));
     }
+
+    #[test]
+    fn matchers_pick_the_matcher_of_the_package_registry() {
+        let resolvers = create_resolvers(&LspConfig::default());
+        let matchers = Matchers::from_resolvers(&resolvers, RegistryType::GitHubActions).unwrap();
+        let package = |registry_type| PackageInfo {
+            name: "node".to_string(),
+            version: "18-alpine".to_string(),
+            commit_hash: None,
+            registry_type,
+            start_offset: 0,
+            end_offset: 0,
+            line: 0,
+            column: 0,
+            extra_info: None,
+        };
+
+        assert_eq!(
+            matchers.get(&package(RegistryType::Docker)).registry_type(),
+            RegistryType::Docker
+        );
+        assert_eq!(
+            matchers
+                .get(&package(RegistryType::GitHubActions))
+                .registry_type(),
+            RegistryType::GitHubActions
+        );
+    }
+
+    #[test]
+    fn matchers_fall_back_to_the_document_matcher() {
+        let matchers = Matchers::from(CratesVersionMatcher);
+        let package = PackageInfo {
+            name: "serde".to_string(),
+            version: "1.0.0".to_string(),
+            commit_hash: None,
+            registry_type: RegistryType::Npm,
+            start_offset: 0,
+            end_offset: 0,
+            line: 0,
+            column: 0,
+            extra_info: None,
+        };
+
+        assert_eq!(
+            matchers.get(&package).registry_type(),
+            RegistryType::CratesIo
+        );
+    }
+
+    #[rstest]
+    // A comment with `=` made content sniffing mistake this for mise.toml
+    #[case("file:///project/.tool-versions", "# pinned = yes\nnodejs 20.11.0\n")]
+    #[case("file:///project/mise.toml", "[tools]\nnode = \"20.11.0\"\n")]
+    #[case("file:///project/.mise.toml", "[tools]\nnode = \"20.11.0\"\n")]
+    #[case("file:///project/mise.local.toml", "[tools]\nnode = \"20.11.0\"\n")]
+    fn parser_for_picks_the_tool_format_by_file_name(#[case] uri: &str, #[case] content: &str) {
+        let resolvers = create_resolvers(&LspConfig::default());
+
+        let packages = resolvers[&RegistryType::ToolVersions]
+            .parser_for(uri)
+            .parse(content)
+            .unwrap();
+
+        assert_eq!(packages.len(), 1);
+        assert_eq!(packages[0].name, "nodejs");
+        assert_eq!(packages[0].version, "20.11.0");
+    }
 }
@@ -1,4 +1,8 @@
-use clap::{Parser, Subcommand};
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use clap::{Parser, Subcommand, ValueEnum};
+use tower_lsp::lsp_types::DiagnosticSeverity;
 
 #[derive(Parser)]
 #[command(name = "version-lsp")]
@@ -10,17 +14,73 @@ struct Cli {
 
 #[derive(Subcommand)]
 enum Command {
-    // Future subcommands will be added here
-    // e.g., Cache { #[command(subcommand)] action: CacheAction }
+    /// Check dependency files and print diagnostics without an editor
+    Check {
+        /// Files to check (package.json, Cargo.toml, go.mod, ...)
+        #[arg(required = true)]
+        paths: Vec<PathBuf>,
+        /// JSON file with the language server settings to check with
+        #[arg(long)]
+        config: Option<PathBuf>,
+        /// Print diagnostics as JSON
+        #[arg(long)]
+        json: bool,
+        /// Exit with status 1 when a diagnostic at least this severe is found
+        #[arg(long, value_enum, default_value_t = FailOn::Warning)]
+        fail_on: FailOn,
+    },
+}
+
+/// Severity threshold for `check --fail-on`
+#[derive(Clone, Copy, ValueEnum)]
+enum FailOn {
+    Error,
+    Warning,
+    Information,
+    Hint,
+    Never,
+}
+
+impl FailOn {
+    fn threshold(self) -> Option<DiagnosticSeverity> {
+        match self {
+            FailOn::Error => Some(DiagnosticSeverity::ERROR),
+            FailOn::Warning => Some(DiagnosticSeverity::WARNING),
+            FailOn::Information => Some(DiagnosticSeverity::INFORMATION),
+            FailOn::Hint => Some(DiagnosticSeverity::HINT),
+            FailOn::Never => None,
+        }
+    }
 }
 
-fn main() -> anyhow::Result<()> {
+fn main() -> anyhow::Result<ExitCode> {
     let cli = Cli::parse();
+    let runtime = tokio::runtime::Builder::new_multi_thread()
+        .enable_all()
+        .build()?;
 
     match cli.command {
-        None => tokio::runtime::Builder::new_multi_thread()
-            .enable_all()
-            .build()?
-            .block_on(version_lsp::lsp::server::run_server()),
+        None => {
+            runtime.block_on(version_lsp::lsp::server::run_server())?;
+            Ok(ExitCode::SUCCESS)
+        }
+        Some(Command::Check {
+            paths,
+            config,
+            json,
+            fail_on,
+        }) => {
+            let failed = runtime.block_on(version_lsp::lsp::check::run_check(
+                &paths,
+                config.as_deref(),
+                json,
+                fail_on.threshold(),
+            ))?;
+            Ok(if failed {
+                ExitCode::FAILURE
+            } else {
+                ExitCode::SUCCESS
+            })
+        }
     }
 }
@@ -0,0 +1,205 @@
+//! `.netrc` credentials for private registries
+//!
+//! Registries behind e.g. Artifactory often authenticate with basic auth taken
+//! from `~/.netrc`, as curl and git do. Entries look like:
+//!
+//! ```text
+//! machine artifactory.example.com
+//!   login ci-bot
+//!   password s3cret
+//! default login anonymous password guest
+//! ```
+//!
+//! Only `machine` entries are used: credentials are never sent to a host that
+//! is not listed, so the `default` entry is ignored. `macdef` macros are
+//! skipped, as are `#` comment lines.
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use tracing::debug;
+
+/// Login and password of a `.netrc` entry
+#[derive(Debug, Clone, PartialEq)]
+pub struct NetrcCredentials {
+    pub login: String,
+    pub password: Option<String>,
+}
+
+/// Parsed `.netrc` file
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Netrc {
+    machines: HashMap<String, NetrcCredentials>,
+}
+
+/// Entry being read: `None` for `default`, otherwise the machine name
+struct Entry {
+    machine: Option<String>,
+    login: Option<String>,
+    password: Option<String>,
+}
+
+impl Netrc {
+    /// Read `$NETRC`, or `~/.netrc` when unset. `None` if there is no file.
+    pub fn load() -> Option<Self> {
+        let path = std::env::var_os("NETRC")
+            .map(PathBuf::from)
+            .or_else(|| dirs::home_dir().map(|home| home.join(".netrc")))?;
+        let content = std::fs::read_to_string(&path).ok()?;
+        debug!("Loaded registry credentials from {}", path.display());
+        Some(Self::parse(&content))
+    }
+
+    /// Parse the contents of a `.netrc` file
+    ///
+    /// When a machine is listed more than once, the first entry wins.
+    /// Entries without a `login`, and the `default` entry, are ignored.
+    pub fn parse(content: &str) -> Self {
+        let mut netrc = Netrc::default();
+        let mut entry: Option<Entry> = None;
+        let mut in_macdef = false;
+
+        for line in content.lines() {
+            // A macro definition runs until the next empty line
+            if in_macdef {
+                in_macdef = !line.trim().is_empty();
+                continue;
+            }
+            if line.trim_start().starts_with('#') {
+                continue;
+            }
+
+            let mut tokens = line.split_whitespace();
+            while let Some(token) = tokens.next() {
+                match token {
+                    "machine" | "default" => {
+                        netrc.add(entry.take());
+                        let machine = match token {
+                            "machine" => tokens.next().map(str::to_lowercase),
+                            _ => None,
+                        };
+                        entry = Some(Entry {
+                            machine,
+                            login: None,
+                            password: None,
+                        });
+                    }
+                    "login" | "password" | "account" => {
+                        let value = tokens.next().map(str::to_string);
+                        if let Some(entry) = entry.as_mut() {
+                            match token {
+                                "login" => entry.login = value,
+                                "password" => entry.password = value,
+                                _ => {}
+                            }
+                        }
+                    }
+                    "macdef" => {
+                        netrc.add(entry.take());
+                        in_macdef = true;
+                        break;
+                    }
+                    _ => {}
+                }
+            }
+        }
+        netrc.add(entry);
+
+        netrc
+    }
+
+    fn add(&mut self, entry: Option<Entry>) {
+        let Some(Entry {
+            machine: Some(machine),
+            login: Some(login),
+            password,
+        }) = entry
+        else {
+            return;
+        };
+        self.machines
+            .entry(machine)
+            .or_insert(NetrcCredentials { login, password });
+    }
+
+    /// Credentials of the `machine` entry for a host
+    pub fn credentials_for(&self, host: &str) -> Option<&NetrcCredentials> {
+        self.machines.get(&host.to_lowercase())
+    }
+
+    /// Credentials for the host of a request URL
+    pub fn credentials_for_url(&self, url: &str) -> Option<&NetrcCredentials> {
+        let url = reqwest::Url::parse(url).ok()?;
+        self.credentials_for(url.host_str()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_extracts_credentials_for_matching_machine_only() {
+        let netrc = Netrc::parse(
+            "# Corporate mirrors
+machine github.com login octocat password ghp_token
+machine artifactory.example.com
+  login ci-bot
+  password s3cret
+",
+        );
+
+        assert_eq!(
+            netrc.credentials_for("artifactory.example.com"),
+            Some(&NetrcCredentials {
+                login: "ci-bot".to_string(),
+                password: Some("s3cret".to_string()),
+            })
+        );
+        assert_eq!(
+            netrc
+                .credentials_for("github.com")
+                .map(|c| c.login.as_str()),
+            Some("octocat")
+        );
+        assert_eq!(netrc.credentials_for("registry.npmjs.org"), None);
+    }
+
+    #[test]
+    fn parse_ignores_default_entry_and_skips_macros() {
+        let netrc = Netrc::parse(
+            "machine first.example.com login first password one
+machine first.example.com login second password two
+macdef init
+machine macro.example.com login ignored
+
+default login anonymous
+",
+        );
+
+        assert_eq!(
+            netrc
+                .credentials_for("FIRST.example.com")
+                .map(|c| c.login.as_str()),
+            Some("first")
+        );
+        assert_eq!(netrc.credentials_for("other.example.com"), None);
+        assert_eq!(netrc.credentials_for("macro.example.com"), None);
+    }
+
+    #[test]
+    fn credentials_for_url_matches_on_host() {
+        let netrc = Netrc::parse("machine npm.example.com login user password pass");
+
+        assert!(
+            netrc
+                .credentials_for_url("https://npm.example.com:8443/api/npm/react")
+                .is_some()
+        );
+        assert!(
+            netrc
+                .credentials_for_url("https://registry.npmjs.org/react")
+                .is_none()
+        );
+    }
+}
@@ -0,0 +1,220 @@
+//! `.npmrc` awareness
+//!
+//! Reads the registry settings npm itself uses, so private registries work
+//! without repeating them in the editor configuration. Only a small subset is
+//! understood: `registry=`, `@scope:registry=` and `//host/path/:_authToken=`.
+//! `${VAR}` references in values are expanded from the environment.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::Path;
+
+use tracing::{info, warn};
+
+use crate::config::{NpmRegistryConfig, interpolate_env};
+
+/// File name of npm's config, both in the project root and the home directory
+pub const NPMRC_FILE: &str = ".npmrc";
+
+/// Registry settings from `.npmrc`
+#[derive(Clone, Default, PartialEq)]
+pub struct Npmrc {
+    /// Default registry URL (`registry=`)
+    pub registry: Option<String>,
+    /// Registry URL per scope (`@scope:registry=`)
+    pub scoped_registries: BTreeMap<String, String>,
+    /// Auth token per registry URL without scheme, e.g. `//npm.example.com/`
+    pub auth_tokens: BTreeMap<String, String>,
+}
+
+impl Npmrc {
+    /// Parse `.npmrc` content, expanding `${VAR}` with `lookup`
+    ///
+    /// Lines referencing an unset variable are skipped, as are settings
+    /// version-lsp does not use.
+    pub fn parse(content: &str, lookup: impl Fn(&str) -> Option<String>) -> Self {
+        let mut npmrc = Self::default();
+
+        for line in content.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') || line.starts_with(';') {
+                continue;
+            }
+            let Some((key, value)) = line.split_once('=') else {
+                continue;
+            };
+            let key = key.trim();
+            let value = value.trim().trim_matches(['"', '\'']);
+
+            let value = match interpolate_env(value, key, &lookup) {
+                Ok(value) => value,
+                Err(e) => {
+                    warn!("Skipping .npmrc entry: {}", e);
+                    continue;
+                }
+            };
+
+            if key == "registry" {
+                npmrc.registry = Some(value);
+            } else if let Some(scope) = key
+                .strip_suffix(":registry")
+                .filter(|scope| scope.starts_with('@'))
+            {
+                npmrc.scoped_registries.insert(scope.to_string(), value);
+            } else if let Some(registry) = key
+                .strip_suffix(":_authToken")
+                .filter(|registry| registry.starts_with("//"))
+            {
+                npmrc.auth_tokens.insert(registry.to_string(), value);
+            }
+        }
+
+        npmrc
+    }
+
+    /// Load the project `.npmrc` on top of the user-level one
+    ///
+    /// Either file may be missing; settings in the project file win.
+    pub fn load(workspace_root: Option<&Path>, user_npmrc: Option<&Path>) -> Self {
+        let project_npmrc = workspace_root.map(|root| root.join(NPMRC_FILE));
+
+        [user_npmrc, project_npmrc.as_deref()]
+            .into_iter()
+            .flatten()
+            .filter_map(|path| {
+                let content = std::fs::read_to_string(path).ok()?;
+                info!("Loaded npm registry settings from {:?}", path);
+                Some(Self::parse(&content, |name| std::env::var(name).ok()))
+            })
+            .fold(Self::default(), Self::merge)
+    }
+
+    /// Overlay `other` on top of `self`
+    fn merge(mut self, other: Self) -> Self {
+        if other.registry.is_some() {
+            self.registry = other.registry;
+        }
+        self.scoped_registries.extend(other.scoped_registries);
+        self.auth_tokens.extend(other.auth_tokens);
+        self
+    }
+
+    /// Fill in npm registry settings the editor configuration leaves unset
+    pub fn apply_to(&self, npm: &mut NpmRegistryConfig) {
+        if npm.url.is_none() {
+            npm.url = self.registry.clone();
+        }
+        for (scope, url) in &self.scoped_registries {
+            npm.scoped_registries
+                .entry(scope.clone())
+                .or_insert_with(|| url.clone());
+        }
+        npm.auth_tokens = self.auth_tokens.clone();
+    }
+}
+
+impl fmt::Debug for Npmrc {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Npmrc")
+            .field("registry", &self.registry)
+            .field("scoped_registries", &self.scoped_registries)
+            .field("auth_tokens", &self.auth_tokens.keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lookup(name: &str) -> Option<String> {
+        match name {
+            "NPM_TOKEN" => Some("s3cret".to_string()),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn parse_reads_registries_and_auth_tokens() {
+        let content = r#"
+# Company registry
+registry=https://registry.mycompany.example/
+@mycompany:registry = https://verdaccio.mycompany.example/
+//verdaccio.mycompany.example/:_authToken=${NPM_TOKEN}
+; unrelated settings are ignored
+save-exact=true
+"#;
+
+        let npmrc = Npmrc::parse(content, lookup);
+
+        assert_eq!(
+            npmrc,
+            Npmrc {
+                registry: Some("https://registry.mycompany.example/".to_string()),
+                scoped_registries: BTreeMap::from([(
+                    "@mycompany".to_string(),
+                    "https://verdaccio.mycompany.example/".to_string()
+                )]),
+                auth_tokens: BTreeMap::from([(
+                    "//verdaccio.mycompany.example/".to_string(),
+                    "s3cret".to_string()
+                )]),
+            }
+        );
+    }
+
+    #[test]
+    fn parse_skips_entries_with_unset_variables() {
+        let content =
+            "//npm.example.com/:_authToken=${MISSING_TOKEN}\nregistry=https://npm.example.com/\n";
+
+        let npmrc = Npmrc::parse(content, lookup);
+
+        assert!(npmrc.auth_tokens.is_empty());
+        assert_eq!(npmrc.registry.as_deref(), Some("https://npm.example.com/"));
+    }
+
+    #[test]
+    fn load_prefers_project_settings_over_user_settings() {
+        let user_dir = tempfile::TempDir::new().unwrap();
+        let project_dir = tempfile::TempDir::new().unwrap();
+        let user_npmrc = user_dir.path().join(NPMRC_FILE);
+        std::fs::write(
+            &user_npmrc,
+            "registry=https://user.example/\n@a:registry=https://a.user.example/\n",
+        )
+        .unwrap();
+        std::fs::write(
+            project_dir.path().join(NPMRC_FILE),
+            "registry=https://project.example/\n",
+        )
+        .unwrap();
+
+        let npmrc = Npmrc::load(Some(project_dir.path()), Some(&user_npmrc));
+
+        assert_eq!(npmrc.registry.as_deref(), Some("https://project.example/"));
+        assert_eq!(npmrc.scoped_registries["@a"], "https://a.user.example/");
+    }
+
+    #[test]
+    fn apply_to_keeps_explicit_editor_settings() {
+        let npmrc = Npmrc::parse(
+            "registry=https://npmrc.example/\n@a:registry=https://a.npmrc.example/\n@b:registry=https://b.npmrc.example/\n",
+            lookup,
+        );
+        let mut npm = NpmRegistryConfig {
+            url: Some("https://editor.example/".to_string()),
+            scoped_registries: BTreeMap::from([(
+                "@a".to_string(),
+                "https://a.editor.example/".to_string(),
+            )]),
+            ..NpmRegistryConfig::default()
+        };
+
+        npmrc.apply_to(&mut npm);
+
+        assert_eq!(npm.url.as_deref(), Some("https://editor.example/"));
+        assert_eq!(npm.scoped_registries["@a"], "https://a.editor.example/");
+        assert_eq!(npm.scoped_registries["@b"], "https://b.npmrc.example/");
+    }
+}
@@ -0,0 +1,160 @@
+//! Brewfile parser
+//!
+//! Brewfiles rarely pin versions, so only versioned formulae (`brew "node@18"`)
+//! are extracted: the package is the base formula (`node`) and the version is
+//! the `@` suffix (`18`), checked against the formula's latest stable version.
+//! Plain `brew "node"` entries, casks and tap formulae (`user/tap/name`) are
+//! skipped.
+//!
+//! Format examples:
+//! - `brew "node@18"`
+//! - `brew "python@3.11", link: true`
+
+use regex::Regex;
+
+use crate::parser::traits::{ParseError, Parser};
+use crate::parser::types::{PackageInfo, RegistryType};
+
+/// Parser for Brewfiles
+pub struct BrewfileParser {
+    /// Regex for a versioned formula: `brew "name@version"`
+    brew_re: Regex,
+}
+
+impl BrewfileParser {
+    pub fn new() -> Self {
+        Self {
+            // Match: brew "name@version" [, options] (optionally parenthesized)
+            brew_re: Regex::new(r#"^\s*brew\s*\(?\s*["']([^"'@/\s]+)@([^"'\s]+)["']"#).unwrap(),
+        }
+    }
+}
+
+impl Default for BrewfileParser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Parser for BrewfileParser {
+    fn parse(&self, content: &str) -> Result<Vec<PackageInfo>, ParseError> {
+        let mut results = Vec::new();
+        let mut line_start = 0;
+
+        for (line_num, raw_line) in content.split_inclusive('\n').enumerate() {
+            let current_line_start = line_start;
+            line_start += raw_line.len();
+            let line = raw_line.trim_end_matches(['\n', '\r']);
+
+            // Skip comments
+            if line.trim_start().starts_with('#') {
+                continue;
+            }
+
+            let Some(caps) = self.brew_re.captures(line) else {
+                continue;
+            };
+
+            let name = caps.get(1).unwrap().as_str();
+            let version_match = caps.get(2).unwrap();
+
+            results.push(PackageInfo {
+                name: name.to_string(),
+                version: version_match.as_str().to_string(),
+                commit_hash: None,
+                registry_type: RegistryType::Homebrew,
+                start_offset: current_line_start + version_match.start(),
+                end_offset: current_line_start + version_match.end(),
+                line: line_num,
+                column: version_match.start(),
+                extra_info: None,
+            });
+        }
+
+        Ok(results)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_extracts_versioned_formula() {
+        let parser = BrewfileParser::new();
+        let content = "tap \"homebrew/bundle\"\nbrew \"node@18\"\n";
+
+        let result = parser.parse(content).unwrap();
+
+        assert_eq!(
+            result,
+            vec![PackageInfo {
+                name: "node".to_string(),
+                version: "18".to_string(),
+                commit_hash: None,
+                registry_type: RegistryType::Homebrew,
+                start_offset: 33,
+                end_offset: 35,
+                line: 1,
+                column: 11,
+                extra_info: None,
+            }]
+        );
+        assert_eq!(&content[33..35], "18");
+    }
+
+    #[test]
+    fn parse_skips_unversioned_formulae_casks_and_taps() {
+        let parser = BrewfileParser::new();
+        let content = r#"tap "homebrew/cask"
+brew "node"
+brew 'python@3.11', link: true
+# brew "postgresql@14"
+brew "user/tap/tool@2"
+cask "firefox"
+brew("openssl@3")
+"#;
+
+        let result = parser.parse(content).unwrap();
+
+        let summary: Vec<_> = result
+            .iter()
+            .map(|p| (p.name.as_str(), p.version.as_str(), p.line))
+            .collect();
+        assert_eq!(summary, vec![("python", "3.11", 2), ("openssl", "3", 6)]);
+        for package in &result {
+            assert_eq!(
+                &content[package.start_offset..package.end_offset],
+                package.version
+            );
+        }
+    }
+
+    #[test]
+    fn parse_returns_empty_for_unpinned_brewfile() {
+        let parser = BrewfileParser::new();
+        let content = "brew \"git\"\nbrew \"jq\"\n";
+
+        assert!(parser.parse(content).unwrap().is_empty());
+    }
+
+    #[test]
+    fn parse_locates_formulae_in_crlf_files() {
+        let parser = BrewfileParser::new();
+        let content = "tap \"homebrew/bundle\"\r\nbrew \"node@18\"\r\nbrew \"python@3.12\"\r\n";
+
+        let result = parser.parse(content).unwrap();
+
+        let offsets: Vec<_> = result
+            .iter()
+            .map(|p| (p.start_offset, p.end_offset, p.line, p.column))
+            .collect();
+        assert_eq!(offsets, vec![(34, 36, 1, 11), (52, 56, 2, 13)]);
+        for package in &result {
+            assert_eq!(
+                &content[package.start_offset..package.end_offset],
+                package.version
+            );
+        }
+    }
+}
@@ -1,6 +1,20 @@
 //! Cargo.toml parser
+//!
+//! Member crates of a workspace may inherit a dependency with
+//! `serde = { workspace = true }` or `serde.workspace = true`. Those entries
+//! carry no version of their own and are skipped; the version is checked where
+//! it is declared, in the root manifest's `[workspace.dependencies]`.
+//!
+//! Only crates.io dependencies are checked. Entries under `[patch.*]` replace
+//! a crate with another source and are never read, and dependencies with a
+//! `registry` key are skipped unless it names the default `crates-io`
+//! registry, as alternate registries have no source to compare against.
+//!
+//! A dependency named after the manifest's own `[package]` (typically a
+//! dev-dependency on the crate itself) is skipped as well, since it refers to
+//! the local crate rather than to a release on crates.io.
 
-use crate::parser::traits::{ParseError, Parser};
+use crate::parser::traits::{ParseError, Parser, has_tree_sitter_errors};
 use crate::parser::types::{PackageInfo, RegistryType};
 use tracing::warn;
 
@@ -38,8 +52,16 @@ impl Parser for CargoTomlParser {
 
         self.extract_dependencies(root, content, &mut results);
 
+        if let Some(own_name) = Self::package_name(root, content) {
+            results.retain(|package| !Self::is_same_crate(&package.name, &own_name));
+        }
+
         Ok(results)
     }
+
+    fn has_syntax_errors(&self, content: &str) -> bool {
+        has_tree_sitter_errors(tree_sitter_toml_ng::LANGUAGE.into(), content)
+    }
 }
 
 impl CargoTomlParser {
@@ -67,6 +89,35 @@ impl CargoTomlParser {
         }
     }
 
+    /// Name of the crate declared by `[package]`, if any
+    fn package_name(root: tree_sitter::Node, content: &str) -> Option<String> {
+        let mut cursor = root.walk();
+        let package_table = root.children(&mut cursor).find(|child| {
+            child.kind() == "table"
+                && child
+                    .named_child(0)
+                    .is_some_and(|key| &content[key.byte_range()] == "package")
+        })?;
+
+        let mut cursor = package_table.walk();
+        let name_pair = package_table.children(&mut cursor).find(|child| {
+            child.kind() == "pair"
+                && child
+                    .named_child(0)
+                    .is_some_and(|key| &content[key.byte_range()] == "name")
+        })?;
+
+        let value = name_pair
+            .named_child(1)
+            .filter(|value| value.kind() == "string")?;
+        Self::extract_string_value(value, content).map(|(name, ..)| name)
+    }
+
+    /// crates.io treats `-` and `_` in crate names as the same character
+    fn is_same_crate(a: &str, b: &str) -> bool {
+        a.replace('_', "-") == b.replace('_', "-")
+    }
+
     /// Process a TOML table node
     fn process_table(
         &self,
@@ -97,8 +148,30 @@ impl CargoTomlParser {
         let Some(name) = table_name else {
             return;
         };
+        // Platform-specific form: [target.'cfg(windows)'.dependencies]
+        let name = Self::strip_target_prefix(&name);
 
-        if !Self::DEPENDENCY_TABLES.contains(&name.as_str()) {
+        // Sub-table form: [dependencies.serde] / [workspace.dependencies.serde]
+        if let Some(package_name) = Self::dependency_subtable_name(name) {
+            if let Some((version, start_offset, end_offset, line, column)) =
+                self.extract_version_from_inline_table(table_node, content)
+            {
+                results.push(PackageInfo {
+                    name: package_name.to_string(),
+                    version,
+                    commit_hash: None,
+                    registry_type: RegistryType::CratesIo,
+                    start_offset,
+                    end_offset,
+                    line,
+                    column,
+                    extra_info: None,
+                });
+            }
+            return;
+        }
+
+        if !Self::DEPENDENCY_TABLES.contains(&name) {
             return;
         }
 
@@ -111,6 +184,49 @@ impl CargoTomlParser {
         }
     }
 
+    /// Strip a `target.<triple or cfg>.` prefix from a table name
+    /// (e.g., "target.'cfg(windows)'.dependencies" -> "dependencies")
+    fn strip_target_prefix(table_name: &str) -> &str {
+        let Some(rest) = table_name.strip_prefix("target.") else {
+            return table_name;
+        };
+
+        // The target key is either quoted (cfg expressions) or a bare triple
+        let key_end = match rest.chars().next() {
+            Some('\'') => rest[1..].find('\'').map(|i| i + 2),
+            Some('"') => {
+                let mut escaped = false;
+                rest.char_indices().skip(1).find_map(|(i, c)| match c {
+                    '\\' if !escaped => {
+                        escaped = true;
+                        None
+                    }
+                    '"' if !escaped => Some(i + 1),
+                    _ => {
+                        escaped = false;
+                        None
+                    }
+                })
+            }
+            _ => rest.find('.'),
+        };
+
+        key_end
+            .and_then(|end| rest[end..].strip_prefix('.'))
+            .unwrap_or(table_name)
+    }
+
+    /// Return the package name if the table is a dependency sub-table
+    /// (e.g., "workspace.dependencies.serde" -> "serde")
+    fn dependency_subtable_name(table_name: &str) -> Option<&str> {
+        Self::DEPENDENCY_TABLES.iter().find_map(|table| {
+            table_name
+                .strip_prefix(table)
+                .and_then(|rest| rest.strip_prefix('.'))
+                .filter(|name| !name.is_empty() && !name.contains('.'))
+        })
+    }
+
     /// Extract package info from a key-value pair
     fn extract_package_from_pair(
         &self,
@@ -143,38 +259,12 @@ impl CargoTomlParser {
                     if is_dotted_key {
                         // For dotted keys, only extract if suffix is "version"
                         if dotted_key_suffix.as_deref() == Some("version") {
-                            let text = &content[child.byte_range()];
-                            let version = text
-                                .trim()
-                                .trim_start_matches('"')
-                                .trim_end_matches('"')
-                                .to_string();
-                            let start_point = child.start_position();
-                            version_info = Some((
-                                version,
-                                child.start_byte() + 1,
-                                child.end_byte() - 1,
-                                start_point.row,
-                                start_point.column + 1,
-                            ));
+                            version_info = Self::extract_string_value(child, content);
                         }
                         // Skip if suffix is path, workspace, registry, or other
                     } else {
                         // Non-dotted simple version: serde = "1.0"
-                        let text = &content[child.byte_range()];
-                        let version = text
-                            .trim()
-                            .trim_start_matches('"')
-                            .trim_end_matches('"')
-                            .to_string();
-                        let start_point = child.start_position();
-                        version_info = Some((
-                            version,
-                            child.start_byte() + 1,
-                            child.end_byte() - 1,
-                            start_point.row,
-                            start_point.column + 1,
-                        ));
+                        version_info = Self::extract_string_value(child, content);
                     }
                 }
                 "inline_table" => {
@@ -203,10 +293,18 @@ impl CargoTomlParser {
     }
 
     /// Keys that indicate dependencies that should be skipped
-    const SKIP_KEYS: [&'static str; 3] = ["path", "workspace", "registry"];
+    ///
+    /// git and path dependencies resolve from their source even when a
+    /// `version` is also given, so comparing them against crates.io is misleading.
+    const SKIP_KEYS: [&'static str; 4] = ["path", "git", "workspace", "registry-index"];
+
+    /// Name Cargo gives the default registry in `registry = "..."`
+    const CRATES_IO_REGISTRY: &'static str = "crates-io";
 
     /// Extract version from an inline table: { version = "1.0", ... }
-    /// Returns None if the dependency should be skipped (path, workspace, or registry)
+    /// Also used for dependency sub-tables, whose pairs have the same shape.
+    /// Returns None if the dependency should be skipped (path, git, workspace,
+    /// or an alternate registry)
     fn extract_version_from_inline_table(
         &self,
         table_node: tree_sitter::Node,
@@ -231,20 +329,7 @@ impl CargoTomlParser {
                             is_version_key = key == "version";
                         }
                         "string" if is_version_key => {
-                            let text = &content[pair_child.byte_range()];
-                            let version = text
-                                .trim()
-                                .trim_start_matches('"')
-                                .trim_end_matches('"')
-                                .to_string();
-                            let start_point = pair_child.start_position();
-                            return Some((
-                                version,
-                                pair_child.start_byte() + 1,
-                                pair_child.end_byte() - 1,
-                                start_point.row,
-                                start_point.column + 1,
-                            ));
+                            return Self::extract_string_value(pair_child, content);
                         }
                         _ => {}
                     }
@@ -255,6 +340,50 @@ impl CargoTomlParser {
         None
     }
 
+    /// Extract the contents of a TOML string node along with its position
+    ///
+    /// Handles basic (`"1.0"`), literal (`'1.0'`) and multi-line (`"""1.0"""`,
+    /// `'''1.0'''`) strings by stripping exactly one delimiter on each side.
+    /// Whitespace inside the quotes is excluded from both the returned version
+    /// and its byte range, so `" 1.0 "` yields `1.0` pointing at the digits.
+    ///
+    /// Returns `(version, start_offset, end_offset, line, column)`, or None if
+    /// the string is empty.
+    fn extract_string_value(
+        string_node: tree_sitter::Node,
+        content: &str,
+    ) -> Option<(String, usize, usize, usize, usize)> {
+        let text = &content[string_node.byte_range()];
+        let quote_len = ["\"\"\"", "'''", "\"", "'"]
+            .into_iter()
+            .find(|q| text.len() >= q.len() * 2 && text.starts_with(q) && text.ends_with(q))
+            .map_or(0, str::len);
+
+        let inner = &text[quote_len..text.len() - quote_len];
+        let version = inner.trim();
+        if version.is_empty() {
+            return None;
+        }
+
+        let leading = &inner[..inner.len() - inner.trim_start().len()];
+        let start_offset = string_node.start_byte() + quote_len + leading.len();
+        let end_offset = start_offset + version.len();
+
+        // Multi-line strings may put the version on a later line than the
+        // opening delimiter, so derive line/column from the skipped prefix.
+        let start_point = string_node.start_position();
+        let prefix = &text[..quote_len + leading.len()];
+        let (line, column) = match prefix.rfind('\n') {
+            Some(pos) => (
+                start_point.row + prefix.matches('\n').count(),
+                prefix.len() - pos - 1,
+            ),
+            None => (start_point.row, start_point.column + prefix.len()),
+        };
+
+        Some((version.to_string(), start_offset, end_offset, line, column))
+    }
+
     /// Check if an inline table contains keys that should cause the dependency to be skipped
     fn should_skip_inline_table(&self, table_node: tree_sitter::Node, content: &str) -> bool {
         let mut cursor = table_node.walk();
@@ -262,13 +391,26 @@ impl CargoTomlParser {
         for child in table_node.children(&mut cursor) {
             if child.kind() == "pair" {
                 let mut pair_cursor = child.walk();
+                let mut is_registry_key = false;
 
                 for pair_child in child.children(&mut pair_cursor) {
-                    if pair_child.kind() == "bare_key" {
-                        let key = &content[pair_child.byte_range()];
-                        if Self::SKIP_KEYS.contains(&key) {
-                            return true;
+                    match pair_child.kind() {
+                        "bare_key" => {
+                            let key = &content[pair_child.byte_range()];
+                            if Self::SKIP_KEYS.contains(&key) {
+                                return true;
+                            }
+                            is_registry_key = key == "registry";
+                        }
+                        // registry = "crates-io" is the default source
+                        "string" if is_registry_key => {
+                            let registry = Self::extract_string_value(pair_child, content)
+                                .map(|(registry, ..)| registry);
+                            if registry.as_deref() != Some(Self::CRATES_IO_REGISTRY) {
+                                return true;
+                            }
                         }
+                        _ => {}
                     }
                 }
             }
@@ -281,6 +423,7 @@ impl CargoTomlParser {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rstest::rstest;
 
     #[test]
     fn parse_extracts_dependencies() {
@@ -379,6 +522,20 @@ serde = { version = "1.0", features = ["derive"] }
         assert_eq!(result[0].version, "1.0");
     }
 
+    #[test]
+    fn parse_extracts_same_package_from_string_and_inline_table() {
+        let parser = CargoTomlParser::new();
+        let plain = parser.parse("[dependencies]\nserde = \"1.0\"\n").unwrap();
+        let inline = parser
+            .parse("[dependencies]\nserde = { version = \"1.0\", features = [\"derive\"] }\n")
+            .unwrap();
+
+        assert_eq!(plain.len(), 1);
+        assert_eq!(inline.len(), 1);
+        assert!(plain[0].matches_logical(&inline[0]));
+        assert_ne!(plain[0].start_offset, inline[0].start_offset);
+    }
+
     #[test]
     fn parse_extracts_all_dependency_types() {
         let parser = CargoTomlParser::new();
@@ -412,6 +569,14 @@ version = "0.1.0"
         assert!(result.is_empty());
     }
 
+    #[rstest]
+    #[case("[dependencies]\nserde = \"1.0\"\n", false)]
+    #[case("[dependencies]\nserde = \"1.0\"\ntokio = { version = \"1.0\"\n", true)]
+    #[case("[dependencies\nserde = \"1.0\"\n", true)]
+    fn has_syntax_errors_detects_broken_toml(#[case] content: &str, #[case] expected: bool) {
+        assert_eq!(CargoTomlParser::new().has_syntax_errors(content), expected);
+    }
+
     #[test]
     fn parse_handles_version_requirements() {
         let parser = CargoTomlParser::new();
@@ -420,13 +585,15 @@ serde = "^1.0"
 tokio = "~1.35"
 anyhow = ">=1.0"
 thiserror = "=2.0"
+rand = ">=0.8, <0.10"
 "#;
         let result = parser.parse(content).unwrap();
-        assert_eq!(result.len(), 4);
+        assert_eq!(result.len(), 5);
         assert_eq!(result[0].version, "^1.0");
         assert_eq!(result[1].version, "~1.35");
         assert_eq!(result[2].version, ">=1.0");
         assert_eq!(result[3].version, "=2.0");
+        assert_eq!(result[4].version, ">=0.8, <0.10");
     }
 
     #[test]
@@ -444,6 +611,63 @@ another-local = { path = "./another", version = "0.1" }
         assert_eq!(result[1].name, "tokio");
     }
 
+    #[test]
+    fn parse_skips_dependency_on_own_package() {
+        let parser = CargoTomlParser::new();
+        let content = r#"[package]
+name = "my_crate"
+version = "0.3.0"
+
+[dependencies]
+serde = "1.0"
+
+[dev-dependencies]
+my-crate = { version = "0.3", features = ["test-utils"] }
+tokio = "1.0"
+"#;
+        let result = parser.parse(content).unwrap();
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0].name, "serde");
+        assert_eq!(result[1].name, "tokio");
+    }
+
+    #[test]
+    fn parse_skips_git_and_path_inline_tables() {
+        let parser = CargoTomlParser::new();
+        let content = r#"[dependencies]
+from-git = { git = "https://github.com/example/from-git", version = "1.0" }
+from-branch = { git = "https://github.com/example/from-branch", branch = "main" }
+local = { path = "../local" }
+serde = { version = "1.0", features = ["derive"] }
+"#;
+        let result = parser.parse(content).unwrap();
+        assert_eq!(
+            result,
+            vec![PackageInfo {
+                name: "serde".to_string(),
+                version: "1.0".to_string(),
+                commit_hash: None,
+                registry_type: RegistryType::CratesIo,
+                start_offset: 224,
+                end_offset: 227,
+                line: 4,
+                column: 21,
+                extra_info: None,
+            }]
+        );
+    }
+
+    #[test]
+    fn parse_skips_git_dependency_subtable() {
+        let parser = CargoTomlParser::new();
+        let content = r#"[dependencies.from-git]
+git = "https://github.com/example/from-git"
+version = "1.0"
+"#;
+        let result = parser.parse(content).unwrap();
+        assert!(result.is_empty());
+    }
+
     #[test]
     fn parse_skips_workspace_dependencies() {
         let parser = CargoTomlParser::new();
@@ -459,6 +683,25 @@ utils = { workspace = true, features = ["full"] }
         assert_eq!(result[1].name, "tokio");
     }
 
+    #[test]
+    fn parse_skips_workspace_inherited_subtables_and_target_dependencies() {
+        let parser = CargoTomlParser::new();
+        let content = r#"[dependencies.shared]
+workspace = true
+features = ["full"]
+
+[target.'cfg(unix)'.dependencies]
+nix = { workspace = true }
+libc.workspace = true
+
+[dev-dependencies]
+tokio = "1.0"
+"#;
+        let result = parser.parse(content).unwrap();
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].name, "tokio");
+    }
+
     #[test]
     fn parse_skips_registry_dependencies() {
         let parser = CargoTomlParser::new();
@@ -473,6 +716,48 @@ tokio = "1.0"
         assert_eq!(result[1].name, "tokio");
     }
 
+    #[test]
+    fn parse_skips_internal_registry_but_checks_explicit_crates_io() {
+        let parser = CargoTomlParser::new();
+        let content = r#"[dependencies]
+internal-crate = { version = "1.0", registry = "internal" }
+serde = { version = "1.0", registry = "crates-io" }
+
+[dependencies.private]
+version = "2.0"
+registry = "internal"
+"#;
+        let result = parser.parse(content).unwrap();
+        let names: Vec<_> = result.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, vec!["serde"]);
+        assert_eq!(
+            &content[result[0].start_offset..result[0].end_offset],
+            "1.0"
+        );
+    }
+
+    #[test]
+    fn parse_skips_patch_tables() {
+        let parser = CargoTomlParser::new();
+        let content = r#"[dependencies]
+serde = "1.0"
+
+[patch.crates-io]
+serde = { git = "https://github.com/serde-rs/serde" }
+tokio = "1.40"
+
+[patch.crates-io.rand]
+version = "0.8"
+
+[patch."https://github.com/example/repo"]
+example = { path = "../example" }
+"#;
+        let result = parser.parse(content).unwrap();
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].name, "serde");
+        assert_eq!(result[0].line, 1);
+    }
+
     #[test]
     fn parse_skips_mixed_special_dependencies() {
         let parser = CargoTomlParser::new();
@@ -547,4 +832,263 @@ serde = { version = "1.0", features = ["derive"] }
         assert_eq!(result[1].name, "serde");
         assert_eq!(result[1].version, "1.0");
     }
+
+    #[test]
+    fn parse_extracts_root_workspace_dependencies_alongside_inherited_ones() {
+        let parser = CargoTomlParser::new();
+        // A root manifest that is also a package inheriting its own workspace deps
+        let content = r#"[workspace.dependencies]
+serde = "1.0.200"
+
+[package]
+name = "root"
+
+[dependencies]
+serde.workspace = true
+"#;
+        let result = parser.parse(content).unwrap();
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].name, "serde");
+        assert_eq!(result[0].version, "1.0.200");
+        assert_eq!(result[0].line, 1);
+        assert_eq!(
+            &content[result[0].start_offset..result[0].end_offset],
+            "1.0.200"
+        );
+    }
+
+    #[test]
+    fn parse_extracts_single_quoted_version() {
+        let parser = CargoTomlParser::new();
+        let content = r#"[dependencies]
+serde = '1.0.0'
+"#;
+        let result = parser.parse(content).unwrap();
+        assert_eq!(
+            result,
+            vec![PackageInfo {
+                name: "serde".to_string(),
+                version: "1.0.0".to_string(),
+                commit_hash: None,
+                registry_type: RegistryType::CratesIo,
+                start_offset: 24,
+                end_offset: 29,
+                line: 1,
+                column: 9,
+                extra_info: None,
+            }]
+        );
+    }
+
+    #[test]
+    fn parse_extracts_single_quoted_inline_table_version() {
+        let parser = CargoTomlParser::new();
+        let content = r#"[dependencies]
+serde = { version = '1.0', features = ["derive"] }
+"#;
+        let result = parser.parse(content).unwrap();
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].version, "1.0");
+        assert_eq!(
+            &content[result[0].start_offset..result[0].end_offset],
+            "1.0"
+        );
+        assert_eq!(result[0].column, 21);
+    }
+
+    #[test]
+    fn parse_trims_whitespace_inside_quotes_without_shifting_offsets() {
+        let parser = CargoTomlParser::new();
+        let content = r#"[dependencies]
+serde = "  1.0 "
+tokio.version = ' 1.35'
+"#;
+        let result = parser.parse(content).unwrap();
+        assert_eq!(result.len(), 2);
+
+        assert_eq!(result[0].version, "1.0");
+        assert_eq!(
+            &content[result[0].start_offset..result[0].end_offset],
+            "1.0"
+        );
+        assert_eq!((result[0].line, result[0].column), (1, 11));
+
+        assert_eq!(result[1].version, "1.35");
+        assert_eq!(
+            &content[result[1].start_offset..result[1].end_offset],
+            "1.35"
+        );
+        assert_eq!((result[1].line, result[1].column), (2, 18));
+    }
+
+    #[test]
+    fn parse_extracts_multiline_string_version() {
+        let parser = CargoTomlParser::new();
+        let content = "[dependencies]\nserde = \"\"\"1.0\"\"\"\nlog = '''0.4'''\n";
+        let result = parser.parse(content).unwrap();
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0].version, "1.0");
+        assert_eq!(
+            &content[result[0].start_offset..result[0].end_offset],
+            "1.0"
+        );
+        assert_eq!(result[0].column, 11);
+        assert_eq!(result[1].version, "0.4");
+        assert_eq!(
+            &content[result[1].start_offset..result[1].end_offset],
+            "0.4"
+        );
+    }
+
+    #[test]
+    fn parse_skips_empty_version_string() {
+        let parser = CargoTomlParser::new();
+        let content = r#"[dependencies]
+serde = ""
+tokio = "1.0"
+"#;
+        let result = parser.parse(content).unwrap();
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].name, "tokio");
+    }
+
+    #[test]
+    fn parse_handles_virtual_manifest_without_package_table() {
+        let parser = CargoTomlParser::new();
+        let content = r#"[workspace]
+resolver = "2"
+members = ["crates/*"]
+
+[workspace.package]
+version = "0.1.0"
+edition = "2021"
+
+[workspace.dependencies]
+anyhow = "1.0"
+tokio = { version = "1.35", features = ["full"] }
+internal = { path = "crates/internal" }
+"#;
+        let result = parser.parse(content).unwrap();
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0].name, "anyhow");
+        assert_eq!(result[0].version, "1.0");
+        assert_eq!(result[1].name, "tokio");
+        assert_eq!(result[1].version, "1.35");
+    }
+
+    #[test]
+    fn parse_extracts_workspace_dependency_subtable() {
+        let parser = CargoTomlParser::new();
+        let content = r#"[workspace]
+members = ["crates/*"]
+
+[workspace.dependencies.serde]
+version = "1.0"
+features = ["derive"]
+
+[workspace.dependencies.internal]
+path = "crates/internal"
+"#;
+        let result = parser.parse(content).unwrap();
+        assert_eq!(
+            result,
+            vec![PackageInfo {
+                name: "serde".to_string(),
+                version: "1.0".to_string(),
+                commit_hash: None,
+                registry_type: RegistryType::CratesIo,
+                start_offset: 78,
+                end_offset: 81,
+                line: 4,
+                column: 11,
+                extra_info: None,
+            }]
+        );
+    }
+
+    #[test]
+    fn parse_extracts_dependency_subtables() {
+        let parser = CargoTomlParser::new();
+        let content = r#"[dependencies.serde]
+version = "1.0"
+
+[dev-dependencies.mockall]
+version = "0.14"
+
+[build-dependencies.cc]
+git = "https://github.com/rust-lang/cc-rs"
+"#;
+        let result = parser.parse(content).unwrap();
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0].name, "serde");
+        assert_eq!(result[0].version, "1.0");
+        assert_eq!(result[1].name, "mockall");
+        assert_eq!(result[1].version, "0.14");
+    }
+
+    #[test]
+    fn parse_extracts_cfg_target_dependencies() {
+        let parser = CargoTomlParser::new();
+        let content = r#"[target.'cfg(windows)'.dependencies]
+winapi = "0.3"
+"#;
+        let result = parser.parse(content).unwrap();
+        assert_eq!(
+            result,
+            vec![PackageInfo {
+                name: "winapi".to_string(),
+                version: "0.3".to_string(),
+                commit_hash: None,
+                registry_type: RegistryType::CratesIo,
+                start_offset: 47,
+                end_offset: 50,
+                line: 1,
+                column: 10,
+                extra_info: None,
+            }]
+        );
+    }
+
+    #[test]
+    fn parse_extracts_triple_target_dependencies() {
+        let parser = CargoTomlParser::new();
+        let content = r#"[target.x86_64-unknown-linux-gnu.dependencies]
+libc = { version = "0.2", default-features = false }
+"#;
+        let result = parser.parse(content).unwrap();
+        assert_eq!(
+            result,
+            vec![PackageInfo {
+                name: "libc".to_string(),
+                version: "0.2".to_string(),
+                commit_hash: None,
+                registry_type: RegistryType::CratesIo,
+                start_offset: 67,
+                end_offset: 70,
+                line: 1,
+                column: 20,
+                extra_info: None,
+            }]
+        );
+    }
+
+    #[test]
+    fn parse_extracts_target_dependency_variants() {
+        let parser = CargoTomlParser::new();
+        let content = r#"[target."cfg(target_os = \"linux\")".dev-dependencies]
+procfs = "0.16"
+
+[target.'cfg(unix)'.build-dependencies.cc]
+version = "1.0"
+
+[target.'cfg(unix)'.features]
+default = []
+"#;
+        let result = parser.parse(content).unwrap();
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0].name, "procfs");
+        assert_eq!(result[0].version, "0.16");
+        assert_eq!(result[1].name, "cc");
+        assert_eq!(result[1].version, "1.0");
+    }
 }
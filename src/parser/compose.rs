@@ -3,7 +3,7 @@
 //! Parses compose.yaml / docker-compose.yaml to extract container image tags.
 //! Supports Docker Hub (official and user images) and ghcr.io images.
 
-use crate::parser::traits::{ParseError, Parser};
+use crate::parser::traits::{ParseError, Parser, has_tree_sitter_errors};
 use crate::parser::types::{PackageInfo, RegistryType};
 use tracing::warn;
 
@@ -38,6 +38,10 @@ impl Parser for ComposeParser {
 
         Ok(results)
     }
+
+    fn has_syntax_errors(&self, content: &str) -> bool {
+        has_tree_sitter_errors(tree_sitter_yaml::LANGUAGE.into(), content)
+    }
 }
 
 /// Find services section and extract image fields
@@ -184,7 +188,7 @@ fn parse_image_value(node: tree_sitter::Node, content: &str) -> Option<PackageIn
 /// - `myuser/myapp` → `myuser/myapp` (Docker Hub user)
 /// - `ghcr.io/owner/repo` → `ghcr.io/owner/repo` (GitHub Container Registry)
 /// - `mcr.microsoft.com/...` → None (unsupported)
-fn resolve_image_name(image_name: &str) -> Option<String> {
+pub(crate) fn resolve_image_name(image_name: &str) -> Option<String> {
     // Check if it has a domain (contains '.')
     if let Some((domain, _rest)) = image_name.split_once('/')
         && domain.contains('.')
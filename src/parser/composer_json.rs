@@ -0,0 +1,248 @@
+//! composer.json parser
+//!
+//! Extracts packages from `require` and `require-dev`. Platform requirements
+//! (`php`, `ext-*`, `lib-*`, `composer-plugin-api`, ...) have no vendor prefix
+//! and are skipped, as are development branches (`dev-main`, `2.x-dev`).
+
+use crate::parser::traits::{ParseError, Parser, has_tree_sitter_errors};
+use crate::parser::types::{PackageInfo, RegistryType};
+use tracing::warn;
+
+/// Parser for composer.json files
+#[derive(Default)]
+pub struct ComposerJsonParser;
+
+impl ComposerJsonParser {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Parser for ComposerJsonParser {
+    fn parse(&self, content: &str) -> Result<Vec<PackageInfo>, ParseError> {
+        let mut parser = tree_sitter::Parser::new();
+        let language = tree_sitter_json::LANGUAGE;
+        parser.set_language(&language.into()).map_err(|e| {
+            warn!("Failed to set JSON language for tree-sitter: {}", e);
+            ParseError::TreeSitter(e.to_string())
+        })?;
+
+        let tree = parser.parse(content, None).ok_or_else(|| {
+            warn!("Failed to parse JSON content");
+            ParseError::ParseFailed("Failed to parse JSON".to_string())
+        })?;
+
+        let root = tree.root_node();
+        let mut results = Vec::new();
+
+        if let Some(document) = root.child(0)
+            && document.kind() == "object"
+        {
+            for (key_node, requirements) in object_pairs(document) {
+                if Self::REQUIRE_FIELDS.contains(&string_value(key_node, content))
+                    && requirements.kind() == "object"
+                {
+                    self.extract_packages(requirements, content, &mut results);
+                }
+            }
+        }
+
+        Ok(results)
+    }
+
+    fn has_syntax_errors(&self, content: &str) -> bool {
+        has_tree_sitter_errors(tree_sitter_json::LANGUAGE.into(), content)
+    }
+}
+
+impl ComposerJsonParser {
+    /// Requirement field names to extract
+    const REQUIRE_FIELDS: [&'static str; 2] = ["require", "require-dev"];
+
+    /// Extract packages from a requirement object (e.g., "require": { ... })
+    fn extract_packages(
+        &self,
+        object_node: tree_sitter::Node,
+        content: &str,
+        results: &mut Vec<PackageInfo>,
+    ) {
+        for (key_node, value_node) in object_pairs(object_node) {
+            if value_node.kind() != "string" {
+                continue;
+            }
+
+            // Platform requirements have no vendor prefix
+            let name = string_value(key_node, content);
+            if !name.contains('/') {
+                continue;
+            }
+
+            let version = string_value(value_node, content);
+            if is_dev_branch(version) {
+                continue;
+            }
+
+            // The version starts after the opening quote
+            let start_point = value_node.start_position();
+            let start_offset = value_node.start_byte() + 1;
+
+            results.push(PackageInfo {
+                name: name.to_string(),
+                version: version.to_string(),
+                commit_hash: None,
+                registry_type: RegistryType::Composer,
+                start_offset,
+                end_offset: start_offset + version.len(),
+                line: start_point.row,
+                column: start_point.column + 1,
+                extra_info: None,
+            });
+        }
+    }
+}
+
+/// Collect the `(key, value)` nodes of a JSON object
+fn object_pairs(
+    object_node: tree_sitter::Node<'_>,
+) -> Vec<(tree_sitter::Node<'_>, tree_sitter::Node<'_>)> {
+    let mut cursor = object_node.walk();
+    object_node
+        .children(&mut cursor)
+        .filter(|child| child.kind() == "pair")
+        .filter_map(|pair| {
+            Some((
+                pair.child_by_field_name("key")?,
+                pair.child_by_field_name("value")?,
+            ))
+        })
+        .collect()
+}
+
+/// Get the contents of a string node without its quotes
+fn string_value<'a>(node: tree_sitter::Node, content: &'a str) -> &'a str {
+    content[node.byte_range()].trim().trim_matches('"')
+}
+
+/// Whether a constraint points at a development branch rather than a release
+fn is_dev_branch(version: &str) -> bool {
+    let version = version.split(" as ").next().unwrap_or(version).trim();
+    version.starts_with("dev-") || version.ends_with("-dev")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_extracts_require_packages() {
+        let parser = ComposerJsonParser::new();
+        let content = r#"{
+  "name": "acme/app",
+  "require": {
+    "monolog/monolog": "^3.0"
+  }
+}"#;
+
+        let result = parser.parse(content).unwrap();
+
+        assert_eq!(
+            result,
+            vec![PackageInfo {
+                name: "monolog/monolog".to_string(),
+                version: "^3.0".to_string(),
+                commit_hash: None,
+                registry_type: RegistryType::Composer,
+                start_offset: 63,
+                end_offset: 67,
+                line: 3,
+                column: 24,
+                extra_info: None,
+            }]
+        );
+        assert_eq!(&content[63..67], "^3.0");
+    }
+
+    #[test]
+    fn parse_extracts_require_and_require_dev() {
+        let parser = ComposerJsonParser::new();
+        let content = r#"{
+  "require": {
+    "symfony/console": "^7.0",
+    "guzzlehttp/guzzle": "~7.8"
+  },
+  "require-dev": {
+    "phpunit/phpunit": "^10.5"
+  },
+  "suggest": {
+    "ext-intl": "For locale support"
+  }
+}"#;
+
+        let result = parser.parse(content).unwrap();
+
+        assert_eq!(
+            result
+                .iter()
+                .map(PackageInfo::logical_key)
+                .collect::<Vec<_>>(),
+            vec![
+                ("symfony/console", "^7.0", None, RegistryType::Composer),
+                ("guzzlehttp/guzzle", "~7.8", None, RegistryType::Composer),
+                ("phpunit/phpunit", "^10.5", None, RegistryType::Composer),
+            ]
+        );
+        for package in &result {
+            assert_eq!(
+                &content[package.start_offset..package.end_offset],
+                package.version
+            );
+        }
+    }
+
+    #[test]
+    fn parse_skips_platform_requirements() {
+        let parser = ComposerJsonParser::new();
+        let content = r#"{
+  "require": {
+    "php": ">=8.1",
+    "ext-json": "*",
+    "ext-mbstring": "*",
+    "lib-curl": ">=7.0",
+    "composer-plugin-api": "^2.0",
+    "laravel/framework": "^11.0"
+  }
+}"#;
+
+        let result = parser.parse(content).unwrap();
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].name, "laravel/framework");
+    }
+
+    #[test]
+    fn parse_skips_dev_branches() {
+        let parser = ComposerJsonParser::new();
+        let content = r#"{
+  "require": {
+    "acme/foo": "dev-main",
+    "acme/bar": "2.x-dev",
+    "acme/baz": "dev-feature as 1.0.0",
+    "acme/qux": "^1.2"
+  }
+}"#;
+
+        let result = parser.parse(content).unwrap();
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].name, "acme/qux");
+    }
+
+    #[test]
+    fn parse_returns_empty_for_no_requirements() {
+        let parser = ComposerJsonParser::new();
+
+        let result = parser.parse(r#"{"name": "acme/app"}"#).unwrap();
+
+        assert!(result.is_empty());
+    }
+}
@@ -1,6 +1,9 @@
 //! deno.json parser
+//!
+//! Reads the `imports` map. `jsr:` specifiers are checked against JSR and
+//! `npm:` specifiers against npm; URL imports are skipped.
 
-use crate::parser::traits::{ParseError, Parser};
+use crate::parser::traits::{ParseError, Parser, has_tree_sitter_errors};
 use crate::parser::types::{PackageInfo, RegistryType};
 use tracing::warn;
 
@@ -45,45 +48,60 @@ impl Parser for DenoJsonParser {
 
         Ok(results)
     }
+
+    fn has_syntax_errors(&self, content: &str) -> bool {
+        has_tree_sitter_errors(tree_sitter_json::LANGUAGE.into(), content)
+    }
 }
 
-/// Parsed JSR specifier (`jsr:@scope/pkg@version`).
+/// Parsed `jsr:` or `npm:` specifier (`jsr:@scope/pkg@version`).
 ///
 /// `version_offset_in_value` is `Some(idx)` when an explicit version is
 /// present and points at the version token's start inside the raw value,
 /// so code-action edits can target only the version range. For specifiers
 /// without a version, it is `None` and `version` is the sentinel `"latest"`.
-struct JsrSpecifier {
+struct ImportSpecifier {
+    registry_type: RegistryType,
     package_name: String,
     version: String,
     version_offset_in_value: Option<usize>,
 }
 
 impl DenoJsonParser {
-    /// Parse JSR specifier format: `jsr:@scope/package@version`.
-    fn parse_jsr_specifier(value: &str) -> Option<JsrSpecifier> {
-        let rest = value.strip_prefix("jsr:")?;
-        let prefix_len = "jsr:".len();
-
-        let slash_pos = rest.find('/')?;
-        let after_slash = &rest[slash_pos + 1..];
-
-        if let Some(at_pos) = after_slash.find('@') {
-            let package_name = &rest[..slash_pos + 1 + at_pos];
-            let version = &after_slash[at_pos + 1..];
-            let version_offset_in_value = prefix_len + slash_pos + 1 + at_pos + 1;
-            Some(JsrSpecifier {
-                package_name: package_name.to_string(),
-                version: version.to_string(),
-                version_offset_in_value: Some(version_offset_in_value),
-            })
+    /// Parse an import map value, returning None for URL and other imports
+    fn parse_specifier(value: &str) -> Option<ImportSpecifier> {
+        if let Some(rest) = value.strip_prefix("jsr:") {
+            Self::parse_package_specifier(RegistryType::Jsr, rest, "jsr:".len())
+                .filter(|specifier| specifier.package_name.contains('/'))
+        } else if let Some(rest) = value.strip_prefix("npm:") {
+            Self::parse_package_specifier(RegistryType::Npm, rest, "npm:".len())
         } else {
-            Some(JsrSpecifier {
+            None
+        }
+    }
+
+    /// Split `name@version` at the final `@`; a leading `@` belongs to the scope.
+    fn parse_package_specifier(
+        registry_type: RegistryType,
+        rest: &str,
+        prefix_len: usize,
+    ) -> Option<ImportSpecifier> {
+        let specifier = match rest.rfind('@').filter(|&at_pos| at_pos > 0) {
+            Some(at_pos) => ImportSpecifier {
+                registry_type,
+                package_name: rest[..at_pos].to_string(),
+                version: rest[at_pos + 1..].to_string(),
+                version_offset_in_value: Some(prefix_len + at_pos + 1),
+            },
+            None => ImportSpecifier {
+                registry_type,
                 package_name: rest.to_string(),
                 version: "latest".to_string(),
                 version_offset_in_value: None,
-            })
-        }
+            },
+        };
+
+        (!specifier.package_name.is_empty()).then_some(specifier)
     }
 
     /// Extract imports from the root object
@@ -144,8 +162,8 @@ impl DenoJsonParser {
 
             let raw_value = self.get_string_value(value_node, content);
 
-            // Only process jsr: prefixed entries
-            let Some(specifier) = Self::parse_jsr_specifier(&raw_value) else {
+            // Only process jsr: and npm: prefixed entries
+            let Some(specifier) = Self::parse_specifier(&raw_value) else {
                 continue;
             };
 
@@ -172,7 +190,7 @@ impl DenoJsonParser {
                 name: specifier.package_name,
                 version: specifier.version,
                 commit_hash: None,
-                registry_type: RegistryType::Jsr,
+                registry_type: specifier.registry_type,
                 start_offset: version_start_offset,
                 end_offset: version_end_offset,
                 line: start_point.row,
@@ -262,7 +280,65 @@ mod tests {
     }
 
     #[test]
-    fn parse_skips_non_jsr_entries() {
+    fn parse_extracts_npm_packages() {
+        let parser = DenoJsonParser::new();
+        let content = r#"{
+  "imports": {
+    "lodash": "npm:lodash@4.17.21",
+    "@types/node": "npm:@types/node@^20.0.0",
+    "chalk": "npm:chalk"
+  }
+}"#;
+        let result = parser.parse(content).unwrap();
+
+        let summary: Vec<_> = result
+            .iter()
+            .map(|p| (p.name.as_str(), p.version.as_str(), p.registry_type))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("lodash", "4.17.21", RegistryType::Npm),
+                ("@types/node", "^20.0.0", RegistryType::Npm),
+                ("chalk", "latest", RegistryType::Npm),
+            ]
+        );
+        assert_eq!(
+            &content[result[0].start_offset..result[0].end_offset],
+            "4.17.21"
+        );
+        assert_eq!(
+            &content[result[1].start_offset..result[1].end_offset],
+            "^20.0.0"
+        );
+    }
+
+    #[test]
+    fn parse_extracts_jsr_and_npm_packages_side_by_side() {
+        let parser = DenoJsonParser::new();
+        let content = r#"{
+  "imports": {
+    "@std/fs": "jsr:@std/fs@^1.0.0",
+    "lodash": "npm:lodash@4.17.21"
+  }
+}"#;
+        let result = parser.parse(content).unwrap();
+
+        let summary: Vec<_> = result
+            .iter()
+            .map(|p| (p.name.as_str(), p.version.as_str(), p.registry_type))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("@std/fs", "^1.0.0", RegistryType::Jsr),
+                ("lodash", "4.17.21", RegistryType::Npm),
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_skips_url_entries() {
         let parser = DenoJsonParser::new();
         let content = r#"{
   "imports": {
@@ -0,0 +1,82 @@
+//! Container image parser
+//!
+//! Dockerfiles and compose files share the Docker registry resolver, so this
+//! parser picks the format from the content: documents with a `FROM`
+//! instruction are parsed as Dockerfiles, everything else as compose YAML.
+
+use crate::parser::compose::ComposeParser;
+use crate::parser::dockerfile::DockerfileParser;
+use crate::parser::traits::{ParseError, Parser};
+use crate::parser::types::PackageInfo;
+
+/// Parser for Dockerfiles and compose.yaml / docker-compose.yaml files
+#[derive(Default)]
+pub struct DockerParser {
+    compose: ComposeParser,
+    dockerfile: DockerfileParser,
+}
+
+impl DockerParser {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Parser for DockerParser {
+    fn parse(&self, content: &str) -> Result<Vec<PackageInfo>, ParseError> {
+        if is_dockerfile_content(content) {
+            self.dockerfile.parse(content)
+        } else {
+            self.compose.parse(content)
+        }
+    }
+
+    fn has_syntax_errors(&self, content: &str) -> bool {
+        if is_dockerfile_content(content) {
+            self.dockerfile.has_syntax_errors(content)
+        } else {
+            self.compose.has_syntax_errors(content)
+        }
+    }
+}
+
+/// Whether the content has a Dockerfile `FROM` instruction
+///
+/// A line starting with `FROM ` is not valid in a compose file outside of a
+/// block scalar, so this does not misdetect compose files in practice.
+pub fn is_dockerfile_content(content: &str) -> bool {
+    content.lines().any(|line| {
+        line.split_whitespace()
+            .next()
+            .is_some_and(|instruction| instruction.eq_ignore_ascii_case("FROM"))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_dispatches_dockerfile_content() {
+        let parser = DockerParser::new();
+
+        let result = parser.parse("FROM node:18.19.0\n").unwrap();
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].name, "library/node");
+        assert_eq!(result[0].version, "18.19.0");
+    }
+
+    #[test]
+    fn parse_dispatches_compose_content() {
+        let parser = DockerParser::new();
+
+        let result = parser
+            .parse("services:\n  web:\n    image: nginx:1.25\n")
+            .unwrap();
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].name, "library/nginx");
+        assert_eq!(result[0].version, "1.25");
+    }
+}
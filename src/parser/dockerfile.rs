@@ -0,0 +1,318 @@
+//! Dockerfile parser
+//!
+//! Parses `FROM` instructions to extract base image tags. Supports the same
+//! images as the compose parser: Docker Hub (official and user images) and
+//! ghcr.io images.
+//!
+//! Format examples:
+//! - `FROM node:18.19.0`
+//! - `FROM --platform=linux/amd64 library/postgres:16.1 AS db`
+//! - `FROM node:18.19.0@sha256:...` (pinned by digest)
+//!
+//! Images pinned by digest are reported with `commit_hash` set to the digest
+//! so they are left out of version comparison. `scratch`, untagged or
+//! `latest` images, variable references and earlier build stages are skipped.
+
+use crate::parser::compose::resolve_image_name;
+use crate::parser::traits::{ParseError, Parser};
+use crate::parser::types::{PackageInfo, RegistryType};
+
+/// Parser for Dockerfiles
+#[derive(Default)]
+pub struct DockerfileParser;
+
+impl DockerfileParser {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Parser for DockerfileParser {
+    fn parse(&self, content: &str) -> Result<Vec<PackageInfo>, ParseError> {
+        let mut results = Vec::new();
+        let mut stages: Vec<String> = Vec::new();
+        let mut line_start = 0;
+
+        for (line_num, raw_line) in content.split_inclusive('\n').enumerate() {
+            let current_line_start = line_start;
+            line_start += raw_line.len();
+            let line = raw_line.trim_end_matches(['\n', '\r']);
+
+            let Some(from) = parse_from_line(line) else {
+                continue;
+            };
+
+            let is_stage = stages.iter().any(|s| s.eq_ignore_ascii_case(from.image));
+            if let Some(alias) = from.alias {
+                stages.push(alias.to_string());
+            }
+            if is_stage {
+                continue;
+            }
+
+            if let Some(mut info) = parse_image_reference(from.image, from.image_start) {
+                info.start_offset += current_line_start;
+                info.end_offset += current_line_start;
+                info.line = line_num;
+                results.push(info);
+            }
+        }
+
+        Ok(results)
+    }
+}
+
+/// Image reference and stage alias of a `FROM` instruction
+struct FromInstruction<'a> {
+    image: &'a str,
+    /// Byte offset of the image within the line
+    image_start: usize,
+    alias: Option<&'a str>,
+}
+
+/// Parse `FROM [--flag=value ...] image [AS name]`
+fn parse_from_line(line: &str) -> Option<FromInstruction<'_>> {
+    let mut tokens = tokenize(line);
+
+    let (_, instruction) = tokens.next()?;
+    if !instruction.eq_ignore_ascii_case("FROM") {
+        return None;
+    }
+
+    let (image_start, image) = tokens.find(|(_, token)| !token.starts_with("--"))?;
+
+    let alias = match (tokens.next(), tokens.next()) {
+        (Some((_, keyword)), Some((_, name))) if keyword.eq_ignore_ascii_case("AS") => Some(name),
+        _ => None,
+    };
+
+    Some(FromInstruction {
+        image,
+        image_start,
+        alias,
+    })
+}
+
+/// Split a line into whitespace-separated tokens with their byte offsets
+fn tokenize(line: &str) -> impl Iterator<Item = (usize, &str)> {
+    line.split_whitespace().map(move |token| {
+        let offset = token.as_ptr() as usize - line.as_ptr() as usize;
+        (offset, token)
+    })
+}
+
+/// Parse an image reference like `node:18.19.0` or `node:18@sha256:...`
+///
+/// Offsets in the returned package are relative to the start of the line;
+/// `line` is left at 0 for the caller to fill in.
+pub(crate) fn parse_image_reference(image_ref: &str, image_start: usize) -> Option<PackageInfo> {
+    // Skip variable expansions and the empty base image
+    if image_ref.contains('$') || image_ref.eq_ignore_ascii_case("scratch") {
+        return None;
+    }
+
+    let (reference, digest) = match image_ref.split_once('@') {
+        Some((reference, digest)) => (reference, Some(digest)),
+        None => (image_ref, None),
+    };
+
+    // A colon followed by a slash belongs to a registry port (localhost:5000/app)
+    let (image_name, tag) = match reference.rsplit_once(':') {
+        Some((name, tag)) if !tag.contains('/') => (name, Some(tag)),
+        _ => (reference, None),
+    };
+    let tag = tag.filter(|tag| !tag.is_empty());
+
+    let name = resolve_image_name(image_name)?;
+
+    let (version, version_start) = match (tag, digest) {
+        (Some(tag), _) if digest.is_some() || tag != "latest" => {
+            (tag, image_start + image_name.len() + 1)
+        }
+        (None, Some(digest)) if !digest.is_empty() => (digest, image_start + reference.len() + 1),
+        _ => return None,
+    };
+
+    Some(PackageInfo {
+        name,
+        version: version.to_string(),
+        commit_hash: digest.map(str::to_string),
+        registry_type: RegistryType::Docker,
+        start_offset: version_start,
+        end_offset: version_start + version.len(),
+        line: 0,
+        column: version_start,
+        extra_info: None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[test]
+    fn parse_extracts_docker_hub_official_image() {
+        let parser = DockerfileParser::new();
+        let content = "# syntax=docker/dockerfile:1\nFROM node:18.19.0\n";
+
+        let result = parser.parse(content).unwrap();
+
+        assert_eq!(
+            result,
+            vec![PackageInfo {
+                name: "library/node".to_string(),
+                version: "18.19.0".to_string(),
+                commit_hash: None,
+                registry_type: RegistryType::Docker,
+                start_offset: 39,
+                end_offset: 46,
+                line: 1,
+                column: 10,
+                extra_info: None,
+            }]
+        );
+        assert_eq!(&content[39..46], "18.19.0");
+    }
+
+    #[rstest]
+    #[case("FROM library/postgres:16.1", "library/postgres", "16.1")]
+    #[case("FROM myuser/myapp:2.0.0", "myuser/myapp", "2.0.0")]
+    #[case("FROM ghcr.io/owner/repo:v1.2.3", "ghcr.io/owner/repo", "v1.2.3")]
+    #[case("from python:3.12-slim", "library/python", "3.12-slim")]
+    #[case(
+        "FROM --platform=linux/amd64 golang:1.22 AS build",
+        "library/golang",
+        "1.22"
+    )]
+    fn parse_extracts_image_tag(#[case] content: &str, #[case] name: &str, #[case] version: &str) {
+        let parser = DockerfileParser::new();
+
+        let result = parser.parse(content).unwrap();
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].name, name);
+        assert_eq!(result[0].version, version);
+        assert_eq!(
+            &content[result[0].start_offset..result[0].end_offset],
+            version
+        );
+    }
+
+    #[test]
+    fn parse_extracts_every_stage_of_multi_stage_build() {
+        let parser = DockerfileParser::new();
+        let content = r#"FROM node:20.11.0 AS deps
+WORKDIR /app
+COPY package.json ./
+RUN npm ci
+
+FROM deps AS build
+RUN npm run build
+
+FROM --platform=$BUILDPLATFORM golang:1.22.0 AS tools
+
+FROM nginx:1.25.3
+COPY --from=build /app/dist /usr/share/nginx/html
+"#;
+
+        let result = parser.parse(content).unwrap();
+
+        let summary: Vec<_> = result
+            .iter()
+            .map(|p| (p.name.as_str(), p.version.as_str(), p.line))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("library/node", "20.11.0", 0),
+                ("library/golang", "1.22.0", 8),
+                ("library/nginx", "1.25.3", 10),
+            ]
+        );
+        for package in &result {
+            assert_eq!(
+                &content[package.start_offset..package.end_offset],
+                package.version
+            );
+        }
+    }
+
+    #[test]
+    fn parse_records_digest_as_commit_hash() {
+        let parser = DockerfileParser::new();
+        let content = "FROM node:18.19.0@sha256:abc123 AS base\nFROM alpine@sha256:def456\n";
+
+        let result = parser.parse(content).unwrap();
+
+        let summary: Vec<_> = result
+            .iter()
+            .map(|p| {
+                (
+                    p.name.as_str(),
+                    p.version.as_str(),
+                    p.commit_hash.as_deref(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("library/node", "18.19.0", Some("sha256:abc123")),
+                ("library/alpine", "sha256:def456", Some("sha256:def456")),
+            ]
+        );
+        for package in &result {
+            assert_eq!(
+                &content[package.start_offset..package.end_offset],
+                package.version
+            );
+        }
+    }
+
+    #[rstest]
+    #[case("FROM scratch")]
+    #[case("FROM node")]
+    #[case("FROM node:latest")]
+    #[case("FROM node:${NODE_VERSION}")]
+    #[case("FROM $BASE_IMAGE")]
+    #[case("FROM mcr.microsoft.com/dotnet/sdk:8.0")]
+    #[case("# FROM node:18.19.0")]
+    #[case("RUN echo FROM node:18.19.0")]
+    fn parse_skips_unsupported_images(#[case] content: &str) {
+        let parser = DockerfileParser::new();
+
+        let result = parser.parse(content).unwrap();
+
+        assert!(result.is_empty());
+    }
+
+    #[test]
+    fn parse_skips_registry_port_without_tag() {
+        let parser = DockerfileParser::new();
+
+        let result = parser.parse("FROM localhost:5000/app").unwrap();
+
+        assert!(result.is_empty());
+    }
+
+    #[test]
+    fn parse_locates_images_in_crlf_files() {
+        let parser = DockerfileParser::new();
+        let content = "FROM node:20.11.0 AS build\r\nRUN npm ci\r\n\r\nFROM nginx:1.25.3\r\n";
+
+        let result = parser.parse(content).unwrap();
+
+        let offsets: Vec<_> = result
+            .iter()
+            .map(|p| (p.start_offset, p.end_offset, p.line, p.column))
+            .collect();
+        assert_eq!(offsets, vec![(10, 17, 0, 10), (53, 59, 3, 11)]);
+        for package in &result {
+            assert_eq!(
+                &content[package.start_offset..package.end_offset],
+                package.version
+            );
+        }
+    }
+}
@@ -0,0 +1,286 @@
+//! flake.lock parser
+//!
+//! Extracts the GitHub inputs of a Nix flake from `nodes.<name>`: the locked
+//! `rev` and `lastModified`, and the `owner`/`repo`/`ref` of the `original`
+//! flake reference. Inputs are named like the flake reference without its
+//! scheme (`NixOS/nixpkgs` or `NixOS/nixpkgs/nixos-24.05`) and versioned by
+//! their short rev. Inputs of other types (`path`, `git`, `tarball`,
+//! `indirect`) and inputs whose `original` pins a `rev` are skipped.
+
+use crate::parser::traits::{ParseError, Parser, has_tree_sitter_errors};
+use crate::parser::types::{ExtraInfo, PackageInfo, RegistryType};
+use tracing::warn;
+
+/// Length of the short rev used as the version of an input
+pub const SHORT_REV_LEN: usize = 7;
+
+/// Parser for flake.lock files
+#[derive(Default)]
+pub struct FlakeLockParser;
+
+impl FlakeLockParser {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Parser for FlakeLockParser {
+    fn parse(&self, content: &str) -> Result<Vec<PackageInfo>, ParseError> {
+        let mut parser = tree_sitter::Parser::new();
+        let language = tree_sitter_json::LANGUAGE;
+        parser.set_language(&language.into()).map_err(|e| {
+            warn!("Failed to set JSON language for tree-sitter: {}", e);
+            ParseError::TreeSitter(e.to_string())
+        })?;
+
+        let tree = parser.parse(content, None).ok_or_else(|| {
+            warn!("Failed to parse JSON content");
+            ParseError::ParseFailed("Failed to parse JSON".to_string())
+        })?;
+
+        let root = tree.root_node();
+        let mut results = Vec::new();
+
+        let nodes = root
+            .child(0)
+            .filter(|document| document.kind() == "object")
+            .and_then(|document| find_value(document, content, "nodes"))
+            .filter(|nodes| nodes.kind() == "object");
+
+        if let Some(nodes) = nodes {
+            for (_, node) in object_pairs(nodes) {
+                if node.kind() == "object"
+                    && let Some(package) = Self::extract_input(node, content)
+                {
+                    results.push(package);
+                }
+            }
+        }
+
+        Ok(results)
+    }
+
+    fn has_syntax_errors(&self, content: &str) -> bool {
+        has_tree_sitter_errors(tree_sitter_json::LANGUAGE.into(), content)
+    }
+}
+
+impl FlakeLockParser {
+    /// Extract a GitHub input from a `nodes.<name>` object
+    fn extract_input(node: tree_sitter::Node, content: &str) -> Option<PackageInfo> {
+        let locked = find_value(node, content, "locked")?;
+        let original = find_value(node, content, "original")?;
+
+        let original_field =
+            |key| find_value(original, content, key).map(|v| string_value(v, content));
+        if original_field("type")? != "github" || original_field("rev").is_some() {
+            return None;
+        }
+        let name = match original_field("ref") {
+            Some(git_ref) => format!(
+                "{}/{}/{}",
+                original_field("owner")?,
+                original_field("repo")?,
+                git_ref
+            ),
+            None => format!("{}/{}", original_field("owner")?, original_field("repo")?),
+        };
+
+        let rev_node = find_value(locked, content, "rev")?;
+        let rev = string_value(rev_node, content);
+        if rev.len() < SHORT_REV_LEN {
+            return None;
+        }
+        let last_modified = find_value(locked, content, "lastModified")
+            .and_then(|v| content[v.byte_range()].parse().ok());
+
+        // The range covers the full rev, after the opening quote
+        let start_point = rev_node.start_position();
+        let start_offset = rev_node.start_byte() + 1;
+
+        Some(PackageInfo {
+            name,
+            version: rev[..SHORT_REV_LEN].to_string(),
+            commit_hash: Some(rev.to_string()),
+            registry_type: RegistryType::NixFlake,
+            start_offset,
+            end_offset: start_offset + rev.len(),
+            line: start_point.row,
+            column: start_point.column + 1,
+            extra_info: last_modified.map(|last_modified| ExtraInfo::FlakeLock { last_modified }),
+        })
+    }
+}
+
+/// Collect the `(key, value)` nodes of a JSON object
+fn object_pairs(
+    object_node: tree_sitter::Node<'_>,
+) -> Vec<(tree_sitter::Node<'_>, tree_sitter::Node<'_>)> {
+    let mut cursor = object_node.walk();
+    object_node
+        .children(&mut cursor)
+        .filter(|child| child.kind() == "pair")
+        .filter_map(|pair| {
+            Some((
+                pair.child_by_field_name("key")?,
+                pair.child_by_field_name("value")?,
+            ))
+        })
+        .collect()
+}
+
+/// Find the value of `key` in a JSON object
+fn find_value<'a>(
+    object_node: tree_sitter::Node<'a>,
+    content: &str,
+    key: &str,
+) -> Option<tree_sitter::Node<'a>> {
+    object_pairs(object_node)
+        .into_iter()
+        .find(|(key_node, _)| string_value(*key_node, content) == key)
+        .map(|(_, value)| value)
+}
+
+/// Get the contents of a string node without its quotes
+fn string_value<'a>(node: tree_sitter::Node, content: &'a str) -> &'a str {
+    content[node.byte_range()].trim().trim_matches('"')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FLAKE_LOCK: &str = r#"{
+  "nodes": {
+    "flake-utils": {
+      "locked": {
+        "lastModified": 1710146030,
+        "narHash": "sha256-SZ5L6eA7HJ/nmkzGG7/ISclqe6oZdOZTNoesiInkXPQ=",
+        "owner": "numtide",
+        "repo": "flake-utils",
+        "rev": "b1d9ab70662946ef0850d488da1c9019f3a9752a",
+        "type": "github"
+      },
+      "original": {
+        "owner": "numtide",
+        "repo": "flake-utils",
+        "type": "github"
+      }
+    },
+    "nixpkgs": {
+      "locked": {
+        "lastModified": 1717144377,
+        "narHash": "sha256-F/TKWETwB5RaR8owkPPi+SPJh83AQsm6KrQAlJ8v/uA=",
+        "owner": "NixOS",
+        "repo": "nixpkgs",
+        "rev": "805a384895c696f802a9bf5bf4720f37385df547",
+        "type": "github"
+      },
+      "original": {
+        "owner": "NixOS",
+        "ref": "nixos-24.05",
+        "repo": "nixpkgs",
+        "type": "github"
+      }
+    },
+    "root": {
+      "inputs": {
+        "flake-utils": "flake-utils",
+        "nixpkgs": "nixpkgs"
+      }
+    }
+  },
+  "root": "root",
+  "version": 7
+}"#;
+
+    #[test]
+    fn parse_extracts_github_inputs() {
+        let parser = FlakeLockParser::new();
+
+        let result = parser.parse(FLAKE_LOCK).unwrap();
+
+        assert_eq!(
+            result,
+            vec![
+                PackageInfo {
+                    name: "numtide/flake-utils".to_string(),
+                    version: "b1d9ab7".to_string(),
+                    commit_hash: Some("b1d9ab70662946ef0850d488da1c9019f3a9752a".to_string()),
+                    registry_type: RegistryType::NixFlake,
+                    start_offset: 239,
+                    end_offset: 279,
+                    line: 8,
+                    column: 16,
+                    extra_info: Some(ExtraInfo::FlakeLock {
+                        last_modified: 1710146030
+                    }),
+                },
+                PackageInfo {
+                    name: "NixOS/nixpkgs/nixos-24.05".to_string(),
+                    version: "805a384".to_string(),
+                    commit_hash: Some("805a384895c696f802a9bf5bf4720f37385df547".to_string()),
+                    registry_type: RegistryType::NixFlake,
+                    start_offset: 649,
+                    end_offset: 689,
+                    line: 23,
+                    column: 16,
+                    extra_info: Some(ExtraInfo::FlakeLock {
+                        last_modified: 1717144377
+                    }),
+                },
+            ]
+        );
+        for package in &result {
+            assert_eq!(
+                Some(&FLAKE_LOCK[package.start_offset..package.end_offset]),
+                package.commit_hash.as_deref()
+            );
+        }
+    }
+
+    #[test]
+    fn parse_skips_non_github_and_rev_pinned_inputs() {
+        let parser = FlakeLockParser::new();
+        let content = r#"{
+  "nodes": {
+    "home-manager": {
+      "locked": {
+        "lastModified": 1717097707,
+        "ref": "refs/heads/master",
+        "rev": "0eb314b4f0ba337e88123e0b1e57ef58346aafd9",
+        "type": "git",
+        "url": "https://github.com/nix-community/home-manager"
+      },
+      "original": {
+        "type": "git",
+        "url": "https://github.com/nix-community/home-manager"
+      }
+    },
+    "pinned": {
+      "locked": {
+        "owner": "NixOS",
+        "repo": "nixpkgs",
+        "rev": "805a384895c696f802a9bf5bf4720f37385df547",
+        "type": "github"
+      },
+      "original": {
+        "owner": "NixOS",
+        "repo": "nixpkgs",
+        "rev": "805a384895c696f802a9bf5bf4720f37385df547",
+        "type": "github"
+      }
+    },
+    "root": {
+      "inputs": {}
+    }
+  },
+  "root": "root",
+  "version": 7
+}"#;
+
+        let result = parser.parse(content).unwrap();
+
+        assert!(result.is_empty());
+    }
+}
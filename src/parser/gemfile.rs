@@ -0,0 +1,201 @@
+//! Gemfile parser
+//!
+//! Parses Gemfiles to extract gem dependencies with a version requirement.
+//! Gems declared without a version (`gem "rails"` or `gem "rails", require: false`)
+//! are skipped. Only the first version requirement of a gem is reported.
+//!
+//! Format examples:
+//! - `gem "rails", "~> 7.0"`
+//! - `gem 'pg', '>= 1.1', '< 2.0'`
+//! - `gem "bootsnap", "1.18.3", require: false`
+
+use regex::Regex;
+
+use crate::parser::traits::{ParseError, Parser};
+use crate::parser::types::{PackageInfo, RegistryType};
+
+/// Parser for Gemfiles
+pub struct GemfileParser {
+    /// Regex for a gem with a version: `gem "name", "requirement"`
+    gem_re: Regex,
+}
+
+impl GemfileParser {
+    pub fn new() -> Self {
+        Self {
+            // Match: gem "name", "requirement" [, ...] (optionally parenthesized)
+            gem_re: Regex::new(r#"^\s*gem\s*\(?\s*["']([^"']+)["']\s*,\s*["']([^"']+)["']"#)
+                .unwrap(),
+        }
+    }
+}
+
+impl Default for GemfileParser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Parser for GemfileParser {
+    fn parse(&self, content: &str) -> Result<Vec<PackageInfo>, ParseError> {
+        let mut results = Vec::new();
+        let mut line_start = 0;
+
+        for (line_num, raw_line) in content.split_inclusive('\n').enumerate() {
+            let current_line_start = line_start;
+            line_start += raw_line.len();
+            let line = raw_line.trim_end_matches(['\n', '\r']);
+
+            // Skip comments
+            if line.trim_start().starts_with('#') {
+                continue;
+            }
+
+            let Some(caps) = self.gem_re.captures(line) else {
+                continue;
+            };
+
+            let name = caps.get(1).unwrap().as_str();
+            let version_match = caps.get(2).unwrap();
+
+            results.push(PackageInfo {
+                name: name.to_string(),
+                version: version_match.as_str().to_string(),
+                commit_hash: None,
+                registry_type: RegistryType::RubyGems,
+                start_offset: current_line_start + version_match.start(),
+                end_offset: current_line_start + version_match.end(),
+                line: line_num,
+                column: version_match.start(),
+                extra_info: None,
+            });
+        }
+
+        Ok(results)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[test]
+    fn parse_extracts_gem_with_version() {
+        let parser = GemfileParser::new();
+        let content = "source \"https://rubygems.org\"\n\ngem \"rails\", \"~> 7.0\"\n";
+
+        let result = parser.parse(content).unwrap();
+
+        assert_eq!(
+            result,
+            vec![PackageInfo {
+                name: "rails".to_string(),
+                version: "~> 7.0".to_string(),
+                commit_hash: None,
+                registry_type: RegistryType::RubyGems,
+                start_offset: 45,
+                end_offset: 51,
+                line: 2,
+                column: 14,
+                extra_info: None,
+            }]
+        );
+        assert_eq!(&content[45..51], "~> 7.0");
+    }
+
+    #[test]
+    fn parse_extracts_multiple_gems() {
+        let parser = GemfileParser::new();
+        let content = r#"source "https://rubygems.org"
+
+gem "rails", "~> 7.0"
+gem 'pg', '>= 1.1', '< 2.0'
+
+group :development do
+  gem "debug", ">= 1.0.0"
+end
+"#;
+
+        let result = parser.parse(content).unwrap();
+
+        let summary: Vec<_> = result
+            .iter()
+            .map(|p| (p.name.as_str(), p.version.as_str(), p.line))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("rails", "~> 7.0", 2),
+                ("pg", ">= 1.1", 3),
+                ("debug", ">= 1.0.0", 6),
+            ]
+        );
+        for package in &result {
+            assert_eq!(
+                &content[package.start_offset..package.end_offset],
+                package.version
+            );
+        }
+    }
+
+    #[rstest]
+    #[case(r#"gem "rails""#)]
+    #[case(r#"gem "rails", require: false"#)]
+    #[case(r#"gem "rails", github: "rails/rails""#)]
+    #[case(r#"# gem "rails", "~> 7.0""#)]
+    #[case(r#"ruby "3.2.2""#)]
+    fn parse_skips_gems_without_version(#[case] content: &str) {
+        let parser = GemfileParser::new();
+
+        let result = parser.parse(content).unwrap();
+
+        assert!(result.is_empty());
+    }
+
+    #[rstest]
+    #[case(r#"gem "bootsnap", "1.18.3", require: false"#, "bootsnap", "1.18.3")]
+    #[case(r#"gem("rack", "~> 3.0")"#, "rack", "~> 3.0")]
+    #[case(
+        r#"gem "tzinfo-data", ">= 1.2", platforms: %i[windows jruby]"#,
+        "tzinfo-data",
+        ">= 1.2"
+    )]
+    fn parse_extracts_version_followed_by_options(
+        #[case] content: &str,
+        #[case] name: &str,
+        #[case] version: &str,
+    ) {
+        let parser = GemfileParser::new();
+
+        let result = parser.parse(content).unwrap();
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].name, name);
+        assert_eq!(result[0].version, version);
+        assert_eq!(
+            &content[result[0].start_offset..result[0].end_offset],
+            version
+        );
+    }
+
+    #[test]
+    fn parse_locates_gems_in_crlf_files() {
+        let parser = GemfileParser::new();
+        let content = "source \"https://rubygems.org\"\r\n\r\ngem \"rails\", \"~> 7.0\"\r\ngem \"pg\", \">= 1.1\"\r\n";
+
+        let result = parser.parse(content).unwrap();
+
+        let offsets: Vec<_> = result
+            .iter()
+            .map(|p| (p.start_offset, p.end_offset, p.line, p.column))
+            .collect();
+        assert_eq!(offsets, vec![(47, 53, 2, 14), (67, 73, 3, 11)]);
+        for package in &result {
+            assert_eq!(
+                &content[package.start_offset..package.end_offset],
+                package.version
+            );
+        }
+    }
+}
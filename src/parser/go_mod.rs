@@ -1,7 +1,7 @@
 //! go.mod parser
 //!
 //! Parses go.mod files to extract module dependencies.
-//! Supports both single-line require directives and require blocks.
+//! Supports both single-line directives and directive blocks.
 //!
 //! Format examples:
 //! - Single: `require golang.org/x/text v0.14.0`
@@ -12,31 +12,266 @@
 //!       golang.org/x/net v0.20.0 // indirect
 //!   )
 //!   ```
+//!
+//! `replace` directives are applied to the required modules:
+//! - Replaced by a filesystem path (`=> ./fork`) or by a different module: skipped
+//! - Replaced by another version of the same module: the replacement version is reported
+//!
+//! `exclude` directives are attached to the module as [`ExtraInfo::GoMod`] so the
+//! checker does not offer excluded versions as the latest. The `// indirect`
+//! comment on a require is recorded there as well.
+//!
+//! The `go` and `toolchain` directives are not modules; they are read
+//! separately by [`GoModParser::find_go_directives`] so they can be checked
+//! against the Go release list.
 
 use regex::Regex;
 
 use crate::parser::traits::{ParseError, Parser};
-use crate::parser::types::{PackageInfo, RegistryType};
+use crate::parser::types::{ExtraInfo, PackageInfo, RegistryType};
+
+/// Name under which the list of Go releases is fetched and cached in the
+/// [`RegistryType::GoRelease`] registry
+pub const GO_RELEASES_PACKAGE: &str = "go";
+
+/// Directive that declared a Go version
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GoDirectiveKind {
+    /// `go 1.21`: the minimum Go version of the module
+    Go,
+    /// `toolchain go1.22.0`: the preferred Go toolchain
+    Toolchain,
+}
+
+/// Go version declared by a `go` or `toolchain` directive
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GoDirectiveInfo {
+    pub kind: GoDirectiveKind,
+    /// Version without the `go` prefix of toolchain names (e.g., "1.22.0")
+    pub version: String,
+    /// Byte offset of the version in the source (start)
+    pub start_offset: usize,
+    /// Byte offset of the version in the source (end)
+    pub end_offset: usize,
+    /// Line number (0-indexed)
+    pub line: usize,
+    /// Column number (0-indexed)
+    pub column: usize,
+}
+
+impl GoDirectiveInfo {
+    /// The Go release list as a package, so it is fetched and cached like a module
+    pub fn release_package(&self) -> PackageInfo {
+        PackageInfo {
+            name: GO_RELEASES_PACKAGE.to_string(),
+            version: self.version.clone(),
+            commit_hash: None,
+            registry_type: RegistryType::GoRelease,
+            start_offset: self.start_offset,
+            end_offset: self.end_offset,
+            line: self.line,
+            column: self.column,
+            extra_info: None,
+        }
+    }
+}
 
 /// Parser for go.mod files
 pub struct GoModParser {
-    /// Regex for single-line require: `require module/path v1.2.3`
-    single_require_re: Regex,
-    /// Regex for require block start: `require (`
+    /// Regex for a single-line directive prefix: `require `, `replace `, `exclude `
+    single_directive_re: Regex,
+    /// Regex for directive block start: `require (`
     block_start_re: Regex,
-    /// Regex for require spec inside block: `module/path v1.2.3`
+    /// Regex for require spec: `module/path v1.2.3`
     require_spec_re: Regex,
+    /// Regex for replace spec: `module/path [v1.2.3] => target [v1.2.4]`
+    replace_spec_re: Regex,
+    /// Regex for exclude spec: `module/path v1.2.3`
+    exclude_spec_re: Regex,
+    /// Regex for Go version directives: `go 1.21`, `toolchain go1.22.0`
+    go_directive_re: Regex,
+}
+
+/// go.mod directive kinds relevant to dependency checking
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Directive {
+    Require,
+    Replace,
+    Exclude,
+    /// Any other block (e.g. `retract (`), whose lines are ignored
+    Other,
+}
+
+impl Directive {
+    fn from_keyword(keyword: &str) -> Self {
+        match keyword {
+            "require" => Directive::Require,
+            "replace" => Directive::Replace,
+            "exclude" => Directive::Exclude,
+            _ => Directive::Other,
+        }
+    }
+}
+
+/// A parsed `replace` directive
+struct Replace {
+    /// Module path on the left-hand side
+    module: String,
+    /// Version on the left-hand side; when absent all versions are replaced
+    version: Option<String>,
+    /// Right-hand side module with its version location. `None` when the
+    /// target is a filesystem path (which never carries a version).
+    replacement: Option<PackageInfo>,
+}
+
+impl Replace {
+    fn applies_to(&self, package: &PackageInfo) -> bool {
+        self.module == package.name
+            && self
+                .version
+                .as_ref()
+                .is_none_or(|version| *version == package.version)
+    }
+}
+
+/// Directives collected while scanning a go.mod file
+#[derive(Default)]
+struct Directives {
+    requires: Vec<PackageInfo>,
+    replaces: Vec<Replace>,
+    /// (module path, excluded version)
+    excludes: Vec<(String, String)>,
 }
 
 impl GoModParser {
     pub fn new() -> Self {
         Self {
-            // Match: require module/path v1.2.3 [// comment]
-            single_require_re: Regex::new(r"^require\s+(\S+)\s+(v[^\s]+)(?:\s*//.*)?$").unwrap(),
-            // Match: require (
-            block_start_re: Regex::new(r"^require\s*\(\s*$").unwrap(),
+            // Match: require|replace|exclude followed by a spec on the same line
+            single_directive_re: Regex::new(r"^\s*(require|replace|exclude)\s+").unwrap(),
+            // Match: require ( / replace ( / exclude ( / retract (
+            block_start_re: Regex::new(r"^(\w+)\s*\(\s*$").unwrap(),
             // Match: module/path v1.2.3 [// comment]
-            require_spec_re: Regex::new(r"^\s*(\S+)\s+(v[^\s]+)(?:\s*//.*)?$").unwrap(),
+            require_spec_re: Regex::new(r"^\s*(\S+)\s+(v[^\s]+)(?:\s*//(.*))?$").unwrap(),
+            // Match: module/path [v1.2.3] => target [v1.2.4] [// comment]
+            replace_spec_re: Regex::new(
+                r"^\s*(\S+)(?:\s+(v[^\s]+))?\s*=>\s*(\S+)(?:\s+(v[^\s]+))?(?:\s*//.*)?$",
+            )
+            .unwrap(),
+            // Match: module/path v1.2.3 [// comment]
+            exclude_spec_re: Regex::new(r"^\s*(\S+)\s+(v[^\s]+)(?:\s*//.*)?$").unwrap(),
+            // Match: go 1.21 / toolchain go1.22.0 [// comment]
+            go_directive_re: Regex::new(r"^\s*(go|toolchain)\s+([^\s/]+)").unwrap(),
+        }
+    }
+
+    /// Find the `go` and `toolchain` directives of a go.mod file
+    ///
+    /// `toolchain default` names no version and is skipped.
+    pub fn find_go_directives(&self, content: &str) -> Vec<GoDirectiveInfo> {
+        let mut directives = Vec::new();
+        let mut in_block = false;
+        let mut line_start = 0;
+
+        for (line_num, raw_line) in content.split_inclusive('\n').enumerate() {
+            let current_line_start = line_start;
+            line_start += raw_line.len();
+            let line = raw_line.trim_end_matches(['\n', '\r']);
+
+            let trimmed = line.trim();
+            if in_block {
+                in_block = trimmed != ")";
+                continue;
+            }
+            if self.block_start_re.is_match(trimmed) {
+                in_block = true;
+                continue;
+            }
+
+            let Some(caps) = self.go_directive_re.captures(line) else {
+                continue;
+            };
+            let value = caps.get(2).unwrap();
+            let (kind, prefix_len) = match &caps[1] {
+                "go" => (GoDirectiveKind::Go, 0),
+                _ if value.as_str().starts_with("go") => (GoDirectiveKind::Toolchain, 2),
+                _ => continue,
+            };
+            let column = value.start() + prefix_len;
+            directives.push(GoDirectiveInfo {
+                kind,
+                version: value.as_str()[prefix_len..].to_string(),
+                start_offset: current_line_start + column,
+                end_offset: current_line_start + value.end(),
+                line: line_num,
+                column,
+            });
+        }
+
+        directives
+    }
+
+    /// Parse a directive spec starting at `spec_start` within `line`
+    fn parse_spec(
+        &self,
+        directive: Directive,
+        line: &str,
+        spec_start: usize,
+        line_start: usize,
+        line_num: usize,
+        directives: &mut Directives,
+    ) {
+        let spec = &line[spec_start..];
+        let located = |name: &str, version: regex::Match<'_>| {
+            let column = spec_start + version.start();
+            PackageInfo {
+                name: name.to_string(),
+                version: version.as_str().to_string(),
+                commit_hash: None,
+                registry_type: RegistryType::GoProxy,
+                start_offset: line_start + column,
+                end_offset: line_start + column + version.len(),
+                line: line_num,
+                column,
+                extra_info: None,
+            }
+        };
+
+        match directive {
+            Directive::Require => {
+                if let Some(caps) = self.require_spec_re.captures(spec) {
+                    let module_path = caps.get(1).unwrap().as_str();
+                    let mut package = located(module_path, caps.get(2).unwrap());
+                    if caps
+                        .get(3)
+                        .is_some_and(|comment| is_indirect(comment.as_str()))
+                    {
+                        package.extra_info = Some(ExtraInfo::GoMod {
+                            excluded_versions: Vec::new(),
+                            indirect: true,
+                        });
+                    }
+                    directives.requires.push(package);
+                }
+            }
+            Directive::Replace => {
+                if let Some(caps) = self.replace_spec_re.captures(spec) {
+                    let target = caps.get(3).unwrap().as_str();
+                    directives.replaces.push(Replace {
+                        module: caps.get(1).unwrap().as_str().to_string(),
+                        version: caps.get(2).map(|m| m.as_str().to_string()),
+                        replacement: caps.get(4).map(|version| located(target, version)),
+                    });
+                }
+            }
+            Directive::Exclude => {
+                if let Some(caps) = self.exclude_spec_re.captures(spec) {
+                    directives.excludes.push((
+                        caps.get(1).unwrap().as_str().to_string(),
+                        caps.get(2).unwrap().as_str().to_string(),
+                    ));
+                }
+            }
+            Directive::Other => {}
         }
     }
 }
@@ -49,10 +284,15 @@ impl Default for GoModParser {
 
 impl Parser for GoModParser {
     fn parse(&self, content: &str) -> Result<Vec<PackageInfo>, ParseError> {
-        let mut results = Vec::new();
-        let mut in_require_block = false;
+        let mut directives = Directives::default();
+        let mut current_block: Option<Directive> = None;
+        let mut line_start = 0;
+
+        for (line_num, raw_line) in content.split_inclusive('\n').enumerate() {
+            let current_line_start = line_start;
+            line_start += raw_line.len();
+            let line = raw_line.trim_end_matches(['\n', '\r']);
 
-        for (line_num, line) in content.lines().enumerate() {
             let trimmed = line.trim();
 
             // Skip empty lines and comments
@@ -61,86 +301,93 @@ impl Parser for GoModParser {
             }
 
             // Check for block end
-            if in_require_block && trimmed == ")" {
-                in_require_block = false;
+            if current_block.is_some() && trimmed == ")" {
+                current_block = None;
                 continue;
             }
 
             // Check for block start
-            if self.block_start_re.is_match(trimmed) {
-                in_require_block = true;
+            if let Some(caps) = self.block_start_re.captures(trimmed) {
+                current_block = Some(Directive::from_keyword(&caps[1]));
                 continue;
             }
 
-            // Parse require spec
-            if in_require_block {
-                if let Some(caps) = self.require_spec_re.captures(line) {
-                    let module_path = caps.get(1).unwrap().as_str();
-                    let version_match = caps.get(2).unwrap();
-                    let version = version_match.as_str();
-
-                    // Calculate byte offset for version
-                    let line_start = content
-                        .lines()
-                        .take(line_num)
-                        .map(|l| l.len() + 1)
-                        .sum::<usize>();
-                    let version_start = line_start + version_match.start();
-                    let version_end = line_start + version_match.end();
-
-                    // Calculate column (byte offset within line)
-                    let column = version_match.start();
-
-                    results.push(PackageInfo {
-                        name: module_path.to_string(),
-                        version: version.to_string(),
-                        commit_hash: None,
-                        registry_type: RegistryType::GoProxy,
-                        start_offset: version_start,
-                        end_offset: version_end,
-                        line: line_num,
-                        column,
-                        extra_info: None,
-                    });
-                }
-            } else if let Some(caps) = self.single_require_re.captures(trimmed) {
-                let module_path = caps.get(1).unwrap().as_str();
-                let version_match = caps.get(2).unwrap();
-                let version = version_match.as_str();
-
-                // Calculate byte offset for version
-                let line_start = content
-                    .lines()
-                    .take(line_num)
-                    .map(|l| l.len() + 1)
-                    .sum::<usize>();
-                // Find actual position in the original line (not trimmed)
-                let require_pos = line.find("require").unwrap_or(0);
-                let version_pos_in_line = line[require_pos..]
-                    .find(version)
-                    .map(|p| require_pos + p)
-                    .unwrap_or(0);
-                let version_start = line_start + version_pos_in_line;
-                let version_end = version_start + version.len();
-
-                results.push(PackageInfo {
-                    name: module_path.to_string(),
-                    version: version.to_string(),
-                    commit_hash: None,
-                    registry_type: RegistryType::GoProxy,
-                    start_offset: version_start,
-                    end_offset: version_end,
-                    line: line_num,
-                    column: version_pos_in_line,
-                    extra_info: None,
-                });
-            }
+            let (directive, spec_start) = match current_block {
+                Some(directive) => (directive, 0),
+                None => match self.single_directive_re.captures(line) {
+                    Some(caps) => (
+                        Directive::from_keyword(&caps[1]),
+                        caps.get(0).unwrap().end(),
+                    ),
+                    None => continue,
+                },
+            };
+
+            self.parse_spec(
+                directive,
+                line,
+                spec_start,
+                current_line_start,
+                line_num,
+                &mut directives,
+            );
         }
 
-        Ok(results)
+        Ok(resolve_directives(directives))
     }
 }
 
+/// Apply replace and exclude directives to the required modules
+fn resolve_directives(directives: Directives) -> Vec<PackageInfo> {
+    let Directives {
+        requires,
+        replaces,
+        excludes,
+    } = directives;
+
+    requires
+        .into_iter()
+        .filter_map(|package| {
+            // The last matching replace wins, as with the go command
+            let Some(replace) = replaces.iter().rev().find(|r| r.applies_to(&package)) else {
+                return Some(package);
+            };
+            // Filesystem paths and other modules can't be checked against this module's versions
+            replace
+                .replacement
+                .as_ref()
+                .filter(|replacement| replacement.name == package.name)
+                .map(|replacement| PackageInfo {
+                    extra_info: package.extra_info.clone(),
+                    ..replacement.clone()
+                })
+        })
+        .map(|mut package| {
+            let excluded_versions: Vec<String> = excludes
+                .iter()
+                .filter(|(module, _)| *module == package.name)
+                .map(|(_, version)| version.clone())
+                .collect();
+            if !excluded_versions.is_empty() {
+                package.extra_info = Some(ExtraInfo::GoMod {
+                    excluded_versions,
+                    indirect: package.is_indirect(),
+                });
+            }
+            package
+        })
+        .collect()
+}
+
+/// Whether a require comment marks the module as indirect
+///
+/// Same rule as the go command: the comment is exactly `indirect`, or starts
+/// with `indirect;` followed by other text.
+fn is_indirect(comment: &str) -> bool {
+    let comment = comment.trim();
+    comment == "indirect" || comment.starts_with("indirect;")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -161,6 +408,66 @@ require golang.org/x/text v0.14.0
         assert_eq!(result[0].registry_type, RegistryType::GoProxy);
     }
 
+    #[test]
+    fn find_go_directives_extracts_go_and_toolchain_versions() {
+        let parser = GoModParser::new();
+        let content = r#"module example.com/myapp
+
+go 1.21
+
+toolchain go1.22.0 // pinned for CI
+
+require golang.org/x/text v0.14.0
+"#;
+
+        let directives = parser.find_go_directives(content);
+
+        assert_eq!(
+            directives,
+            vec![
+                GoDirectiveInfo {
+                    kind: GoDirectiveKind::Go,
+                    version: "1.21".to_string(),
+                    start_offset: 29,
+                    end_offset: 33,
+                    line: 2,
+                    column: 3,
+                },
+                GoDirectiveInfo {
+                    kind: GoDirectiveKind::Toolchain,
+                    version: "1.22.0".to_string(),
+                    start_offset: 47,
+                    end_offset: 53,
+                    line: 4,
+                    column: 12,
+                },
+            ]
+        );
+        for directive in &directives {
+            assert_eq!(
+                &content[directive.start_offset..directive.end_offset],
+                directive.version
+            );
+        }
+        // Directives are not modules
+        assert_eq!(parser.parse(content).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn find_go_directives_skips_toolchain_default_and_block_entries() {
+        let parser = GoModParser::new();
+        let content = r#"module example.com/myapp
+
+toolchain default
+
+require (
+	go.uber.org/zap v1.27.0
+)
+"#;
+
+        assert!(parser.find_go_directives(content).is_empty());
+    }
+
     #[test]
     fn parse_extracts_require_block() {
         let parser = GoModParser::new();
@@ -181,6 +488,52 @@ require (
         assert_eq!(result[1].version, "v0.20.0");
     }
 
+    #[test]
+    fn parse_locates_each_require_of_a_shared_version() {
+        let parser = GoModParser::new();
+        let content = "module example.com/myapp\n\ngo 1.21\n\nrequire (\n\tgithub.com/a/one v1.0.0\n\tgithub.com/b/two v1.0.0\n)\n";
+
+        let result = parser.parse(content).unwrap();
+
+        let located = |name: &str, start_offset: usize, line: usize| PackageInfo {
+            name: name.to_string(),
+            version: "v1.0.0".to_string(),
+            commit_hash: None,
+            registry_type: RegistryType::GoProxy,
+            start_offset,
+            end_offset: start_offset + "v1.0.0".len(),
+            line,
+            column: 18,
+            extra_info: None,
+        };
+        assert_eq!(
+            result,
+            vec![
+                located("github.com/a/one", 63, 5),
+                located("github.com/b/two", 88, 6),
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_locates_requires_in_crlf_files() {
+        let parser = GoModParser::new();
+        let content = "module example.com/myapp\r\n\r\ngo 1.21\r\n\r\nrequire (\r\n\tgithub.com/a/one v1.0.0\r\n\tgithub.com/b/two v1.0.0\r\n)\r\n";
+
+        let result = parser.parse(content).unwrap();
+
+        let offsets: Vec<_> = result
+            .iter()
+            .map(|p| (p.start_offset, p.end_offset, p.line, p.column))
+            .collect();
+        assert_eq!(offsets, vec![(68, 74, 5, 18), (94, 100, 6, 18)]);
+        assert!(
+            result
+                .iter()
+                .all(|p| &content[p.start_offset..p.end_offset] == "v1.0.0")
+        );
+    }
+
     #[test]
     fn parse_handles_indirect_dependencies() {
         let parser = GoModParser::new();
@@ -195,6 +548,57 @@ require (
         assert_eq!(result.len(), 2);
         assert_eq!(result[0].name, "golang.org/x/text");
         assert_eq!(result[0].version, "v0.14.0");
+        assert!(result[0].is_indirect());
+        assert!(!result[1].is_indirect());
+    }
+
+    #[test]
+    fn parse_captures_indirect_flag_on_single_line_require() {
+        let parser = GoModParser::new();
+        let content = r#"module example.com/myapp
+
+require golang.org/x/text v0.14.0 // indirect
+require golang.org/x/net v0.20.0 // pinned for CVE fix
+require golang.org/x/sys v0.16.0 // indirect; needed by x/net
+"#;
+        let result = parser.parse(content).unwrap();
+        assert_eq!(result.len(), 3);
+        assert_eq!(
+            result[0].extra_info,
+            Some(ExtraInfo::GoMod {
+                excluded_versions: vec![],
+                indirect: true,
+            })
+        );
+        assert_eq!(result[1].extra_info, None);
+        assert!(result[2].is_indirect());
+    }
+
+    #[test]
+    fn parse_keeps_indirect_flag_with_replace_and_exclude() {
+        let parser = GoModParser::new();
+        let content = r#"module example.com/myapp
+
+require (
+	golang.org/x/text v0.14.0 // indirect
+	golang.org/x/net v0.20.0 // indirect
+)
+
+replace golang.org/x/text v0.14.0 => golang.org/x/text v0.14.1
+
+exclude golang.org/x/net v0.21.0
+"#;
+        let result = parser.parse(content).unwrap();
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0].version, "v0.14.1");
+        assert!(result[0].is_indirect());
+        assert_eq!(
+            result[1].extra_info,
+            Some(ExtraInfo::GoMod {
+                excluded_versions: vec!["v0.21.0".to_string()],
+                indirect: true,
+            })
+        );
     }
 
     #[test]
@@ -262,24 +666,108 @@ require (
     }
 
     #[test]
-    fn parse_skips_replace_directive() {
+    fn parse_skips_modules_replaced_by_local_path_single_line() {
         let parser = GoModParser::new();
         let content = r#"module example.com/myapp
 
 go 1.21
 
-require golang.org/x/text v0.14.0
+require (
+	golang.org/x/text v0.14.0
+	golang.org/x/net v0.20.0
+)
 
-replace golang.org/x/text v0.14.0 => ./local/text
+replace golang.org/x/text => ./fork
+"#;
+        let result = parser.parse(content).unwrap();
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].name, "golang.org/x/net");
+    }
+
+    #[test]
+    fn parse_skips_modules_replaced_by_local_path_or_other_module_in_block() {
+        let parser = GoModParser::new();
+        let content = r#"module example.com/myapp
+
+require (
+	golang.org/x/text v0.14.0
+	golang.org/x/net v0.20.0
+	example.com/old v1.0.0
+	golang.org/x/sync v0.5.0
+)
 
 replace (
+	golang.org/x/text v0.14.0 => ./local/text
 	golang.org/x/net => ../fork/net
 	example.com/old => example.com/new v1.0.0
 )
+"#;
+        let result = parser.parse(content).unwrap();
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].name, "golang.org/x/sync");
+    }
+
+    #[test]
+    fn parse_ignores_replace_for_other_version() {
+        let parser = GoModParser::new();
+        let content = r#"module example.com/myapp
+
+require golang.org/x/text v0.14.0
+
+replace golang.org/x/text v0.13.0 => ./local/text
 "#;
         let result = parser.parse(content).unwrap();
         assert_eq!(result.len(), 1);
         assert_eq!(result[0].name, "golang.org/x/text");
+        assert_eq!(result[0].version, "v0.14.0");
+    }
+
+    #[test]
+    fn parse_uses_replacement_version_for_same_module_single_line() {
+        let parser = GoModParser::new();
+        let content = r#"module example.com/myapp
+
+require golang.org/x/text v1.0.0
+
+replace golang.org/x/text v1.0.0 => golang.org/x/text v1.1.0
+"#;
+        let result = parser.parse(content).unwrap();
+        let start_offset = content.find("v1.1.0").unwrap();
+        assert_eq!(
+            result,
+            vec![PackageInfo {
+                name: "golang.org/x/text".to_string(),
+                version: "v1.1.0".to_string(),
+                commit_hash: None,
+                registry_type: RegistryType::GoProxy,
+                start_offset,
+                end_offset: start_offset + 6,
+                line: 4,
+                column: 54,
+                extra_info: None,
+            }]
+        );
+    }
+
+    #[test]
+    fn parse_uses_replacement_version_for_same_module_in_block() {
+        let parser = GoModParser::new();
+        let content = r#"module example.com/myapp
+
+require golang.org/x/text v1.0.0
+
+replace (
+	golang.org/x/text => golang.org/x/text v1.1.0 // pinned
+)
+"#;
+        let result = parser.parse(content).unwrap();
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].version, "v1.1.0");
+        assert_eq!(result[0].line, 5);
+        assert_eq!(
+            &content[result[0].start_offset..result[0].end_offset],
+            "v1.1.0"
+        );
     }
 
     #[test]
@@ -299,6 +787,13 @@ exclude (
         let result = parser.parse(content).unwrap();
         assert_eq!(result.len(), 1);
         assert_eq!(result[0].name, "golang.org/x/text");
+        assert_eq!(
+            result[0].extra_info,
+            Some(ExtraInfo::GoMod {
+                excluded_versions: vec!["v1.6.7".to_string()],
+                indirect: false,
+            })
+        );
     }
 
     #[test]
@@ -339,8 +834,7 @@ exclude golang.org/x/crypto v1.0.0
 retract v0.0.1
 "#;
         let result = parser.parse(content).unwrap();
-        assert_eq!(result.len(), 2);
-        assert_eq!(result[0].name, "golang.org/x/text");
-        assert_eq!(result[1].name, "golang.org/x/net");
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].name, "golang.org/x/net");
     }
 }
@@ -0,0 +1,401 @@
+//! Gradle version catalog parser (gradle/libs.versions.toml)
+//!
+//! Libraries are declared in `[libraries]` with `module = "group:name"`,
+//! separate `group` and `name` keys, or the `"group:name:version"` shorthand.
+//! Their version is either written inline (`version = "1.2.3"`) or references
+//! an alias of the `[versions]` table (`version.ref = "kotlin"`), in which case
+//! the position points at the version string in `[versions]`. An alias shared
+//! by several libraries is checked once, against the first library using it.
+//!
+//! Rich versions (`{ strictly = "..." }`), version ranges (`[1.0,2.0)`) and
+//! `[plugins]` are skipped.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::parser::traits::{ParseError, Parser, has_tree_sitter_errors};
+use crate::parser::types::{PackageInfo, RegistryType};
+use tracing::warn;
+
+/// Parser for Gradle version catalogs (*.versions.toml)
+pub struct GradleVersionsTomlParser;
+
+impl GradleVersionsTomlParser {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for GradleVersionsTomlParser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A version string of the catalog and its position
+#[derive(Debug, Clone)]
+struct VersionString {
+    version: String,
+    start_offset: usize,
+    end_offset: usize,
+    line: usize,
+    column: usize,
+}
+
+/// Version of a library entry
+enum LibraryVersion {
+    /// `version = "1.2.3"` or the version of a `"group:name:1.2.3"` shorthand
+    Inline(VersionString),
+    /// `version.ref = "alias"` or `version = { ref = "alias" }`
+    Ref(String),
+}
+
+impl Parser for GradleVersionsTomlParser {
+    fn parse(&self, content: &str) -> Result<Vec<PackageInfo>, ParseError> {
+        let mut parser = tree_sitter::Parser::new();
+        let language = tree_sitter_toml_ng::LANGUAGE;
+        parser.set_language(&language.into()).map_err(|e| {
+            warn!("Failed to set TOML language for tree-sitter: {}", e);
+            ParseError::TreeSitter(e.to_string())
+        })?;
+
+        let tree = parser.parse(content, None).ok_or_else(|| {
+            warn!("Failed to parse TOML content");
+            ParseError::ParseFailed("Failed to parse TOML".to_string())
+        })?;
+
+        Ok(self.extract_libraries(tree.root_node(), content))
+    }
+
+    fn has_syntax_errors(&self, content: &str) -> bool {
+        has_tree_sitter_errors(tree_sitter_toml_ng::LANGUAGE.into(), content)
+    }
+}
+
+impl GradleVersionsTomlParser {
+    /// Collect `[versions]` and `[libraries]`, then resolve version references
+    ///
+    /// The tables may appear in any order, so references are only resolved
+    /// once the whole document has been read.
+    fn extract_libraries(&self, root: tree_sitter::Node, content: &str) -> Vec<PackageInfo> {
+        let mut versions: HashMap<String, VersionString> = HashMap::new();
+        let mut libraries: Vec<(String, LibraryVersion)> = Vec::new();
+
+        let mut cursor = root.walk();
+        for table in root
+            .children(&mut cursor)
+            .filter(|child| child.kind() == "table")
+        {
+            match table_name(table, content).as_deref() {
+                Some("versions") => {
+                    for pair in pairs(table) {
+                        if let Some(alias) = pair_key(pair, content)
+                            && let Some(value) = pair_value(pair)
+                            && let Some(version) = version_string(value, content)
+                        {
+                            versions.entry(alias).or_insert(version);
+                        }
+                    }
+                }
+                Some("libraries") => {
+                    libraries
+                        .extend(pairs(table).filter_map(|pair| self.parse_library(pair, content)));
+                }
+                _ => {}
+            }
+        }
+
+        let mut checked_aliases = HashSet::new();
+        libraries
+            .into_iter()
+            .filter_map(|(name, version)| {
+                let version = match version {
+                    LibraryVersion::Inline(version) => version,
+                    LibraryVersion::Ref(alias) => {
+                        if !checked_aliases.insert(alias.clone()) {
+                            return None;
+                        }
+                        versions.get(&alias)?.clone()
+                    }
+                };
+                if is_version_range(&version.version) {
+                    return None;
+                }
+
+                Some(PackageInfo {
+                    name,
+                    version: version.version,
+                    commit_hash: None,
+                    registry_type: RegistryType::Maven,
+                    start_offset: version.start_offset,
+                    end_offset: version.end_offset,
+                    line: version.line,
+                    column: version.column,
+                    extra_info: None,
+                })
+            })
+            .collect()
+    }
+
+    /// Parse a `[libraries]` entry into its `group:name` coordinates and version
+    fn parse_library(
+        &self,
+        pair: tree_sitter::Node,
+        content: &str,
+    ) -> Option<(String, LibraryVersion)> {
+        let value = pair_value(pair)?;
+        match value.kind() {
+            // Shorthand: guava = "com.google.guava:guava:33.0.0-jre"
+            "string" => {
+                let version = version_string(value, content)?;
+                let (module, rest) = version.version.rsplit_once(':')?;
+                if !module.contains(':') || rest.is_empty() {
+                    return None;
+                }
+                let prefix_len = module.len() + 1;
+                let library_version = VersionString {
+                    version: rest.to_string(),
+                    start_offset: version.start_offset + prefix_len,
+                    end_offset: version.end_offset,
+                    line: version.line,
+                    column: version.column + prefix_len,
+                };
+                Some((module.to_string(), LibraryVersion::Inline(library_version)))
+            }
+            "inline_table" => {
+                let mut module = None;
+                let mut group = None;
+                let mut name = None;
+                let mut version = None;
+
+                for entry in pairs(value) {
+                    let (Some(key), Some(entry_value)) =
+                        (pair_key(entry, content), pair_value(entry))
+                    else {
+                        continue;
+                    };
+                    match (key.as_str(), entry_value.kind()) {
+                        ("module", _) => module = string_text(entry_value, content),
+                        ("group", _) => group = string_text(entry_value, content),
+                        ("name", _) => name = string_text(entry_value, content),
+                        ("version", "string") => {
+                            version =
+                                version_string(entry_value, content).map(LibraryVersion::Inline);
+                        }
+                        ("version.ref", _) => {
+                            version = string_text(entry_value, content).map(LibraryVersion::Ref);
+                        }
+                        ("version", "inline_table") => {
+                            version = pairs(entry_value)
+                                .find(|p| pair_key(*p, content).as_deref() == Some("ref"))
+                                .and_then(pair_value)
+                                .and_then(|v| string_text(v, content))
+                                .map(LibraryVersion::Ref);
+                        }
+                        _ => {}
+                    }
+                }
+
+                let module = match module {
+                    Some(module) => module,
+                    None => format!("{}:{}", group?, name?),
+                };
+                Some((module, version?))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Name in the header of a table (e.g. `versions` for `[versions]`)
+fn table_name(table: tree_sitter::Node, content: &str) -> Option<String> {
+    let mut cursor = table.walk();
+    table
+        .children(&mut cursor)
+        .find(|child| matches!(child.kind(), "bare_key" | "dotted_key" | "quoted_key"))
+        .map(|key| key_text(key, content))
+}
+
+/// `key = value` pairs of a table or inline table
+fn pairs<'a>(node: tree_sitter::Node<'a>) -> impl Iterator<Item = tree_sitter::Node<'a>> {
+    let mut cursor = node.walk();
+    node.children(&mut cursor)
+        .filter(|child| child.kind() == "pair")
+        .collect::<Vec<_>>()
+        .into_iter()
+}
+
+/// Key of a pair, with the quotes of quoted keys removed
+fn pair_key(pair: tree_sitter::Node, content: &str) -> Option<String> {
+    pair.named_child(0).map(|key| key_text(key, content))
+}
+
+/// Value of a pair
+fn pair_value(pair: tree_sitter::Node) -> Option<tree_sitter::Node> {
+    let mut cursor = pair.walk();
+    pair.named_children(&mut cursor).skip(1).last()
+}
+
+/// Text of a bare, quoted or dotted key (`version.ref`, `"version".ref`)
+fn key_text(key: tree_sitter::Node, content: &str) -> String {
+    content[key.byte_range()]
+        .split('.')
+        .map(|part| part.trim().trim_matches(['"', '\'']))
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+/// Contents of a single-line string value
+fn string_text(node: tree_sitter::Node, content: &str) -> Option<String> {
+    version_string(node, content).map(|v| v.version)
+}
+
+/// Contents of a single-line string value with their position
+///
+/// Whitespace inside the quotes is excluded, and empty strings yield None.
+fn version_string(node: tree_sitter::Node, content: &str) -> Option<VersionString> {
+    if node.kind() != "string" {
+        return None;
+    }
+    let text = &content[node.byte_range()];
+    let inner = text.strip_prefix(['"', '\''])?.strip_suffix(['"', '\''])?;
+    let version = inner.trim();
+    // Multi-line strings are not valid versions
+    if version.is_empty() || version.contains(['\n', '"', '\'']) {
+        return None;
+    }
+
+    let offset_in_node = 1 + (inner.len() - inner.trim_start().len());
+    let start_offset = node.start_byte() + offset_in_node;
+    let start_point = node.start_position();
+    Some(VersionString {
+        version: version.to_string(),
+        start_offset,
+        end_offset: start_offset + version.len(),
+        line: start_point.row,
+        column: start_point.column + offset_in_node,
+    })
+}
+
+/// Whether a version is a Maven range such as `[1.0,2.0)`
+fn is_version_range(version: &str) -> bool {
+    version.starts_with(['[', '(', ']']) || version.contains(',')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_resolves_version_ref_to_versions_table() {
+        let parser = GradleVersionsTomlParser::new();
+        let content = r#"[versions]
+kotlin = "1.9.22"
+
+[libraries]
+kotlin-stdlib = { module = "org.jetbrains.kotlin:kotlin-stdlib", version.ref = "kotlin" }
+kotlin-reflect = { group = "org.jetbrains.kotlin", name = "kotlin-reflect", version.ref = "kotlin" }
+"#;
+        let result = parser.parse(content).unwrap();
+        assert_eq!(
+            result,
+            vec![PackageInfo {
+                name: "org.jetbrains.kotlin:kotlin-stdlib".to_string(),
+                version: "1.9.22".to_string(),
+                commit_hash: None,
+                registry_type: RegistryType::Maven,
+                start_offset: 21,
+                end_offset: 27,
+                line: 1,
+                column: 10,
+                extra_info: None,
+            }]
+        );
+    }
+
+    #[test]
+    fn parse_resolves_version_ref_declared_after_libraries() {
+        let parser = GradleVersionsTomlParser::new();
+        let content = r#"[libraries]
+okhttp = { group = "com.squareup.okhttp3", name = "okhttp", version = { ref = "okhttp" } }
+
+[versions]
+okhttp = "4.12.0"
+"#;
+        let result = parser.parse(content).unwrap();
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].name, "com.squareup.okhttp3:okhttp");
+        assert_eq!(result[0].version, "4.12.0");
+        assert_eq!(
+            &content[result[0].start_offset..result[0].end_offset],
+            "4.12.0"
+        );
+        assert_eq!((result[0].line, result[0].column), (4, 10));
+    }
+
+    #[test]
+    fn parse_extracts_inline_version() {
+        let parser = GradleVersionsTomlParser::new();
+        let content = r#"[libraries]
+junit = { module = "junit:junit", version = "4.13.2" }
+"#;
+        let result = parser.parse(content).unwrap();
+        assert_eq!(
+            result,
+            vec![PackageInfo {
+                name: "junit:junit".to_string(),
+                version: "4.13.2".to_string(),
+                commit_hash: None,
+                registry_type: RegistryType::Maven,
+                start_offset: 57,
+                end_offset: 63,
+                line: 1,
+                column: 45,
+                extra_info: None,
+            }]
+        );
+    }
+
+    #[test]
+    fn parse_extracts_version_of_string_notation() {
+        let parser = GradleVersionsTomlParser::new();
+        let content = r#"[libraries]
+guava = "com.google.guava:guava:33.0.0-jre"
+"#;
+        let result = parser.parse(content).unwrap();
+        assert_eq!(
+            result,
+            vec![PackageInfo {
+                name: "com.google.guava:guava".to_string(),
+                version: "33.0.0-jre".to_string(),
+                commit_hash: None,
+                registry_type: RegistryType::Maven,
+                start_offset: 44,
+                end_offset: 54,
+                line: 1,
+                column: 32,
+                extra_info: None,
+            }]
+        );
+    }
+
+    #[test]
+    fn parse_skips_entries_without_checkable_version() {
+        let parser = GradleVersionsTomlParser::new();
+        let content = r#"[versions]
+strict = { strictly = "1.0.0" }
+range = "[1.0,2.0)"
+
+[libraries]
+no-version = { module = "org.example:no-version" }
+strict = { module = "org.example:strict", version.ref = "strict" }
+range = { module = "org.example:range", version.ref = "range" }
+missing = { module = "org.example:missing", version.ref = "missing" }
+bom-managed = "org.example:bom-managed"
+
+[plugins]
+kotlin-jvm = { id = "org.jetbrains.kotlin.jvm", version = "1.9.22" }
+"#;
+        let result = parser.parse(content).unwrap();
+        assert!(result.is_empty());
+    }
+}
@@ -0,0 +1,241 @@
+//! mise.toml parser
+//!
+//! Tools are pinned in the `[tools]` table, either to a single version
+//! (`node = "20.11.0"`) or to a list of fallbacks (`node = ["20", "lts"]`),
+//! in which case the first concrete version is used. Tools are mapped to
+//! release feeds like in `.tool-versions`; entries such as `latest`, `lts`,
+//! `ref:...`, options tables and tools without a feed are skipped.
+
+use tracing::warn;
+
+use crate::parser::tool_versions::{is_release_version, tool_release_source};
+use crate::parser::traits::{ParseError, Parser, has_tree_sitter_errors};
+use crate::parser::types::PackageInfo;
+
+/// Parser for mise.toml / .mise.toml files
+pub struct MiseTomlParser;
+
+impl MiseTomlParser {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for MiseTomlParser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Parser for MiseTomlParser {
+    fn parse(&self, content: &str) -> Result<Vec<PackageInfo>, ParseError> {
+        let mut parser = tree_sitter::Parser::new();
+        let language = tree_sitter_toml_ng::LANGUAGE;
+        parser.set_language(&language.into()).map_err(|e| {
+            warn!("Failed to set TOML language for tree-sitter: {}", e);
+            ParseError::TreeSitter(e.to_string())
+        })?;
+
+        let tree = parser.parse(content, None).ok_or_else(|| {
+            warn!("Failed to parse TOML content");
+            ParseError::ParseFailed("Failed to parse TOML".to_string())
+        })?;
+
+        let root = tree.root_node();
+        let mut results = Vec::new();
+
+        let mut cursor = root.walk();
+        for table in root.children(&mut cursor) {
+            if table.kind() == "table" && Self::table_name(table, content) == Some("tools") {
+                Self::extract_tools(table, content, &mut results);
+            }
+        }
+
+        Ok(results)
+    }
+
+    fn has_syntax_errors(&self, content: &str) -> bool {
+        has_tree_sitter_errors(tree_sitter_toml_ng::LANGUAGE.into(), content)
+    }
+}
+
+impl MiseTomlParser {
+    /// Name of a `[table]` header
+    fn table_name<'a>(table_node: tree_sitter::Node, content: &'a str) -> Option<&'a str> {
+        let mut cursor = table_node.walk();
+        table_node
+            .children(&mut cursor)
+            .find(|child| child.kind() == "bare_key" || child.kind() == "dotted_key")
+            .map(|key| &content[key.byte_range()])
+    }
+
+    /// Extract the tool versions of the `[tools]` table
+    fn extract_tools(table_node: tree_sitter::Node, content: &str, results: &mut Vec<PackageInfo>) {
+        let mut cursor = table_node.walk();
+
+        for pair in table_node.children(&mut cursor) {
+            if pair.kind() != "pair" {
+                continue;
+            }
+
+            let mut pair_cursor = pair.walk();
+            let mut tool: Option<&str> = None;
+            let mut version_node: Option<tree_sitter::Node> = None;
+
+            for child in pair.children(&mut pair_cursor) {
+                match child.kind() {
+                    "bare_key" | "quoted_key" => {
+                        tool = Some(strip_quotes(&content[child.byte_range()]));
+                    }
+                    "string" => version_node = Some(child),
+                    "array" => version_node = Self::first_release_in_array(child, content),
+                    _ => {}
+                }
+            }
+
+            let (Some(tool), Some(node)) = (tool, version_node) else {
+                continue;
+            };
+            let Some((registry_type, name)) = tool_release_source(tool) else {
+                continue;
+            };
+            let version = strip_quotes(&content[node.byte_range()]);
+            if !is_release_version(version) {
+                continue;
+            }
+
+            // Offsets point inside the quotes
+            let start_offset = node.start_byte() + 1;
+            let start_point = node.start_position();
+
+            results.push(PackageInfo {
+                name: name.to_string(),
+                version: version.to_string(),
+                commit_hash: None,
+                registry_type,
+                start_offset,
+                end_offset: start_offset + version.len(),
+                line: start_point.row,
+                column: start_point.column + 1,
+                extra_info: None,
+            });
+        }
+    }
+
+    /// First string of an array that names a concrete release
+    fn first_release_in_array<'a>(
+        array_node: tree_sitter::Node<'a>,
+        content: &str,
+    ) -> Option<tree_sitter::Node<'a>> {
+        let mut cursor = array_node.walk();
+        array_node.children(&mut cursor).find(|child| {
+            child.kind() == "string"
+                && is_release_version(strip_quotes(&content[child.byte_range()]))
+        })
+    }
+}
+
+/// Strip the quotes of a single-line TOML string or quoted key
+fn strip_quotes(text: &str) -> &str {
+    let trimmed = text.trim();
+    if trimmed.len() >= 2
+        && ((trimmed.starts_with('"') && trimmed.ends_with('"'))
+            || (trimmed.starts_with('\'') && trimmed.ends_with('\'')))
+    {
+        &trimmed[1..trimmed.len() - 1]
+    } else {
+        trimmed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::go_mod::GO_RELEASES_PACKAGE;
+    use crate::parser::tool_versions::NODEJS_PACKAGE;
+    use crate::parser::types::RegistryType;
+
+    #[test]
+    fn parse_extracts_string_versions() {
+        let parser = MiseTomlParser::new();
+        let content = r#"[env]
+NODE_ENV = "development"
+
+[tools]
+node = "20.11.0"
+go = "1.22.0"
+python = "3.12"
+"#;
+
+        let result = parser.parse(content).unwrap();
+
+        assert_eq!(
+            result,
+            vec![
+                PackageInfo {
+                    name: NODEJS_PACKAGE.to_string(),
+                    version: "20.11.0".to_string(),
+                    commit_hash: None,
+                    registry_type: RegistryType::ToolVersions,
+                    start_offset: 48,
+                    end_offset: 55,
+                    line: 4,
+                    column: 8,
+                    extra_info: None,
+                },
+                PackageInfo {
+                    name: GO_RELEASES_PACKAGE.to_string(),
+                    version: "1.22.0".to_string(),
+                    commit_hash: None,
+                    registry_type: RegistryType::GoRelease,
+                    start_offset: 63,
+                    end_offset: 69,
+                    line: 5,
+                    column: 6,
+                    extra_info: None,
+                },
+            ]
+        );
+        assert_eq!(&content[48..55], "20.11.0");
+        assert_eq!(&content[63..69], "1.22.0");
+    }
+
+    #[test]
+    fn parse_takes_first_concrete_version_of_array() {
+        let parser = MiseTomlParser::new();
+        let content = "[tools]\nnode = [\"lts\", \"20\", \"18\"]\n";
+
+        let result = parser.parse(content).unwrap();
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].name, NODEJS_PACKAGE);
+        assert_eq!(result[0].version, "20");
+        assert_eq!(&content[result[0].start_offset..result[0].end_offset], "20");
+        assert_eq!((result[0].line, result[0].column), (1, 16));
+    }
+
+    #[test]
+    fn parse_skips_latest_and_unsupported_entries() {
+        let parser = MiseTomlParser::new();
+        let content = r#"[tools]
+node = "latest"
+go = ["ref:master"]
+"npm:prettier" = "3.3.3"
+golang = { version = "1.22.0" }
+"#;
+
+        let result = parser.parse(content).unwrap();
+
+        assert!(result.is_empty());
+    }
+
+    #[test]
+    fn parse_ignores_tools_outside_tools_table() {
+        let parser = MiseTomlParser::new();
+        let content = "[settings]\nnode = \"20.11.0\"\n";
+
+        let result = parser.parse(content).unwrap();
+
+        assert!(result.is_empty());
+    }
+}
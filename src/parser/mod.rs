@@ -8,25 +8,56 @@
 //! - pnpm_workspace.rs: pnpm-workspace.yaml catalog parser
 //! - deno_json.rs: deno.json parser
 //! - pyproject_toml.rs: pyproject.toml parser
+//! - gemfile.rs: Gemfile parser
+//! - composer_json.rs: composer.json parser
+//! - brewfile.rs: Brewfile parser
+//! - flake_lock.rs: Nix flake.lock parser
+//! - tool_versions.rs: asdf .tool-versions parser
+//! - mise_toml.rs: mise.toml parser
+//! - compose.rs: compose.yaml parser
+//! - dockerfile.rs: Dockerfile parser
+//! - docker.rs: Dispatches Docker documents to the compose or Dockerfile parser
+//! - terraform.rs: Terraform required_providers parser
+//! - gradle_versions_toml.rs: Gradle version catalog (libs.versions.toml) parser
 
+pub mod brewfile;
 pub mod cargo_toml;
 pub mod compose;
+pub mod composer_json;
 pub mod deno_json;
+pub mod docker;
+pub mod dockerfile;
+pub mod flake_lock;
+pub mod gemfile;
 pub mod github_actions;
 pub mod go_mod;
+pub mod gradle_versions_toml;
+pub mod mise_toml;
 pub mod package_json;
 pub mod pnpm_workspace;
 pub mod pyproject_toml;
+pub mod terraform;
+pub mod tool_versions;
 pub mod traits;
 pub mod types;
 
+pub use brewfile::BrewfileParser;
 pub use cargo_toml::CargoTomlParser;
 pub use compose::ComposeParser;
+pub use composer_json::ComposerJsonParser;
 pub use deno_json::DenoJsonParser;
+pub use docker::DockerParser;
+pub use dockerfile::DockerfileParser;
+pub use flake_lock::FlakeLockParser;
+pub use gemfile::GemfileParser;
 pub use github_actions::GitHubActionsParser;
 pub use go_mod::GoModParser;
+pub use gradle_versions_toml::GradleVersionsTomlParser;
+pub use mise_toml::MiseTomlParser;
 pub use package_json::PackageJsonParser;
 pub use pnpm_workspace::PnpmWorkspaceParser;
 pub use pyproject_toml::PyprojectTomlParser;
+pub use terraform::TerraformParser;
+pub use tool_versions::ToolVersionsParser;
 pub use traits::{ParseError, Parser};
 pub use types::{PackageInfo, RegistryType};
@@ -1,9 +1,20 @@
 //! package.json parser
+//!
+//! Extracts `dependencies`, `devDependencies`, `peerDependencies` and
+//! `optionalDependencies`, plus the pinned versions in npm `overrides` and yarn `resolutions`. Overrides may be
+//! nested (`"foo": { "bar": "1.2.3" }`); each leaf is recorded under its own
+//! package name, and a `"."` key stands for the enclosing package.
 
-use crate::parser::traits::{ParseError, Parser};
+use crate::parser::traits::{ParseError, Parser, has_tree_sitter_errors};
 use crate::parser::types::{PackageInfo, RegistryType};
 use tracing::warn;
 
+/// Version protocols that do not refer to a registry version
+///
+/// `catalog:` entries are resolved from pnpm-workspace.yaml and checked there;
+/// the others point at workspace packages or local paths.
+const NON_REGISTRY_PROTOCOLS: &[&str] = &["workspace:", "catalog:", "file:", "link:", "portal:"];
+
 /// Parser for package.json files
 pub struct PackageJsonParser;
 
@@ -45,17 +56,27 @@ impl Parser for PackageJsonParser {
 
         Ok(results)
     }
+
+    fn has_syntax_errors(&self, content: &str) -> bool {
+        has_tree_sitter_errors(tree_sitter_json::LANGUAGE.into(), content)
+    }
 }
 
 impl PackageJsonParser {
     /// Dependency field names to extract
+    ///
+    /// `bundledDependencies` only lists names of packages already declared
+    /// elsewhere, so it is not among them.
     const DEPENDENCY_FIELDS: [&'static str; 4] = [
         "dependencies",
         "devDependencies",
         "peerDependencies",
-        "overrides",
+        "optionalDependencies",
     ];
 
+    /// Fields pinning nested dependency versions (npm, yarn)
+    const OVERRIDE_FIELDS: [&'static str; 2] = ["overrides", "resolutions"];
+
     /// Parse npm alias format: npm:package@version or npm:@scope/package@version
     /// Returns (actual_package_name, version)
     fn parse_npm_alias(value: &str) -> Option<(String, String)> {
@@ -110,16 +131,69 @@ impl PackageJsonParser {
 
             let key_text = self.get_string_value(key_node, content);
 
-            if !Self::DEPENDENCY_FIELDS.contains(&key_text.as_str()) {
+            let Some(value_node) = child.child_by_field_name("value") else {
+                continue;
+            };
+
+            if value_node.kind() != "object" {
                 continue;
             }
 
+            if Self::DEPENDENCY_FIELDS.contains(&key_text.as_str()) {
+                self.extract_packages_from_object(value_node, content, results);
+            } else if Self::OVERRIDE_FIELDS.contains(&key_text.as_str()) {
+                self.extract_overrides(value_node, None, content, results);
+            }
+        }
+    }
+
+    /// Extract pinned versions from an `overrides`/`resolutions` object, recursing
+    /// into nested overrides
+    fn extract_overrides(
+        &self,
+        object_node: tree_sitter::Node,
+        parent: Option<&str>,
+        content: &str,
+        results: &mut Vec<PackageInfo>,
+    ) {
+        let mut cursor = object_node.walk();
+
+        for child in object_node.children(&mut cursor) {
+            if child.kind() != "pair" {
+                continue;
+            }
+
+            let Some(key_node) = child.child_by_field_name("key") else {
+                continue;
+            };
+
             let Some(value_node) = child.child_by_field_name("value") else {
                 continue;
             };
 
-            if value_node.kind() == "object" {
-                self.extract_packages_from_object(value_node, content, results);
+            // `"."` overrides the version of the enclosing package itself
+            let key_text = self.get_string_value(key_node, content);
+            let package_name = if key_text == "." {
+                let Some(parent) = parent else {
+                    continue;
+                };
+                parent.to_string()
+            } else {
+                override_package_name(&key_text).to_string()
+            };
+
+            match value_node.kind() {
+                "string" => {
+                    // `$foo` refers to the version of a direct dependency
+                    if self.get_string_value(value_node, content).starts_with('$') {
+                        continue;
+                    }
+                    self.push_package(package_name, value_node, content, results);
+                }
+                "object" => {
+                    self.extract_overrides(value_node, Some(&package_name), content, results);
+                }
+                _ => {}
             }
         }
     }
@@ -151,59 +225,95 @@ impl PackageJsonParser {
             }
 
             let key_name = self.get_string_value(key_node, content);
-            let raw_version = self.get_string_value(value_node, content);
-
-            // Skip pnpm catalog references (e.g., "catalog:ag-grid" or "catalog:")
-            // These are resolved from pnpm-workspace.yaml, not version-checked here
-            if raw_version.starts_with("catalog:") {
-                continue;
-            }
-
-            // Check for npm alias format: npm:package@version
-            let (package_name, version) =
-                if let Some((name, ver)) = Self::parse_npm_alias(&raw_version) {
-                    (name, ver)
-                } else {
-                    (key_name, raw_version)
-                };
-
-            let start_point = value_node.start_position();
-            let start_offset = value_node.start_byte();
-            let end_offset = value_node.end_byte();
+            self.push_package(key_name, value_node, content, results);
+        }
+    }
 
-            // Adjust for quotes - the actual version starts after the opening quote
-            let version_start_offset = start_offset + 1;
-            let version_end_offset = end_offset - 1;
-            let version_column = start_point.column + 1;
+    /// Record the version string `value_node` of the package `key_name`
+    fn push_package(
+        &self,
+        key_name: String,
+        value_node: tree_sitter::Node,
+        content: &str,
+        results: &mut Vec<PackageInfo>,
+    ) {
+        let raw_version = self.get_string_value(value_node, content);
 
-            results.push(PackageInfo {
-                name: package_name,
-                version,
-                commit_hash: None,
-                registry_type: RegistryType::Npm,
-                start_offset: version_start_offset,
-                end_offset: version_end_offset,
-                line: start_point.row,
-                column: version_column,
-                extra_info: None,
-            });
+        // Skip workspace, catalog and local path references
+        if NON_REGISTRY_PROTOCOLS
+            .iter()
+            .any(|protocol| raw_version.starts_with(protocol))
+        {
+            return;
         }
+
+        // Check for npm alias format: npm:package@version
+        let (package_name, version) = if let Some((name, ver)) = Self::parse_npm_alias(&raw_version)
+        {
+            (name, ver)
+        } else {
+            (key_name, raw_version)
+        };
+
+        let start_point = value_node.start_position();
+        let start_offset = value_node.start_byte();
+        let end_offset = value_node.end_byte();
+
+        // Adjust for quotes - the actual version starts after the opening quote
+        let version_start_offset = start_offset + 1;
+        let version_end_offset = end_offset - 1;
+        let version_column = start_point.column + 1;
+
+        results.push(PackageInfo {
+            name: package_name,
+            version,
+            commit_hash: None,
+            registry_type: RegistryType::Npm,
+            start_offset: version_start_offset,
+            end_offset: version_end_offset,
+            line: start_point.row,
+            column: version_column,
+            extra_info: None,
+        });
     }
 
-    /// Get the string value from a string node (removes quotes)
+    /// Get the string value from a string node (removes quotes, decodes escapes)
     fn get_string_value(&self, node: tree_sitter::Node, content: &str) -> String {
-        let text = &content[node.byte_range()];
-        // Remove surrounding quotes
-        text.trim()
-            .trim_start_matches('"')
-            .trim_end_matches('"')
-            .to_string()
+        let text = content[node.byte_range()].trim();
+        // A JSON string literal decodes `\"`, `\\`, `\uXXXX`, ...
+        serde_json::from_str::<String>(text).unwrap_or_else(|_| {
+            // Malformed literal (e.g. while typing): fall back to stripping quotes
+            text.trim_start_matches('"')
+                .trim_end_matches('"')
+                .to_string()
+        })
+    }
+}
+
+/// Package an `overrides`/`resolutions` key applies to
+///
+/// Keys may be yarn path patterns (`**/foo`, `parent/@scope/foo`) and carry a
+/// version selector (`foo@1.x`); only the last package of the path is kept.
+fn override_package_name(key: &str) -> &str {
+    let segments: Vec<&str> = key.split('/').collect();
+    let name_len = match segments.as_slice() {
+        [.., scope, name] if scope.starts_with('@') => scope.len() + 1 + name.len(),
+        [.., name] => name.len(),
+        [] => key.len(),
+    };
+    let name = &key[key.len() - name_len..];
+
+    // Drop the version selector, keeping the `@` of a scope
+    match name.get(1..).and_then(|rest| rest.find('@')) {
+        Some(at) => &name[..at + 1],
+        None => name,
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rstest::rstest;
 
     #[test]
     fn parse_extracts_dependencies() {
@@ -303,41 +413,14 @@ mod tests {
 }"#;
         let result = parser.parse(content).unwrap();
         assert_eq!(
-            result,
+            result
+                .iter()
+                .map(PackageInfo::logical_key)
+                .collect::<Vec<_>>(),
             vec![
-                PackageInfo {
-                    name: "lodash".to_string(),
-                    version: "4.17.21".to_string(),
-                    commit_hash: None,
-                    registry_type: RegistryType::Npm,
-                    start_offset: 57,
-                    end_offset: 64,
-                    line: 3,
-                    column: 15,
-                    extra_info: None,
-                },
-                PackageInfo {
-                    name: "typescript".to_string(),
-                    version: "5.0.0".to_string(),
-                    commit_hash: None,
-                    registry_type: RegistryType::Npm,
-                    start_offset: 113,
-                    end_offset: 118,
-                    line: 6,
-                    column: 19,
-                    extra_info: None,
-                },
-                PackageInfo {
-                    name: "react".to_string(),
-                    version: "18.0.0".to_string(),
-                    commit_hash: None,
-                    registry_type: RegistryType::Npm,
-                    start_offset: 163,
-                    end_offset: 169,
-                    line: 9,
-                    column: 14,
-                    extra_info: None,
-                },
+                ("lodash", "4.17.21", None, RegistryType::Npm),
+                ("typescript", "5.0.0", None, RegistryType::Npm),
+                ("react", "18.0.0", None, RegistryType::Npm),
             ]
         );
     }
@@ -355,41 +438,14 @@ mod tests {
 }"#;
         let result = parser.parse(content).unwrap();
         assert_eq!(
-            result,
+            result
+                .iter()
+                .map(PackageInfo::logical_key)
+                .collect::<Vec<_>>(),
             vec![
-                PackageInfo {
-                    name: "lodash".to_string(),
-                    version: "^4.17.21".to_string(),
-                    commit_hash: None,
-                    registry_type: RegistryType::Npm,
-                    start_offset: 57,
-                    end_offset: 65,
-                    line: 3,
-                    column: 15,
-                    extra_info: None,
-                },
-                PackageInfo {
-                    name: "express".to_string(),
-                    version: "~4.18.0".to_string(),
-                    commit_hash: None,
-                    registry_type: RegistryType::Npm,
-                    start_offset: 84,
-                    end_offset: 91,
-                    line: 4,
-                    column: 16,
-                    extra_info: None,
-                },
-                PackageInfo {
-                    name: "uuid".to_string(),
-                    version: ">=9.0.0".to_string(),
-                    commit_hash: None,
-                    registry_type: RegistryType::Npm,
-                    start_offset: 107,
-                    end_offset: 114,
-                    line: 5,
-                    column: 13,
-                    extra_info: None,
-                },
+                ("lodash", "^4.17.21", None, RegistryType::Npm),
+                ("express", "~4.18.0", None, RegistryType::Npm),
+                ("uuid", ">=9.0.0", None, RegistryType::Npm),
             ]
         );
     }
@@ -406,30 +462,13 @@ mod tests {
 }"#;
         let result = parser.parse(content).unwrap();
         assert_eq!(
-            result,
+            result
+                .iter()
+                .map(PackageInfo::logical_key)
+                .collect::<Vec<_>>(),
             vec![
-                PackageInfo {
-                    name: "@types/node".to_string(),
-                    version: "20.0.0".to_string(),
-                    commit_hash: None,
-                    registry_type: RegistryType::Npm,
-                    start_offset: 62,
-                    end_offset: 68,
-                    line: 3,
-                    column: 20,
-                    extra_info: None,
-                },
-                PackageInfo {
-                    name: "@babel/core".to_string(),
-                    version: "7.22.0".to_string(),
-                    commit_hash: None,
-                    registry_type: RegistryType::Npm,
-                    start_offset: 91,
-                    end_offset: 97,
-                    line: 4,
-                    column: 20,
-                    extra_info: None,
-                },
+                ("@types/node", "20.0.0", None, RegistryType::Npm),
+                ("@babel/core", "7.22.0", None, RegistryType::Npm),
             ]
         );
     }
@@ -559,21 +598,117 @@ mod tests {
     }
 
     #[test]
-    fn parse_skips_nested_overrides_objects() {
+    fn parse_extracts_nested_overrides() {
         let parser = PackageJsonParser::new();
         let content = r#"{
   "name": "my-app",
   "overrides": {
     "lodash": "4.17.21",
     "foo": {
-      "bar": "1.0.0"
+      ".": "2.0.0",
+      "bar": "1.2.3"
+    },
+    "react": "$react"
+  }
+}"#;
+        let result = parser.parse(content).unwrap();
+        assert_eq!(
+            result
+                .iter()
+                .map(PackageInfo::logical_key)
+                .collect::<Vec<_>>(),
+            vec![
+                ("lodash", "4.17.21", None, RegistryType::Npm),
+                ("foo", "2.0.0", None, RegistryType::Npm),
+                ("bar", "1.2.3", None, RegistryType::Npm),
+            ]
+        );
+        assert_eq!(
+            result[2],
+            PackageInfo {
+                name: "bar".to_string(),
+                version: "1.2.3".to_string(),
+                commit_hash: None,
+                registry_type: RegistryType::Npm,
+                start_offset: 111,
+                end_offset: 116,
+                line: 6,
+                column: 14,
+                extra_info: None,
+            }
+        );
+        assert_eq!(&content[111..116], "1.2.3");
     }
+
+    #[test]
+    fn parse_extracts_optional_dependencies_and_ignores_bundled_dependencies() {
+        let parser = PackageJsonParser::new();
+        let content = r#"{
+  "dependencies": {
+    "sharp": "^0.33.0"
+  },
+  "optionalDependencies": {
+    "fsevents": "^2.3.2"
+  },
+  "bundledDependencies": ["sharp"],
+  "trustedDependencies": ["sharp"]
+}"#;
+        let result = parser.parse(content).unwrap();
+        assert_eq!(
+            result
+                .iter()
+                .map(PackageInfo::logical_key)
+                .collect::<Vec<_>>(),
+            vec![
+                ("sharp", "^0.33.0", None, RegistryType::Npm),
+                ("fsevents", "^2.3.2", None, RegistryType::Npm),
+            ]
+        );
+        assert_eq!(
+            &content[result[1].start_offset..result[1].end_offset],
+            "^2.3.2"
+        );
+    }
+
+    #[test]
+    fn parse_extracts_yarn_resolutions() {
+        let parser = PackageJsonParser::new();
+        let content = r#"{
+  "resolutions": {
+    "**/minimist": "1.2.8",
+    "webpack/@babel/core": "7.24.0",
+    "glob@^7.0.0": "7.2.3"
   }
 }"#;
         let result = parser.parse(content).unwrap();
-        assert_eq!(result.len(), 1);
-        assert_eq!(result[0].name, "lodash");
-        assert_eq!(result[0].version, "4.17.21");
+        assert_eq!(
+            result
+                .iter()
+                .map(PackageInfo::logical_key)
+                .collect::<Vec<_>>(),
+            vec![
+                ("minimist", "1.2.8", None, RegistryType::Npm),
+                ("@babel/core", "7.24.0", None, RegistryType::Npm),
+                ("glob", "7.2.3", None, RegistryType::Npm),
+            ]
+        );
+        for package in &result {
+            assert_eq!(
+                &content[package.start_offset..package.end_offset],
+                package.version
+            );
+        }
+    }
+
+    #[rstest]
+    #[case("lodash", "lodash")]
+    #[case("@babel/core", "@babel/core")]
+    #[case("**/minimist", "minimist")]
+    #[case("webpack/@babel/core", "@babel/core")]
+    #[case("glob@^7.0.0", "glob")]
+    #[case("@types/node@20", "@types/node")]
+    fn override_package_name_returns_leaf_package(#[case] key: &str, #[case] expected: &str) {
+        assert_eq!(override_package_name(key), expected);
     }
 
     #[test]
@@ -605,4 +740,75 @@ mod tests {
         // Should be empty - default catalog reference is skipped
         assert!(result.is_empty());
     }
+
+    #[rstest]
+    #[case("workspace:*")]
+    #[case("workspace:^")]
+    #[case("workspace:^1.2.0")]
+    #[case("catalog:")]
+    #[case("catalog:react18")]
+    #[case("file:../local-pkg")]
+    #[case("link:../linked-pkg")]
+    #[case("portal:../portal-pkg")]
+    fn parse_skips_non_registry_protocols(#[case] version: &str) {
+        let parser = PackageJsonParser::new();
+        let content = format!(
+            r#"{{
+  "dependencies": {{
+    "local-pkg": "{}",
+    "react": "^18.0.0"
+  }}
+}}"#,
+            version
+        );
+
+        let result = parser.parse(&content).unwrap();
+
+        let names: Vec<_> = result.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, vec!["react"]);
+    }
+
+    #[test]
+    fn parse_unescapes_json_string_escapes() {
+        let parser = PackageJsonParser::new();
+        let content = r#"{
+  "dependencies": {
+    "\u0040types/node": "^20.0.0",
+    "odd\\name": "\"1.0.0\""
+  }
+}"#;
+
+        let result = parser.parse(content).unwrap();
+
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0].name, "@types/node");
+        assert_eq!(result[0].version, "^20.0.0");
+        assert_eq!(result[1].name, "odd\\name");
+        assert_eq!(result[1].version, "\"1.0.0\"");
+    }
+
+    #[test]
+    fn parse_extracts_dependencies_around_comments() {
+        let parser = PackageJsonParser::new();
+        let content = r#"{
+  // runtime dependencies
+  "dependencies": {
+    "react": "^18.0.0",
+    // pinned until the next major is tested
+    "lodash": "4.17.21", /* trailing */
+    "zod": "^3.22.0"
+  }
+}"#;
+
+        let result = parser.parse(content).unwrap();
+
+        let names: Vec<_> = result.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, vec!["react", "lodash", "zod"]);
+        for package in &result {
+            assert_eq!(
+                &content[package.start_offset..package.end_offset],
+                package.version
+            );
+        }
+    }
 }
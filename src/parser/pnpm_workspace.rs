@@ -1,9 +1,40 @@
 //! pnpm-workspace.yaml catalog parser
+//!
+//! Entries are version ranges (`react: ^18.2.0`) or npm aliases
+//! (`react18: npm:react@^18`), which are checked as the aliased package at
+//! the trailing range. `workspace:` and local path entries are skipped.
 
-use crate::parser::traits::{ParseError, Parser};
+use crate::parser::traits::{ParseError, Parser, has_tree_sitter_errors};
 use crate::parser::types::{PackageInfo, RegistryType};
 use tracing::warn;
 
+/// Protocols of catalog entries that do not refer to a registry version
+const NON_REGISTRY_PROTOCOLS: &[&str] = &["workspace:", "file:", "link:", "portal:"];
+
+/// Prefix of an npm alias (`npm:react@^18`)
+const NPM_ALIAS_PREFIX: &str = "npm:";
+
+/// Whether a catalog entry refers to a registry version at all
+pub fn is_registry_spec(spec: &str) -> bool {
+    !NON_REGISTRY_PROTOCOLS
+        .iter()
+        .any(|protocol| spec.starts_with(protocol))
+}
+
+/// Split an npm alias (`npm:react@^18`, `npm:@scope/pkg@1.0.0`) into the
+/// aliased package and its version range
+///
+/// Returns None for values that are not aliases and for aliases without a
+/// range (`npm:react`).
+pub fn split_npm_alias(spec: &str) -> Option<(&str, &str)> {
+    let rest = spec.strip_prefix(NPM_ALIAS_PREFIX)?;
+    // The range follows the first `@` after the (possibly scoped) name
+    let name_start = usize::from(rest.starts_with('@'));
+    let at = name_start + rest[name_start..].find('@')?;
+    let (name, range) = (&rest[..at], &rest[at + 1..]);
+    (!name.is_empty() && !range.is_empty()).then_some((name, range))
+}
+
 /// Parser for pnpm-workspace.yaml catalog files
 pub struct PnpmWorkspaceParser;
 
@@ -29,6 +60,10 @@ impl Parser for PnpmWorkspaceParser {
 
         Ok(results)
     }
+
+    fn has_syntax_errors(&self, content: &str) -> bool {
+        has_tree_sitter_errors(tree_sitter_yaml::LANGUAGE.into(), content)
+    }
 }
 
 impl PnpmWorkspaceParser {
@@ -131,11 +166,19 @@ impl PnpmWorkspaceParser {
             (trimmed, false)
         };
 
-        // Skip empty values
-        if version.is_empty() {
+        // Skip empty values and workspace or local path references
+        if version.is_empty() || !is_registry_spec(version) {
             return None;
         }
 
+        // Aliases are checked as the aliased package, at the trailing range
+        let (name, version) = match split_npm_alias(version) {
+            Some((aliased, range)) => (aliased.to_string(), range),
+            // Nothing to check for an alias without a range
+            None if version.starts_with(NPM_ALIAS_PREFIX) => return None,
+            None => (name, version),
+        };
+
         let start_offset = value_node.start_byte();
         let end_offset = value_node.end_byte();
         let start_point = value_node.start_position();
@@ -147,15 +190,18 @@ impl PnpmWorkspaceParser {
             (start_offset, end_offset, start_point.column)
         };
 
+        // The range is the tail of the value, after any alias prefix
+        let range_start = adjusted_end - version.len();
+
         Some(PackageInfo {
             name,
             version: version.to_string(),
             commit_hash: None,
             registry_type: RegistryType::PnpmCatalog,
-            start_offset: adjusted_start,
+            start_offset: range_start,
             end_offset: adjusted_end,
             line: start_point.row,
-            column: adjusted_column,
+            column: adjusted_column + (range_start - adjusted_start),
             extra_info: None,
         })
     }
@@ -313,6 +359,67 @@ mod tests {
         );
     }
 
+    #[test]
+    fn parse_skips_workspace_entries_and_narrows_aliases_to_their_range() {
+        let parser = PnpmWorkspaceParser;
+        let content = r#"catalog:
+  react: ^18.2.0
+  shared: workspace:*
+  react17: npm:react@^17.0.2
+  types-node: "npm:@types/node@20.11.0"
+"#;
+        let result = parser.parse(content).unwrap();
+        assert_eq!(
+            result,
+            vec![
+                PackageInfo {
+                    name: "react".to_string(),
+                    version: "^18.2.0".to_string(),
+                    commit_hash: None,
+                    registry_type: RegistryType::PnpmCatalog,
+                    start_offset: 18,
+                    end_offset: 25,
+                    line: 1,
+                    column: 9,
+                    extra_info: None,
+                },
+                PackageInfo {
+                    name: "react".to_string(),
+                    version: "^17.0.2".to_string(),
+                    commit_hash: None,
+                    registry_type: RegistryType::PnpmCatalog,
+                    start_offset: 69,
+                    end_offset: 76,
+                    line: 3,
+                    column: 21,
+                    extra_info: None,
+                },
+                PackageInfo {
+                    name: "@types/node".to_string(),
+                    version: "20.11.0".to_string(),
+                    commit_hash: None,
+                    registry_type: RegistryType::PnpmCatalog,
+                    start_offset: 108,
+                    end_offset: 115,
+                    line: 4,
+                    column: 31,
+                    extra_info: None,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn split_npm_alias_returns_package_and_range() {
+        assert_eq!(split_npm_alias("npm:react@^18"), Some(("react", "^18")));
+        assert_eq!(
+            split_npm_alias("npm:@types/node@^20.0.0"),
+            Some(("@types/node", "^20.0.0"))
+        );
+        assert_eq!(split_npm_alias("npm:react"), None);
+        assert_eq!(split_npm_alias("^18.2.0"), None);
+    }
+
     #[test]
     fn parse_returns_empty_for_no_catalog() {
         let parser = PnpmWorkspaceParser;
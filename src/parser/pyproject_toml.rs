@@ -5,16 +5,19 @@
 //! - `[build-system].requires` - Build system requirements
 //! - `[project.optional-dependencies]` - Optional dependencies
 //! - `[dependency-groups]` - PEP 735 dependency groups
+//! - `[tool.poetry.dependencies]`, `[tool.poetry.dev-dependencies]` and
+//!   `[tool.poetry.group.<name>.dependencies]` - Poetry dependency tables
 //!
 //! URL dependencies (e.g., `pkg @ git+https://...`) are skipped
-//! as they don't exist on PyPI.
+//! as they don't exist on PyPI. Poetry dependencies with `git`, `path` or
+//! `url` sources are skipped likewise, as is Poetry's `python` entry.
 
 use std::str::FromStr;
 
 use pep508_rs::{Requirement, VerbatimUrl, VersionOrUrl};
 use tracing::warn;
 
-use crate::parser::traits::{ParseError, Parser};
+use crate::parser::traits::{ParseError, Parser, has_tree_sitter_errors};
 use crate::parser::types::{PackageInfo, RegistryType};
 
 /// Parser for pyproject.toml files
@@ -53,6 +56,10 @@ impl Parser for PyprojectTomlParser {
 
         Ok(results)
     }
+
+    fn has_syntax_errors(&self, content: &str) -> bool {
+        has_tree_sitter_errors(tree_sitter_toml_ng::LANGUAGE.into(), content)
+    }
 }
 
 impl PyprojectTomlParser {
@@ -117,10 +124,120 @@ impl PyprojectTomlParser {
                 // [project.optional-dependencies] / [dependency-groups] (PEP 735) - all keys have arrays
                 self.extract_all_arrays(table_node, content, results);
             }
+            name if Self::is_poetry_dependency_table(name) => {
+                // [tool.poetry.dependencies] - `name = "^1.0"` or `name = { version = "^1.0" }`
+                self.extract_poetry_dependencies(table_node, content, results);
+            }
             _ => {}
         }
     }
 
+    /// Check if a table holds Poetry dependencies
+    fn is_poetry_dependency_table(table_name: &str) -> bool {
+        match table_name.strip_prefix("tool.poetry.") {
+            Some("dependencies" | "dev-dependencies") => true,
+            Some(rest) => rest
+                .strip_prefix("group.")
+                .and_then(|group| group.strip_suffix(".dependencies"))
+                .is_some_and(|group| !group.is_empty() && !group.contains('.')),
+            None => false,
+        }
+    }
+
+    /// Keys of a Poetry dependency table that make it a non-PyPI source
+    const POETRY_SKIP_KEYS: [&'static str; 3] = ["git", "path", "url"];
+
+    /// Extract dependencies from a Poetry dependency table
+    fn extract_poetry_dependencies(
+        &self,
+        table_node: tree_sitter::Node,
+        content: &str,
+        results: &mut Vec<PackageInfo>,
+    ) {
+        let mut cursor = table_node.walk();
+
+        for pair in table_node.children(&mut cursor) {
+            if pair.kind() != "pair" {
+                continue;
+            }
+
+            let mut pair_cursor = pair.walk();
+            let mut package_name: Option<String> = None;
+            let mut version_node: Option<tree_sitter::Node> = None;
+
+            for child in pair.children(&mut pair_cursor) {
+                match child.kind() {
+                    "bare_key" | "quoted_key" => {
+                        package_name = Some(strip_quotes(&content[child.byte_range()]).to_string());
+                    }
+                    "string" => version_node = Some(child),
+                    "inline_table" => {
+                        version_node = Self::poetry_inline_table_version(child, content);
+                    }
+                    _ => {}
+                }
+            }
+
+            let (Some(name), Some(node)) = (package_name, version_node) else {
+                continue;
+            };
+            if name == "python" {
+                continue;
+            }
+
+            let version = strip_quotes(&content[node.byte_range()]);
+            if version.is_empty() {
+                continue;
+            }
+
+            // Offsets point inside the quotes
+            let start_offset = node.start_byte() + 1;
+            let start_point = node.start_position();
+
+            results.push(PackageInfo {
+                name,
+                version: version.to_string(),
+                commit_hash: None,
+                registry_type: RegistryType::PyPI,
+                start_offset,
+                end_offset: start_offset + version.len(),
+                line: start_point.row,
+                column: start_point.column + 1,
+                extra_info: None,
+            });
+        }
+    }
+
+    /// Find the `version` string of a Poetry inline table
+    ///
+    /// Returns None for git, path and url dependencies.
+    fn poetry_inline_table_version<'a>(
+        table_node: tree_sitter::Node<'a>,
+        content: &str,
+    ) -> Option<tree_sitter::Node<'a>> {
+        let mut cursor = table_node.walk();
+        let mut version = None;
+
+        for pair in table_node.children(&mut cursor) {
+            if pair.kind() != "pair" {
+                continue;
+            }
+
+            let key = pair.child(0).map(|key| &content[key.byte_range()]);
+            if key.is_some_and(|key| Self::POETRY_SKIP_KEYS.contains(&key)) {
+                return None;
+            }
+            if key == Some("version") {
+                let mut pair_cursor = pair.walk();
+                version = pair
+                    .children(&mut pair_cursor)
+                    .find(|child| child.kind() == "string");
+            }
+        }
+
+        version
+    }
+
     /// Extract dependencies from a specific key's array value
     fn extract_key_array(
         &self,
@@ -179,17 +296,7 @@ impl PyprojectTomlParser {
 
         for child in array_node.children(&mut cursor) {
             if child.kind() == "string" {
-                let text = &content[child.byte_range()];
-                // Remove only the outer quotes from TOML string
-                // TOML strings are either "..." or '...' (literal string)
-                let trimmed = text.trim();
-                let dep_str = if (trimmed.starts_with('"') && trimmed.ends_with('"'))
-                    || (trimmed.starts_with('\'') && trimmed.ends_with('\''))
-                {
-                    &trimmed[1..trimmed.len() - 1]
-                } else {
-                    trimmed
-                };
+                let dep_str = strip_quotes(&content[child.byte_range()]);
 
                 if let Some(info) = self.parse_dependency_string(dep_str, child, content) {
                     results.push(info);
@@ -299,6 +406,19 @@ impl PyprojectTomlParser {
     }
 }
 
+/// Remove the outer quotes of a basic (`"..."`) or literal (`'...'`) TOML string
+fn strip_quotes(text: &str) -> &str {
+    let trimmed = text.trim();
+    if trimmed.len() >= 2
+        && ((trimmed.starts_with('"') && trimmed.ends_with('"'))
+            || (trimmed.starts_with('\'') && trimmed.ends_with('\'')))
+    {
+        &trimmed[1..trimmed.len() - 1]
+    } else {
+        trimmed
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -506,4 +626,98 @@ docs = [
         assert_eq!(result[0].name, "pytest");
         assert_eq!(result[1].name, "sphinx");
     }
+
+    #[test]
+    fn parse_points_offsets_at_specifier_after_extras() {
+        let parser = PyprojectTomlParser::new();
+        let content = "[project]\ndependencies = [\"requests[security]>=2.31\"]\n";
+
+        let result = parser.parse(content).unwrap();
+
+        assert_eq!(
+            result,
+            vec![PackageInfo {
+                name: "requests".to_string(),
+                version: ">=2.31".to_string(),
+                commit_hash: None,
+                registry_type: RegistryType::PyPI,
+                start_offset: 45,
+                end_offset: 51,
+                line: 1,
+                column: 35,
+                extra_info: None,
+            }]
+        );
+        assert_eq!(&content[45..51], ">=2.31");
+    }
+
+    #[test]
+    fn parse_extracts_poetry_dependencies() {
+        let parser = PyprojectTomlParser::new();
+        let content = r#"[tool.poetry.dependencies]
+python = "^3.10"
+requests = "^2.31"
+httpx = { version = "~0.27", extras = ["http2"] }
+"zope.interface" = '6.1'
+"#;
+
+        let result = parser.parse(content).unwrap();
+
+        let summary: Vec<_> = result
+            .iter()
+            .map(|p| (p.name.as_str(), p.version.as_str(), p.line))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("requests", "^2.31", 2),
+                ("httpx", "~0.27", 3),
+                ("zope.interface", "6.1", 4),
+            ]
+        );
+        for package in &result {
+            assert_eq!(package.registry_type, RegistryType::PyPI);
+            assert_eq!(
+                &content[package.start_offset..package.end_offset],
+                package.version
+            );
+        }
+    }
+
+    #[test]
+    fn parse_extracts_poetry_group_dependencies() {
+        let parser = PyprojectTomlParser::new();
+        let content = r#"[tool.poetry.dev-dependencies]
+black = "^23.0"
+
+[tool.poetry.group.test.dependencies]
+pytest = "^8.0"
+
+[tool.poetry.group.test]
+optional = true
+"#;
+
+        let result = parser.parse(content).unwrap();
+
+        let names: Vec<_> = result.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, vec!["black", "pytest"]);
+    }
+
+    #[test]
+    fn parse_skips_poetry_non_pypi_sources() {
+        let parser = PyprojectTomlParser::new();
+        let content = r#"[tool.poetry.dependencies]
+mylib = { git = "https://github.com/user/mylib.git", tag = "v1.0" }
+local = { path = "../local", develop = true }
+wheel = { url = "https://example.com/wheel-1.0-py3-none-any.whl" }
+pinned = { version = "1.0", git = "https://github.com/user/pinned.git" }
+flask = "^3.0"
+"#;
+
+        let result = parser.parse(content).unwrap();
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].name, "flask");
+        assert_eq!(result[0].version, "^3.0");
+    }
 }
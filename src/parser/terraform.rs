@@ -0,0 +1,389 @@
+//! Terraform provider requirements parser
+//!
+//! Providers are pinned in the `required_providers` block of a `terraform`
+//! block, as an object spread over one or several lines:
+//!
+//! ```hcl
+//! required_providers {
+//!   aws = {
+//!     source  = "hashicorp/aws"
+//!     version = "~> 5.0"
+//!   }
+//!   random = { source = "hashicorp/random", version = ">= 3.1" }
+//! }
+//! ```
+//!
+//! or, in the legacy form, as a bare version string (`aws = "~> 5.0"`) whose
+//! source defaults to `hashicorp/<name>`. The package name is the
+//! `namespace/type` of the source. Providers without a version and providers
+//! from registries other than registry.terraform.io are skipped.
+
+use crate::parser::traits::{ParseError, Parser};
+use crate::parser::types::{PackageInfo, RegistryType};
+
+/// Hostname of the public Terraform Registry, which sources may spell out
+const PUBLIC_REGISTRY_HOST: &str = "registry.terraform.io";
+
+/// Namespace of providers whose source is not given
+const DEFAULT_NAMESPACE: &str = "hashicorp";
+
+/// Parser for Terraform `.tf` files
+pub struct TerraformParser;
+
+impl TerraformParser {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for TerraformParser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A lexical token of an HCL document
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Token<'a> {
+    /// Identifier or other bare word (`aws`, `true`, `var.region`)
+    Word(&'a str),
+    /// Contents of a double-quoted string, with the offset of its first character
+    Str(&'a str, usize),
+    /// Any other character (`{`, `}`, `=`, `,`, `[`, ...)
+    Punct(char),
+}
+
+impl Parser for TerraformParser {
+    fn parse(&self, content: &str) -> Result<Vec<PackageInfo>, ParseError> {
+        let tokens = tokenize(content);
+        let mut results = Vec::new();
+
+        let mut i = 0;
+        while i < tokens.len() {
+            if tokens[i] == Token::Word("required_providers")
+                && tokens.get(i + 1) == Some(&Token::Punct('{'))
+            {
+                i = Self::extract_providers(&tokens, i + 2, content, &mut results);
+            } else {
+                i += 1;
+            }
+        }
+
+        Ok(results)
+    }
+}
+
+impl TerraformParser {
+    /// Extract the providers of a `required_providers` block starting at
+    /// `start` (just past its `{`), returning the index past its `}`
+    fn extract_providers(
+        tokens: &[Token],
+        start: usize,
+        content: &str,
+        results: &mut Vec<PackageInfo>,
+    ) -> usize {
+        let mut i = start;
+        loop {
+            match tokens.get(i) {
+                None => return i,
+                Some(Token::Punct('}')) => return i + 1,
+                Some(Token::Word(name)) if tokens.get(i + 1) == Some(&Token::Punct('=')) => {
+                    match tokens.get(i + 2) {
+                        // Legacy form: `aws = "~> 5.0"`
+                        Some(Token::Str(version, offset)) => {
+                            let source = format!("{}/{}", DEFAULT_NAMESPACE, name);
+                            results.extend(Self::package(&source, version, *offset, content));
+                            i += 3;
+                        }
+                        Some(Token::Punct('{')) => {
+                            let (attributes, next) = object_attributes(tokens, i + 3);
+                            let source = attributes
+                                .source
+                                .map(str::to_string)
+                                .unwrap_or_else(|| format!("{}/{}", DEFAULT_NAMESPACE, name));
+                            if let Some((version, offset)) = attributes.version {
+                                results.extend(Self::package(&source, version, offset, content));
+                            }
+                            i = next;
+                        }
+                        _ => i += 2,
+                    }
+                }
+                Some(_) => i += 1,
+            }
+        }
+    }
+
+    /// Build the package of a provider, if its source is on the public registry
+    fn package(source: &str, version: &str, offset: usize, content: &str) -> Option<PackageInfo> {
+        let name = registry_package(source)?;
+        if version.trim().is_empty() || version.contains("${") {
+            return None;
+        }
+
+        let (line, column) = position(content, offset);
+        Some(PackageInfo {
+            name,
+            version: version.to_string(),
+            commit_hash: None,
+            registry_type: RegistryType::TerraformRegistry,
+            start_offset: offset,
+            end_offset: offset + version.len(),
+            line,
+            column,
+            extra_info: None,
+        })
+    }
+}
+
+/// `source` and `version` of a provider object
+#[derive(Debug, Default)]
+struct ProviderAttributes<'a> {
+    source: Option<&'a str>,
+    version: Option<(&'a str, usize)>,
+}
+
+/// Read the string attributes of an object starting at `start` (just past
+/// its `{`), returning them with the index past its `}`
+///
+/// Nested objects and lists (`configuration_aliases = [...]`) are skipped.
+fn object_attributes<'a>(tokens: &[Token<'a>], start: usize) -> (ProviderAttributes<'a>, usize) {
+    let mut attributes = ProviderAttributes::default();
+    let mut depth = 1;
+    let mut i = start;
+
+    while let Some(token) = tokens.get(i) {
+        match token {
+            Token::Punct('{' | '[') => depth += 1,
+            Token::Punct('}' | ']') => {
+                depth -= 1;
+                if depth == 0 {
+                    return (attributes, i + 1);
+                }
+            }
+            Token::Word(key) if depth == 1 && tokens.get(i + 1) == Some(&Token::Punct('=')) => {
+                if let Some(Token::Str(value, offset)) = tokens.get(i + 2) {
+                    match *key {
+                        "source" => attributes.source = Some(value),
+                        "version" => attributes.version = Some((value, *offset)),
+                        _ => {}
+                    }
+                    i += 3;
+                    continue;
+                }
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+
+    (attributes, i)
+}
+
+/// `namespace/type` of a provider source on the public registry
+///
+/// Sources may name the registry host (`registry.terraform.io/hashicorp/aws`)
+/// or omit the namespace (`aws`, meaning `hashicorp/aws`). Names are case
+/// insensitive and normalized to lowercase.
+fn registry_package(source: &str) -> Option<String> {
+    let source = source.trim().to_lowercase();
+    let parts: Vec<&str> = source.split('/').collect();
+    match parts.as_slice() {
+        [provider_type] if !provider_type.is_empty() => {
+            Some(format!("{}/{}", DEFAULT_NAMESPACE, provider_type))
+        }
+        [namespace, provider_type] => Some(format!("{}/{}", namespace, provider_type)),
+        [host, namespace, provider_type] if *host == PUBLIC_REGISTRY_HOST => {
+            Some(format!("{}/{}", namespace, provider_type))
+        }
+        _ => None,
+    }
+}
+
+/// Split HCL into words, strings and punctuation, dropping whitespace and
+/// `#`, `//` and `/* */` comments
+fn tokenize(content: &str) -> Vec<Token<'_>> {
+    let bytes = content.as_bytes();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while i < bytes.len() {
+        match bytes[i] {
+            b'#' => i = line_end(content, i),
+            b'/' if bytes.get(i + 1) == Some(&b'/') => i = line_end(content, i),
+            b'/' if bytes.get(i + 1) == Some(&b'*') => {
+                i = content[i + 2..]
+                    .find("*/")
+                    .map_or(bytes.len(), |end| i + 2 + end + 2);
+            }
+            b'"' => {
+                // Strings end at the closing quote or, when unterminated, the line end
+                let start = i + 1;
+                let mut end = start;
+                while end < bytes.len() && bytes[end] != b'"' && bytes[end] != b'\n' {
+                    end += if bytes[end] == b'\\' { 2 } else { 1 };
+                }
+                let end = end.min(bytes.len());
+                tokens.push(Token::Str(&content[start..end], start));
+                i = end + 1;
+            }
+            c if c.is_ascii_whitespace() => i += 1,
+            c if is_word_byte(c) => {
+                let start = i;
+                while i < bytes.len() && is_word_byte(bytes[i]) {
+                    i += 1;
+                }
+                tokens.push(Token::Word(&content[start..i]));
+            }
+            _ => {
+                let c = content[i..].chars().next().unwrap_or_default();
+                tokens.push(Token::Punct(c));
+                i += c.len_utf8().max(1);
+            }
+        }
+    }
+
+    tokens
+}
+
+fn is_word_byte(c: u8) -> bool {
+    c.is_ascii_alphanumeric() || matches!(c, b'_' | b'-' | b'.')
+}
+
+/// Offset of the newline ending the line that contains `offset`
+fn line_end(content: &str, offset: usize) -> usize {
+    content[offset..]
+        .find('\n')
+        .map_or(content.len(), |end| offset + end)
+}
+
+/// Line and column (in bytes) of an offset
+fn position(content: &str, offset: usize) -> (usize, usize) {
+    let before = &content[..offset];
+    let line = before.matches('\n').count();
+    let column = offset - before.rfind('\n').map_or(0, |newline| newline + 1);
+    (line, column)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_extracts_nested_block_providers() {
+        let parser = TerraformParser::new();
+        let content = r#"terraform {
+  required_version = ">= 1.5"
+
+  required_providers {
+    aws = {
+      source  = "hashicorp/aws"
+      version = "~> 5.0"
+    }
+  }
+}
+"#;
+
+        let result = parser.parse(content).unwrap();
+
+        assert_eq!(
+            result,
+            vec![PackageInfo {
+                name: "hashicorp/aws".to_string(),
+                version: "~> 5.0".to_string(),
+                commit_hash: None,
+                registry_type: RegistryType::TerraformRegistry,
+                start_offset: 127,
+                end_offset: 133,
+                line: 6,
+                column: 17,
+                extra_info: None,
+            }]
+        );
+        assert_eq!(&content[127..133], "~> 5.0");
+    }
+
+    #[test]
+    fn parse_extracts_inline_objects_and_legacy_versions() {
+        let parser = TerraformParser::new();
+        let content = r#"terraform {
+  required_providers {
+    random = { source = "hashicorp/random", version = ">= 3.1, < 4.0" }
+    google = "~> 5.10" # legacy form
+    cloudflare = {
+      # Version comes first here
+      version = "4.20.0"
+      source  = "registry.terraform.io/Cloudflare/cloudflare"
+      configuration_aliases = [cloudflare.eu]
+    }
+  }
+}
+"#;
+
+        let result = parser.parse(content).unwrap();
+
+        let summary: Vec<_> = result
+            .iter()
+            .map(|p| (p.name.as_str(), p.version.as_str(), p.line))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("hashicorp/random", ">= 3.1, < 4.0", 2),
+                ("hashicorp/google", "~> 5.10", 3),
+                ("cloudflare/cloudflare", "4.20.0", 6),
+            ]
+        );
+        for package in &result {
+            assert_eq!(
+                &content[package.start_offset..package.end_offset],
+                package.version
+            );
+        }
+    }
+
+    #[test]
+    fn parse_skips_unversioned_and_private_providers() {
+        let parser = TerraformParser::new();
+        let content = r#"terraform {
+  required_providers {
+    local = { source = "hashicorp/local" }
+    internal = {
+      source  = "tf.example.com/acme/internal"
+      version = "1.0.0"
+    }
+  }
+}
+
+resource "aws_instance" "web" {
+  version = "1.2.3"
+}
+"#;
+
+        let result = parser.parse(content).unwrap();
+
+        assert!(result.is_empty());
+    }
+
+    #[test]
+    fn parse_ignores_commented_out_blocks() {
+        let parser = TerraformParser::new();
+        let content = r#"# required_providers { aws = "~> 4.0" }
+/* required_providers {
+  aws = "~> 3.0"
+} */
+terraform {
+  required_providers {
+    // aws = "~> 2.0"
+    aws = "~> 5.0"
+  }
+}
+"#;
+
+        let result = parser.parse(content).unwrap();
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].version, "~> 5.0");
+        assert_eq!(result[0].line, 7);
+    }
+}
@@ -0,0 +1,185 @@
+//! asdf `.tool-versions` parser
+//!
+//! Each line names a tool followed by one or more versions, the first of
+//! which is used: `nodejs 20.11.0`. Only tools with a release feed are
+//! extracted, and only when pinned to a numeric version; `system`, `latest`,
+//! `ref:` and `path:` versions are skipped, as are tools without a feed
+//! (e.g. `python`, whose releases are not on PyPI).
+//!
+//! Supported tools:
+//! - `nodejs` / `node` - Node.js releases
+//! - `golang` / `go` - Go releases, shared with go.mod `go` directives
+
+use crate::parser::go_mod::GO_RELEASES_PACKAGE;
+use crate::parser::traits::{ParseError, Parser};
+use crate::parser::types::{PackageInfo, RegistryType};
+
+/// Package name of the Node.js release feed
+pub const NODEJS_PACKAGE: &str = "nodejs";
+
+/// Parser for asdf `.tool-versions` files
+pub struct ToolVersionsParser;
+
+impl ToolVersionsParser {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for ToolVersionsParser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Registry and package name holding the releases of a tool
+///
+/// Accepts both asdf plugin names (`nodejs`, `golang`) and mise short names
+/// (`node`, `go`).
+pub(crate) fn tool_release_source(tool: &str) -> Option<(RegistryType, &'static str)> {
+    match tool {
+        "nodejs" | "node" => Some((RegistryType::ToolVersions, NODEJS_PACKAGE)),
+        "golang" | "go" => Some((RegistryType::GoRelease, GO_RELEASES_PACKAGE)),
+        _ => None,
+    }
+}
+
+/// Whether a tool version names a concrete release rather than `system`,
+/// `latest`, `ref:...` or `path:...`
+pub(crate) fn is_release_version(version: &str) -> bool {
+    version.starts_with(|c: char| c.is_ascii_digit())
+}
+
+impl Parser for ToolVersionsParser {
+    fn parse(&self, content: &str) -> Result<Vec<PackageInfo>, ParseError> {
+        let mut results = Vec::new();
+        let mut line_start = 0;
+
+        for (line_num, raw_line) in content.split_inclusive('\n').enumerate() {
+            let current_line_start = line_start;
+            line_start += raw_line.len();
+            let line = raw_line.trim_end_matches(['\n', '\r']);
+
+            // Drop trailing comments; whole-line comments leave nothing
+            let code = line.split('#').next().unwrap_or_default();
+            let mut tokens = code
+                .split_whitespace()
+                .map(|token| (token.as_ptr() as usize - line.as_ptr() as usize, token));
+
+            let (Some((_, tool)), Some((column, version))) = (tokens.next(), tokens.next()) else {
+                continue;
+            };
+            let Some((registry_type, name)) = tool_release_source(tool) else {
+                continue;
+            };
+            if !is_release_version(version) {
+                continue;
+            }
+
+            results.push(PackageInfo {
+                name: name.to_string(),
+                version: version.to_string(),
+                commit_hash: None,
+                registry_type,
+                start_offset: current_line_start + column,
+                end_offset: current_line_start + column + version.len(),
+                line: line_num,
+                column,
+                extra_info: None,
+            });
+        }
+
+        Ok(results)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_extracts_known_tools() {
+        let parser = ToolVersionsParser::new();
+        let content = "nodejs 20.11.0\npython 3.12.1\ngolang 1.22.0 1.21.6\n";
+
+        let result = parser.parse(content).unwrap();
+
+        assert_eq!(
+            result,
+            vec![
+                PackageInfo {
+                    name: "nodejs".to_string(),
+                    version: "20.11.0".to_string(),
+                    commit_hash: None,
+                    registry_type: RegistryType::ToolVersions,
+                    start_offset: 7,
+                    end_offset: 14,
+                    line: 0,
+                    column: 7,
+                    extra_info: None,
+                },
+                PackageInfo {
+                    name: GO_RELEASES_PACKAGE.to_string(),
+                    version: "1.22.0".to_string(),
+                    commit_hash: None,
+                    registry_type: RegistryType::GoRelease,
+                    start_offset: 36,
+                    end_offset: 42,
+                    line: 2,
+                    column: 7,
+                    extra_info: None,
+                },
+            ]
+        );
+        assert_eq!(&content[36..42], "1.22.0");
+    }
+
+    #[test]
+    fn parse_skips_comments_and_unpinned_versions() {
+        let parser = ToolVersionsParser::new();
+        let content = "# nodejs 18.0.0\n\
+            node   22.1.0 # pinned for CI\n\
+            go system\n\
+            nodejs ref:main\n\
+            terraform 1.7.0\n";
+
+        let result = parser.parse(content).unwrap();
+
+        let summary: Vec<_> = result
+            .iter()
+            .map(|p| (p.name.as_str(), p.version.as_str(), p.line, p.column))
+            .collect();
+        assert_eq!(summary, vec![(NODEJS_PACKAGE, "22.1.0", 1, 7)]);
+        assert_eq!(
+            &content[result[0].start_offset..result[0].end_offset],
+            "22.1.0"
+        );
+    }
+
+    #[test]
+    fn parse_returns_empty_for_empty_file() {
+        let parser = ToolVersionsParser::new();
+
+        assert!(parser.parse("").unwrap().is_empty());
+    }
+
+    #[test]
+    fn parse_locates_tools_in_crlf_files() {
+        let parser = ToolVersionsParser::new();
+        let content = "nodejs 20.11.0\r\n\r\ngolang 1.22.0\r\n";
+
+        let result = parser.parse(content).unwrap();
+
+        let offsets: Vec<_> = result
+            .iter()
+            .map(|p| (p.start_offset, p.end_offset, p.line, p.column))
+            .collect();
+        assert_eq!(offsets, vec![(7, 14, 0, 7), (25, 31, 2, 7)]);
+        for package in &result {
+            assert_eq!(
+                &content[package.start_offset..package.end_offset],
+                package.version
+            );
+        }
+    }
+}
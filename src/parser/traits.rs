@@ -10,6 +10,24 @@ use crate::parser::types::PackageInfo;
 pub trait Parser: Send + Sync {
     /// Parse the content and extract package information
     fn parse(&self, content: &str) -> Result<Vec<PackageInfo>, ParseError>;
+
+    /// Whether the content has syntax errors, so `parse` may have skipped packages
+    ///
+    /// Line-based parsers skip what they don't understand and report none.
+    fn has_syntax_errors(&self, _content: &str) -> bool {
+        false
+    }
+}
+
+/// Whether tree-sitter finds syntax errors in `content`
+pub(crate) fn has_tree_sitter_errors(language: tree_sitter::Language, content: &str) -> bool {
+    let mut parser = tree_sitter::Parser::new();
+    if parser.set_language(&language).is_err() {
+        return false;
+    }
+    parser
+        .parse(content, None)
+        .is_some_and(|tree| tree.root_node().has_error())
 }
 
 /// Error type for parsing operations
@@ -1,5 +1,7 @@
 //! Common types for parsers
 
+use crate::parser::docker::is_dockerfile_content;
+
 /// Type of package registry
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum RegistryType {
@@ -11,14 +13,30 @@ pub enum RegistryType {
     CratesIo,
     /// Go proxy (go.mod)
     GoProxy,
+    /// Go releases (go.mod `go`/`toolchain` directives, `.tool-versions`, mise.toml)
+    GoRelease,
     /// pnpm catalog (pnpm-workspace.yaml)
     PnpmCatalog,
     /// JSR (deno.json, deno.jsonc)
     Jsr,
     /// PyPI (pyproject.toml)
     PyPI,
-    /// Docker (compose.yaml)
+    /// Docker (compose.yaml, Dockerfile)
     Docker,
+    /// RubyGems (Gemfile)
+    RubyGems,
+    /// Packagist (composer.json)
+    Composer,
+    /// Homebrew formulae (Brewfile)
+    Homebrew,
+    /// Tool releases (.tool-versions, mise.toml)
+    ToolVersions,
+    /// GitHub flake inputs (flake.lock)
+    NixFlake,
+    /// Terraform Registry providers (*.tf)
+    TerraformRegistry,
+    /// Maven repositories (gradle/libs.versions.toml)
+    Maven,
 }
 
 impl RegistryType {
@@ -29,10 +47,18 @@ impl RegistryType {
             RegistryType::Npm => "npm",
             RegistryType::CratesIo => "crates_io",
             RegistryType::GoProxy => "go_proxy",
+            RegistryType::GoRelease => "go_release",
             RegistryType::PnpmCatalog => "pnpm_catalog",
             RegistryType::Jsr => "jsr",
             RegistryType::PyPI => "pypi",
             RegistryType::Docker => "docker",
+            RegistryType::RubyGems => "rubygems",
+            RegistryType::Composer => "composer",
+            RegistryType::Homebrew => "homebrew",
+            RegistryType::ToolVersions => "tool_versions",
+            RegistryType::NixFlake => "nix_flake",
+            RegistryType::TerraformRegistry => "terraform",
+            RegistryType::Maven => "maven",
         }
     }
 }
@@ -46,10 +72,18 @@ impl std::str::FromStr for RegistryType {
             "npm" => Ok(RegistryType::Npm),
             "crates_io" => Ok(RegistryType::CratesIo),
             "go_proxy" => Ok(RegistryType::GoProxy),
+            "go_release" => Ok(RegistryType::GoRelease),
             "pnpm_catalog" => Ok(RegistryType::PnpmCatalog),
             "jsr" => Ok(RegistryType::Jsr),
             "pypi" => Ok(RegistryType::PyPI),
             "docker" => Ok(RegistryType::Docker),
+            "rubygems" => Ok(RegistryType::RubyGems),
+            "composer" => Ok(RegistryType::Composer),
+            "homebrew" => Ok(RegistryType::Homebrew),
+            "tool_versions" => Ok(RegistryType::ToolVersions),
+            "nix_flake" => Ok(RegistryType::NixFlake),
+            "terraform" => Ok(RegistryType::TerraformRegistry),
+            "maven" => Ok(RegistryType::Maven),
             _ => Err(()),
         }
     }
@@ -71,13 +105,162 @@ pub fn detect_parser_type(uri: &str) -> Option<RegistryType> {
         Some(RegistryType::Jsr)
     } else if uri.ends_with("/pyproject.toml") {
         Some(RegistryType::PyPI)
-    } else if is_compose_file(uri) {
+    } else if is_compose_file(uri) || is_dockerfile(uri) {
         Some(RegistryType::Docker)
+    } else if uri.ends_with("/Gemfile") {
+        Some(RegistryType::RubyGems)
+    } else if uri.ends_with("/composer.json") {
+        Some(RegistryType::Composer)
+    } else if uri.ends_with("/Brewfile") {
+        Some(RegistryType::Homebrew)
+    } else if uri.ends_with("/.tool-versions") || is_mise_toml_file(uri) {
+        Some(RegistryType::ToolVersions)
+    } else if uri.ends_with("/flake.lock") {
+        Some(RegistryType::NixFlake)
+    } else if uri.ends_with(".tf") {
+        Some(RegistryType::TerraformRegistry)
+    } else if uri.ends_with(".versions.toml") {
+        Some(RegistryType::Maven)
     } else {
         None
     }
 }
 
+/// Detect the parser type for an opened document
+///
+/// The URI is authoritative when it names a known manifest. Otherwise the
+/// LSP `languageId` is used as a hint, but only together with content that
+/// looks like the corresponding manifest, since e.g. `toml` alone does not
+/// imply Cargo.toml. Failing both, the content is sniffed on its own, so a
+/// `package.json` saved as `deps.json` is still recognized.
+pub fn detect_document_type(uri: &str, language_id: &str, content: &str) -> Option<RegistryType> {
+    detect_parser_type(uri)
+        .or_else(|| detect_from_language_id(language_id, content))
+        .or_else(|| detect_from_content(uri, content))
+}
+
+/// Number of leading lines searched for go.mod directives
+const GO_MOD_SNIFF_LINES: usize = 20;
+
+/// Detect a manifest with an unusual name from its content
+///
+/// JSON and TOML files are judged by the keys and tables the language hint
+/// would look for; their extension stands in for the `languageId`. Files of
+/// any name whose first lines hold both a `module` and a `go` directive are
+/// go.mod files.
+fn detect_from_content(uri: &str, content: &str) -> Option<RegistryType> {
+    let file_name = uri.rsplit(['/', '\\']).next().unwrap_or(uri);
+    let language_id = match file_name.rsplit_once('.').map(|(_, extension)| extension) {
+        Some("json") => Some("json"),
+        Some("jsonc") => Some("jsonc"),
+        Some("toml") => Some("toml"),
+        _ => None,
+    };
+    if let Some(registry_type) =
+        language_id.and_then(|language_id| detect_from_language_id(language_id, content))
+    {
+        return Some(registry_type);
+    }
+
+    let head: Vec<&str> = content
+        .lines()
+        .take(GO_MOD_SNIFF_LINES)
+        .map(str::trim_start)
+        .collect();
+    let has_directive = |directive: &str| head.iter().any(|line| line.starts_with(directive));
+    (has_directive("module ") && has_directive("go ")).then_some(RegistryType::GoProxy)
+}
+
+fn detect_from_language_id(language_id: &str, content: &str) -> Option<RegistryType> {
+    match language_id {
+        "json" | "jsonc" => {
+            if has_json_key(content, "imports") {
+                Some(RegistryType::Jsr)
+            } else if NPM_DEPENDENCY_KEYS
+                .iter()
+                .any(|key| has_json_key(content, key))
+            {
+                Some(RegistryType::Npm)
+            } else if has_json_key(content, "require") || has_json_key(content, "require-dev") {
+                Some(RegistryType::Composer)
+            } else {
+                None
+            }
+        }
+        "toml" => {
+            if has_toml_table(content, "project") {
+                Some(RegistryType::PyPI)
+            } else if has_toml_table(content, "package")
+                || has_toml_table(content, "workspace")
+                || has_toml_table(content, "dependencies")
+            {
+                Some(RegistryType::CratesIo)
+            } else {
+                None
+            }
+        }
+        "yaml" => {
+            if has_yaml_key(content, "catalog") || has_yaml_key(content, "catalogs") {
+                Some(RegistryType::PnpmCatalog)
+            } else if has_yaml_key(content, "services") && content.contains("image:") {
+                Some(RegistryType::Docker)
+            } else if (has_yaml_key(content, "jobs") || has_yaml_key(content, "runs"))
+                && content.contains("uses:")
+            {
+                Some(RegistryType::GitHubActions)
+            } else {
+                None
+            }
+        }
+        "dockerfile" => is_dockerfile_content(content).then_some(RegistryType::Docker),
+        "ruby" => content
+            .lines()
+            .any(|line| {
+                let line = line.trim_start();
+                line.starts_with("gem \"") || line.starts_with("gem '")
+            })
+            .then_some(RegistryType::RubyGems),
+        "go.mod" | "go-mod" | "gomod" => content
+            .lines()
+            .any(|line| line.trim_start().starts_with("module "))
+            .then_some(RegistryType::GoProxy),
+        _ => None,
+    }
+}
+
+const NPM_DEPENDENCY_KEYS: &[&str] = &[
+    "dependencies",
+    "devDependencies",
+    "peerDependencies",
+    "optionalDependencies",
+];
+
+fn has_json_key(content: &str, key: &str) -> bool {
+    let quoted = format!("\"{}\"", key);
+    content
+        .match_indices(&quoted)
+        .any(|(idx, _)| content[idx + quoted.len()..].trim_start().starts_with(':'))
+}
+
+fn has_toml_table(content: &str, table: &str) -> bool {
+    content.lines().any(|line| {
+        let line = line.trim();
+        line.strip_prefix('[')
+            .and_then(|rest| rest.strip_suffix(']'))
+            .is_some_and(|header| {
+                let header = header.trim();
+                header == table || header.starts_with(&format!("{}.", table))
+            })
+    })
+}
+
+fn has_yaml_key(content: &str, key: &str) -> bool {
+    content.lines().any(|line| {
+        line.strip_prefix(key)
+            .is_some_and(|rest| rest.trim_start().starts_with(':'))
+    })
+}
+
 fn is_compose_file(uri: &str) -> bool {
     uri.ends_with("/compose.yaml")
         || uri.ends_with("/compose.yml")
@@ -85,13 +268,30 @@ fn is_compose_file(uri: &str) -> bool {
         || uri.ends_with("/docker-compose.yml")
 }
 
+fn is_dockerfile(uri: &str) -> bool {
+    let file_name = uri.rsplit(['/', '\\']).next().unwrap_or(uri);
+    file_name == "Dockerfile"
+        || file_name.starts_with("Dockerfile.")
+        || file_name.ends_with(".Dockerfile")
+        || file_name.ends_with(".dockerfile")
+}
+
+/// mise configs: `mise.toml`, `.mise.toml` or an environment's `mise.<env>.toml`
+pub fn is_mise_toml_file(uri: &str) -> bool {
+    let file_name = uri.rsplit(['/', '\\']).next().unwrap_or(uri);
+    file_name == ".mise.toml" || (file_name.starts_with("mise.") && file_name.ends_with(".toml"))
+}
+
+/// Workflows and actions under `.github/`, or the `action.yml` metadata of
+/// an action published from its repository root
 fn is_github_actions_workflow(uri: &str) -> bool {
     let is_github_dir = uri.contains(".github/workflows/")
         || uri.contains(".github\\workflows\\")
         || uri.contains(".github/actions/")
         || uri.contains(".github\\actions\\");
     let is_yaml = uri.ends_with(".yml") || uri.ends_with(".yaml");
-    is_github_dir && is_yaml
+    let file_name = uri.rsplit(['/', '\\']).next().unwrap_or(uri);
+    (is_github_dir && is_yaml) || matches!(file_name, "action.yml" | "action.yaml")
 }
 
 /// Registry-specific additional information
@@ -106,6 +306,18 @@ pub enum ExtraInfo {
         /// End offset of the comment
         comment_end_offset: usize,
     },
+    /// go.mod specific: directives and comments attached to a required module
+    GoMod {
+        /// Versions listed in `exclude` directives for this module
+        excluded_versions: Vec<String>,
+        /// Whether the require carries an `// indirect` comment
+        indirect: bool,
+    },
+    /// flake.lock specific: when the locked rev was committed
+    FlakeLock {
+        /// `lastModified` of the locked input (Unix timestamp)
+        last_modified: i64,
+    },
 }
 
 /// Information about a package dependency found in a file
@@ -115,7 +327,8 @@ pub struct PackageInfo {
     pub name: String,
     /// Current version specified in the file (may be extracted from comment if hash is used)
     pub version: String,
-    /// Commit hash if pinned to specific commit (GitHub Actions only)
+    /// Commit hash if pinned to specific commit (GitHub Actions), or image
+    /// digest if pinned by digest (Docker)
     /// When present, version may be extracted from trailing comment
     pub commit_hash: Option<String>,
     /// Type of registry this package belongs to
@@ -132,11 +345,89 @@ pub struct PackageInfo {
     pub extra_info: Option<ExtraInfo>,
 }
 
+impl PackageInfo {
+    /// Position-independent identity: (name, version, commit_hash, registry_type)
+    pub fn logical_key(&self) -> (&str, &str, Option<&str>, RegistryType) {
+        (
+            &self.name,
+            &self.version,
+            self.commit_hash.as_deref(),
+            self.registry_type,
+        )
+    }
+
+    /// Whether this is a container image pinned by digest
+    ///
+    /// The digest decides the image, so its tag is not compared against the registry.
+    pub fn is_digest_pinned(&self) -> bool {
+        self.registry_type == RegistryType::Docker && self.commit_hash.is_some()
+    }
+
+    /// Whether this is an action pinned by commit without a `# vX.Y.Z` comment
+    ///
+    /// Such pins carry no version to compare, so they are skipped rather than
+    /// reported as invalid; a version comment is checked in place of the hash.
+    pub fn is_unversioned_commit_pin(&self) -> bool {
+        self.registry_type == RegistryType::GitHubActions
+            && self.commit_hash.is_some()
+            && self.extra_info.is_none()
+    }
+
+    /// Whether this is a go.mod require marked `// indirect`
+    pub fn is_indirect(&self) -> bool {
+        matches!(
+            self.extra_info,
+            Some(ExtraInfo::GoMod { indirect: true, .. })
+        )
+    }
+
+    /// Compare two packages ignoring source positions and extra info
+    ///
+    /// Useful in parser tests where offsets, line and column are not what is being checked.
+    pub fn matches_logical(&self, other: &PackageInfo) -> bool {
+        self.logical_key() == other.logical_key()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use rstest::rstest;
 
+    #[test]
+    fn matches_logical_ignores_positions_and_extra_info() {
+        let package = PackageInfo {
+            name: "lodash".to_string(),
+            version: "4.17.21".to_string(),
+            commit_hash: None,
+            registry_type: RegistryType::Npm,
+            start_offset: 10,
+            end_offset: 17,
+            line: 1,
+            column: 5,
+            extra_info: None,
+        };
+        let moved = PackageInfo {
+            start_offset: 100,
+            end_offset: 107,
+            line: 8,
+            column: 2,
+            ..package.clone()
+        };
+        let other_version = PackageInfo {
+            version: "4.17.20".to_string(),
+            ..package.clone()
+        };
+
+        assert!(package.matches_logical(&moved));
+        assert_ne!(package, moved);
+        assert!(!package.matches_logical(&other_version));
+        assert_eq!(
+            package.logical_key(),
+            ("lodash", "4.17.21", None, RegistryType::Npm)
+        );
+    }
+
     #[test]
     fn extra_info_github_actions_holds_comment_data() {
         let extra = ExtraInfo::GitHubActions {
@@ -145,17 +436,17 @@ mod tests {
             comment_end_offset: 108,
         };
 
-        match extra {
-            ExtraInfo::GitHubActions {
-                comment_text,
-                comment_start_offset,
-                comment_end_offset,
-            } => {
-                assert_eq!(comment_text, "v4.1.6");
-                assert_eq!(comment_start_offset, 100);
-                assert_eq!(comment_end_offset, 108);
-            }
-        }
+        let ExtraInfo::GitHubActions {
+            comment_text,
+            comment_start_offset,
+            comment_end_offset,
+        } = extra
+        else {
+            panic!("expected GitHub Actions extra info");
+        };
+        assert_eq!(comment_text, "v4.1.6");
+        assert_eq!(comment_start_offset, 100);
+        assert_eq!(comment_end_offset, 108);
     }
 
     #[test]
@@ -199,6 +490,13 @@ mod tests {
         ".github\\actions\\my-action\\action.yml",
         Some(RegistryType::GitHubActions)
     )]
+    #[case("/home/user/setup-tool/action.yml", Some(RegistryType::GitHubActions))]
+    #[case(
+        "file:///home/user/setup-tool/action.yaml",
+        Some(RegistryType::GitHubActions)
+    )]
+    #[case("C:\\src\\setup-tool\\action.yml", Some(RegistryType::GitHubActions))]
+    #[case("/home/user/project/my-action.yml", None)]
     #[case("/path/to/package.json", Some(RegistryType::Npm))]
     #[case("/path/to/Cargo.toml", Some(RegistryType::CratesIo))]
     #[case("/path/to/go.mod", Some(RegistryType::GoProxy))]
@@ -222,6 +520,33 @@ mod tests {
     #[case("/path/to/docker-compose.yaml", Some(RegistryType::Docker))]
     #[case("/path/to/docker-compose.yml", Some(RegistryType::Docker))]
     #[case("file:///home/user/compose.yaml", Some(RegistryType::Docker))]
+    #[case("/path/to/Dockerfile", Some(RegistryType::Docker))]
+    #[case("/path/to/Dockerfile.dev", Some(RegistryType::Docker))]
+    #[case("/path/to/app.Dockerfile", Some(RegistryType::Docker))]
+    #[case("C:\\project\\Dockerfile", Some(RegistryType::Docker))]
+    #[case("/path/to/Dockerfile.d/notes.txt", None)]
+    #[case("/path/to/Gemfile", Some(RegistryType::RubyGems))]
+    #[case("file:///home/user/Gemfile", Some(RegistryType::RubyGems))]
+    #[case("/path/to/Gemfile.lock", None)]
+    #[case("/path/to/composer.json", Some(RegistryType::Composer))]
+    #[case("/path/to/composer.lock", None)]
+    #[case("/path/to/Brewfile", Some(RegistryType::Homebrew))]
+    #[case("/path/to/Brewfile.lock.json", None)]
+    #[case("/path/to/.tool-versions", Some(RegistryType::ToolVersions))]
+    #[case("/path/to/mise.toml", Some(RegistryType::ToolVersions))]
+    #[case("/path/to/.mise.toml", Some(RegistryType::ToolVersions))]
+    #[case("/path/to/.config/mise.toml", Some(RegistryType::ToolVersions))]
+    #[case("/path/to/mise.local.toml", Some(RegistryType::ToolVersions))]
+    #[case("/path/to/mise.production.toml", Some(RegistryType::ToolVersions))]
+    #[case("/path/to/not-mise.toml", None)]
+    #[case("/path/to/flake.lock", Some(RegistryType::NixFlake))]
+    #[case("/path/to/flake.nix", None)]
+    #[case("/path/to/versions.tf", Some(RegistryType::TerraformRegistry))]
+    #[case("/path/to/terraform.tfvars", None)]
+    #[case("/path/to/terraform.tfstate", None)]
+    #[case("/path/to/gradle/libs.versions.toml", Some(RegistryType::Maven))]
+    #[case("/path/to/gradle/test-libs.versions.toml", Some(RegistryType::Maven))]
+    #[case("/path/to/versions.toml", None)]
     #[case("workflow.yml", None)]
     #[case("random.txt", None)]
     fn detect_parser_type_returns_expected(
@@ -230,4 +555,128 @@ mod tests {
     ) {
         assert_eq!(detect_parser_type(uri), expected);
     }
+
+    #[rstest]
+    #[case(
+        "file:///tmp/untitled-1",
+        "json",
+        r#"{"name": "app", "dependencies": {"lodash": "4.17.21"}}"#,
+        Some(RegistryType::Npm)
+    )]
+    #[case(
+        "file:///tmp/untitled-2",
+        "jsonc",
+        r#"{ "imports": { "@std/path": "jsr:@std/path@1.0.0" } }"#,
+        Some(RegistryType::Jsr)
+    )]
+    #[case(
+        "file:///tmp/untitled-3",
+        "toml",
+        "[project]\ndependencies = [\"requests>=2.0\"]\n",
+        Some(RegistryType::PyPI)
+    )]
+    #[case(
+        "file:///tmp/untitled-4",
+        "toml",
+        "[dependencies]\nserde = \"1.0\"\n",
+        Some(RegistryType::CratesIo)
+    )]
+    #[case(
+        "file:///tmp/untitled-5",
+        "toml",
+        "[tool.ruff]\nline-length = 100\n",
+        None
+    )]
+    #[case(
+        "file:///tmp/untitled-6",
+        "yaml",
+        "catalog:\n  react: ^18.0.0\n",
+        Some(RegistryType::PnpmCatalog)
+    )]
+    #[case(
+        "file:///tmp/untitled-7",
+        "yaml",
+        "services:\n  web:\n    image: nginx:1.25\n",
+        Some(RegistryType::Docker)
+    )]
+    #[case(
+        "file:///tmp/untitled-8",
+        "yaml",
+        "jobs:\n  build:\n    steps:\n      - uses: actions/checkout@v4\n",
+        Some(RegistryType::GitHubActions)
+    )]
+    #[case(
+        "file:///tmp/untitled-9",
+        "json",
+        r#"{"name": "not-a-manifest", "description": "no deps here"}"#,
+        None
+    )]
+    #[case("file:///tmp/untitled-10", "plaintext", "dependencies", None)]
+    #[case(
+        "file:///tmp/untitled-11",
+        "ruby",
+        "source \"https://rubygems.org\"\ngem \"rails\", \"~> 7.0\"\n",
+        Some(RegistryType::RubyGems)
+    )]
+    #[case("file:///tmp/untitled-12", "ruby", "puts 'hello'\n", None)]
+    #[case(
+        "file:///tmp/untitled-13",
+        "dockerfile",
+        "FROM node:18.19.0\nRUN npm ci\n",
+        Some(RegistryType::Docker)
+    )]
+    #[case(
+        "file:///tmp/untitled-14",
+        "json",
+        r#"{"require": {"monolog/monolog": "^3.0"}}"#,
+        Some(RegistryType::Composer)
+    )]
+    #[case(
+        "file:///project/package.json",
+        "yaml",
+        r#"{"dependencies": {}}"#,
+        Some(RegistryType::Npm)
+    )]
+    fn detect_document_type_uses_language_id_as_fallback(
+        #[case] uri: &str,
+        #[case] language_id: &str,
+        #[case] content: &str,
+        #[case] expected: Option<RegistryType>,
+    ) {
+        assert_eq!(detect_document_type(uri, language_id, content), expected);
+    }
+
+    #[rstest]
+    #[case(
+        "file:///project/deps.json",
+        r#"{
+  "name": "app",
+  "dependencies": { "lodash": "^4.17.21" }
+}"#,
+        Some(RegistryType::Npm)
+    )]
+    #[case(
+        "file:///project/crates.toml",
+        "[package]\nname = \"app\"\n\n[dependencies]\nserde = \"1.0\"\n",
+        Some(RegistryType::CratesIo)
+    )]
+    #[case(
+        "file:///project/tools.mod",
+        "// Tool dependencies\nmodule example.com/tools\n\ngo 1.22\n\nrequire golang.org/x/tools v0.20.0\n",
+        Some(RegistryType::GoProxy)
+    )]
+    #[case("file:///project/settings.json", r#"{"editor.tabSize": 2}"#, None)]
+    #[case(
+        "file:///project/notes.md",
+        "Add \"dependencies\": {} and a [dependencies] table.\n",
+        None
+    )]
+    #[case("file:///project/README", "module docs only, no go directive\n", None)]
+    fn detect_document_type_sniffs_content_of_generic_files(
+        #[case] uri: &str,
+        #[case] content: &str,
+        #[case] expected: Option<RegistryType>,
+    ) {
+        assert_eq!(detect_document_type(uri, "plaintext", content), expected);
+    }
 }
@@ -0,0 +1,234 @@
+//! Renovate configuration awareness
+//!
+//! Reads the workspace's Renovate config so diagnostics line up with what
+//! Renovate will actually update. Only a small subset is understood:
+//! `ignoreDeps`, `ignorePaths`, and `packageRules` with `enabled: false`.
+
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+use tracing::{info, warn};
+
+/// Renovate config file locations, relative to the workspace root, in lookup order
+pub const RENOVATE_CONFIG_FILES: &[&str] = &[
+    "renovate.json",
+    ".github/renovate.json",
+    ".gitlab/renovate.json",
+    ".renovaterc",
+    ".renovaterc.json",
+];
+
+/// Subset of renovate.json that version-lsp understands
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+struct RenovateConfig {
+    ignore_deps: Vec<String>,
+    ignore_paths: Vec<String>,
+    package_rules: Vec<PackageRule>,
+}
+
+/// A single `packageRules` entry
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+struct PackageRule {
+    match_package_names: Vec<String>,
+    match_package_prefixes: Vec<String>,
+    enabled: Option<bool>,
+}
+
+/// Update policy derived from a Renovate config
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RenovatePolicy {
+    /// Directory the config was loaded from; `ignorePaths` are relative to it
+    root: PathBuf,
+    /// Package names (or glob patterns) Renovate will not update
+    ignored_packages: HashSet<String>,
+    /// Package name prefixes disabled via `matchPackagePrefixes`
+    ignored_prefixes: Vec<String>,
+    /// Path patterns of manifests Renovate skips
+    ignore_paths: Vec<String>,
+}
+
+impl RenovatePolicy {
+    /// Derive a policy from renovate.json content
+    pub fn from_json(content: &str) -> Result<Self, serde_json::Error> {
+        let config: RenovateConfig = serde_json::from_str(content)?;
+
+        let mut ignored_packages: HashSet<String> = config.ignore_deps.into_iter().collect();
+        let mut ignored_prefixes = Vec::new();
+        for rule in config.package_rules {
+            if rule.enabled == Some(false) {
+                ignored_packages.extend(rule.match_package_names);
+                ignored_prefixes.extend(rule.match_package_prefixes);
+            }
+        }
+
+        Ok(Self {
+            root: PathBuf::new(),
+            ignored_packages,
+            ignored_prefixes,
+            ignore_paths: config.ignore_paths,
+        })
+    }
+
+    /// Load the first Renovate config found under `workspace_root`
+    ///
+    /// Returns None if no config exists or it cannot be parsed.
+    pub fn load(workspace_root: &Path) -> Option<Self> {
+        let (path, content) = RENOVATE_CONFIG_FILES.iter().find_map(|name| {
+            let path = workspace_root.join(name);
+            std::fs::read_to_string(&path).ok().map(|c| (path, c))
+        })?;
+
+        let policy = Self::from_json(&content)
+            .inspect_err(|e| warn!("Failed to parse {:?}: {}", path, e))
+            .ok()?;
+        info!("Loaded Renovate policy from {:?}", path);
+
+        Some(Self {
+            root: workspace_root.to_path_buf(),
+            ..policy
+        })
+    }
+
+    /// Whether Renovate is configured not to update this package
+    pub fn is_package_ignored(&self, package_name: &str) -> bool {
+        self.ignored_packages.contains(package_name)
+            || self
+                .ignored_packages
+                .iter()
+                .any(|pattern| glob_match(pattern, package_name))
+            || self
+                .ignored_prefixes
+                .iter()
+                .any(|prefix| package_name.starts_with(prefix.as_str()))
+    }
+
+    /// Whether Renovate skips the manifest at `path`
+    ///
+    /// Like Renovate, a pattern matches if it is a substring of the path
+    /// (relative to the workspace root) or matches it as a glob.
+    pub fn is_path_ignored(&self, path: &Path) -> bool {
+        let relative = path.strip_prefix(&self.root).unwrap_or(path);
+        let relative = relative.to_string_lossy().replace('\\', "/");
+
+        self.ignore_paths
+            .iter()
+            .any(|pattern| relative.contains(pattern.as_str()) || glob_match(pattern, &relative))
+    }
+}
+
+/// Minimal glob matching: `**` crosses `/`, `*` and `?` do not
+fn glob_match(pattern: &str, text: &str) -> bool {
+    fn matches(p: &[u8], t: &[u8]) -> bool {
+        match p {
+            [] => t.is_empty(),
+            // `**/` may also match zero directories
+            [b'*', b'*', b'/', rest @ ..] => {
+                matches(rest, t) || (0..t.len()).any(|i| t[i] == b'/' && matches(rest, &t[i + 1..]))
+            }
+            [b'*', b'*', rest @ ..] => (0..=t.len()).any(|i| matches(rest, &t[i..])),
+            [b'*', rest @ ..] => (0..=t.len())
+                .take_while(|&i| i == 0 || t[i - 1] != b'/')
+                .any(|i| matches(rest, &t[i..])),
+            [b'?', rest @ ..] => matches!(t, [c, ..] if *c != b'/') && matches(rest, &t[1..]),
+            [c, rest @ ..] => t.first() == Some(c) && matches(rest, &t[1..]),
+        }
+    }
+    matches(pattern.as_bytes(), text.as_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    const SAMPLE: &str = r#"{
+        "extends": ["config:recommended"],
+        "ignoreDeps": ["lodash", "actions/checkout"],
+        "ignorePaths": ["**/fixtures/**", "legacy/"],
+        "packageRules": [
+            { "matchPackageNames": ["serde"], "enabled": false },
+            { "matchPackagePrefixes": ["@types/"], "enabled": false },
+            { "matchPackageNames": ["tokio"], "rangeStrategy": "pin" },
+            { "matchPackageNames": ["axios"], "enabled": true }
+        ]
+    }"#;
+
+    #[test]
+    fn from_json_derives_ignore_set() {
+        let policy = RenovatePolicy::from_json(SAMPLE).unwrap();
+
+        assert_eq!(
+            policy.ignored_packages,
+            HashSet::from([
+                "lodash".to_string(),
+                "actions/checkout".to_string(),
+                "serde".to_string(),
+            ])
+        );
+        assert_eq!(policy.ignored_prefixes, vec!["@types/".to_string()]);
+    }
+
+    #[rstest]
+    #[case("lodash", true)]
+    #[case("serde", true)]
+    #[case("@types/node", true)]
+    #[case("tokio", false)]
+    #[case("axios", false)]
+    fn is_package_ignored_returns_expected(#[case] name: &str, #[case] expected: bool) {
+        let policy = RenovatePolicy::from_json(SAMPLE).unwrap();
+        assert_eq!(policy.is_package_ignored(name), expected);
+    }
+
+    #[rstest]
+    #[case("/repo/tests/fixtures/package.json", true)]
+    #[case("/repo/fixtures/Cargo.toml", true)]
+    #[case("/repo/legacy/package.json", true)]
+    #[case("/repo/package.json", false)]
+    fn is_path_ignored_matches_relative_to_root(#[case] path: &str, #[case] expected: bool) {
+        let policy = RenovatePolicy {
+            root: PathBuf::from("/repo"),
+            ..RenovatePolicy::from_json(SAMPLE).unwrap()
+        };
+        assert_eq!(policy.is_path_ignored(Path::new(path)), expected);
+    }
+
+    #[test]
+    fn from_json_handles_empty_config() {
+        let policy = RenovatePolicy::from_json("{}").unwrap();
+        assert!(!policy.is_package_ignored("lodash"));
+        assert!(!policy.is_path_ignored(Path::new("package.json")));
+    }
+
+    #[test]
+    fn load_reads_config_from_workspace_root() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        std::fs::create_dir(temp_dir.path().join(".github")).unwrap();
+        std::fs::write(
+            temp_dir.path().join(".github/renovate.json"),
+            r#"{ "ignoreDeps": ["react"] }"#,
+        )
+        .unwrap();
+
+        let policy = RenovatePolicy::load(temp_dir.path()).unwrap();
+
+        assert!(policy.is_package_ignored("react"));
+    }
+
+    #[rstest]
+    #[case("**/node_modules/**", "node_modules/foo/package.json", true)]
+    #[case("**/node_modules/**", "a/node_modules/b", true)]
+    #[case("*.json", "package.json", true)]
+    #[case("*.json", "a/package.json", false)]
+    #[case("@types/*", "@types/node", true)]
+    #[case("packages/?/Cargo.toml", "packages/a/Cargo.toml", true)]
+    fn glob_match_returns_expected(
+        #[case] pattern: &str,
+        #[case] text: &str,
+        #[case] expected: bool,
+    ) {
+        assert_eq!(glob_match(pattern, text), expected);
+    }
+}
@@ -1,14 +1,18 @@
 use std::collections::{HashMap, HashSet};
+use std::num::NonZeroUsize;
 use std::path::Path;
+use std::sync::atomic::{AtomicI64, Ordering};
 use std::sync::{Mutex, MutexGuard};
 
+use lru::LruCache;
 use rusqlite::Connection;
 use tracing::{debug, info};
 
-use crate::config::FETCH_TIMEOUT_MS;
+use crate::config::{DEFAULT_NOT_FOUND_TTL_MS, FETCH_TIMEOUT_MS, MEMORY_CACHE_CAPACITY};
 use crate::parser::types::RegistryType;
 use crate::version::checker::VersionStorer;
 use crate::version::error::CacheError;
+use crate::version::types::PackageVersions;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct PackageId {
@@ -23,15 +27,46 @@ const MIGRATIONS: &[&[&str]] = &[
     &["ALTER TABLE packages ADD COLUMN fetching_since INTEGER"],
     // v2: not_found column
     &["ALTER TABLE packages ADD COLUMN not_found INTEGER NOT NULL DEFAULT 0"],
+    // v3: yanked column
+    &["ALTER TABLE versions ADD COLUMN yanked INTEGER NOT NULL DEFAULT 0"],
+    // v4: not_found_at column
+    &["ALTER TABLE packages ADD COLUMN not_found_at INTEGER"],
+    // v5: published_at column
+    &["ALTER TABLE versions ADD COLUMN published_at INTEGER"],
 ];
 
+/// Versions of a package held in memory, with the `updated_at` they were read at
+#[derive(Clone)]
+struct MemoryEntry {
+    versions: PackageVersions,
+    updated_at: i64,
+}
+
+/// In-memory LRU of recently read packages, keyed by registry and name
+type MemoryCache = LruCache<(RegistryType, String), MemoryEntry>;
+
 pub struct Cache {
     conn: Mutex<Connection>,
-    refresh_interval: i64,
+    /// Recently read packages, so repeated lookups skip SQLite.
+    /// Lock order: `conn` before `memory` when both are held.
+    memory: Mutex<MemoryCache>,
+    /// Updated when the client sends new configuration
+    refresh_interval: AtomicI64,
     ignore_prerelease: bool,
+    /// Packages whose last fetch failed, other than not found (memory only)
+    unreachable: Mutex<HashSet<(RegistryType, String)>>,
+    /// Entries older than this (ms) are not served and are refetched as if missing.
+    /// `None` means stale entries are always served while revalidating.
+    max_stale_age: Option<i64>,
+    /// How long (ms) a package marked as not found is trusted before it is refetched
+    not_found_ttl: i64,
 }
 
 impl Cache {
+    /// Open the cache database at `db_path`
+    ///
+    /// `:memory:` keeps the database in memory. The single connection is
+    /// shared by every user of the cache, so all of them see the same data.
     pub fn new(
         db_path: &Path,
         refresh_interval: i64,
@@ -49,8 +84,14 @@ impl Cache {
 
         let cache = Self {
             conn: Mutex::new(conn),
-            refresh_interval,
+            memory: Mutex::new(LruCache::new(
+                NonZeroUsize::new(MEMORY_CACHE_CAPACITY).expect("capacity is non-zero"),
+            )),
+            refresh_interval: AtomicI64::new(refresh_interval),
             ignore_prerelease,
+            unreachable: Mutex::new(HashSet::new()),
+            max_stale_age: None,
+            not_found_ttl: DEFAULT_NOT_FOUND_TTL_MS,
         };
 
         cache.create_schema()?;
@@ -59,11 +100,144 @@ impl Cache {
         Ok(cache)
     }
 
+    /// Set the maximum age (ms) of cached entries that may be served while revalidating
+    pub fn with_max_stale_age(mut self, max_stale_age: Option<i64>) -> Self {
+        self.max_stale_age = max_stale_age;
+        self
+    }
+
+    /// Set how long (ms) a package marked as not found is trusted before it is refetched
+    pub fn with_not_found_ttl(mut self, not_found_ttl: i64) -> Self {
+        self.not_found_ttl = not_found_ttl;
+        self
+    }
+
+    /// Timestamp before which packages marked as not found are fetched again
+    fn not_found_threshold(&self) -> i64 {
+        Self::current_timestamp_ms() - self.not_found_ttl
+    }
+
+    /// Timestamp before which cached entries are too stale to serve, if bounded
+    fn max_stale_threshold(&self) -> Option<i64> {
+        self.max_stale_age
+            .map(|max_stale_age| Self::current_timestamp_ms() - max_stale_age)
+    }
+
     /// Acquire database connection lock with proper error handling
     fn lock_conn(&self) -> Result<MutexGuard<'_, Connection>, CacheError> {
         self.conn.lock().map_err(|_| CacheError::LockPoisoned)
     }
 
+    /// Acquire the in-memory cache lock
+    fn lock_memory(&self) -> Result<MutexGuard<'_, MemoryCache>, CacheError> {
+        self.memory.lock().map_err(|_| CacheError::LockPoisoned)
+    }
+
+    /// Acquire the lock on packages whose registry could not be reached
+    fn lock_unreachable(
+        &self,
+    ) -> Result<MutexGuard<'_, HashSet<(RegistryType, String)>>, CacheError> {
+        self.unreachable
+            .lock()
+            .map_err(|_| CacheError::LockPoisoned)
+    }
+
+    /// Get a package from memory, reading it from SQLite on a miss
+    ///
+    /// Packages that were never cached are not remembered, so they are picked
+    /// up as soon as a fetch stores them.
+    fn memory_entry(
+        &self,
+        registry_type: RegistryType,
+        package_name: &str,
+    ) -> Result<Option<MemoryEntry>, CacheError> {
+        let key = (registry_type, package_name.to_string());
+        if let Some(entry) = self.lock_memory()?.get(&key) {
+            return Ok(Some(entry.clone()));
+        }
+
+        let conn = self.lock_conn()?;
+        let Some(entry) = Self::load_package(&conn, registry_type, package_name)? else {
+            return Ok(None);
+        };
+        // Still holding `conn`, so a concurrent write cannot slip in between
+        // the read and the insert and leave an outdated entry behind
+        self.lock_memory()?.put(key, entry.clone());
+        Ok(Some(entry))
+    }
+
+    /// Drop a package from memory after it was written to SQLite
+    ///
+    /// Callers hold the `conn` lock (see [`Self::memory_entry`]).
+    fn forget(&self, registry_type: RegistryType, package_name: &str) -> Result<(), CacheError> {
+        self.lock_memory()?
+            .pop(&(registry_type, package_name.to_string()));
+        Ok(())
+    }
+
+    /// Read a package's versions, yanked versions, publish times, dist tags and
+    /// `updated_at` from SQLite
+    fn load_package(
+        conn: &Connection,
+        registry_type: RegistryType,
+        package_name: &str,
+    ) -> Result<Option<MemoryEntry>, CacheError> {
+        let (package_id, updated_at) = match conn.query_row(
+            "SELECT id, updated_at FROM packages WHERE registry_type = ?1 AND package_name = ?2",
+            (registry_type.as_str(), package_name),
+            |row| Ok((row.get::<_, i64>(0)?, row.get::<_, i64>(1)?)),
+        ) {
+            Ok(row) => row,
+            Err(rusqlite::Error::QueryReturnedNoRows) => return Ok(None),
+            Err(e) => return Err(e.into()),
+        };
+
+        let rows = conn
+            .prepare(
+                "SELECT version, yanked, published_at FROM versions WHERE package_id = ?1 ORDER BY id",
+            )?
+            .query_map([package_id], |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    row.get::<_, bool>(1)?,
+                    row.get::<_, Option<i64>>(2)?,
+                ))
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+        let published_at = rows
+            .iter()
+            .filter_map(|(v, _, ts)| ts.map(|ts| (v.clone(), ts)))
+            .collect();
+        let (yanked, versions): (Vec<_>, Vec<_>) =
+            rows.into_iter().partition(|(_, yanked, _)| *yanked);
+        let versions = versions.into_iter().map(|(v, _, _)| v).collect();
+        let yanked = yanked.into_iter().map(|(v, _, _)| v).collect();
+
+        let dist_tags = conn
+            .prepare("SELECT tag_name, version FROM dist_tags WHERE package_id = ?1")?
+            .query_map([package_id], |row| Ok((row.get(0)?, row.get(1)?)))?
+            .collect::<Result<HashMap<String, String>, _>>()?;
+
+        Ok(Some(MemoryEntry {
+            versions: PackageVersions::with_dist_tags(versions, dist_tags)
+                .with_yanked(yanked)
+                .with_published_at(published_at),
+            updated_at,
+        }))
+    }
+
+    /// Drop prereleases when `ignore_prerelease` is set
+    fn filter_prereleases(&self, versions: Vec<String>) -> Vec<String> {
+        if self.ignore_prerelease {
+            versions
+                .into_iter()
+                .filter(|v| !crate::version::semver::is_prerelease(v))
+                .collect()
+        } else {
+            versions
+        }
+    }
+
     /// Get current timestamp in milliseconds since UNIX epoch
     fn current_timestamp_ms() -> i64 {
         std::time::SystemTime::now()
@@ -185,7 +359,7 @@ impl Cache {
             r#"
             SELECT v.version FROM versions v
             JOIN packages p ON v.package_id = p.id
-            WHERE p.registry_type = ?1 AND p.package_name = ?2
+            WHERE p.registry_type = ?1 AND p.package_name = ?2 AND v.yanked = 0
             "#,
         )?;
 
@@ -196,6 +370,190 @@ impl Cache {
         Ok(versions)
     }
 
+    /// Insert a package and its versions, bumping `updated_at`
+    ///
+    /// Runs in a single transaction. Versions already stored are kept,
+    /// so insertion order (oldest first) is preserved across refreshes.
+    pub fn upsert_package_versions(
+        &self,
+        registry_type: RegistryType,
+        package_name: &str,
+        versions: &[String],
+    ) -> Result<(), CacheError> {
+        let mut conn = self.lock_conn()?;
+        let tx = conn.transaction()?;
+        Self::upsert_versions_in(&tx, registry_type, package_name, versions)?;
+        tx.commit()?;
+        self.forget(registry_type, package_name)
+    }
+
+    /// Insert a package with its versions, dist tags, yanked versions and
+    /// publish times in one transaction
+    ///
+    /// Dist tags replace the stored ones when present; an empty map keeps
+    /// the existing tags, as registries without dist tags never send any.
+    pub fn upsert_package(
+        &self,
+        registry_type: RegistryType,
+        package_name: &str,
+        package_versions: &PackageVersions,
+    ) -> Result<(), CacheError> {
+        let mut conn = self.lock_conn()?;
+        let tx = conn.transaction()?;
+        let package_id =
+            Self::upsert_versions_in(&tx, registry_type, package_name, &package_versions.versions)?;
+        if !package_versions.dist_tags.is_empty() {
+            Self::replace_dist_tags_in(&tx, package_id, &package_versions.dist_tags)?;
+        }
+        Self::mark_yanked_in(&tx, package_id, &package_versions.yanked)?;
+        Self::set_published_at_in(&tx, package_id, &package_versions.published_at)?;
+        tx.commit()?;
+        self.forget(registry_type, package_name)
+    }
+
+    /// Upsert the package row and its versions, returning the package id
+    fn upsert_versions_in(
+        conn: &Connection,
+        registry_type: RegistryType,
+        package_name: &str,
+        versions: &[String],
+    ) -> Result<i64, CacheError> {
+        let registry_type = registry_type.as_str();
+        debug!(
+            "Saving {} versions for {}/{}",
+            versions.len(),
+            registry_type,
+            package_name
+        );
+
+        let now = Self::current_timestamp_ms();
+
+        // Insert or update package; versions were found, so it exists again
+        conn.execute(
+            r#"
+            INSERT INTO packages (registry_type, package_name, updated_at)
+            VALUES (?1, ?2, ?3)
+            ON CONFLICT(registry_type, package_name) DO UPDATE
+            SET updated_at = excluded.updated_at, not_found = 0, not_found_at = NULL
+            "#,
+            (registry_type, package_name, now),
+        )?;
+
+        // Get package_id
+        let package_id: i64 = conn.query_row(
+            "SELECT id FROM packages WHERE registry_type = ?1 AND package_name = ?2",
+            (registry_type, package_name),
+            |row| row.get(0),
+        )?;
+
+        // Insert only new versions; existing ones keep their row (and order).
+        // A version listed again is available, so it is no longer yanked.
+        let mut stmt = conn.prepare(
+            r#"
+            INSERT INTO versions (package_id, version) VALUES (?1, ?2)
+            ON CONFLICT(package_id, version) DO UPDATE SET yanked = 0
+            "#,
+        )?;
+        for version in versions {
+            stmt.execute((package_id, version))?;
+        }
+
+        debug!(
+            "Successfully saved versions for {}/{}",
+            registry_type, package_name
+        );
+        Ok(package_id)
+    }
+
+    /// Mark versions of a package as yanked, inserting the ones not stored yet
+    fn mark_yanked_in(
+        conn: &Connection,
+        package_id: i64,
+        yanked: &[String],
+    ) -> Result<(), CacheError> {
+        let mut stmt = conn.prepare(
+            r#"
+            INSERT INTO versions (package_id, version, yanked) VALUES (?1, ?2, 1)
+            ON CONFLICT(package_id, version) DO UPDATE SET yanked = 1
+            "#,
+        )?;
+        for version in yanked {
+            stmt.execute((package_id, version))?;
+        }
+        Ok(())
+    }
+
+    /// Record the publish time of stored versions; unknown versions are ignored
+    fn set_published_at_in(
+        conn: &Connection,
+        package_id: i64,
+        published_at: &HashMap<String, i64>,
+    ) -> Result<(), CacheError> {
+        let mut stmt = conn.prepare(
+            "UPDATE versions SET published_at = ?3 WHERE package_id = ?1 AND version = ?2",
+        )?;
+        for (version, timestamp) in published_at {
+            stmt.execute((package_id, version, timestamp))?;
+        }
+        Ok(())
+    }
+
+    /// Replace all dist tags of a package
+    fn replace_dist_tags_in(
+        conn: &Connection,
+        package_id: i64,
+        dist_tags: &HashMap<String, String>,
+    ) -> Result<(), CacheError> {
+        conn.execute("DELETE FROM dist_tags WHERE package_id = ?1", [package_id])?;
+
+        let mut stmt = conn
+            .prepare("INSERT INTO dist_tags (package_id, tag_name, version) VALUES (?1, ?2, ?3)")?;
+        for (tag_name, version) in dist_tags {
+            stmt.execute((package_id, tag_name, version))?;
+        }
+        Ok(())
+    }
+
+    /// Get the cached versions and dist tags of a package
+    ///
+    /// Versions are returned in insertion order, or None when the package was
+    /// never cached. Versions are served from memory after the first read.
+    pub fn get_cached_versions(
+        &self,
+        registry_type: RegistryType,
+        package_name: &str,
+    ) -> Result<Option<PackageVersions>, CacheError> {
+        Ok(self
+            .memory_entry(registry_type, package_name)?
+            .map(|entry| entry.versions))
+    }
+
+    /// Whether a package was marked as not found within the not-found TTL
+    fn is_known_not_found(
+        &self,
+        registry_type: RegistryType,
+        package_name: &str,
+    ) -> Result<bool, CacheError> {
+        let conn = self.lock_conn()?;
+        match conn.query_row(
+            r#"
+            SELECT 1 FROM packages
+            WHERE registry_type = ?1 AND package_name = ?2
+              AND not_found = 1 AND not_found_at >= ?3
+            "#,
+            (
+                registry_type.as_str(),
+                package_name,
+                self.not_found_threshold(),
+            ),
+            |_| Ok(()),
+        ) {
+            Ok(()) => Ok(true),
+            Err(rusqlite::Error::QueryReturnedNoRows) => Ok(false),
+            Err(e) => Err(e.into()),
+        }
+    }
+
     /// Save dist tags for a package
     pub fn save_dist_tags(
         &self,
@@ -230,19 +588,108 @@ impl Cache {
         )?;
 
         // Delete existing dist tags and insert new ones
-        tx.execute("DELETE FROM dist_tags WHERE package_id = ?1", [package_id])?;
+        Self::replace_dist_tags_in(&tx, package_id, dist_tags)?;
 
-        {
-            let mut stmt = tx.prepare(
-                "INSERT INTO dist_tags (package_id, tag_name, version) VALUES (?1, ?2, ?3)",
+        tx.commit()?;
+        self.forget(registry_type, package_name)
+    }
+
+    /// Save the versions a registry has withdrawn (e.g., yanked crates)
+    pub fn save_yanked_versions(
+        &self,
+        registry_type: RegistryType,
+        package_name: &str,
+        yanked: &[String],
+    ) -> Result<(), CacheError> {
+        if yanked.is_empty() {
+            return Ok(());
+        }
+
+        let registry_type_str = registry_type.as_str();
+        let mut conn = self.lock_conn()?;
+        let tx = conn.transaction()?;
+
+        let now = Self::current_timestamp_ms();
+
+        tx.execute(
+            r#"
+            INSERT INTO packages (registry_type, package_name, updated_at)
+            VALUES (?1, ?2, ?3)
+            ON CONFLICT(registry_type, package_name) DO NOTHING
+            "#,
+            (registry_type_str, package_name, now),
+        )?;
+
+        let package_id: i64 = tx.query_row(
+            "SELECT id FROM packages WHERE registry_type = ?1 AND package_name = ?2",
+            (registry_type_str, package_name),
+            |row| row.get(0),
+        )?;
+
+        Self::mark_yanked_in(&tx, package_id, yanked)?;
+
+        tx.commit()?;
+        self.forget(registry_type, package_name)
+    }
+
+    /// Save the publish time (Unix seconds) of a package's stored versions
+    pub fn save_published_at(
+        &self,
+        registry_type: RegistryType,
+        package_name: &str,
+        published_at: &HashMap<String, i64>,
+    ) -> Result<(), CacheError> {
+        if published_at.is_empty() {
+            return Ok(());
+        }
+
+        let mut conn = self.lock_conn()?;
+        let tx = conn.transaction()?;
+
+        let package_id: i64 = match tx.query_row(
+            "SELECT id FROM packages WHERE registry_type = ?1 AND package_name = ?2",
+            (registry_type.as_str(), package_name),
+            |row| row.get(0),
+        ) {
+            Ok(id) => id,
+            Err(rusqlite::Error::QueryReturnedNoRows) => return Ok(()),
+            Err(e) => return Err(e.into()),
+        };
+
+        Self::set_published_at_in(&tx, package_id, published_at)?;
+
+        tx.commit()?;
+        self.forget(registry_type, package_name)
+    }
+
+    /// Remove a package with its versions and dist tags, so it is fetched again
+    pub fn invalidate(
+        &self,
+        registry_type: RegistryType,
+        package_name: &str,
+    ) -> Result<(), CacheError> {
+        let registry_type_str = registry_type.as_str();
+        let mut conn = self.lock_conn()?;
+        let tx = conn.transaction()?;
+
+        for table in ["versions", "dist_tags"] {
+            tx.execute(
+                &format!(
+                    "DELETE FROM {} WHERE package_id IN \
+                     (SELECT id FROM packages WHERE registry_type = ?1 AND package_name = ?2)",
+                    table
+                ),
+                (registry_type_str, package_name),
             )?;
-            for (tag_name, version) in dist_tags {
-                stmt.execute((package_id, tag_name, version))?;
-            }
         }
+        tx.execute(
+            "DELETE FROM packages WHERE registry_type = ?1 AND package_name = ?2",
+            (registry_type_str, package_name),
+        )?;
 
         tx.commit()?;
-        Ok(())
+        debug!("Invalidated {}/{}", registry_type_str, package_name);
+        self.forget(registry_type, package_name)
     }
 
     /// Get a specific dist tag for a package
@@ -252,23 +699,9 @@ impl Cache {
         package_name: &str,
         tag_name: &str,
     ) -> Result<Option<String>, CacheError> {
-        let registry_type_str = registry_type.as_str();
-        let conn = self.lock_conn()?;
-        let result = conn.query_row(
-            r#"
-            SELECT dt.version FROM dist_tags dt
-            JOIN packages p ON dt.package_id = p.id
-            WHERE p.registry_type = ?1 AND p.package_name = ?2 AND dt.tag_name = ?3
-            "#,
-            (registry_type_str, package_name, tag_name),
-            |row| row.get(0),
-        );
-
-        match result {
-            Ok(version) => Ok(Some(version)),
-            Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
-            Err(e) => Err(e.into()),
-        }
+        Ok(self
+            .memory_entry(registry_type, package_name)?
+            .and_then(|entry| entry.versions.dist_tags.get(tag_name).cloned()))
     }
 }
 
@@ -278,34 +711,27 @@ impl VersionStorer for Cache {
         registry_type: RegistryType,
         package_name: &str,
     ) -> Result<Option<String>, CacheError> {
-        let conn = self.lock_conn()?;
+        let Some(entry) = self.memory_entry(registry_type, package_name)? else {
+            return Ok(None);
+        };
 
-        // First, try to get the "latest" dist-tag (for npm packages)
-        let dist_tag_result = conn.query_row(
-            r#"
-            SELECT dt.version FROM dist_tags dt
-            JOIN packages p ON dt.package_id = p.id
-            WHERE p.registry_type = ?1 AND p.package_name = ?2 AND dt.tag_name = 'latest'
-            "#,
-            (registry_type.as_str(), package_name),
-            |row| row.get::<_, String>(0),
-        );
+        // Entries past max_stale_age are treated as missing until refetched
+        if self
+            .max_stale_threshold()
+            .is_some_and(|threshold| entry.updated_at < threshold)
+        {
+            return Ok(None);
+        }
 
-        if let Ok(version) = dist_tag_result {
-            return Ok(Some(version));
+        // First, try to get the "latest" dist-tag (for npm packages)
+        if let Some(version) = entry.versions.dist_tags.get("latest") {
+            return Ok(Some(version.clone()));
         }
 
         // For registries without dist-tags (GitHub Actions, Go, etc.),
         // find the semantically highest version
-        drop(conn); // Release lock before calling get_versions
-        let versions = VersionStorer::get_versions(self, registry_type, package_name)?;
-
-        if versions.is_empty() {
-            return Ok(None);
-        }
-
-        // Find the semantically highest version
-        let latest = versions
+        let latest = self
+            .filter_prereleases(entry.versions.versions)
             .into_iter()
             .filter_map(|v| {
                 let parsed = crate::version::semver::parse_version(&v)?;
@@ -322,15 +748,11 @@ impl VersionStorer for Cache {
         registry_type: RegistryType,
         package_name: &str,
     ) -> Result<Vec<String>, CacheError> {
-        let versions = Cache::get_versions(self, registry_type, package_name)?;
-        if self.ignore_prerelease {
-            Ok(versions
-                .into_iter()
-                .filter(|v| !crate::version::semver::is_prerelease(v))
-                .collect())
-        } else {
-            Ok(versions)
-        }
+        let versions = self
+            .memory_entry(registry_type, package_name)?
+            .map(|entry| entry.versions.versions)
+            .unwrap_or_default();
+        Ok(self.filter_prereleases(versions))
     }
 
     fn version_exists(
@@ -339,21 +761,9 @@ impl VersionStorer for Cache {
         package_name: &str,
         version: &str,
     ) -> Result<bool, CacheError> {
-        let registry_type = registry_type.as_str();
-        let conn = self.lock_conn()?;
-        let exists: bool = conn.query_row(
-            r#"
-            SELECT EXISTS(
-                SELECT 1 FROM versions v
-                JOIN packages p ON v.package_id = p.id
-                WHERE p.registry_type = ?1 AND p.package_name = ?2 AND v.version = ?3
-            )
-            "#,
-            (registry_type, package_name, version),
-            |row| row.get(0),
-        )?;
-
-        Ok(exists)
+        Ok(self
+            .memory_entry(registry_type, package_name)?
+            .is_some_and(|entry| entry.versions.versions.iter().any(|v| v == version)))
     }
 
     fn replace_versions(
@@ -362,63 +772,37 @@ impl VersionStorer for Cache {
         package_name: &str,
         versions: Vec<String>,
     ) -> Result<(), CacheError> {
-        let registry_type = registry_type.as_str();
-        debug!(
-            "Saving {} versions for {}/{}",
-            versions.len(),
-            registry_type,
-            package_name
-        );
-
-        let now = Self::current_timestamp_ms();
-
-        let mut conn = self.lock_conn()?;
-        let tx = conn.transaction()?;
-
-        // Insert or update package
-        tx.execute(
-            r#"
-            INSERT INTO packages (registry_type, package_name, updated_at)
-            VALUES (?1, ?2, ?3)
-            ON CONFLICT(registry_type, package_name) DO UPDATE SET updated_at = excluded.updated_at
-            "#,
-            (registry_type, package_name, now),
-        )?;
-
-        // Get package_id
-        let package_id: i64 = tx.query_row(
-            "SELECT id FROM packages WHERE registry_type = ?1 AND package_name = ?2",
-            (registry_type, package_name),
-            |row| row.get(0),
-        )?;
-
-        // Insert only new versions (skip existing ones)
-        // Using INSERT OR IGNORE with UNIQUE constraint on (package_id, version)
-        {
-            let mut stmt =
-                tx.prepare("INSERT OR IGNORE INTO versions (package_id, version) VALUES (?1, ?2)")?;
-            for version in &versions {
-                stmt.execute((package_id, version))?;
-            }
-        }
-
-        tx.commit()?;
+        self.upsert_package_versions(registry_type, package_name, &versions)?;
+        self.lock_unreachable()?
+            .remove(&(registry_type, package_name.to_string()));
+        Ok(())
+    }
 
-        debug!(
-            "Successfully saved versions for {}/{}",
-            registry_type, package_name
-        );
+    fn save_package(
+        &self,
+        registry_type: RegistryType,
+        package_name: &str,
+        package_versions: &PackageVersions,
+    ) -> Result<(), CacheError> {
+        self.upsert_package(registry_type, package_name, package_versions)?;
+        self.lock_unreachable()?
+            .remove(&(registry_type, package_name.to_string()));
         Ok(())
     }
 
     fn get_packages_needing_refresh(&self) -> Result<Vec<PackageId>, CacheError> {
         let now = Self::current_timestamp_ms();
-        let threshold = now - self.refresh_interval;
+        let threshold = now - self.refresh_interval.load(Ordering::Relaxed);
 
         let conn = self.lock_conn()?;
-        // Exclude packages marked as not found to avoid repeated fetch attempts
+        // Exclude packages marked as not found to avoid repeated fetch attempts.
+        // Oldest first, so the stalest entries are refreshed first.
         let mut stmt = conn.prepare(
-            "SELECT registry_type, package_name FROM packages WHERE updated_at < ?1 AND not_found = 0",
+            r#"
+            SELECT registry_type, package_name FROM packages
+            WHERE updated_at < ?1 AND not_found = 0
+            ORDER BY updated_at ASC, id ASC
+            "#,
         )?;
 
         let packages = stmt
@@ -520,6 +904,46 @@ impl VersionStorer for Cache {
         Cache::save_dist_tags(self, registry_type, package_name, dist_tags)
     }
 
+    fn get_yanked_versions(
+        &self,
+        registry_type: RegistryType,
+        package_name: &str,
+    ) -> Result<Vec<String>, CacheError> {
+        Ok(self
+            .memory_entry(registry_type, package_name)?
+            .map(|entry| entry.versions.yanked)
+            .unwrap_or_default())
+    }
+
+    fn save_yanked_versions(
+        &self,
+        registry_type: RegistryType,
+        package_name: &str,
+        yanked: &[String],
+    ) -> Result<(), CacheError> {
+        Cache::save_yanked_versions(self, registry_type, package_name, yanked)
+    }
+
+    fn get_published_at(
+        &self,
+        registry_type: RegistryType,
+        package_name: &str,
+    ) -> Result<HashMap<String, i64>, CacheError> {
+        Ok(self
+            .memory_entry(registry_type, package_name)?
+            .map(|entry| entry.versions.published_at)
+            .unwrap_or_default())
+    }
+
+    fn save_published_at(
+        &self,
+        registry_type: RegistryType,
+        package_name: &str,
+        published_at: &HashMap<String, i64>,
+    ) -> Result<(), CacheError> {
+        Cache::save_published_at(self, registry_type, package_name, published_at)
+    }
+
     fn filter_packages_not_in_cache(
         &self,
         registry_type: RegistryType,
@@ -539,25 +963,36 @@ impl VersionStorer for Cache {
         let placeholders_str = placeholders.join(", ");
 
         // Consider packages as "cached" if:
-        // 1. They have at least one version, OR
-        // 2. They are marked as not found (to skip repeated fetch attempts)
+        // 1. They have at least one version and are within max_stale_age, OR
+        // 2. They were marked as not found within the not-found TTL
+        //    (to skip repeated fetch attempts)
+        let max_stale_param = package_names.len() + 2;
+        let not_found_param = package_names.len() + 3;
         let query = format!(
             r#"
             SELECT p.package_name
             FROM packages p
             WHERE p.registry_type = ?1
               AND p.package_name IN ({})
-              AND (EXISTS (SELECT 1 FROM versions v WHERE v.package_id = p.id) OR p.not_found = 1)
+              AND ((EXISTS (SELECT 1 FROM versions v WHERE v.package_id = p.id)
+                    AND (?{max_stale_param} IS NULL OR p.updated_at >= ?{max_stale_param}))
+                   OR (p.not_found = 1 AND p.not_found_at >= ?{not_found_param}))
             "#,
             placeholders_str
         );
 
         let mut stmt = conn.prepare(&query)?;
 
-        // Build params: registry_type followed by all package names
+        // Build params: registry_type, all package names, then the thresholds
+        let max_stale_threshold = self.max_stale_threshold();
+        let not_found_threshold = self.not_found_threshold();
         let params: Vec<&dyn rusqlite::ToSql> =
             std::iter::once(&registry_type as &dyn rusqlite::ToSql)
                 .chain(package_names.iter().map(|s| s as &dyn rusqlite::ToSql))
+                .chain([
+                    &max_stale_threshold as &dyn rusqlite::ToSql,
+                    &not_found_threshold,
+                ])
                 .collect();
 
         let cached_packages: HashSet<String> = stmt
@@ -584,20 +1019,121 @@ impl VersionStorer for Cache {
         let conn = self.lock_conn()?;
 
         conn.execute(
-            "UPDATE packages SET not_found = 1 WHERE registry_type = ?1 AND package_name = ?2",
-            (registry_type, package_name),
+            r#"
+            UPDATE packages SET not_found = 1, not_found_at = ?3
+            WHERE registry_type = ?1 AND package_name = ?2
+            "#,
+            (registry_type, package_name, Self::current_timestamp_ms()),
         )?;
 
         Ok(())
     }
+
+    fn filter_stale_packages(
+        &self,
+        registry_type: RegistryType,
+        package_names: &[String],
+    ) -> Result<Vec<String>, CacheError> {
+        if package_names.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let registry_type = registry_type.as_str();
+        let stale_threshold =
+            Self::current_timestamp_ms() - self.refresh_interval.load(Ordering::Relaxed);
+        let max_stale_threshold = self.max_stale_threshold();
+        let conn = self.lock_conn()?;
+
+        let placeholders: Vec<_> = (0..package_names.len())
+            .map(|i| format!("?{}", i + 4))
+            .collect();
+
+        // Stale = cached with versions, older than refresh_interval, but still
+        // within max_stale_age (older entries are handled as missing instead)
+        let query = format!(
+            r#"
+            SELECT p.package_name
+            FROM packages p
+            WHERE p.registry_type = ?1
+              AND p.updated_at < ?2
+              AND (?3 IS NULL OR p.updated_at >= ?3)
+              AND p.not_found = 0
+              AND p.package_name IN ({})
+              AND EXISTS (SELECT 1 FROM versions v WHERE v.package_id = p.id)
+            "#,
+            placeholders.join(", ")
+        );
+
+        let mut stmt = conn.prepare(&query)?;
+
+        let params: Vec<&dyn rusqlite::ToSql> = [
+            &registry_type as &dyn rusqlite::ToSql,
+            &stale_threshold,
+            &max_stale_threshold,
+        ]
+        .into_iter()
+        .chain(package_names.iter().map(|s| s as &dyn rusqlite::ToSql))
+        .collect();
+
+        let stale_packages: HashSet<String> = stmt
+            .query_map(params.as_slice(), |row| row.get(0))?
+            .filter_map(|r| r.ok())
+            .collect();
+
+        // Preserve original order
+        Ok(package_names
+            .iter()
+            .filter(|name| stale_packages.contains(*name))
+            .cloned()
+            .collect())
+    }
+
+    fn invalidate(
+        &self,
+        registry_type: RegistryType,
+        package_name: &str,
+    ) -> Result<(), CacheError> {
+        Cache::invalidate(self, registry_type, package_name)
+    }
+
+    fn set_refresh_interval(&self, refresh_interval: i64) {
+        self.refresh_interval
+            .store(refresh_interval, Ordering::Relaxed);
+    }
+
+    fn mark_unreachable(&self, registry_type: RegistryType, package_name: &str) {
+        if let Ok(mut unreachable) = self.lock_unreachable() {
+            unreachable.insert((registry_type, package_name.to_string()));
+        }
+    }
+
+    fn is_not_found(&self, registry_type: RegistryType, package_name: &str) -> bool {
+        self.is_known_not_found(registry_type, package_name)
+            .unwrap_or(false)
+    }
+
+    fn is_unreachable(&self, registry_type: RegistryType, package_name: &str) -> bool {
+        self.lock_unreachable().is_ok_and(|unreachable| {
+            unreachable.contains(&(registry_type, package_name.to_string()))
+        })
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config::IN_MEMORY_CACHE_PATH;
     use rstest::rstest;
     use tempfile::TempDir;
 
+    /// Versions and dist tags cached for a package
+    fn found(cache: &Cache, registry_type: RegistryType, package_name: &str) -> PackageVersions {
+        cache
+            .get_cached_versions(registry_type, package_name)
+            .unwrap()
+            .expect("Expected cached versions")
+    }
+
     #[test]
     fn replace_versions_creates_new_package() {
         let temp_dir = TempDir::new().unwrap();
@@ -657,21 +1193,258 @@ mod tests {
             "2.0.0".to_string(), // new
         ];
         cache
-            .replace_versions(RegistryType::Npm, "axios", updated_versions)
+            .replace_versions(RegistryType::Npm, "axios", updated_versions)
+            .unwrap();
+
+        // Verify all versions are present (no duplicates)
+        let mut saved = cache.get_versions(RegistryType::Npm, "axios").unwrap();
+        saved.sort();
+        assert_eq!(
+            saved,
+            vec![
+                "1.0.0".to_string(),
+                "1.1.0".to_string(),
+                "1.2.0".to_string(),
+                "2.0.0".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn upsert_package_versions_round_trips_in_insertion_order() {
+        let temp_dir = TempDir::new().unwrap();
+        let db_path = temp_dir.path().join("test.db");
+        let cache = Cache::new(&db_path, 86400, false).unwrap();
+
+        let versions = vec![
+            "1.0.0".to_string(),
+            "0.9.0".to_string(),
+            "2.0.0".to_string(),
+        ];
+        cache
+            .upsert_package_versions(RegistryType::Npm, "axios", &versions)
+            .unwrap();
+        cache
+            .save_dist_tags(
+                RegistryType::Npm,
+                "axios",
+                &HashMap::from([("latest".to_string(), "2.0.0".to_string())]),
+            )
+            .unwrap();
+
+        let cached = found(&cache, RegistryType::Npm, "axios");
+        assert_eq!(cached.versions, versions);
+        assert_eq!(cached.resolve_dist_tag("latest"), Some("2.0.0"));
+    }
+
+    #[test]
+    fn upsert_package_versions_updates_timestamp() {
+        let temp_dir = TempDir::new().unwrap();
+        let db_path = temp_dir.path().join("test.db");
+        let cache = Cache::new(&db_path, 86400, false).unwrap();
+
+        cache
+            .upsert_package_versions(RegistryType::Npm, "axios", &["1.0.0".to_string()])
+            .unwrap();
+        {
+            let conn = cache.lock_conn().unwrap();
+            conn.execute("UPDATE packages SET updated_at = 0", [])
+                .unwrap();
+        }
+
+        cache
+            .upsert_package_versions(RegistryType::Npm, "axios", &["1.1.0".to_string()])
+            .unwrap();
+
+        let updated_at: i64 = cache
+            .lock_conn()
+            .unwrap()
+            .query_row(
+                "SELECT updated_at FROM packages WHERE package_name = 'axios'",
+                [],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert!(updated_at > 0);
+        let cached = found(&cache, RegistryType::Npm, "axios");
+        assert_eq!(cached.versions, vec!["1.0.0", "1.1.0"]);
+    }
+
+    #[test]
+    fn upsert_package_round_trips_dist_tags() {
+        let temp_dir = TempDir::new().unwrap();
+        let db_path = temp_dir.path().join("test.db");
+        let cache = Cache::new(&db_path, 86400, false).unwrap();
+
+        let package = PackageVersions::with_dist_tags(
+            vec![
+                "1.0.0".to_string(),
+                "2.0.0-beta.1".to_string(),
+                "1.1.0".to_string(),
+            ],
+            HashMap::from([
+                ("latest".to_string(), "1.1.0".to_string()),
+                ("beta".to_string(), "2.0.0-beta.1".to_string()),
+            ]),
+        );
+        cache
+            .upsert_package(RegistryType::Npm, "react", &package)
+            .unwrap();
+
+        // Reopen to make sure the tags survive a restart
+        drop(cache);
+        let cache = Cache::new(&db_path, 86400, false).unwrap();
+
+        let cached = found(&cache, RegistryType::Npm, "react");
+        assert_eq!(cached, package);
+        assert_eq!(cached.resolve_dist_tag("latest"), Some("1.1.0"));
+        assert_eq!(cached.resolve_dist_tag("beta"), Some("2.0.0-beta.1"));
+        assert_eq!(cached.resolve_dist_tag("next"), None);
+    }
+
+    #[test]
+    fn publish_times_survive_a_restart() {
+        let temp_dir = TempDir::new().unwrap();
+        let db_path = temp_dir.path().join("test.db");
+        let cache = Cache::new(&db_path, 86400, false).unwrap();
+
+        let package = PackageVersions::new(vec!["1.0.0".to_string(), "1.1.0".to_string()])
+            .with_published_at(HashMap::from([("1.0.0".to_string(), 1_600_000_000)]));
+        cache
+            .upsert_package(RegistryType::CratesIo, "foo", &package)
+            .unwrap();
+        cache
+            .save_published_at(
+                RegistryType::CratesIo,
+                "foo",
+                &HashMap::from([("1.1.0".to_string(), 1_700_000_000)]),
+            )
+            .unwrap();
+
+        drop(cache);
+        let cache = Cache::new(&db_path, 86400, false).unwrap();
+
+        assert_eq!(
+            cache
+                .get_published_at(RegistryType::CratesIo, "foo")
+                .unwrap(),
+            HashMap::from([
+                ("1.0.0".to_string(), 1_600_000_000),
+                ("1.1.0".to_string(), 1_700_000_000),
+            ])
+        );
+    }
+
+    #[test]
+    fn upsert_package_keeps_dist_tags_when_none_given() {
+        let temp_dir = TempDir::new().unwrap();
+        let db_path = temp_dir.path().join("test.db");
+        let cache = Cache::new(&db_path, 86400, false).unwrap();
+
+        let tagged = PackageVersions::with_dist_tags(
+            vec!["1.0.0".to_string()],
+            HashMap::from([("latest".to_string(), "1.0.0".to_string())]),
+        );
+        cache
+            .upsert_package(RegistryType::Npm, "react", &tagged)
+            .unwrap();
+        cache
+            .upsert_package(
+                RegistryType::Npm,
+                "react",
+                &PackageVersions::new(vec!["1.1.0".to_string()]),
+            )
+            .unwrap();
+
+        let cached = found(&cache, RegistryType::Npm, "react");
+        assert_eq!(cached.versions, vec!["1.0.0", "1.1.0"]);
+        assert_eq!(cached.resolve_dist_tag("latest"), Some("1.0.0"));
+    }
+
+    #[test]
+    fn yanked_versions_are_kept_apart_from_available_versions() {
+        let temp_dir = TempDir::new().unwrap();
+        let db_path = temp_dir.path().join("test.db");
+        let cache = Cache::new(&db_path, 86400, false).unwrap();
+
+        // 1.0.1 was cached before it got yanked
+        cache
+            .replace_versions(
+                RegistryType::CratesIo,
+                "foo",
+                vec!["1.0.0".to_string(), "1.0.1".to_string()],
+            )
+            .unwrap();
+        let package = PackageVersions::new(vec!["1.0.0".to_string(), "1.0.2".to_string()])
+            .with_yanked(vec!["1.0.1".to_string()]);
+        cache
+            .upsert_package(RegistryType::CratesIo, "foo", &package)
+            .unwrap();
+
+        assert_eq!(
+            VersionStorer::get_versions(&cache, RegistryType::CratesIo, "foo").unwrap(),
+            vec!["1.0.0", "1.0.2"]
+        );
+        assert_eq!(
+            cache.get_versions(RegistryType::CratesIo, "foo").unwrap(),
+            vec!["1.0.0", "1.0.2"]
+        );
+        assert_eq!(
+            cache
+                .get_yanked_versions(RegistryType::CratesIo, "foo")
+                .unwrap(),
+            vec!["1.0.1"]
+        );
+        assert!(
+            !cache
+                .version_exists(RegistryType::CratesIo, "foo", "1.0.1")
+                .unwrap()
+        );
+
+        // Listed again: no longer yanked
+        cache
+            .replace_versions(RegistryType::CratesIo, "foo", vec!["1.0.1".to_string()])
+            .unwrap();
+        assert!(
+            cache
+                .get_yanked_versions(RegistryType::CratesIo, "foo")
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn get_cached_versions_returns_none_for_uncached_package() {
+        let temp_dir = TempDir::new().unwrap();
+        let db_path = temp_dir.path().join("test.db");
+        let cache = Cache::new(&db_path, 86400, false).unwrap();
+
+        let cached = cache
+            .get_cached_versions(RegistryType::Npm, "nonexistent")
+            .unwrap();
+        assert!(cached.is_none());
+    }
+
+    #[tokio::test]
+    async fn get_cached_versions_round_trips_upserted_versions_in_order() {
+        let temp_dir = TempDir::new().unwrap();
+        let db_path = temp_dir.path().join("test.db");
+        let cache = Cache::new(&db_path, 86400, false).unwrap();
+
+        let versions = vec![
+            "1.0.0".to_string(),
+            "1.1.0".to_string(),
+            "2.0.0".to_string(),
+        ];
+        cache
+            .upsert_package_versions(RegistryType::CratesIo, "serde", &versions)
             .unwrap();
 
-        // Verify all versions are present (no duplicates)
-        let mut saved = cache.get_versions(RegistryType::Npm, "axios").unwrap();
-        saved.sort();
-        assert_eq!(
-            saved,
-            vec![
-                "1.0.0".to_string(),
-                "1.1.0".to_string(),
-                "1.2.0".to_string(),
-                "2.0.0".to_string(),
-            ]
-        );
+        let cached = cache
+            .get_cached_versions(RegistryType::CratesIo, "serde")
+            .unwrap()
+            .expect("Expected cached versions");
+        assert_eq!(cached.versions, versions);
     }
 
     #[test]
@@ -799,6 +1572,264 @@ mod tests {
         }));
     }
 
+    #[test]
+    fn invalidate_removes_package_versions_and_dist_tags() {
+        let temp_dir = TempDir::new().unwrap();
+        let db_path = temp_dir.path().join("test.db");
+        let cache = Cache::new(&db_path, 86400, false).unwrap();
+
+        let package = PackageVersions::with_dist_tags(
+            vec!["1.0.0".to_string(), "1.1.0".to_string()],
+            HashMap::from([("latest".to_string(), "1.1.0".to_string())]),
+        );
+        cache
+            .upsert_package(RegistryType::Npm, "axios", &package)
+            .unwrap();
+        cache
+            .replace_versions(RegistryType::Npm, "react", vec!["18.0.0".to_string()])
+            .unwrap();
+        // Read once so the package is held in memory
+        assert!(
+            cache
+                .memory_entry(RegistryType::Npm, "axios")
+                .unwrap()
+                .is_some()
+        );
+
+        cache.invalidate(RegistryType::Npm, "axios").unwrap();
+
+        let count = |table: &str| -> i64 {
+            cache
+                .lock_conn()
+                .unwrap()
+                .query_row(&format!("SELECT COUNT(*) FROM {}", table), [], |row| {
+                    row.get(0)
+                })
+                .unwrap()
+        };
+        // Only react is left
+        assert_eq!(count("packages"), 1);
+        assert_eq!(count("versions"), 1);
+        assert_eq!(count("dist_tags"), 0);
+        assert!(
+            cache
+                .memory_entry(RegistryType::Npm, "axios")
+                .unwrap()
+                .is_none()
+        );
+        assert_eq!(
+            cache
+                .filter_packages_not_in_cache(RegistryType::Npm, &["axios".to_string()])
+                .unwrap(),
+            vec!["axios"]
+        );
+    }
+
+    #[test]
+    fn set_refresh_interval_applies_to_later_refresh_checks() {
+        let temp_dir = TempDir::new().unwrap();
+        let db_path = temp_dir.path().join("test.db");
+        // refresh_interval = 1 hour
+        let cache = Cache::new(&db_path, 3_600_000, false).unwrap();
+
+        cache
+            .replace_versions(RegistryType::Npm, "axios", vec!["1.0.0".to_string()])
+            .unwrap();
+        std::thread::sleep(std::time::Duration::from_millis(150));
+        assert!(cache.get_packages_needing_refresh().unwrap().is_empty());
+
+        cache.set_refresh_interval(100);
+
+        let stale = cache.get_packages_needing_refresh().unwrap();
+        assert_eq!(
+            stale,
+            vec![PackageId {
+                registry_type: RegistryType::Npm,
+                package_name: "axios".to_string()
+            }]
+        );
+    }
+
+    #[test]
+    fn get_packages_needing_refresh_returns_stale_packages_oldest_first() {
+        let temp_dir = TempDir::new().unwrap();
+        let db_path = temp_dir.path().join("test.db");
+        // refresh_interval = 1 hour
+        let cache = Cache::new(&db_path, 3_600_000, false).unwrap();
+
+        for name in ["fresh", "stale", "stalest"] {
+            cache
+                .replace_versions(RegistryType::Npm, name, vec!["1.0.0".to_string()])
+                .unwrap();
+        }
+
+        // Backdate two packages beyond the refresh interval
+        let now = Cache::current_timestamp_ms();
+        {
+            let conn = cache.lock_conn().unwrap();
+            conn.execute(
+                "UPDATE packages SET updated_at = ?1 WHERE package_name = 'stale'",
+                [now - 2 * 3_600_000],
+            )
+            .unwrap();
+            conn.execute(
+                "UPDATE packages SET updated_at = ?1 WHERE package_name = 'stalest'",
+                [now - 5 * 3_600_000],
+            )
+            .unwrap();
+        }
+
+        let stale = cache.get_packages_needing_refresh().unwrap();
+        assert_eq!(
+            stale,
+            vec![
+                PackageId {
+                    registry_type: RegistryType::Npm,
+                    package_name: "stalest".to_string()
+                },
+                PackageId {
+                    registry_type: RegistryType::Npm,
+                    package_name: "stale".to_string()
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn filter_stale_packages_returns_cached_packages_older_than_refresh_interval() {
+        let temp_dir = TempDir::new().unwrap();
+        let db_path = temp_dir.path().join("test.db");
+        // refresh_interval = 100ms
+        let cache = Cache::new(&db_path, 100, false).unwrap();
+
+        cache
+            .replace_versions(RegistryType::Npm, "axios", vec!["1.0.0".to_string()])
+            .unwrap();
+
+        // Wait for axios to become stale, then cache a fresh lodash
+        std::thread::sleep(std::time::Duration::from_millis(150));
+        cache
+            .replace_versions(RegistryType::Npm, "lodash", vec!["4.0.0".to_string()])
+            .unwrap();
+
+        let names = vec![
+            "axios".to_string(),
+            "lodash".to_string(),
+            "missing".to_string(),
+        ];
+        let stale = cache
+            .filter_stale_packages(RegistryType::Npm, &names)
+            .unwrap();
+        assert_eq!(stale, vec!["axios".to_string()]);
+
+        // Stale entries are still served
+        assert_eq!(
+            cache
+                .get_latest_version(RegistryType::Npm, "axios")
+                .unwrap(),
+            Some("1.0.0".to_string())
+        );
+    }
+
+    #[test]
+    fn entries_beyond_max_stale_age_are_treated_as_missing() {
+        let temp_dir = TempDir::new().unwrap();
+        let db_path = temp_dir.path().join("test.db");
+        // refresh_interval = 50ms, max_stale_age = 100ms
+        let cache = Cache::new(&db_path, 50, false)
+            .unwrap()
+            .with_max_stale_age(Some(100));
+
+        cache
+            .replace_versions(RegistryType::Npm, "axios", vec!["1.0.0".to_string()])
+            .unwrap();
+
+        std::thread::sleep(std::time::Duration::from_millis(150));
+
+        let names = vec!["axios".to_string()];
+        assert!(
+            cache
+                .filter_stale_packages(RegistryType::Npm, &names)
+                .unwrap()
+                .is_empty()
+        );
+        assert_eq!(
+            cache
+                .filter_packages_not_in_cache(RegistryType::Npm, &names)
+                .unwrap(),
+            names
+        );
+        assert_eq!(
+            cache
+                .get_latest_version(RegistryType::Npm, "axios")
+                .unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn repeated_reads_are_served_from_memory() {
+        let temp_dir = TempDir::new().unwrap();
+        let db_path = temp_dir.path().join("test.db");
+        let cache = Cache::new(&db_path, 86400, false).unwrap();
+        cache
+            .replace_versions(RegistryType::Npm, "axios", vec!["1.0.0".to_string()])
+            .unwrap();
+
+        assert_eq!(
+            cache
+                .get_latest_version(RegistryType::Npm, "axios")
+                .unwrap()
+                .as_deref(),
+            Some("1.0.0")
+        );
+
+        // Only the first read reaches SQLite
+        cache
+            .lock_conn()
+            .unwrap()
+            .execute("DELETE FROM versions", [])
+            .unwrap();
+
+        assert_eq!(
+            cache
+                .get_latest_version(RegistryType::Npm, "axios")
+                .unwrap()
+                .as_deref(),
+            Some("1.0.0")
+        );
+        assert!(
+            cache
+                .version_exists(RegistryType::Npm, "axios", "1.0.0")
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn writes_replace_versions_held_in_memory() {
+        let temp_dir = TempDir::new().unwrap();
+        let db_path = temp_dir.path().join("test.db");
+        let cache = Cache::new(&db_path, 86400, false).unwrap();
+        cache
+            .replace_versions(RegistryType::Npm, "axios", vec!["1.0.0".to_string()])
+            .unwrap();
+        cache
+            .get_latest_version(RegistryType::Npm, "axios")
+            .unwrap();
+
+        cache
+            .replace_versions(RegistryType::Npm, "axios", vec!["1.1.0".to_string()])
+            .unwrap();
+
+        assert_eq!(
+            cache
+                .get_latest_version(RegistryType::Npm, "axios")
+                .unwrap()
+                .as_deref(),
+            Some("1.1.0")
+        );
+    }
+
     #[test]
     fn get_packages_needing_refresh_excludes_fresh_packages() {
         let temp_dir = TempDir::new().unwrap();
@@ -1135,6 +2166,60 @@ mod tests {
         assert_eq!(latest, Some("3.0.0-alpha".to_string())); // includes prerelease
     }
 
+    #[test]
+    fn save_package_stores_everything_fetched_and_clears_unreachable() {
+        let temp_dir = TempDir::new().unwrap();
+        let db_path = temp_dir.path().join("test.db");
+        let cache = Cache::new(&db_path, 86400, false).unwrap();
+        cache.mark_unreachable(RegistryType::CratesIo, "foo");
+
+        let package = PackageVersions::with_dist_tags(
+            vec!["1.0.0".to_string(), "1.1.0".to_string()],
+            HashMap::from([("latest".to_string(), "1.0.0".to_string())]),
+        )
+        .with_yanked(vec!["1.1.0".to_string()])
+        .with_published_at(HashMap::from([("1.0.0".to_string(), 1_600_000_000)]));
+        cache
+            .save_package(RegistryType::CratesIo, "foo", &package)
+            .unwrap();
+
+        assert!(!cache.is_unreachable(RegistryType::CratesIo, "foo"));
+        assert_eq!(
+            cache
+                .get_dist_tag(RegistryType::CratesIo, "foo", "latest")
+                .unwrap(),
+            Some("1.0.0".to_string())
+        );
+        assert_eq!(
+            cache
+                .get_yanked_versions(RegistryType::CratesIo, "foo")
+                .unwrap(),
+            vec!["1.1.0"]
+        );
+        assert_eq!(
+            cache
+                .get_published_at(RegistryType::CratesIo, "foo")
+                .unwrap(),
+            HashMap::from([("1.0.0".to_string(), 1_600_000_000)])
+        );
+    }
+
+    #[test]
+    fn mark_unreachable_is_cleared_when_versions_are_saved() {
+        let temp_dir = TempDir::new().unwrap();
+        let db_path = temp_dir.path().join("test.db");
+        let cache = Cache::new(&db_path, 86400, false).unwrap();
+
+        cache.mark_unreachable(RegistryType::Npm, "lodash");
+        assert!(cache.is_unreachable(RegistryType::Npm, "lodash"));
+        assert!(!cache.is_unreachable(RegistryType::CratesIo, "lodash"));
+
+        cache
+            .replace_versions(RegistryType::Npm, "lodash", vec!["4.17.21".to_string()])
+            .unwrap();
+        assert!(!cache.is_unreachable(RegistryType::Npm, "lodash"));
+    }
+
     #[test]
     fn get_latest_version_returns_none_when_all_versions_are_prerelease_and_filtering_enabled() {
         let temp_dir = TempDir::new().unwrap();
@@ -1291,6 +2376,76 @@ mod tests {
         assert_eq!(not_in_cache, vec!["express".to_string()]);
     }
 
+    #[test]
+    fn not_found_entries_expire_after_their_own_ttl() {
+        let temp_dir = TempDir::new().unwrap();
+        let db_path = temp_dir.path().join("test.db");
+        // Positive entries stay fresh for a day, negative ones for 100ms
+        let cache = Cache::new(&db_path, 86400000, false)
+            .unwrap()
+            .with_not_found_ttl(100);
+
+        cache.try_start_fetch(RegistryType::Npm, "typo").unwrap();
+        cache.mark_not_found(RegistryType::Npm, "typo").unwrap();
+        cache.finish_fetch(RegistryType::Npm, "typo").unwrap();
+        cache
+            .replace_versions(RegistryType::Npm, "axios", vec!["1.0.0".to_string()])
+            .unwrap();
+
+        let package_names = vec!["typo".to_string(), "axios".to_string()];
+        assert!(cache.is_not_found(RegistryType::Npm, "typo"));
+        assert!(
+            cache
+                .filter_packages_not_in_cache(RegistryType::Npm, &package_names)
+                .unwrap()
+                .is_empty()
+        );
+
+        std::thread::sleep(std::time::Duration::from_millis(150));
+
+        assert!(!cache.is_not_found(RegistryType::Npm, "typo"));
+        assert_eq!(
+            cache
+                .filter_packages_not_in_cache(RegistryType::Npm, &package_names)
+                .unwrap(),
+            vec!["typo".to_string()]
+        );
+    }
+
+    #[test]
+    fn saving_versions_clears_not_found() {
+        let temp_dir = TempDir::new().unwrap();
+        let db_path = temp_dir.path().join("test.db");
+        let cache = Cache::new(&db_path, 86400000, false).unwrap();
+
+        cache.try_start_fetch(RegistryType::Npm, "new-pkg").unwrap();
+        cache.mark_not_found(RegistryType::Npm, "new-pkg").unwrap();
+        cache
+            .replace_versions(RegistryType::Npm, "new-pkg", vec!["0.1.0".to_string()])
+            .unwrap();
+
+        assert_eq!(
+            found(&cache, RegistryType::Npm, "new-pkg").versions,
+            vec!["0.1.0"]
+        );
+        assert!(!cache.is_not_found(RegistryType::Npm, "new-pkg"));
+    }
+
+    #[test]
+    fn in_memory_database_keeps_versions_without_a_file() {
+        let cache = Cache::new(Path::new(IN_MEMORY_CACHE_PATH), 86400000, false).unwrap();
+
+        cache
+            .replace_versions(RegistryType::Npm, "pkg", vec!["1.0.0".to_string()])
+            .unwrap();
+
+        assert_eq!(
+            found(&cache, RegistryType::Npm, "pkg").versions,
+            vec!["1.0.0"]
+        );
+        assert!(!Path::new(IN_MEMORY_CACHE_PATH).exists());
+    }
+
     mod migrations {
         use super::*;
 
@@ -1353,14 +2508,14 @@ mod tests {
         }
 
         #[rstest]
-        // New DB: both columns added
-        #[case(false, false, 0, 2)]
+        // New DB: all columns added
+        #[case(false, false, 0, 5)]
         // Existing DB with fetching_since only: not_found added
-        #[case(true, false, 0, 2)]
+        #[case(true, false, 0, 5)]
         // Existing DB with both columns: skip (duplicate detection)
-        #[case(true, true, 0, 2)]
-        // Existing DB with user_version already set: skip migrations
-        #[case(true, true, 2, 2)]
+        #[case(true, true, 0, 5)]
+        // Existing DB at v2: only later migrations applied
+        #[case(true, true, 2, 5)]
         fn migration_applies_correctly(
             #[case] has_fetching_since: bool,
             #[case] has_not_found: bool,
@@ -1389,6 +2544,14 @@ mod tests {
                 column_exists(&conn, "packages", "not_found"),
                 "not_found should exist"
             );
+            assert!(
+                column_exists(&conn, "packages", "not_found_at"),
+                "not_found_at should exist"
+            );
+            assert!(
+                column_exists(&conn, "versions", "published_at"),
+                "published_at should exist"
+            );
             assert_eq!(get_user_version(&conn), expected_version);
         }
 
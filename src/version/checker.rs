@@ -3,10 +3,11 @@
 #[cfg(test)]
 use mockall::automock;
 
-use crate::parser::types::RegistryType;
+use crate::parser::types::{ExtraInfo, PackageInfo, RegistryType};
 use crate::version::error::CacheError;
 use crate::version::matcher::VersionMatcher;
-use crate::version::semver::CompareResult;
+use crate::version::semver::{CompareResult, is_prerelease, parse_version};
+use crate::version::types::PackageVersions;
 
 use crate::version::cache::PackageId;
 
@@ -77,6 +78,72 @@ pub trait VersionStorer: Send + Sync + 'static {
         dist_tags: &std::collections::HashMap<String, String>,
     ) -> Result<(), CacheError>;
 
+    /// Get the versions withdrawn from the registry (e.g., yanked crates)
+    fn get_yanked_versions(
+        &self,
+        registry_type: RegistryType,
+        package_name: &str,
+    ) -> Result<Vec<String>, CacheError>;
+
+    /// Save the versions withdrawn from the registry
+    fn save_yanked_versions(
+        &self,
+        registry_type: RegistryType,
+        package_name: &str,
+        yanked: &[String],
+    ) -> Result<(), CacheError>;
+
+    /// Get the publish time (Unix seconds) of each version, where known
+    ///
+    /// Storers that don't keep publish times report none.
+    fn get_published_at(
+        &self,
+        _registry_type: RegistryType,
+        _package_name: &str,
+    ) -> Result<std::collections::HashMap<String, i64>, CacheError> {
+        Ok(std::collections::HashMap::new())
+    }
+
+    /// Save the publish time (Unix seconds) of each version
+    ///
+    /// Storers that don't keep publish times ignore this.
+    fn save_published_at(
+        &self,
+        _registry_type: RegistryType,
+        _package_name: &str,
+        _published_at: &std::collections::HashMap<String, i64>,
+    ) -> Result<(), CacheError> {
+        Ok(())
+    }
+
+    /// Save everything fetched for a package: versions, dist tags, yanked
+    /// versions and publish times
+    ///
+    /// Storers that support it save all of it at once, so readers never see a
+    /// partial refresh. The default saves each part in turn.
+    fn save_package(
+        &self,
+        registry_type: RegistryType,
+        package_name: &str,
+        package_versions: &PackageVersions,
+    ) -> Result<(), CacheError> {
+        self.replace_versions(
+            registry_type,
+            package_name,
+            package_versions.versions.clone(),
+        )?;
+        if !package_versions.dist_tags.is_empty() {
+            self.save_dist_tags(registry_type, package_name, &package_versions.dist_tags)?;
+        }
+        if !package_versions.yanked.is_empty() {
+            self.save_yanked_versions(registry_type, package_name, &package_versions.yanked)?;
+        }
+        if !package_versions.published_at.is_empty() {
+            self.save_published_at(registry_type, package_name, &package_versions.published_at)?;
+        }
+        Ok(())
+    }
+
     /// Filter packages that are not in the cache
     /// Returns package names that have no entries in the cache
     fn filter_packages_not_in_cache(
@@ -92,6 +159,41 @@ pub trait VersionStorer: Send + Sync + 'static {
         registry_type: RegistryType,
         package_name: &str,
     ) -> Result<(), CacheError>;
+
+    /// Filter packages that are cached but older than the refresh interval
+    /// Returns package names whose stale versions can still be served while revalidating
+    fn filter_stale_packages(
+        &self,
+        registry_type: RegistryType,
+        package_names: &[String],
+    ) -> Result<Vec<String>, CacheError>;
+
+    /// Remove everything cached for a package so it is fetched again
+    fn invalidate(&self, registry_type: RegistryType, package_name: &str)
+    -> Result<(), CacheError>;
+
+    /// Change how old (ms) cached entries may get before they are refreshed
+    ///
+    /// Storers without a refresh interval ignore this.
+    fn set_refresh_interval(&self, _refresh_interval: i64) {}
+
+    /// Remember that the last fetch of a package failed (other than not found)
+    ///
+    /// Saving versions for the package clears this. Storers that can't keep
+    /// it ignore this.
+    fn mark_unreachable(&self, _registry_type: RegistryType, _package_name: &str) {}
+
+    /// Whether the registry recently reported that a package does not exist
+    ///
+    /// Storers that don't keep not-found packages ignore this.
+    fn is_not_found(&self, _registry_type: RegistryType, _package_name: &str) -> bool {
+        false
+    }
+
+    /// Whether the last fetch of a package failed (other than not found)
+    fn is_unreachable(&self, _registry_type: RegistryType, _package_name: &str) -> bool {
+        false
+    }
 }
 
 /// Result of version comparison
@@ -101,6 +203,12 @@ pub struct VersionCompareResult {
     pub current_version: String,
     /// Latest version from the registry (if available)
     pub latest_version: Option<String>,
+    /// Newest version within the current major.minor, when the package is
+    /// outdated and it differs from `latest_version`
+    pub latest_patch: Option<String>,
+    /// Newest version the current range allows, when range-aware updates are
+    /// on, the package is outdated and it differs from `latest_version`
+    pub latest_in_range: Option<String>,
     /// Version status
     pub status: VersionStatus,
 }
@@ -118,8 +226,12 @@ pub enum VersionStatus {
     Invalid,
     /// Version not found in cache
     NotInCache,
+    /// Not in cache because fetching it failed (network error, timeout, bad response)
+    Unreachable,
     /// Current version doesn't exist in registry
     NotFound,
+    /// Current version exists but has been withdrawn from the registry
+    Yanked,
 }
 
 /// Common npm dist-tag names that we should not treat as invalid versions
@@ -146,71 +258,410 @@ fn is_potential_dist_tag(version: &str) -> bool {
     KNOWN_DIST_TAGS.contains(&version.to_lowercase().as_str())
 }
 
+/// Whether a registry publishes dist tags that specs may name (npm)
+pub(crate) fn publishes_dist_tags(registry_type: RegistryType) -> bool {
+    matches!(registry_type, RegistryType::Npm | RegistryType::PnpmCatalog)
+}
+
+/// Whether a version spec has the shape of an npm dist tag (`latest`, `next`,
+/// `canary-2`): a name starting with a letter, without range syntax
+///
+/// Names that also parse as a range (`x`, `v1`) are left to the matcher.
+pub fn is_dist_tag_name(version: &str) -> bool {
+    version.starts_with(|c: char| c.is_ascii_alphabetic())
+        && version
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+}
+
+/// Settings that change which version a package is compared against
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CompareOptions {
+    /// Whether pre-releases may be suggested as the latest version of a stable spec
+    pub include_prerelease: bool,
+    /// Dist tag (e.g. `next`) npm versions are compared against instead of the newest
+    pub target_dist_tag: Option<String>,
+    /// Whether outdated packages report the newest version their range allows
+    pub range_aware_updates: bool,
+}
+
+/// Compare the version status for a parsed package
+///
+/// Versions excluded by the manifest itself (go.mod `exclude`) are never
+/// considered as the latest version.
+pub fn compare_package<S: VersionStorer>(
+    storer: &S,
+    matcher: &dyn VersionMatcher,
+    package: &PackageInfo,
+    options: &CompareOptions,
+) -> Result<VersionCompareResult, CacheError> {
+    let excluded: &[String] = match &package.extra_info {
+        Some(ExtraInfo::GoMod {
+            excluded_versions, ..
+        }) => excluded_versions,
+        _ => &[],
+    };
+    compare_in_registry(
+        storer,
+        matcher,
+        package.registry_type,
+        &package.name,
+        &package.version,
+        excluded,
+        options,
+    )
+}
+
 /// Compare the version status for a package
 pub fn compare_version<S: VersionStorer>(
     storer: &S,
     matcher: &dyn VersionMatcher,
     package_name: &str,
     current_version: &str,
+    options: &CompareOptions,
 ) -> Result<VersionCompareResult, CacheError> {
-    let registry_type = matcher.registry_type();
+    compare_version_excluding(storer, matcher, package_name, current_version, &[], options)
+}
 
-    // Get latest version from storer
-    let latest_version = storer.get_latest_version(registry_type, package_name)?;
+/// Compare the version status for a package, skipping `excluded` versions
+/// when determining the latest version
+pub fn compare_version_excluding<S: VersionStorer>(
+    storer: &S,
+    matcher: &dyn VersionMatcher,
+    package_name: &str,
+    current_version: &str,
+    excluded: &[String],
+    options: &CompareOptions,
+) -> Result<VersionCompareResult, CacheError> {
+    compare_in_registry(
+        storer,
+        matcher,
+        matcher.registry_type(),
+        package_name,
+        current_version,
+        excluded,
+        options,
+    )
+}
 
-    // If no versions in cache, return NotInCache
+/// Compare using versions cached under `registry_type`
+///
+/// Documents may mix registries (e.g. `npm:` imports in deno.json), so the
+/// cache is looked up by the package's registry rather than the matcher's.
+fn compare_in_registry<S: VersionStorer>(
+    storer: &S,
+    matcher: &dyn VersionMatcher,
+    registry_type: RegistryType,
+    package_name: &str,
+    current_version: &str,
+    excluded: &[String],
+    options: &CompareOptions,
+) -> Result<VersionCompareResult, CacheError> {
+    // Get latest version from storer, falling back to the highest non-excluded one
+    let latest_version = match storer.get_latest_version(registry_type, package_name)? {
+        Some(latest) if excluded.contains(&latest) => {
+            let versions = storer.get_versions(registry_type, package_name)?;
+            latest_not_excluded(&versions, excluded)
+        }
+        latest => latest,
+    };
+
+    // If no versions in cache, return NotInCache (or why they are missing).
+    // NotFound without a latest version means the package itself is missing.
     let Some(latest) = latest_version else {
+        let status = if storer.is_not_found(registry_type, package_name) {
+            VersionStatus::NotFound
+        } else if storer.is_unreachable(registry_type, package_name) {
+            VersionStatus::Unreachable
+        } else {
+            VersionStatus::NotInCache
+        };
         return Ok(VersionCompareResult {
             current_version: current_version.to_string(),
             latest_version: None,
-            status: VersionStatus::NotInCache,
+            latest_patch: None,
+            latest_in_range: None,
+            status,
         });
     };
 
     // Try to resolve dist-tag to actual version (e.g., "latest" -> "4.17.21")
     let dist_tag_resolution = storer.get_dist_tag(registry_type, package_name, current_version)?;
 
-    // If version looks like a dist-tag but we couldn't resolve it, return NotInCache
-    // This avoids showing "Invalid version format" for unresolved dist-tags like "latest"
+    // If version looks like a dist-tag but we couldn't resolve it, the package
+    // doesn't publish that tag when its tags are cached (every npm package has
+    // `latest`); otherwise return NotInCache. This avoids showing "Invalid
+    // version format" for unresolved dist-tags like "latest"
+    let names_dist_tag = is_potential_dist_tag(current_version)
+        || (publishes_dist_tags(registry_type)
+            && is_dist_tag_name(current_version)
+            && matcher.compare_to_latest(current_version, &latest) == CompareResult::Invalid);
     let resolved_version = match dist_tag_resolution {
         Some(version) => version,
-        None if is_potential_dist_tag(current_version) => {
+        None if names_dist_tag => {
+            let tags_cached = publishes_dist_tags(registry_type)
+                && storer
+                    .get_dist_tag(registry_type, package_name, "latest")?
+                    .is_some();
             return Ok(VersionCompareResult {
                 current_version: current_version.to_string(),
                 latest_version: Some(latest),
-                status: VersionStatus::NotInCache,
+                latest_patch: None,
+                latest_in_range: None,
+                status: if tags_cached {
+                    VersionStatus::NotFound
+                } else {
+                    VersionStatus::NotInCache
+                },
             });
         }
         None => current_version.to_string(),
     };
 
     // Check if current version exists in registry
-    let all_versions = storer.get_versions(registry_type, package_name)?;
-    let version_exists = matcher.version_exists(&resolved_version, &all_versions);
+    let all_versions = PackageVersions::new(storer.get_versions(registry_type, package_name)?);
+    let version_exists = matcher.version_exists(&resolved_version, &all_versions.versions);
+
+    // Compare against the configured dist tag when the package publishes it,
+    // otherwise stable specs against the latest stable release
+    let latest = match target_dist_tag_version(storer, registry_type, package_name, options)? {
+        Some(tagged) => tagged,
+        None => select_latest_version(
+            &all_versions,
+            &latest,
+            &resolved_version,
+            options.include_prerelease,
+        ),
+    };
 
     // Let matcher resolve the effective latest version (e.g., Docker suffix matching)
-    let effective_latest = matcher.resolve_latest(&resolved_version, &latest, &all_versions);
+    let effective_latest =
+        matcher.resolve_latest(&resolved_version, &latest, &all_versions.versions);
 
     // Compare versions
     let status = match matcher.compare_to_latest(&resolved_version, &effective_latest) {
         CompareResult::Invalid => VersionStatus::Invalid,
-        _ if !version_exists => VersionStatus::NotFound,
+        _ if !version_exists => {
+            let yanked = storer.get_yanked_versions(registry_type, package_name)?;
+            if matcher.version_exists(&resolved_version, &yanked) {
+                VersionStatus::Yanked
+            } else {
+                VersionStatus::NotFound
+            }
+        }
         CompareResult::Latest => VersionStatus::Latest,
         CompareResult::Outdated => VersionStatus::Outdated,
         CompareResult::Newer => VersionStatus::Newer,
     };
 
+    // Point out a newer patch in the current minor alongside the latest overall
+    let latest_patch = if status == VersionStatus::Outdated {
+        latest_in_minor(matcher, &resolved_version, &all_versions.versions, excluded)
+            .filter(|patch| *patch != effective_latest)
+    } else {
+        None
+    };
+
+    // Point out what a range-respecting update (`npm update`) would install
+    let latest_in_range = if status == VersionStatus::Outdated && options.range_aware_updates {
+        let candidates: Vec<String> = all_versions
+            .versions
+            .iter()
+            .filter(|v| !excluded.contains(v))
+            .cloned()
+            .collect();
+        matcher
+            .latest_in_range(&resolved_version, &candidates)
+            .filter(|in_range| *in_range != effective_latest)
+    } else {
+        None
+    };
+
     Ok(VersionCompareResult {
         current_version: current_version.to_string(),
         latest_version: Some(effective_latest),
+        latest_patch,
+        latest_in_range,
         status,
     })
 }
 
+/// How far a version trails the latest one
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VersionLag {
+    /// Releases published after the current version, up to and including the latest
+    pub releases: usize,
+    /// Seconds between the publish times of both versions, when the registry reports them
+    pub elapsed_secs: Option<i64>,
+}
+
+/// Count the releases and time separating `current_version` from `latest_version`
+///
+/// Range operators are dropped from the current version (`^1.2.0` counts from
+/// `1.2.0`). Returns None when either version is not cached, or the current
+/// one is not older than the latest.
+pub fn version_lag<S: VersionStorer>(
+    storer: &S,
+    registry_type: RegistryType,
+    package_name: &str,
+    current_version: &str,
+    latest_version: &str,
+) -> Result<Option<VersionLag>, CacheError> {
+    let versions = storer.get_versions(registry_type, package_name)?;
+    let current = if versions.iter().any(|v| v == current_version) {
+        current_version
+    } else {
+        current_version.trim_start_matches(['^', '~', '=', '>', ' '])
+    };
+    let Some(releases) = releases_behind(&versions, current, latest_version) else {
+        return Ok(None);
+    };
+
+    let published_at = storer.get_published_at(registry_type, package_name)?;
+    let elapsed_secs = published_at
+        .get(latest_version)
+        .zip(published_at.get(current))
+        .map(|(latest, current)| latest - current)
+        .filter(|secs| *secs > 0);
+
+    Ok(Some(VersionLag {
+        releases,
+        elapsed_secs,
+    }))
+}
+
+/// Number of releases after `current` up to and including `latest`
+///
+/// `versions` are in publish order (oldest first), as the cache keeps them,
+/// so backports published in between count too. Returns None when either
+/// version is missing or `latest` does not come after `current`.
+pub fn releases_behind(versions: &[String], current: &str, latest: &str) -> Option<usize> {
+    let current_pos = versions.iter().position(|v| v == current)?;
+    let latest_pos = versions.iter().position(|v| v == latest)?;
+    latest_pos
+        .checked_sub(current_pos)
+        .filter(|releases| *releases > 0)
+}
+
+/// Version of the configured target dist tag, for registries with dist tags (npm)
+fn target_dist_tag_version<S: VersionStorer>(
+    storer: &S,
+    registry_type: RegistryType,
+    package_name: &str,
+    options: &CompareOptions,
+) -> Result<Option<String>, CacheError> {
+    if !publishes_dist_tags(registry_type) {
+        return Ok(None);
+    }
+    let Some(tag) = &options.target_dist_tag else {
+        return Ok(None);
+    };
+    storer.get_dist_tag(registry_type, package_name, tag)
+}
+
+/// Find the newest non-excluded version in the current major.minor that the
+/// matcher considers an update, spelled as the registry lists it (e.g. `v0.13.5`)
+fn latest_in_minor(
+    matcher: &dyn VersionMatcher,
+    current_version: &str,
+    versions: &[String],
+    excluded: &[String],
+) -> Option<String> {
+    let candidates: Vec<String> = versions
+        .iter()
+        .filter(|v| !excluded.contains(v))
+        .cloned()
+        .collect();
+    let patch = parse_version(
+        &matcher
+            .calculate_bump_targets(current_version, &candidates)
+            .patch?,
+    )?;
+
+    candidates
+        .into_iter()
+        .find(|v| parse_version(v).as_ref() == Some(&patch))
+        .filter(|v| matcher.compare_to_latest(current_version, v) == CompareResult::Outdated)
+}
+
+/// Pick the latest version a spec should be compared against
+///
+/// Pre-releases are only considered when the current spec is itself a
+/// pre-release, or for any spec when `include_prerelease` is set (the highest
+/// version overall then wins over `latest`). The registry-reported `latest`
+/// (e.g. the npm `latest` dist-tag) is kept when it qualifies; otherwise the
+/// highest stable version is used, falling back to `latest` when no stable
+/// version exists.
+pub fn select_latest_version(
+    versions: &PackageVersions,
+    latest: &str,
+    current_version: &str,
+    include_prerelease: bool,
+) -> String {
+    if include_prerelease {
+        let latest_parsed = parse_version(latest);
+        return versions
+            .versions
+            .iter()
+            .filter_map(|v| Some((v, parse_version(v)?)))
+            .filter(|(_, parsed)| latest_parsed.as_ref().is_none_or(|l| parsed > l))
+            .max_by(|(_, a), (_, b)| a.cmp(b))
+            .map(|(v, _)| v.clone())
+            .unwrap_or_else(|| latest.to_string());
+    }
+
+    if !is_prerelease(latest) || is_prerelease(current_version) {
+        return latest.to_string();
+    }
+
+    versions
+        .versions
+        .iter()
+        .filter(|v| !is_prerelease(v))
+        .filter_map(|v| Some((v, parse_version(v)?)))
+        .max_by(|(_, a), (_, b)| a.cmp(b))
+        .map(|(v, _)| v.clone())
+        .unwrap_or_else(|| latest.to_string())
+}
+
+/// Newest stable Go release that is newer than a `go` or `toolchain` version
+///
+/// `go 1.21` (no patch) names a language version, so only a newer minor is
+/// an update; with a patch (`1.21.3`, as toolchains always have), a newer
+/// patch is too. Returns None when `current` doesn't parse.
+pub fn newer_go_release(current: &str, releases: &[String]) -> Option<String> {
+    let current_version = parse_version(current)?;
+    let (latest_version, latest) = releases
+        .iter()
+        .filter_map(|release| Some((parse_version(release)?, release)))
+        .filter(|(version, _)| version.pre.is_empty())
+        .max_by(|(a, _), (b, _)| a.cmp(b))?;
+
+    let is_newer = if current.split('.').count() > 2 {
+        latest_version > current_version
+    } else {
+        (latest_version.major, latest_version.minor)
+            > (current_version.major, current_version.minor)
+    };
+    is_newer.then(|| latest.clone())
+}
+
+/// Find the semantically highest version that is not excluded
+fn latest_not_excluded(versions: &[String], excluded: &[String]) -> Option<String> {
+    versions
+        .iter()
+        .filter(|v| !excluded.contains(v))
+        .filter_map(|v| Some((v, parse_version(v)?)))
+        .max_by(|(_, a), (_, b)| a.cmp(b))
+        .map(|(v, _)| v.clone())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::version::matchers::GitHubActionsMatcher;
+    use crate::version::matchers::{
+        CratesVersionMatcher, GitHubActionsMatcher, GoVersionMatcher, NpmVersionMatcher,
+    };
     use rstest::rstest;
 
     /// Mock storer for testing
@@ -218,6 +669,7 @@ mod tests {
         latest_version: Option<String>,
         existing_versions: Vec<String>,
         dist_tags: std::collections::HashMap<String, String>,
+        yanked_versions: Vec<String>,
     }
 
     impl MockStorer {
@@ -226,6 +678,7 @@ mod tests {
                 latest_version: latest.map(|s| s.to_string()),
                 existing_versions: versions.into_iter().map(|s| s.to_string()).collect(),
                 dist_tags: std::collections::HashMap::new(),
+                yanked_versions: vec![],
             }
         }
 
@@ -238,8 +691,14 @@ mod tests {
                 latest_version: latest.map(|s| s.to_string()),
                 existing_versions: versions.into_iter().map(|s| s.to_string()).collect(),
                 dist_tags,
+                yanked_versions: vec![],
             }
         }
+
+        fn with_yanked(mut self, yanked: Vec<&str>) -> Self {
+            self.yanked_versions = yanked.into_iter().map(|s| s.to_string()).collect();
+            self
+        }
     }
 
     impl VersionStorer for MockStorer {
@@ -324,6 +783,23 @@ mod tests {
             Ok(package_names.to_vec())
         }
 
+        fn get_yanked_versions(
+            &self,
+            _registry_type: RegistryType,
+            _package_name: &str,
+        ) -> Result<Vec<String>, CacheError> {
+            Ok(self.yanked_versions.clone())
+        }
+
+        fn save_yanked_versions(
+            &self,
+            _registry_type: RegistryType,
+            _package_name: &str,
+            _yanked: &[String],
+        ) -> Result<(), CacheError> {
+            Ok(())
+        }
+
         fn mark_not_found(
             &self,
             _registry_type: RegistryType,
@@ -331,6 +807,22 @@ mod tests {
         ) -> Result<(), CacheError> {
             Ok(())
         }
+
+        fn filter_stale_packages(
+            &self,
+            _registry_type: RegistryType,
+            _package_names: &[String],
+        ) -> Result<Vec<String>, CacheError> {
+            Ok(vec![])
+        }
+
+        fn invalidate(
+            &self,
+            _registry_type: RegistryType,
+            _package_name: &str,
+        ) -> Result<(), CacheError> {
+            Ok(())
+        }
     }
 
     #[rstest]
@@ -348,25 +840,89 @@ mod tests {
         let storer = MockStorer::new(Some(latest), existing);
         let matcher = GitHubActionsMatcher;
 
-        let result = compare_version(&storer, &matcher, "actions/checkout", current).unwrap();
+        let result = compare_version(
+            &storer,
+            &matcher,
+            "actions/checkout",
+            current,
+            &CompareOptions::default(),
+        )
+        .unwrap();
 
         assert_eq!(result.current_version, current);
         assert_eq!(result.latest_version, Some(latest.to_string()));
         assert_eq!(result.status, expected);
     }
 
+    #[rstest]
+    #[case("=1.0.1", VersionStatus::Yanked)]
+    #[case("=1.0.3", VersionStatus::NotFound)]
+    // The range still matches 1.0.2 and 1.1.0
+    #[case("1.0.1", VersionStatus::Latest)]
+    fn compare_version_reports_yanked_current_version(
+        #[case] current: &str,
+        #[case] expected: VersionStatus,
+    ) {
+        let storer = MockStorer::new(Some("1.1.0"), vec!["1.0.0", "1.0.2", "1.1.0"])
+            .with_yanked(vec!["1.0.1"]);
+
+        let result = compare_version(
+            &storer,
+            &CratesVersionMatcher,
+            "foo",
+            current,
+            &CompareOptions::default(),
+        )
+        .unwrap();
+
+        assert_eq!(result.status, expected);
+    }
+
+    #[rstest]
+    // A floor is met by any newer release
+    #[case(">=16.8.0", VersionStatus::Latest)]
+    #[case(">16.8.0", VersionStatus::Latest)]
+    // Only a floor above every release is reported
+    #[case(">=19.0.0", VersionStatus::NotFound)]
+    fn compare_version_treats_lower_bounds_as_floors(
+        #[case] current: &str,
+        #[case] expected: VersionStatus,
+    ) {
+        let storer = MockStorer::new(Some("18.3.1"), vec!["16.8.0", "17.0.2", "18.3.1"]);
+        let options = CompareOptions {
+            range_aware_updates: true,
+            ..Default::default()
+        };
+
+        let result =
+            compare_version(&storer, &NpmVersionMatcher, "react", current, &options).unwrap();
+
+        assert_eq!(result.status, expected);
+        assert_eq!(result.latest_patch, None);
+        assert_eq!(result.latest_in_range, None);
+    }
+
     #[test]
     fn compare_version_returns_not_in_cache_when_package_not_cached() {
         let storer = MockStorer::new(None, vec![]);
         let matcher = GitHubActionsMatcher;
 
-        let result = compare_version(&storer, &matcher, "nonexistent/repo", "1.0.0").unwrap();
+        let result = compare_version(
+            &storer,
+            &matcher,
+            "nonexistent/repo",
+            "1.0.0",
+            &CompareOptions::default(),
+        )
+        .unwrap();
 
         assert_eq!(
             result,
             VersionCompareResult {
                 current_version: "1.0.0".to_string(),
                 latest_version: None,
+                latest_patch: None,
+                latest_in_range: None,
                 status: VersionStatus::NotInCache,
             }
         );
@@ -392,14 +948,318 @@ mod tests {
         let storer = MockStorer::new(Some(latest), existing);
         let matcher = GitHubActionsMatcher;
 
-        let result = compare_version(&storer, &matcher, "actions/checkout", current).unwrap();
+        let result = compare_version(
+            &storer,
+            &matcher,
+            "actions/checkout",
+            current,
+            &CompareOptions::default(),
+        )
+        .unwrap();
+
+        assert_eq!(result.status, expected);
+    }
 
+    #[rstest]
+    // Excluded latest falls back to the highest remaining version
+    #[case("v1.1.0", vec!["v1.2.0"], Some("v1.1.0"), VersionStatus::Latest)]
+    #[case("v1.0.0", vec!["v1.2.0"], Some("v1.1.0"), VersionStatus::Outdated)]
+    #[case("v1.0.0", vec!["v1.2.0", "v1.1.0"], Some("v1.0.0"), VersionStatus::Latest)]
+    // Excluding a non-latest version doesn't change the latest
+    #[case("v1.1.0", vec!["v1.0.0"], Some("v1.2.0"), VersionStatus::Outdated)]
+    fn compare_version_excluding_skips_excluded_latest(
+        #[case] current: &str,
+        #[case] excluded: Vec<&str>,
+        #[case] expected_latest: Option<&str>,
+        #[case] expected: VersionStatus,
+    ) {
+        let storer = MockStorer::new(Some("v1.2.0"), vec!["v1.0.0", "v1.1.0", "v1.2.0"]);
+        let excluded: Vec<String> = excluded.into_iter().map(String::from).collect();
+
+        let result = compare_version_excluding(
+            &storer,
+            &GoVersionMatcher,
+            "golang.org/x/text",
+            current,
+            &excluded,
+            &CompareOptions::default(),
+        )
+        .unwrap();
+
+        assert_eq!(result.latest_version.as_deref(), expected_latest);
         assert_eq!(result.status, expected);
     }
 
+    #[rstest]
+    // A newer patch in the current minor is reported next to the latest
+    #[case(
+        "v0.13.0",
+        vec!["v0.13.0", "v0.13.5", "v0.14.1"],
+        VersionStatus::Outdated,
+        Some("v0.13.5")
+    )]
+    // Only a newer minor exists
+    #[case("v0.13.0", vec!["v0.13.0", "v0.14.1"], VersionStatus::Outdated, None)]
+    // Already on the latest patch of an older minor
+    #[case(
+        "v0.13.5",
+        vec!["v0.13.0", "v0.13.5", "v0.14.1"],
+        VersionStatus::Outdated,
+        None
+    )]
+    fn compare_version_reports_latest_patch_in_current_minor(
+        #[case] current: &str,
+        #[case] existing: Vec<&str>,
+        #[case] expected_status: VersionStatus,
+        #[case] expected_patch: Option<&str>,
+    ) {
+        let storer = MockStorer::new(Some("v0.14.1"), existing);
+
+        let result = compare_version(
+            &storer,
+            &GoVersionMatcher,
+            "golang.org/x/text",
+            current,
+            &CompareOptions::default(),
+        )
+        .unwrap();
+
+        assert_eq!(result.status, expected_status);
+        assert_eq!(result.latest_version.as_deref(), Some("v0.14.1"));
+        assert_eq!(result.latest_patch.as_deref(), expected_patch);
+    }
+
+    #[test]
+    fn compare_version_omits_latest_patch_when_it_is_the_latest() {
+        let storer = MockStorer::new(Some("v0.13.5"), vec!["v0.13.0", "v0.13.5"]);
+
+        let result = compare_version(
+            &storer,
+            &GoVersionMatcher,
+            "golang.org/x/text",
+            "v0.13.0",
+            &CompareOptions::default(),
+        )
+        .unwrap();
+
+        assert_eq!(result.status, VersionStatus::Outdated);
+        assert_eq!(result.latest_patch, None);
+    }
+
+    #[test]
+    fn compare_package_uses_go_mod_excluded_versions() {
+        let storer = MockStorer::new(Some("v1.2.0"), vec!["v1.0.0", "v1.1.0", "v1.2.0"]);
+        let package = PackageInfo {
+            name: "golang.org/x/text".to_string(),
+            version: "v1.1.0".to_string(),
+            commit_hash: None,
+            registry_type: RegistryType::GoProxy,
+            start_offset: 0,
+            end_offset: 6,
+            line: 0,
+            column: 0,
+            extra_info: Some(ExtraInfo::GoMod {
+                excluded_versions: vec!["v1.2.0".to_string()],
+                indirect: false,
+            }),
+        };
+
+        let result = compare_package(
+            &storer,
+            &GoVersionMatcher,
+            &package,
+            &CompareOptions::default(),
+        )
+        .unwrap();
+
+        assert_eq!(result.latest_version.as_deref(), Some("v1.1.0"));
+        assert_eq!(result.status, VersionStatus::Latest);
+    }
+
+    #[rstest]
+    // Stable spec skips a pre-release latest
+    #[case("^1.0", "1.9.0")]
+    #[case("1.9.0", "1.9.0")]
+    // Pre-release spec keeps the pre-release latest
+    #[case("2.0.0-rc.0", "2.0.0-rc.1")]
+    #[case("^2.0.0-beta.1", "2.0.0-rc.1")]
+    fn select_latest_version_prefers_stable_for_stable_spec(
+        #[case] current: &str,
+        #[case] expected: &str,
+    ) {
+        let versions = PackageVersions::new(
+            ["1.0.0", "1.9.0", "2.0.0-beta.1", "2.0.0-rc.0", "2.0.0-rc.1"]
+                .into_iter()
+                .map(String::from)
+                .collect(),
+        );
+
+        assert_eq!(
+            select_latest_version(&versions, "2.0.0-rc.1", current, false),
+            expected
+        );
+    }
+
+    #[test]
+    fn select_latest_version_keeps_prerelease_when_no_stable_exists() {
+        let versions = PackageVersions::new(vec!["1.0.0-rc.1".to_string()]);
+
+        assert_eq!(
+            select_latest_version(&versions, "1.0.0-rc.1", "^1.0", false),
+            "1.0.0-rc.1"
+        );
+    }
+
+    #[test]
+    fn compare_version_suggests_stable_latest_over_release_candidate() {
+        let storer = MockStorer::new(
+            Some("2.0.0-rc.1"),
+            vec!["1.0.0", "1.5.0", "1.9.0", "2.0.0-rc.1"],
+        );
+
+        let result = compare_version(
+            &storer,
+            &CratesVersionMatcher,
+            "serde",
+            "^1.0",
+            &CompareOptions::default(),
+        )
+        .unwrap();
+
+        assert_eq!(result.latest_version.as_deref(), Some("1.9.0"));
+        assert_eq!(result.status, VersionStatus::Latest);
+
+        let result = compare_version(
+            &storer,
+            &CratesVersionMatcher,
+            "serde",
+            "~1.5.0",
+            &CompareOptions::default(),
+        )
+        .unwrap();
+
+        assert_eq!(result.latest_version.as_deref(), Some("1.9.0"));
+        assert_eq!(result.status, VersionStatus::Outdated);
+    }
+
+    #[rstest]
+    #[case(false, "1.9.0", VersionStatus::Latest)]
+    #[case(true, "2.0.0-beta.2", VersionStatus::Outdated)]
+    fn compare_version_honors_include_prerelease(
+        #[case] include_prerelease: bool,
+        #[case] expected_latest: &str,
+        #[case] expected_status: VersionStatus,
+    ) {
+        let storer = MockStorer::new(
+            Some("1.9.0"),
+            vec!["1.0.0", "1.9.0", "2.0.0-beta.1", "2.0.0-beta.2"],
+        );
+        let options = CompareOptions {
+            include_prerelease,
+            ..Default::default()
+        };
+
+        let result =
+            compare_version(&storer, &CratesVersionMatcher, "serde", "=1.9.0", &options).unwrap();
+
+        assert_eq!(result.latest_version.as_deref(), Some(expected_latest));
+        assert_eq!(result.status, expected_status);
+    }
+
+    #[rstest]
+    // The registry latest stays when nothing newer exists
+    #[case("2.0.0-rc.1", "2.0.0-rc.1")]
+    // A newer pre-release wins over a stable latest
+    #[case("1.9.0", "2.0.0-rc.1")]
+    fn select_latest_version_with_include_prerelease_picks_highest(
+        #[case] latest: &str,
+        #[case] expected: &str,
+    ) {
+        let versions = PackageVersions::new(
+            ["1.0.0", "1.9.0", "2.0.0-beta.1", "2.0.0-rc.1"]
+                .into_iter()
+                .map(String::from)
+                .collect(),
+        );
+
+        assert_eq!(
+            select_latest_version(&versions, latest, "^1.0", true),
+            expected
+        );
+    }
+
+    #[rstest]
+    // A language version is outdated only by a newer minor
+    #[case("1.21", Some("1.23.4"))]
+    #[case("1.23", None)]
+    // Toolchain versions (with a patch) are outdated by a newer patch too
+    #[case("1.22.0", Some("1.23.4"))]
+    #[case("1.23.1", Some("1.23.4"))]
+    #[case("1.23.4", None)]
+    #[case("1.24.0", None)]
+    #[case("not-a-version", None)]
+    fn newer_go_release_compares_against_latest_stable_release(
+        #[case] current: &str,
+        #[case] expected: Option<&str>,
+    ) {
+        let releases: Vec<String> = ["1.22.10", "1.23.4", "1.24.0-rc.1"]
+            .into_iter()
+            .map(String::from)
+            .collect();
+
+        assert_eq!(newer_go_release(current, &releases).as_deref(), expected);
+    }
+
+    #[rstest]
+    #[case("1.0.0", "2.0.0", Some(4))]
+    // A backport published after 2.0.0 is not behind it
+    #[case("1.1.0", "2.0.0", Some(2))]
+    #[case("2.0.0", "2.0.0", None)]
+    #[case("2.0.0", "1.1.0", None)]
+    #[case("0.9.0", "2.0.0", None)]
+    fn releases_behind_counts_releases_published_since_current(
+        #[case] current: &str,
+        #[case] latest: &str,
+        #[case] expected: Option<usize>,
+    ) {
+        let versions: Vec<String> = ["1.0.0", "1.0.1", "1.1.0", "1.2.0", "2.0.0", "1.2.1"]
+            .into_iter()
+            .map(String::from)
+            .collect();
+
+        assert_eq!(releases_behind(&versions, current, latest), expected);
+    }
+
+    #[test]
+    fn version_lag_reports_releases_and_time_between_publishes() {
+        let mut storer = MockVersionStorer::new();
+        storer.expect_get_versions().returning(|_, _| {
+            Ok(vec![
+                "1.0.0".to_string(),
+                "1.1.0".to_string(),
+                "2.0.0".to_string(),
+            ])
+        });
+        storer.expect_get_published_at().returning(|_, _| {
+            Ok(std::collections::HashMap::from([
+                ("1.0.0".to_string(), 1_000),
+                ("2.0.0".to_string(), 91_000),
+            ]))
+        });
+
+        let lag = version_lag(&storer, RegistryType::Npm, "foo", "^1.0.0", "2.0.0").unwrap();
+
+        assert_eq!(
+            lag,
+            Some(VersionLag {
+                releases: 2,
+                elapsed_secs: Some(90_000),
+            })
+        );
+    }
+
     mod dist_tags {
         use super::*;
-        use crate::version::matchers::NpmVersionMatcher;
 
         #[test]
         fn compare_version_resolves_latest_dist_tag_to_actual_version() {
@@ -411,7 +1271,14 @@ mod tests {
             let matcher = NpmVersionMatcher;
 
             // "latest" should resolve to "4.17.21" which is the latest
-            let result = compare_version(&storer, &matcher, "lodash", "latest").unwrap();
+            let result = compare_version(
+                &storer,
+                &matcher,
+                "lodash",
+                "latest",
+                &CompareOptions::default(),
+            )
+            .unwrap();
 
             assert_eq!(result.status, VersionStatus::Latest);
             assert_eq!(result.current_version, "latest");
@@ -430,7 +1297,14 @@ mod tests {
             let matcher = NpmVersionMatcher;
 
             // "beta" should resolve to "5.0.0-beta.1" which is newer than latest stable
-            let result = compare_version(&storer, &matcher, "lodash", "beta").unwrap();
+            let result = compare_version(
+                &storer,
+                &matcher,
+                "lodash",
+                "beta",
+                &CompareOptions::default(),
+            )
+            .unwrap();
 
             assert_eq!(result.status, VersionStatus::Newer);
             assert_eq!(result.current_version, "beta");
@@ -447,7 +1321,14 @@ mod tests {
 
             // "latest" is a potential dist-tag, but we don't have dist-tag info
             // Return NotInCache to avoid confusing "Invalid version format" error
-            let result = compare_version(&storer, &matcher, "lodash", "latest").unwrap();
+            let result = compare_version(
+                &storer,
+                &matcher,
+                "lodash",
+                "latest",
+                &CompareOptions::default(),
+            )
+            .unwrap();
 
             assert_eq!(result.status, VersionStatus::NotInCache);
         }
@@ -462,11 +1343,52 @@ mod tests {
             let matcher = NpmVersionMatcher;
 
             // "beta" is a potential dist-tag that we can't resolve
-            let result = compare_version(&storer, &matcher, "lodash", "beta").unwrap();
+            let result = compare_version(
+                &storer,
+                &matcher,
+                "lodash",
+                "beta",
+                &CompareOptions::default(),
+            )
+            .unwrap();
 
             assert_eq!(result.status, VersionStatus::NotInCache);
         }
 
+        #[rstest]
+        // Tags the package publishes resolve to their version
+        #[case("latest", VersionStatus::Latest)]
+        #[case("next", VersionStatus::Newer)]
+        // Tags it doesn't publish, known names or not
+        #[case("beta", VersionStatus::NotFound)]
+        #[case("nightly-build", VersionStatus::NotFound)]
+        fn compare_version_checks_dist_tag_specs_against_published_tags(
+            #[case] current: &str,
+            #[case] expected: VersionStatus,
+        ) {
+            let dist_tags = std::collections::HashMap::from([
+                ("latest".to_string(), "4.17.21".to_string()),
+                ("next".to_string(), "5.0.0-rc.1".to_string()),
+            ]);
+            let storer = MockStorer::with_dist_tags(
+                Some("4.17.21"),
+                vec!["4.17.20", "4.17.21", "5.0.0-rc.1"],
+                dist_tags,
+            );
+
+            let result = compare_version(
+                &storer,
+                &NpmVersionMatcher,
+                "lodash",
+                current,
+                &CompareOptions::default(),
+            )
+            .unwrap();
+
+            assert_eq!(result.status, expected);
+            assert_eq!(result.current_version, current);
+        }
+
         #[test]
         fn compare_version_returns_invalid_for_truly_invalid_version() {
             let storer = MockStorer::with_dist_tags(
@@ -477,9 +1399,93 @@ mod tests {
             let matcher = NpmVersionMatcher;
 
             // "invalid@#$" is not a valid semver and not a potential dist-tag
-            let result = compare_version(&storer, &matcher, "lodash", "invalid@#$").unwrap();
+            let result = compare_version(
+                &storer,
+                &matcher,
+                "lodash",
+                "invalid@#$",
+                &CompareOptions::default(),
+            )
+            .unwrap();
 
             assert_eq!(result.status, VersionStatus::Invalid);
         }
+
+        #[rstest]
+        #[case(None, "18.3.1", VersionStatus::Latest)]
+        #[case(Some("latest"), "18.3.1", VersionStatus::Latest)]
+        #[case(Some("next"), "19.0.0-rc.1", VersionStatus::Outdated)]
+        // Packages without the tag fall back to the newest version
+        #[case(Some("canary"), "18.3.1", VersionStatus::Latest)]
+        fn compare_version_targets_configured_dist_tag(
+            #[case] target_dist_tag: Option<&str>,
+            #[case] expected_latest: &str,
+            #[case] expected_status: VersionStatus,
+        ) {
+            let dist_tags = std::collections::HashMap::from([
+                ("latest".to_string(), "18.3.1".to_string()),
+                ("next".to_string(), "19.0.0-rc.1".to_string()),
+            ]);
+            let storer = MockStorer::with_dist_tags(
+                Some("18.3.1"),
+                vec!["18.2.0", "18.3.1", "19.0.0-rc.1"],
+                dist_tags,
+            );
+            let options = CompareOptions {
+                target_dist_tag: target_dist_tag.map(str::to_string),
+                ..Default::default()
+            };
+
+            let result =
+                compare_version(&storer, &NpmVersionMatcher, "react", "^18.3.1", &options).unwrap();
+
+            assert_eq!(result.latest_version.as_deref(), Some(expected_latest));
+            assert_eq!(result.status, expected_status);
+        }
+
+        #[test]
+        fn compare_version_ignores_target_dist_tag_outside_npm() {
+            let dist_tags =
+                std::collections::HashMap::from([("next".to_string(), "2.0.0".to_string())]);
+            let storer =
+                MockStorer::with_dist_tags(Some("1.9.0"), vec!["1.9.0", "2.0.0"], dist_tags);
+            let options = CompareOptions {
+                target_dist_tag: Some("next".to_string()),
+                ..Default::default()
+            };
+
+            let result =
+                compare_version(&storer, &CratesVersionMatcher, "serde", "1.9.0", &options)
+                    .unwrap();
+
+            assert_eq!(result.latest_version.as_deref(), Some("1.9.0"));
+        }
+
+        #[rstest]
+        #[case(false, "~1.2.0", VersionStatus::Outdated, None)]
+        #[case(true, "~1.2.0", VersionStatus::Outdated, Some("1.2.9"))]
+        // Already on the newest version the range allows
+        #[case(true, "~1.2.9", VersionStatus::Outdated, None)]
+        // The range reaches the latest, so there is nothing to add
+        #[case(true, "^1.2.0", VersionStatus::Latest, None)]
+        fn compare_version_reports_latest_in_range_when_range_aware(
+            #[case] range_aware_updates: bool,
+            #[case] current: &str,
+            #[case] expected_status: VersionStatus,
+            #[case] expected_in_range: Option<&str>,
+        ) {
+            let storer = MockStorer::new(Some("1.3.4"), vec!["1.2.0", "1.2.9", "1.3.4"]);
+            let options = CompareOptions {
+                range_aware_updates,
+                ..Default::default()
+            };
+
+            let result =
+                compare_version(&storer, &NpmVersionMatcher, "lodash", current, &options).unwrap();
+
+            assert_eq!(result.status, expected_status);
+            assert_eq!(result.latest_version.as_deref(), Some("1.3.4"));
+            assert_eq!(result.latest_in_range.as_deref(), expected_in_range);
+        }
     }
 }
@@ -1,3 +1,5 @@
+use std::sync::Arc;
+
 use thiserror::Error;
 
 #[derive(Debug, Error)]
@@ -9,10 +11,14 @@ pub enum CacheError {
     LockPoisoned,
 }
 
-#[derive(Debug, Error)]
+/// Cloneable so coalesced fetches can hand the same outcome to every waiter
+#[derive(Debug, Clone, Error)]
 pub enum RegistryError {
     #[error("Network error: {0}")]
-    Network(#[from] reqwest::Error),
+    Network(#[source] Arc<reqwest::Error>),
+
+    #[error("Request timed out")]
+    Timeout,
 
     #[error("Rate limited: retry after {retry_after_secs:?} seconds")]
     RateLimited { retry_after_secs: Option<u64> },
@@ -23,3 +29,13 @@ pub enum RegistryError {
     #[error("Invalid response: {0}")]
     InvalidResponse(String),
 }
+
+impl From<reqwest::Error> for RegistryError {
+    fn from(e: reqwest::Error) -> Self {
+        if e.is_timeout() {
+            RegistryError::Timeout
+        } else {
+            RegistryError::Network(Arc::new(e))
+        }
+    }
+}
@@ -53,6 +53,19 @@ pub trait VersionMatcher: Send + Sync {
         latest_version.to_string()
     }
 
+    /// Newest stable version in `available_versions` that the spec allows,
+    /// when it is newer than the version the spec names
+    ///
+    /// This is what a range-respecting update (e.g. `npm update`) would install.
+    /// Matchers without range semantics return None.
+    fn latest_in_range(
+        &self,
+        _version_spec: &str,
+        _available_versions: &[String],
+    ) -> Option<String> {
+        None
+    }
+
     /// Calculate bump targets (patch, next/latest minor, next/latest major) for code actions.
     ///
     /// Default implementation uses semver-based calculation.
@@ -0,0 +1,134 @@
+//! Composer version matcher
+//!
+//! Composer constraints follow npm's semantics closely, so they are rewritten
+//! into npm syntax and matched with the npm logic:
+//! - `^1.2.3`, `1.2.*`, `>=1.0 <2.0`, exact versions - same as npm
+//! - `~1.2` - next significant release: >=1.2.0 <2.0.0 (npm's `~1.2` stops at 1.3)
+//! - `>=1.0,<2.0` - comma-separated constraints (all must match)
+//! - `^1.0 | ^2.0` - a single `|` is an OR like `||`
+//! - `v1.2.3` - the `v` prefix is ignored
+//! - `^1.0@beta` - stability flags are ignored
+
+use crate::parser::types::RegistryType;
+use crate::version::matcher::VersionMatcher;
+use crate::version::matchers::npm::{npm_compare_to_latest, npm_version_exists};
+use crate::version::semver::CompareResult;
+
+pub struct ComposerVersionMatcher;
+
+impl VersionMatcher for ComposerVersionMatcher {
+    fn registry_type(&self) -> RegistryType {
+        RegistryType::Composer
+    }
+
+    fn version_exists(&self, version_spec: &str, available_versions: &[String]) -> bool {
+        npm_version_exists(&to_npm_spec(version_spec), available_versions)
+    }
+
+    fn compare_to_latest(&self, current_version: &str, latest_version: &str) -> CompareResult {
+        npm_compare_to_latest(
+            &to_npm_spec(current_version),
+            latest_version.trim_start_matches('v'),
+        )
+    }
+}
+
+/// Rewrite a Composer constraint into the equivalent npm range
+fn to_npm_spec(spec: &str) -> String {
+    spec.split('|')
+        .map(str::trim)
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            part.split([',', ' '])
+                .filter(|token| !token.is_empty())
+                .map(to_npm_range)
+                .collect::<Vec<_>>()
+                .join(" ")
+        })
+        .collect::<Vec<_>>()
+        .join(" || ")
+}
+
+/// Rewrite a single constraint such as `~1.2` or `^v2.0@dev`
+fn to_npm_range(constraint: &str) -> String {
+    // Drop the stability flag (`@dev`, `@beta`, ...)
+    let constraint = constraint.split('@').next().unwrap_or(constraint);
+
+    let operator_len = constraint
+        .find(|c: char| !matches!(c, '<' | '>' | '=' | '!' | '^' | '~'))
+        .unwrap_or(constraint.len());
+    let (operator, version) = constraint.split_at(operator_len);
+    let version = version.trim_start_matches(['v', 'V']);
+
+    // Composer's `~X.Y` allows minor updates, unlike npm's
+    if operator == "~"
+        && let Some((major, minor)) = version.split_once('.')
+        && let (Ok(major), Ok(_)) = (major.parse::<u64>(), minor.parse::<u64>())
+    {
+        return format!(">={}.0 <{}.0.0", version, major + 1);
+    }
+
+    format!("{}{}", operator, version)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("^8.1", "^8.1")]
+    #[case("~1.2", ">=1.2.0 <2.0.0")]
+    #[case("~1.2.3", "~1.2.3")]
+    #[case(">=1.0,<2.0", ">=1.0 <2.0")]
+    #[case(">=1.0, <2.0", ">=1.0 <2.0")]
+    #[case("^1.0 | ^2.0", "^1.0 || ^2.0")]
+    #[case("^1.0 || ^2.0", "^1.0 || ^2.0")]
+    #[case("v2.1.0", "2.1.0")]
+    #[case("^3.0@beta", "^3.0")]
+    #[case("1.2.*", "1.2.*")]
+    fn to_npm_spec_rewrites_composer_constraints(#[case] spec: &str, #[case] expected: &str) {
+        assert_eq!(to_npm_spec(spec), expected);
+    }
+
+    #[rstest]
+    #[case("^8.1", vec!["8.3.0"], true)]
+    #[case("^8.1", vec!["9.0.0"], false)]
+    #[case("~1.2", vec!["1.9.0"], true)]
+    #[case("~1.2", vec!["2.0.0"], false)]
+    #[case("~1.2.3", vec!["1.3.0"], false)]
+    #[case(">=2.0,<3.0", vec!["2.5.0"], true)]
+    #[case("^1.0 | ^2.0", vec!["2.1.0"], true)]
+    #[case("v3.5.0", vec!["3.5.0"], true)]
+    #[case("", vec!["1.0.0"], false)]
+    fn version_exists_returns_expected(
+        #[case] version_spec: &str,
+        #[case] available: Vec<&str>,
+        #[case] expected: bool,
+    ) {
+        let available: Vec<String> = available.into_iter().map(|s| s.to_string()).collect();
+        assert_eq!(
+            ComposerVersionMatcher.version_exists(version_spec, &available),
+            expected
+        );
+    }
+
+    #[rstest]
+    #[case("^3.0", "3.5.0", CompareResult::Latest)]
+    #[case("^2.9", "3.5.0", CompareResult::Outdated)]
+    #[case("~3.4", "3.5.0", CompareResult::Latest)]
+    #[case("~3.4.0", "3.5.0", CompareResult::Outdated)]
+    #[case("^7.0", "v7.0.1", CompareResult::Latest)]
+    #[case("4.0.0", "3.5.0", CompareResult::Newer)]
+    #[case("invalid", "3.5.0", CompareResult::Invalid)]
+    fn compare_to_latest_returns_expected(
+        #[case] current: &str,
+        #[case] latest: &str,
+        #[case] expected: CompareResult,
+    ) {
+        assert_eq!(
+            ComposerVersionMatcher.compare_to_latest(current, latest),
+            expected
+        );
+    }
+}
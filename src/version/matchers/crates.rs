@@ -2,10 +2,13 @@
 //!
 //! Supports Cargo version requirement specifications:
 //! - `1.2.3` - default (caret-like): >=1.2.3 <2.0.0 (or special cases for 0.x)
+//! - `1.2`, `1`, `0.0` - partial: same as `^1.2.0`, `1.*`, `0.0.*`
 //! - `^1.2.3` - explicit caret (same as default)
 //! - `~1.2.3` - tilde: >=1.2.3 <1.3.0
 //! - `>=1.2.3`, `>1.2.3`, `<=1.2.3`, `<1.2.3`, `=1.2.3` - comparison operators
+//! - `=1.2`, `=1` - partial exact: same as `1.2.*`, `1.*`
 //! - `1.2.*`, `1.*`, `*` - wildcards
+//! - `>=1.2, <1.5` - comma-separated requirements, all of which must match
 
 use semver::Version;
 
@@ -54,18 +57,69 @@ impl VersionRequirement {
         } else if let Some(rest) = spec.strip_prefix('<') {
             parse_version(rest.trim()).map(VersionRequirement::Lt)
         } else if let Some(rest) = spec.strip_prefix('=') {
-            parse_version(rest.trim()).map(VersionRequirement::Exact)
+            Self::parse_exact(rest.trim())
         } else if let Some(rest) = spec.strip_prefix('^') {
-            parse_version(rest.trim()).map(VersionRequirement::Caret)
+            Self::parse_caret(rest.trim())
         } else if let Some(rest) = spec.strip_prefix('~') {
-            parse_version(rest.trim()).map(VersionRequirement::Tilde)
+            Self::parse_tilde(rest.trim())
         } else if spec == "*" {
             Some(VersionRequirement::Any)
         } else if let Some(req) = Self::parse_wildcard(spec) {
             Some(req)
         } else {
             // Default (no prefix) behaves like caret in Cargo
-            parse_version(spec).map(VersionRequirement::Caret)
+            Self::parse_caret(spec)
+        }
+    }
+
+    /// Parse the version of a caret (or bare) requirement
+    ///
+    /// Missing components are not simply zero-filled: `^1` is `1.*` and
+    /// `^0.0` is `0.0.*`, whereas `^0.0.0` would only match `0.0.0`.
+    fn parse_caret(version: &str) -> Option<Self> {
+        match Self::parse_partial(version) {
+            Some((major, None)) => Some(VersionRequirement::WildcardMajor(major)),
+            Some((0, Some(0))) => Some(VersionRequirement::WildcardMinor(0, 0)),
+            _ => parse_version(version).map(VersionRequirement::Caret),
+        }
+    }
+
+    /// Parse the version of a tilde requirement (`~1` is `1.*`)
+    fn parse_tilde(version: &str) -> Option<Self> {
+        match Self::parse_partial(version) {
+            Some((major, None)) => Some(VersionRequirement::WildcardMajor(major)),
+            _ => parse_version(version).map(VersionRequirement::Tilde),
+        }
+    }
+
+    /// Split a partial version (`1` or `1.2`) into its major and minor
+    fn parse_partial(version: &str) -> Option<(u64, Option<u64>)> {
+        match version.split('.').collect::<Vec<_>>().as_slice() {
+            [major] => Some((major.parse().ok()?, None)),
+            [major, minor] => Some((major.parse().ok()?, Some(minor.parse().ok()?))),
+            _ => None,
+        }
+    }
+
+    /// Parse the version of an `=` requirement
+    ///
+    /// Cargo only pins the components that are given: `=1.2` matches any
+    /// `1.2.x` and `=1` any `1.x.y`.
+    fn parse_exact(version: &str) -> Option<Self> {
+        let core = version.split(['-', '+']).next().unwrap_or(version);
+        match core.split('.').count() {
+            1 => core
+                .parse::<u64>()
+                .ok()
+                .map(VersionRequirement::WildcardMajor),
+            2 => {
+                let (major, minor) = core.split_once('.')?;
+                Some(VersionRequirement::WildcardMinor(
+                    major.parse().ok()?,
+                    minor.parse().ok()?,
+                ))
+            }
+            _ => Version::parse(version).ok().map(VersionRequirement::Exact),
         }
     }
 
@@ -130,6 +184,18 @@ impl VersionRequirement {
         }
     }
 
+    /// Whether a version that does not satisfy this requirement lies above
+    /// its range rather than below it
+    fn is_above(&self, version: &Version) -> bool {
+        match self {
+            VersionRequirement::Lt(_) | VersionRequirement::Lte(_) => true,
+            VersionRequirement::Gt(_) | VersionRequirement::Gte(_) | VersionRequirement::Any => {
+                false
+            }
+            _ => self.base_version().is_some_and(|base| *version > base),
+        }
+    }
+
     /// Get the base version from this requirement (for comparison purposes)
     fn base_version(&self) -> Option<Version> {
         match self {
@@ -178,9 +244,12 @@ impl VersionSpec {
         self.requirements.iter().all(|req| req.satisfies(version))
     }
 
-    /// Get the base version from the first requirement
-    fn base_version(&self) -> Option<Version> {
-        self.requirements.first().and_then(|r| r.base_version())
+    /// Whether a version lies above the range, i.e. exceeds the upper bound
+    /// of a requirement it does not satisfy
+    fn is_exceeded_by(&self, version: &Version) -> bool {
+        self.requirements
+            .iter()
+            .any(|req| !req.satisfies(version) && req.is_above(version))
     }
 }
 
@@ -215,12 +284,9 @@ impl VersionMatcher for CratesVersionMatcher {
             return CompareResult::Latest;
         }
 
-        // For Any (*), if not satisfied (which can't happen), treat as Latest
-        let Some(base) = spec.base_version() else {
-            return CompareResult::Latest;
-        };
-
-        if base < latest {
+        // Outside the range: outdated when latest is past an upper bound
+        // (`<2.0`, or the implicit one of `^1.2`), newer when below a lower one
+        if spec.is_exceeded_by(&latest) {
             CompareResult::Outdated
         } else {
             CompareResult::Newer
@@ -305,6 +371,13 @@ mod tests {
     #[case("<1.0.0", vec!["1.0.0"], false)]
     #[case("=1.0.0", vec!["1.0.0"], true)]
     #[case("=1.0.0", vec!["1.0.1"], false)]
+    #[case("=1.0.100", vec!["1.0.99", "1.0.101", "1.0.210"], false)]
+    #[case("=1.0.100", vec!["1.0.100", "1.0.210"], true)]
+    #[case("= 1.0.100", vec!["1.0.100"], true)]
+    // Partial exact versions pin only the given components
+    #[case("=1.2", vec!["1.2.7"], true)]
+    #[case("=1.2", vec!["1.3.0"], false)]
+    #[case("=1", vec!["1.9.0"], true)]
     fn version_exists_comparison_operators(
         #[case] version_spec: &str,
         #[case] available: Vec<&str>,
@@ -342,6 +415,8 @@ mod tests {
     #[case(">=1.0.0, <2.0.0", vec!["1.5.0"], true)]
     #[case(">=1.0.0, <2.0.0", vec!["2.0.0"], false)]
     #[case(">=1.0.0, <2.0.0", vec!["0.9.0"], false)]
+    #[case(">=1.2, <1.5", vec!["1.4.9"], true)]
+    #[case(">=1.2, <1.5", vec!["1.1.0", "1.5.0"], false)]
     fn version_exists_multiple_requirements(
         #[case] version_spec: &str,
         #[case] available: Vec<&str>,
@@ -368,6 +443,16 @@ mod tests {
     // ~1.2 should be treated as ~1.2.0
     #[case("~1.2", vec!["1.2.0", "1.2.9"], true)]
     #[case("~1.2", vec!["1.3.0"], false)]
+    // 1.0 should be treated as ^1.0.0
+    #[case("1.0", vec!["1.9.9"], true)]
+    #[case("1.0", vec!["2.0.0"], false)]
+    // Missing components loosen the range rather than being zero-filled
+    #[case("0", vec!["0.9.0"], true)]
+    #[case("0", vec!["1.0.0"], false)]
+    #[case("0.0", vec!["0.0.7"], true)]
+    #[case("0.0", vec!["0.1.0"], false)]
+    #[case("~1", vec!["1.9.0"], true)]
+    #[case("~1", vec!["2.0.0"], false)]
     // >=1.2 should be treated as >=1.2.0
     #[case(">=1.2", vec!["1.2.0", "2.0.0"], true)]
     #[case(">=1.2", vec!["1.1.9"], false)]
@@ -390,6 +475,15 @@ mod tests {
     #[case("0.14", "0.15.0", CompareResult::Outdated)]
     #[case("1", "1.9.9", CompareResult::Latest)]
     #[case("1", "2.0.0", CompareResult::Outdated)]
+    #[case("1.0", "1.9.9", CompareResult::Latest)]
+    #[case("1.0", "2.0.0", CompareResult::Outdated)]
+    #[case("^1", "1.9.9", CompareResult::Latest)]
+    #[case("^1", "2.0.0", CompareResult::Outdated)]
+    #[case("0", "0.9.0", CompareResult::Latest)]
+    #[case("0", "1.0.0", CompareResult::Outdated)]
+    #[case("0.0", "0.0.7", CompareResult::Latest)]
+    #[case("0.0", "0.1.0", CompareResult::Outdated)]
+    #[case("~1", "1.9.0", CompareResult::Latest)]
     // Default requirements
     #[case("1.0.0", "1.9.9", CompareResult::Latest)]
     #[case("1.0.0", "2.0.0", CompareResult::Outdated)]
@@ -400,6 +494,29 @@ mod tests {
     // Tilde
     #[case("~1.2.0", "1.2.9", CompareResult::Latest)]
     #[case("~1.2.0", "1.3.0", CompareResult::Outdated)]
+    // Exact
+    #[case("=1.0.100", "1.0.100", CompareResult::Latest)]
+    #[case("=1.0.100", "1.0.101", CompareResult::Outdated)]
+    #[case("=1.0.100", "1.0.99", CompareResult::Newer)]
+    #[case("=1.2", "1.2.9", CompareResult::Latest)]
+    #[case("=1.2", "1.3.0", CompareResult::Outdated)]
+    // Multiple requirements
+    #[case(">=1.2, <1.5", "1.4.3", CompareResult::Latest)]
+    #[case(">=1.2, <1.5", "1.5.0", CompareResult::Outdated)]
+    #[case(">=1.2, <1.5", "1.1.0", CompareResult::Newer)]
+    #[case(">=1.0, <2.0", "1.5.0", CompareResult::Latest)]
+    #[case(">=1.0, <2.0", "2.1.0", CompareResult::Outdated)]
+    #[case(">=1.0, <2.0", "0.9.0", CompareResult::Newer)]
+    // The order of the bounds does not matter
+    #[case("<2.0, >=1.0", "2.1.0", CompareResult::Outdated)]
+    #[case("<2.0, >=1.0", "0.9.0", CompareResult::Newer)]
+    // Upper bounds alone
+    #[case("<2.0.0", "2.0.0", CompareResult::Outdated)]
+    #[case("<=1.5", "1.6.0", CompareResult::Outdated)]
+    #[case(">=1.5", "1.4.0", CompareResult::Newer)]
+    // Lower bounds alone accept any newer release
+    #[case(">=1.5", "2.1.0", CompareResult::Latest)]
+    #[case(">1.5", "1.5.1", CompareResult::Latest)]
     // Wildcards
     #[case("*", "999.0.0", CompareResult::Latest)]
     #[case("1.*", "1.9.9", CompareResult::Latest)]
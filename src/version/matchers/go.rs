@@ -7,7 +7,7 @@
 
 use crate::parser::types::RegistryType;
 use crate::version::matcher::VersionMatcher;
-use crate::version::semver::CompareResult;
+use crate::version::semver::{CompareResult, strip_v_prefix};
 use semver::Version;
 use tracing::warn;
 
@@ -42,10 +42,10 @@ impl VersionMatcher for GoVersionMatcher {
 /// Normalize a Go module version for comparison.
 ///
 /// Handles:
-/// - v prefix: v1.2.3 -> 1.2.3
+/// - v prefix: v1.2.3 -> 1.2.3 (also `V` and unprefixed versions)
 /// - +incompatible suffix: v2.0.0+incompatible -> 2.0.0
 fn normalize_go_version(version: &str) -> String {
-    let version = version.strip_prefix('v').unwrap_or(version);
+    let version = strip_v_prefix(version);
     let version = version.strip_suffix("+incompatible").unwrap_or(version);
     version.to_string()
 }
@@ -53,91 +53,57 @@ fn normalize_go_version(version: &str) -> String {
 /// Check if a version is a pseudo-version.
 ///
 /// Pseudo-version formats:
-/// - v0.0.0-YYYYMMDDHHMMSS-commit (no base version)
-/// - vX.Y.Z-0.YYYYMMDDHHMMSS-commit (with base version)
+/// - vX.0.0-YYYYMMDDHHMMSS-commit (no base version)
+/// - vX.Y.Z-pre.0.YYYYMMDDHHMMSS-commit (base version is a pre-release)
+/// - vX.Y.Z-0.YYYYMMDDHHMMSS-commit (base version is a release)
 fn is_pseudo_version(version: &str) -> bool {
     let normalized = normalize_go_version(version);
 
-    let Some((_, rest)) = normalized.split_once('-') else {
+    let Some((rest, revision)) = normalized.rsplit_once('-') else {
         return false;
     };
-
-    let parts: Vec<&str> = rest.split('-').collect();
-    if parts.len() < 2 {
+    if revision.is_empty() || !revision.chars().all(|c| c.is_ascii_alphanumeric()) {
         return false;
     }
 
-    // Check for timestamp: either direct (14 digits) or prefixed with "0." (16 chars)
-    let timestamp = parts[0];
-    if timestamp.len() == 14 && timestamp.chars().all(|c| c.is_ascii_digit()) {
-        return true;
-    }
-    if timestamp.starts_with("0.") && timestamp.len() == 16 {
-        let after_prefix = &timestamp[2..];
-        if after_prefix.chars().all(|c| c.is_ascii_digit()) {
-            return true;
-        }
+    let Some((_, prerelease)) = rest.split_once('-') else {
+        return false;
+    };
+    let timestamp = prerelease.rsplit('.').next().unwrap_or(prerelease);
+    if timestamp.len() != 14 || !timestamp.chars().all(|c| c.is_ascii_digit()) {
+        return false;
     }
 
-    false
+    let prefix = &prerelease[..prerelease.len() - timestamp.len()];
+    prefix.is_empty() || prefix == "0." || prefix.ends_with(".0.")
 }
 
 /// Parse a Go version into semver::Version
 ///
-/// Handles pseudo-versions by extracting the timestamp for comparison.
-fn parse_go_version(version: &str) -> Option<(Version, Option<String>)> {
-    let normalized = normalize_go_version(version);
-
-    // Check for pseudo-version: v0.0.0-YYYYMMDDHHMMSS-commit
-    if let Some((base, rest)) = normalized.split_once('-') {
-        // Check if it looks like a pseudo-version (timestamp + commit)
-        let parts: Vec<&str> = rest.split('-').collect();
-        if parts.len() >= 2 && parts[0].len() == 14 && parts[0].chars().all(|c| c.is_ascii_digit())
-        {
-            // It's a pseudo-version, use the timestamp for sorting
-            let parsed = Version::parse(base).ok()?;
-            return Some((parsed, Some(parts[0].to_string())));
-        }
-
-        // Regular pre-release version
-        let full = format!("{}-{}", base, rest);
-        let parsed = Version::parse(&full).ok()?;
-        return Some((parsed, None));
-    }
-
-    let parsed = Version::parse(&normalized).ok()?;
-    Some((parsed, None))
+/// Pseudo-versions are valid semver pre-releases whose identifiers start with
+/// the commit timestamp, so semver precedence matches Go's ordering: a
+/// pseudo-version sorts after its base version and before the next release,
+/// and pseudo-versions on the same base sort by timestamp.
+fn parse_go_version(version: &str) -> Option<Version> {
+    Version::parse(&normalize_go_version(version)).ok()
 }
 
 /// Compare two Go module versions
 fn compare_go_versions(current: &str, latest: &str) -> CompareResult {
-    let Some((current_ver, current_timestamp)) = parse_go_version(current) else {
+    let Some(current_ver) = parse_go_version(current) else {
         warn!("Invalid Go version format: '{}'", current);
         return CompareResult::Invalid;
     };
 
-    let Some((latest_ver, latest_timestamp)) = parse_go_version(latest) else {
+    let Some(latest_ver) = parse_go_version(latest) else {
         warn!("Invalid Go version format: '{}'", latest);
         return CompareResult::Invalid;
     };
 
-    // First compare by semver
     match current_ver.cmp(&latest_ver) {
         std::cmp::Ordering::Less => CompareResult::Outdated,
         std::cmp::Ordering::Greater => CompareResult::Newer,
-        std::cmp::Ordering::Equal => {
-            // If semver is equal, compare by timestamp (for pseudo-versions)
-            match (current_timestamp, latest_timestamp) {
-                (Some(ct), Some(lt)) => match ct.cmp(&lt) {
-                    std::cmp::Ordering::Equal => CompareResult::Latest,
-                    std::cmp::Ordering::Less => CompareResult::Outdated,
-                    std::cmp::Ordering::Greater => CompareResult::Newer,
-                },
-                (None, Some(_)) => CompareResult::Outdated, // Regular version vs pseudo
-                (Some(_), None) => CompareResult::Newer,    // Pseudo vs regular
-                (None, None) => CompareResult::Latest,
-            }
-        }
+        std::cmp::Ordering::Equal => CompareResult::Latest,
     }
 }
 
@@ -152,6 +118,13 @@ mod tests {
     #[case("v2.0.0", "v1.0.0", CompareResult::Newer)]
     #[case("v1.0.0", "v1.0.1", CompareResult::Outdated)]
     #[case("v1.0.1", "v1.0.0", CompareResult::Newer)]
+    // With and without the v prefix
+    #[case("0.12.0", "v0.14.0", CompareResult::Outdated)]
+    #[case("v0.12.0", "0.14.0", CompareResult::Outdated)]
+    #[case("0.12.0", "0.14.0", CompareResult::Outdated)]
+    #[case("v0.14.0", "0.14.0", CompareResult::Latest)]
+    #[case("V0.14.0", "v0.14.0", CompareResult::Latest)]
+    #[case("0.15.0", "v0.14.0", CompareResult::Newer)]
     // With +incompatible suffix
     #[case("v2.0.0+incompatible", "v2.0.0+incompatible", CompareResult::Latest)]
     #[case("v2.0.0+incompatible", "v3.0.0+incompatible", CompareResult::Outdated)]
@@ -177,6 +150,44 @@ mod tests {
         "v0.0.0-20210101000000-abc123",
         CompareResult::Latest
     )]
+    // Pseudo-versions against tagged releases
+    #[case("v1.2.4-0.20231201000000-abcdef123456", "v1.2.3", CompareResult::Newer)]
+    #[case(
+        "v1.2.3-0.20231201000000-abcdef123456",
+        "v1.2.3",
+        CompareResult::Outdated
+    )]
+    #[case(
+        "v1.2.3",
+        "v1.2.4-0.20231201000000-abcdef123456",
+        CompareResult::Outdated
+    )]
+    #[case(
+        "v1.2.3-rc.1.0.20231201000000-abcdef123456",
+        "v1.2.3-rc.1",
+        CompareResult::Newer
+    )]
+    #[case(
+        "v1.2.3-rc.1.0.20231201000000-abcdef123456",
+        "v1.2.3",
+        CompareResult::Outdated
+    )]
+    #[case(
+        "v0.0.0-20231201000000-abcdef123456",
+        "v0.1.0",
+        CompareResult::Outdated
+    )]
+    #[case(
+        "v1.2.4-0.20231201000000-abcdef123456",
+        "v1.2.4-0.20240101000000-123456abcdef",
+        CompareResult::Outdated
+    )]
+    // Pseudo-versions with +incompatible
+    #[case(
+        "v2.0.1-0.20231201000000-abcdef123456+incompatible",
+        "v2.0.0+incompatible",
+        CompareResult::Newer
+    )]
     fn compare_to_latest_returns_expected(
         #[case] current: &str,
         #[case] latest: &str,
@@ -192,6 +203,8 @@ mod tests {
     #[case("v2.0.0+incompatible", &["v2.0.0+incompatible", "v3.0.0"], true)]
     #[case("v2.0.0+incompatible", &["v2.0.0"], true)] // +incompatible matches without suffix
     #[case("v2.0.0", &["v2.0.0+incompatible"], true)]
+    #[case("0.14.0", &["v0.14.0"], true)]
+    #[case("v0.14.0", &["0.14.0"], true)]
     // without suffix matches +incompatible
     // Pseudo-versions should always return true (skip validation)
     #[case("v0.0.0-20210101000000-abc123", &["v1.0.0", "v2.0.0"], true)]
@@ -211,6 +224,7 @@ mod tests {
         assert_eq!(normalize_go_version("v1.0.0"), "1.0.0");
         assert_eq!(normalize_go_version("v2.0.0+incompatible"), "2.0.0");
         assert_eq!(normalize_go_version("1.0.0"), "1.0.0");
+        assert_eq!(normalize_go_version("V1.0.0"), "1.0.0");
     }
 
     #[rstest]
@@ -220,11 +234,16 @@ mod tests {
     // Pseudo-versions with base version (0.timestamp format)
     #[case("v1.1.3-0.20240916144458-20a13a1f6b7c", true)]
     #[case("v2.0.0-0.20200101120000-abcdef123456", true)]
+    // Pseudo-versions with a pre-release base version
+    #[case("v1.2.3-rc.1.0.20231201000000-abcdef123456", true)]
+    // Pseudo-versions with +incompatible
+    #[case("v2.0.1-0.20231201000000-abcdef123456+incompatible", true)]
     // Regular versions (not pseudo)
     #[case("v1.0.0", false)]
     #[case("v1.0.0-beta.1", false)]
     #[case("v1.0.0-alpha", false)]
     #[case("v2.0.0+incompatible", false)]
+    #[case("v1.0.0-rc.1.20231201000000", false)]
     fn is_pseudo_version_returns_expected(#[case] version: &str, #[case] expected: bool) {
         assert_eq!(is_pseudo_version(version), expected);
     }
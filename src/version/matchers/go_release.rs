@@ -0,0 +1,65 @@
+//! Go release version matcher
+//!
+//! Go versions are pinned to a release (`go 1.22.0`, `golang 1.22.0`) or a
+//! release series (`go 1.22`). Like tool releases, they are compared against
+//! the latest release only up to their own precision.
+
+use crate::parser::types::RegistryType;
+use crate::version::matcher::VersionMatcher;
+use crate::version::matchers::homebrew::{series_compare_to_latest, series_version_exists};
+use crate::version::semver::CompareResult;
+
+pub struct GoReleaseMatcher;
+
+impl VersionMatcher for GoReleaseMatcher {
+    fn registry_type(&self) -> RegistryType {
+        RegistryType::GoRelease
+    }
+
+    fn version_exists(&self, version_spec: &str, available_versions: &[String]) -> bool {
+        series_version_exists(version_spec, available_versions)
+    }
+
+    fn compare_to_latest(&self, current_version: &str, latest_version: &str) -> CompareResult {
+        series_compare_to_latest(current_version, latest_version)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("1.22.0", vec!["1.21.13", "1.22.0", "1.23.4"], true)]
+    #[case("1.22", vec!["1.21.13", "1.22.0", "1.23.4"], true)]
+    #[case("1.22.99", vec!["1.21.13", "1.22.0", "1.23.4"], false)]
+    #[case("v1.22.0", vec!["1.22.0"], false)]
+    fn version_exists_returns_expected(
+        #[case] version_spec: &str,
+        #[case] available: Vec<&str>,
+        #[case] expected: bool,
+    ) {
+        let available: Vec<String> = available.into_iter().map(|s| s.to_string()).collect();
+        assert_eq!(
+            GoReleaseMatcher.version_exists(version_spec, &available),
+            expected
+        );
+    }
+
+    #[rstest]
+    #[case("1.23.4", "1.23.4", CompareResult::Latest)]
+    #[case("1.23", "1.23.4", CompareResult::Latest)]
+    #[case("1.22.0", "1.23.4", CompareResult::Outdated)]
+    #[case("1.24.0", "1.23.4", CompareResult::Newer)]
+    fn compare_to_latest_returns_expected(
+        #[case] current: &str,
+        #[case] latest: &str,
+        #[case] expected: CompareResult,
+    ) {
+        assert_eq!(
+            GoReleaseMatcher.compare_to_latest(current, latest),
+            expected
+        );
+    }
+}
@@ -0,0 +1,114 @@
+//! Homebrew version matcher
+//!
+//! Versioned formulae pin a release series rather than a version:
+//! - `node@18` - the 18.x series
+//! - `python@3.11` - the 3.11.x series
+//!
+//! The suffix is compared against the latest stable version only up to its
+//! own precision, so `python@3.13` is latest while stable is `3.13.1`.
+
+use std::cmp::Ordering;
+
+use crate::parser::types::RegistryType;
+use crate::version::matcher::VersionMatcher;
+use crate::version::semver::CompareResult;
+
+pub struct HomebrewVersionMatcher;
+
+impl VersionMatcher for HomebrewVersionMatcher {
+    fn registry_type(&self) -> RegistryType {
+        RegistryType::Homebrew
+    }
+
+    fn version_exists(&self, version_spec: &str, available_versions: &[String]) -> bool {
+        series_version_exists(version_spec, available_versions)
+    }
+
+    fn compare_to_latest(&self, current_version: &str, latest_version: &str) -> CompareResult {
+        series_compare_to_latest(current_version, latest_version)
+    }
+}
+
+/// Whether a dotted numeric version, or a release of the series it names, is available
+pub(crate) fn series_version_exists(version_spec: &str, available_versions: &[String]) -> bool {
+    if parse_segments(version_spec).is_none() {
+        return false;
+    }
+    let series = format!("{}.", version_spec);
+    available_versions
+        .iter()
+        .any(|v| v == version_spec || v.starts_with(&series))
+}
+
+/// Compare a dotted numeric version to the latest, only up to its own precision
+pub(crate) fn series_compare_to_latest(
+    current_version: &str,
+    latest_version: &str,
+) -> CompareResult {
+    let (Some(current), Some(latest)) = (
+        parse_segments(current_version),
+        parse_segments(latest_version),
+    ) else {
+        return CompareResult::Invalid;
+    };
+
+    // Compare only as many segments as the current version has
+    let latest = &latest[..current.len().min(latest.len())];
+    match current.as_slice().cmp(latest) {
+        Ordering::Less => CompareResult::Outdated,
+        Ordering::Equal => CompareResult::Latest,
+        Ordering::Greater => CompareResult::Newer,
+    }
+}
+
+/// Parse a dotted numeric version (`18`, `3.11`, `23.1.0`) into its segments
+fn parse_segments(version: &str) -> Option<Vec<u64>> {
+    version
+        .trim()
+        .split('.')
+        .map(|segment| segment.parse().ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("18", vec!["18", "20", "23.1.0"], true)]
+    #[case("23", vec!["18", "20", "23.1.0"], true)]
+    #[case("3.11", vec!["3.11", "3.13.1"], true)]
+    #[case("3.1", vec!["3.11", "3.13.1"], false)]
+    #[case("16", vec!["18", "20", "23.1.0"], false)]
+    #[case("", vec!["1.0.0"], false)]
+    fn version_exists_returns_expected(
+        #[case] version_spec: &str,
+        #[case] available: Vec<&str>,
+        #[case] expected: bool,
+    ) {
+        let available: Vec<String> = available.into_iter().map(|s| s.to_string()).collect();
+        assert_eq!(
+            HomebrewVersionMatcher.version_exists(version_spec, &available),
+            expected
+        );
+    }
+
+    #[rstest]
+    #[case("23", "23.1.0", CompareResult::Latest)]
+    #[case("18", "23.1.0", CompareResult::Outdated)]
+    #[case("3.13", "3.13.1", CompareResult::Latest)]
+    #[case("3.11", "3.13.1", CompareResult::Outdated)]
+    #[case("24", "23.1.0", CompareResult::Newer)]
+    #[case("latest", "23.1.0", CompareResult::Invalid)]
+    fn compare_to_latest_returns_expected(
+        #[case] current: &str,
+        #[case] latest: &str,
+        #[case] expected: CompareResult,
+    ) {
+        assert_eq!(
+            HomebrewVersionMatcher.compare_to_latest(current, latest),
+            expected
+        );
+    }
+}
@@ -5,7 +5,9 @@
 
 use crate::parser::types::RegistryType;
 use crate::version::matcher::VersionMatcher;
-use crate::version::matchers::npm::{npm_compare_to_latest, npm_version_exists};
+use crate::version::matchers::npm::{
+    npm_compare_to_latest, npm_latest_in_range, npm_version_exists,
+};
 use crate::version::semver::CompareResult;
 
 pub struct JsrVersionMatcher;
@@ -22,4 +24,8 @@ impl VersionMatcher for JsrVersionMatcher {
     fn compare_to_latest(&self, current_version: &str, latest_version: &str) -> CompareResult {
         npm_compare_to_latest(current_version, latest_version)
     }
+
+    fn latest_in_range(&self, version_spec: &str, available_versions: &[String]) -> Option<String> {
+        npm_latest_in_range(version_spec, available_versions)
+    }
 }
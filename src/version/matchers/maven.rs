@@ -0,0 +1,290 @@
+//! Maven version matcher
+//!
+//! Gradle version catalogs declare a single version (`1.2.3`, `33.0.0-jre`)
+//! or a dynamic prefix (`1.2.+`). Versions are ordered the way Maven orders
+//! them rather than by semver:
+//! - numeric components compare numerically, and trailing zeros are ignored
+//!   (`1.0` equals `1.0.0`)
+//! - qualifiers rank `alpha` < `beta` < `milestone` < `rc` < `snapshot` <
+//!   release (`ga`, `final`) < `sp`, followed by other qualifiers such as
+//!   `jre` or `android` by name
+//!
+//! A version with a flavor qualifier (`33.0.0-jre`) is compared against the
+//! latest release of the same flavor.
+
+use std::cmp::Ordering;
+
+use crate::parser::types::RegistryType;
+use crate::version::matcher::VersionMatcher;
+use crate::version::semver::CompareResult;
+
+pub struct MavenVersionMatcher;
+
+/// Rank of release versions among qualifiers
+const RELEASE_RANK: usize = 5;
+
+/// Component of a Maven version
+#[derive(Debug, PartialEq, Eq)]
+enum Item {
+    Number(u64),
+    /// Qualifier rank and its lowercase name
+    Qualifier(usize, String),
+}
+
+impl Item {
+    fn qualifier(name: &str) -> Self {
+        let name = match name {
+            "a" => "alpha",
+            "b" => "beta",
+            "m" => "milestone",
+            "cr" => "rc",
+            "ga" | "final" | "release" => "",
+            name => name,
+        };
+        let rank = match name {
+            "alpha" => 0,
+            "beta" => 1,
+            "milestone" => 2,
+            "rc" => 3,
+            "snapshot" => 4,
+            "" => RELEASE_RANK,
+            "sp" => 6,
+            _ => 7,
+        };
+        Item::Qualifier(rank, name.to_string())
+    }
+
+    fn is_release(&self) -> bool {
+        matches!(self, Item::Number(0)) || matches!(self, Item::Qualifier(RELEASE_RANK, _))
+    }
+
+    /// Compare against another item, with `None` standing for a missing one
+    ///
+    /// A missing item equals `0` next to a number and a release next to a
+    /// qualifier, so `1.0` equals `1` and `1-rc` is below `1`.
+    fn compare(this: Option<&Item>, other: Option<&Item>) -> Ordering {
+        match (this, other) {
+            (None, None) => Ordering::Equal,
+            (Some(item), None) => Self::compare_to_padding(item),
+            (None, Some(item)) => Self::compare_to_padding(item).reverse(),
+            (Some(Item::Number(a)), Some(Item::Number(b))) => a.cmp(b),
+            (Some(Item::Number(_)), Some(Item::Qualifier(..))) => Ordering::Greater,
+            (Some(Item::Qualifier(..)), Some(Item::Number(_))) => Ordering::Less,
+            (Some(Item::Qualifier(rank_a, a)), Some(Item::Qualifier(rank_b, b))) => {
+                rank_a.cmp(rank_b).then_with(|| a.cmp(b))
+            }
+        }
+    }
+
+    fn compare_to_padding(item: &Item) -> Ordering {
+        match item {
+            Item::Number(n) => n.cmp(&0),
+            Item::Qualifier(rank, _) => rank.cmp(&RELEASE_RANK),
+        }
+    }
+}
+
+/// Split a version into numeric and qualifier items
+///
+/// Components are separated by `.`, `-` and transitions between digits and
+/// letters (`1.0rc1` is `1`, `0`, `rc`, `1`). Returns None for versions that
+/// do not start with a digit.
+fn parse_items(version: &str) -> Option<Vec<Item>> {
+    let version = version.trim().to_ascii_lowercase();
+    if !version.starts_with(|c: char| c.is_ascii_digit()) {
+        return None;
+    }
+
+    let mut items = Vec::new();
+    for component in version.split(['.', '-', '_']) {
+        let mut rest = component;
+        while !rest.is_empty() {
+            let is_digit = rest.starts_with(|c: char| c.is_ascii_digit());
+            let end = rest
+                .find(|c: char| c.is_ascii_digit() != is_digit)
+                .unwrap_or(rest.len());
+            let (token, tail) = rest.split_at(end);
+            items.push(if is_digit {
+                Item::Number(token.parse().ok()?)
+            } else {
+                Item::qualifier(token)
+            });
+            rest = tail;
+        }
+    }
+
+    // Trailing zeros and release qualifiers do not change the version
+    while items.last().is_some_and(Item::is_release) {
+        items.pop();
+    }
+    Some(items)
+}
+
+/// Compare two Maven versions, or None if either does not parse
+pub fn compare_maven_versions(a: &str, b: &str) -> Option<Ordering> {
+    let a = parse_items(a)?;
+    let b = parse_items(b)?;
+    let len = a.len().max(b.len());
+    Some(
+        (0..len)
+            .map(|i| Item::compare(a.get(i), b.get(i)))
+            .find(|ordering| ordering.is_ne())
+            .unwrap_or(Ordering::Equal),
+    )
+}
+
+/// Flavor qualifier after the last `-` (`jre` in `33.0.0-jre`)
+///
+/// Pre-release qualifiers such as `rc1` or `beta` are not flavors.
+fn flavor(version: &str) -> Option<&str> {
+    let (_, suffix) = version.rsplit_once('-')?;
+    let is_flavor = suffix.chars().all(|c| c.is_ascii_alphabetic())
+        && matches!(
+            Item::qualifier(&suffix.to_ascii_lowercase()),
+            Item::Qualifier(7, _)
+        );
+    is_flavor.then_some(suffix)
+}
+
+/// Prefix of a dynamic version (`1.2.+` -> `1.2.`)
+fn dynamic_prefix(version: &str) -> Option<&str> {
+    version.strip_suffix('+')
+}
+
+impl VersionMatcher for MavenVersionMatcher {
+    fn registry_type(&self) -> RegistryType {
+        RegistryType::Maven
+    }
+
+    fn version_exists(&self, version_spec: &str, available_versions: &[String]) -> bool {
+        match dynamic_prefix(version_spec) {
+            Some(prefix) => available_versions.iter().any(|v| v.starts_with(prefix)),
+            None => available_versions
+                .iter()
+                .any(|v| compare_maven_versions(version_spec, v) == Some(Ordering::Equal)),
+        }
+    }
+
+    fn compare_to_latest(&self, current_version: &str, latest_version: &str) -> CompareResult {
+        let current = match dynamic_prefix(current_version) {
+            Some(prefix) if latest_version.starts_with(prefix) => return CompareResult::Latest,
+            Some(prefix) => prefix.trim_end_matches('.'),
+            None => current_version,
+        };
+
+        match compare_maven_versions(current, latest_version) {
+            Some(Ordering::Less) => CompareResult::Outdated,
+            Some(Ordering::Equal) => CompareResult::Latest,
+            Some(Ordering::Greater) => CompareResult::Newer,
+            None => CompareResult::Invalid,
+        }
+    }
+
+    fn resolve_latest(
+        &self,
+        current_version: &str,
+        latest_version: &str,
+        all_versions: &[String],
+    ) -> String {
+        let Some(current_flavor) = flavor(current_version) else {
+            return latest_version.to_string();
+        };
+        if flavor(latest_version) == Some(current_flavor) {
+            return latest_version.to_string();
+        }
+
+        all_versions
+            .iter()
+            .filter(|v| flavor(v) == Some(current_flavor))
+            .max_by(|a, b| compare_maven_versions(a, b).unwrap_or(Ordering::Equal))
+            .cloned()
+            .unwrap_or_else(|| latest_version.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("1.0", "1.0.0", Ordering::Equal)]
+    #[case("1.0-ga", "1", Ordering::Equal)]
+    #[case("1.2.3", "1.10.0", Ordering::Less)]
+    #[case("2.0.0-alpha1", "2.0.0-beta1", Ordering::Less)]
+    #[case("2.0.0-M1", "2.0.0-RC1", Ordering::Less)]
+    #[case("2.0.0-RC1", "2.0.0", Ordering::Less)]
+    #[case("2.0.0-SNAPSHOT", "2.0.0", Ordering::Less)]
+    #[case("2.0.0", "2.0.0-sp1", Ordering::Less)]
+    #[case("2.0.0", "2.0.1-RC1", Ordering::Less)]
+    #[case("32.1.3-jre", "33.0.0-jre", Ordering::Less)]
+    #[case("33.0.0-jre", "33.0.0", Ordering::Greater)]
+    fn compare_maven_versions_returns_expected(
+        #[case] a: &str,
+        #[case] b: &str,
+        #[case] expected: Ordering,
+    ) {
+        assert_eq!(compare_maven_versions(a, b), Some(expected));
+    }
+
+    #[rstest]
+    #[case("4.13.2", vec!["4.13.1", "4.13.2"], true)]
+    #[case("4.13", vec!["4.13.0"], true)]
+    #[case("4.13.3", vec!["4.13.1", "4.13.2"], false)]
+    #[case("4.13.+", vec!["4.13.1"], true)]
+    #[case("4.14.+", vec!["4.13.1"], false)]
+    fn version_exists_returns_expected(
+        #[case] version_spec: &str,
+        #[case] available: Vec<&str>,
+        #[case] expected: bool,
+    ) {
+        let available: Vec<String> = available.into_iter().map(|s| s.to_string()).collect();
+        assert_eq!(
+            MavenVersionMatcher.version_exists(version_spec, &available),
+            expected
+        );
+    }
+
+    #[rstest]
+    #[case("4.13.2", "4.13.2", CompareResult::Latest)]
+    #[case("4.12", "4.13.2", CompareResult::Outdated)]
+    #[case("5.0.0", "4.13.2", CompareResult::Newer)]
+    #[case("4.13.+", "4.13.2", CompareResult::Latest)]
+    #[case("4.12.+", "4.13.2", CompareResult::Outdated)]
+    #[case("latest.release", "4.13.2", CompareResult::Invalid)]
+    fn compare_to_latest_returns_expected(
+        #[case] current: &str,
+        #[case] latest: &str,
+        #[case] expected: CompareResult,
+    ) {
+        assert_eq!(
+            MavenVersionMatcher.compare_to_latest(current, latest),
+            expected
+        );
+    }
+
+    #[rstest]
+    #[case("32.1.3-jre", "33.0.0-android", "33.0.0-jre")]
+    #[case("32.1.3-android", "33.0.0-android", "33.0.0-android")]
+    #[case("2.10.0", "2.11.0", "2.11.0")]
+    fn resolve_latest_keeps_the_flavor(
+        #[case] current: &str,
+        #[case] latest: &str,
+        #[case] expected: &str,
+    ) {
+        let all_versions: Vec<String> = [
+            "32.1.3-android",
+            "32.1.3-jre",
+            "33.0.0-android",
+            "33.0.0-jre",
+        ]
+        .into_iter()
+        .map(String::from)
+        .collect();
+
+        assert_eq!(
+            MavenVersionMatcher.resolve_latest(current, latest, &all_versions),
+            expected
+        );
+    }
+}
@@ -1,19 +1,35 @@
 //! Registry-specific version matchers
 
+pub mod composer;
 pub mod crates;
 pub mod docker;
 pub mod github_actions;
 pub mod go;
+pub mod go_release;
+pub mod homebrew;
 pub mod jsr;
+pub mod maven;
+pub mod nix_flake;
 pub mod npm;
 pub mod pnpm;
 pub mod pypi;
+pub mod rubygems;
+pub mod terraform;
+pub mod tool_versions;
 
+pub use composer::ComposerVersionMatcher;
 pub use crates::CratesVersionMatcher;
 pub use docker::DockerVersionMatcher;
 pub use github_actions::GitHubActionsMatcher;
 pub use go::GoVersionMatcher;
+pub use go_release::GoReleaseMatcher;
+pub use homebrew::HomebrewVersionMatcher;
 pub use jsr::JsrVersionMatcher;
+pub use maven::MavenVersionMatcher;
+pub use nix_flake::NixFlakeMatcher;
 pub use npm::NpmVersionMatcher;
 pub use pnpm::PnpmCatalogMatcher;
 pub use pypi::PypiVersionMatcher;
+pub use rubygems::RubyGemsVersionMatcher;
+pub use terraform::TerraformVersionMatcher;
+pub use tool_versions::ToolVersionsMatcher;
@@ -0,0 +1,76 @@
+//! Nix flake input matcher
+//!
+//! Inputs are versioned by the short rev they are locked to, and the only
+//! version the registry knows is the newest commit of the input's branch.
+//! Any other rev is therefore reported as outdated; whether it exists is not
+//! checked, since nix already verified it when writing the lock file.
+
+use crate::parser::types::RegistryType;
+use crate::version::matcher::VersionMatcher;
+use crate::version::semver::CompareResult;
+
+pub struct NixFlakeMatcher;
+
+impl VersionMatcher for NixFlakeMatcher {
+    fn registry_type(&self) -> RegistryType {
+        RegistryType::NixFlake
+    }
+
+    fn version_exists(&self, version_spec: &str, _available_versions: &[String]) -> bool {
+        is_rev(version_spec)
+    }
+
+    fn compare_to_latest(&self, current_version: &str, latest_version: &str) -> CompareResult {
+        if !is_rev(current_version) || !is_rev(latest_version) {
+            return CompareResult::Invalid;
+        }
+        // Revs of different lengths match when one is a prefix of the other
+        if current_version.starts_with(latest_version)
+            || latest_version.starts_with(current_version)
+        {
+            CompareResult::Latest
+        } else {
+            CompareResult::Outdated
+        }
+    }
+}
+
+/// Whether `version` is an abbreviated or full commit hash
+fn is_rev(version: &str) -> bool {
+    !version.is_empty() && version.chars().all(|c| c.is_ascii_hexdigit())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("805a384", true)]
+    #[case("805a384895c696f802a9bf5bf4720f37385df547", true)]
+    #[case("nixos-24.05", false)]
+    #[case("", false)]
+    fn version_exists_accepts_any_rev(#[case] version_spec: &str, #[case] expected: bool) {
+        assert_eq!(
+            NixFlakeMatcher.version_exists(version_spec, &["9f4128e".to_string()]),
+            expected
+        );
+    }
+
+    #[rstest]
+    #[case("9f4128e", "9f4128e", CompareResult::Latest)]
+    #[case(
+        "9f4128e00b0ae8ec65918efeba59db998750ead6",
+        "9f4128e",
+        CompareResult::Latest
+    )]
+    #[case("805a384", "9f4128e", CompareResult::Outdated)]
+    #[case("main", "9f4128e", CompareResult::Invalid)]
+    fn compare_to_latest_returns_expected(
+        #[case] current: &str,
+        #[case] latest: &str,
+        #[case] expected: CompareResult,
+    ) {
+        assert_eq!(NixFlakeMatcher.compare_to_latest(current, latest), expected);
+    }
+}
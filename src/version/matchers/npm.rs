@@ -4,8 +4,12 @@
 //! - `1.2.3` - exact match
 //! - `^1.2.3` - compatible with version (>=1.2.3 <2.0.0)
 //! - `~1.2.3` - approximately equivalent (>=1.2.3 <1.3.0)
-//! - `>=1.2.3`, `>1.2.3`, `<=1.2.3`, `<1.2.3` - comparison operators
+//! - `>=1.2.3`, `>1.2.3`, `<=1.2.3`, `<1.2.3` - comparison operators; an
+//!   open-ended floor such as a `peerDependencies` `>=16.8.0` is satisfied by
+//!   every newer release, so it is never outdated
 //! - `1.2.x`, `1.x`, `*` - wildcards
+//! - `1.2.3 - 2.3.4` - hyphen ranges (>=1.2.3 <=2.3.4); a partial upper bound
+//!   such as `1.2.3 - 2.3` covers the whole `2.3.x` line (>=1.2.3 <2.4.0)
 
 use semver::Version;
 
@@ -100,6 +104,13 @@ impl VersionSpec {
                         continue;
                     }
 
+                    // An operator separated from its version (">= 1.2.3")
+                    // belongs to the next token
+                    if matches!(*before, ">=" | "<=" | ">" | "<" | "^" | "~") {
+                        i += 1;
+                        continue;
+                    }
+
                     // This is a space separator for AND
                     parts.push(*before);
                     current_start = i + 1;
@@ -131,12 +142,23 @@ impl VersionSpec {
         match self {
             VersionSpec::Single(range) => range.base_version(),
             VersionSpec::And(specs) => {
-                // For AND ranges, use the first range's base version
-                specs.first().and_then(|s| s.base_version())
+                // For AND ranges, use the tightest lower (>=, >) bound,
+                // falling back to the first range when there is none
+                specs
+                    .iter()
+                    .filter_map(|s| match s {
+                        VersionSpec::Single(
+                            range @ (VersionRange::Gte(_) | VersionRange::Gt(_)),
+                        ) => range.base_version(),
+                        _ => None,
+                    })
+                    .max()
+                    .or_else(|| specs.first().and_then(|s| s.base_version()))
             }
             VersionSpec::Or(specs) => {
-                // For OR ranges, use the first spec's base version
-                specs.first().and_then(|s| s.base_version())
+                // For OR ranges, use the highest base version among members
+                // so a spec reaching past latest is not reported as outdated
+                specs.iter().filter_map(|s| s.base_version()).max()
             }
         }
     }
@@ -166,7 +188,14 @@ enum VersionRange {
     /// Wildcard minor: 1.2.x means >=1.2.0 <1.3.0
     WildcardMinor(u64, u64),
     /// Hyphen range: 1.0.0 - 2.0.0 means >=1.0.0 <=2.0.0
-    Hyphen { from: Version, to: Version },
+    ///
+    /// A partial upper bound is exclusive of the next release:
+    /// 1.0.0 - 2.3 means >=1.0.0 <2.4.0
+    Hyphen {
+        from: Version,
+        to: Version,
+        to_exclusive: bool,
+    },
 }
 
 impl VersionRange {
@@ -209,22 +238,53 @@ impl VersionRange {
         }
 
         let from = parse_version(parts[0].trim())?;
-        let to = parse_version(parts[1].trim())?;
+        let (to, to_exclusive) = Self::parse_hyphen_upper(parts[1].trim())?;
 
-        Some(VersionRange::Hyphen { from, to })
+        Some(VersionRange::Hyphen {
+            from,
+            to,
+            to_exclusive,
+        })
+    }
+
+    /// Parse the upper bound of a hyphen range
+    ///
+    /// Partial versions are bumped to the next release and returned as an
+    /// exclusive bound, matching npm: `2.3` becomes `<2.4.0`, `2` becomes `<3.0.0`.
+    fn parse_hyphen_upper(spec: &str) -> Option<(Version, bool)> {
+        let to = parse_version(spec)?;
+        match spec.trim_start_matches('v').split('.').count() {
+            1 => Some((Version::new(to.major + 1, 0, 0), true)),
+            2 => Some((Version::new(to.major, to.minor + 1, 0), true)),
+            _ => Some((to, false)),
+        }
     }
 
-    /// Parse wildcard patterns like "1.x" or "1.2.x"
+    /// Parse wildcard patterns like "1.x", "1.2.x", "1.x.x" or a lone "x"
+    ///
+    /// Missing components count as wildcards too, so a bare "1" behaves as
+    /// "1.x" and "1.2" as "1.2.x" rather than as an exact version.
     fn parse_wildcard(spec: &str) -> Option<Self> {
+        let is_x = |part: &str| part == "*" || part.eq_ignore_ascii_case("x");
         let parts: Vec<&str> = spec.split('.').collect();
 
         match parts.as_slice() {
-            // 1.x or 1.X
-            [major, x] if x.eq_ignore_ascii_case("x") => {
+            // x, X, x.x or x.x.x
+            [x, rest @ ..] if is_x(x) && rest.iter().all(|part| is_x(part)) => {
+                Some(VersionRange::Any)
+            }
+            // 1, 1.x or 1.x.x
+            [major] => major.parse::<u64>().ok().map(VersionRange::WildcardMajor),
+            [major, rest @ ..] if rest.len() <= 2 && rest.iter().all(|part| is_x(part)) => {
                 major.parse::<u64>().ok().map(VersionRange::WildcardMajor)
             }
-            // 1.2.x or 1.2.X
-            [major, minor, x] if x.eq_ignore_ascii_case("x") => {
+            // 1.2 or 1.2.x
+            [major, minor] => {
+                let major = major.parse::<u64>().ok()?;
+                let minor = minor.parse::<u64>().ok()?;
+                Some(VersionRange::WildcardMinor(major, minor))
+            }
+            [major, minor, x] if is_x(x) => {
                 let major = major.parse::<u64>().ok()?;
                 let minor = minor.parse::<u64>().ok()?;
                 Some(VersionRange::WildcardMinor(major, minor))
@@ -270,7 +330,18 @@ impl VersionRange {
             VersionRange::WildcardMinor(major, minor) => {
                 version.major == *major && version.minor == *minor
             }
-            VersionRange::Hyphen { from, to } => version >= from && version <= to,
+            VersionRange::Hyphen {
+                from,
+                to,
+                to_exclusive,
+            } => {
+                let within_upper = if *to_exclusive {
+                    version < to
+                } else {
+                    version <= to
+                };
+                version >= from && within_upper
+            }
         }
     }
 
@@ -305,6 +376,10 @@ impl VersionMatcher for NpmVersionMatcher {
     fn compare_to_latest(&self, current_version: &str, latest_version: &str) -> CompareResult {
         npm_compare_to_latest(current_version, latest_version)
     }
+
+    fn latest_in_range(&self, version_spec: &str, available_versions: &[String]) -> Option<String> {
+        npm_latest_in_range(version_spec, available_versions)
+    }
 }
 
 /// Common implementation for npm version existence check
@@ -320,6 +395,24 @@ pub(crate) fn npm_version_exists(version_spec: &str, available_versions: &[Strin
     })
 }
 
+/// Common implementation for the newest stable version a spec allows
+///
+/// Returns None when nothing newer than the spec's base version satisfies it.
+pub(crate) fn npm_latest_in_range(
+    version_spec: &str,
+    available_versions: &[String],
+) -> Option<String> {
+    let spec = VersionSpec::parse(version_spec)?;
+    let base = spec.base_version()?;
+
+    available_versions
+        .iter()
+        .filter_map(|v| Version::parse(v).ok().map(|ver| (v, ver)))
+        .filter(|(_, ver)| ver.pre.is_empty() && *ver > base && spec.satisfies(ver))
+        .max_by(|(_, a), (_, b)| a.cmp(b))
+        .map(|(v, _)| v.clone())
+}
+
 /// Common implementation for npm version comparison
 pub(crate) fn npm_compare_to_latest(current_version: &str, latest_version: &str) -> CompareResult {
     let Some(spec) = VersionSpec::parse(current_version) else {
@@ -446,6 +539,22 @@ mod tests {
     #[case("1.2.x", vec!["1.2.0", "1.2.9"], true)]
     #[case("1.2.x", vec!["1.1.9", "1.3.0"], false)]
     #[case("1.2.X", vec!["1.2.5"], true)]
+    // 1.x.x and 1.* behave as 1.x
+    #[case("1.x.x", vec!["1.4.0"], true)]
+    #[case("1.*", vec!["2.0.0"], false)]
+    // Bare 1 behaves as 1.x
+    #[case("1", vec!["1.0.0", "1.9.9"], true)]
+    #[case("1", vec!["0.9.9", "2.0.0"], false)]
+    // Bare 1.2 behaves as 1.2.x, not as exact 1.2.0
+    #[case("1.2", vec!["1.2.5"], true)]
+    #[case("1.2", vec!["1.1.9", "1.3.0"], false)]
+    // Lone x and X behave as *
+    #[case("x", vec!["0.0.1"], true)]
+    #[case("X", vec!["1.0.0", "2.0.0"], true)]
+    #[case("X.x", vec!["3.1.4"], true)]
+    // Bare versions combine with other ranges
+    #[case(">=1.1 1", vec!["1.0.0"], false)]
+    #[case("1 || 3", vec!["3.2.0"], true)]
     fn version_exists_wildcards(
         #[case] version_spec: &str,
         #[case] available: Vec<&str>,
@@ -469,6 +578,9 @@ mod tests {
     #[case(">=1.0.0 <1.5.0 || >=2.0.0", vec!["2.5.0"], true)]
     #[case("1.0.0 || 2.0.0 || 3.0.0", vec!["2.0.0"], true)]
     #[case("1.0.0 || 2.0.0 || 3.0.0", vec!["4.0.0"], false)]
+    #[case("^2.0.0 || ^1.0.0", vec!["1.0.0"], true)]
+    #[case("  ^1.0.0||^2.0.0  ", vec!["2.0.0"], true)]
+    #[case("^1.0.0 || invalid", vec!["1.0.0"], false)]
     fn version_exists_or_range(
         #[case] version_spec: &str,
         #[case] available: Vec<&str>,
@@ -494,6 +606,16 @@ mod tests {
     #[case(">1.0.0 <=2.0.0", vec!["1.0.0"], false)]
     #[case(">=1.2.0 <1.3.0", vec!["1.2.5"], true)]
     #[case(">=1.2.0 <1.3.0", vec!["1.3.0"], false)]
+    // Three-part compound ranges
+    #[case(">=1.0.0 <2.0.0 >1.2.0", vec!["1.2.0"], false)]
+    #[case(">=1.0.0 <2.0.0 >1.2.0", vec!["1.5.0"], true)]
+    #[case(">=1.0.0 <2.0.0 >1.2.0", vec!["2.0.0"], false)]
+    // Mixing >= with <=
+    #[case(">=1.2.3 <=1.4.0", vec!["1.4.0"], true)]
+    #[case(">=1.2.3 <=1.4.0", vec!["1.4.1"], false)]
+    // Whitespace between operator and version
+    #[case(">= 1.2.3 < 2.0.0", vec!["1.5.0"], true)]
+    #[case(">= 1.2.3 < 2.0.0", vec!["2.0.0"], false)]
     fn version_exists_and_range(
         #[case] version_spec: &str,
         #[case] available: Vec<&str>,
@@ -517,6 +639,14 @@ mod tests {
     #[case("1.2.3 - 2.3.4", vec!["1.2.3", "2.3.4"], true)]
     #[case("1.2.3 - 2.3.4", vec!["1.2.2"], false)]
     #[case("1.2.3 - 2.3.4", vec!["2.3.5"], false)]
+    // Partial upper bound covers the whole release line
+    #[case("1.2.3 - 2.3", vec!["2.3.9"], true)]
+    #[case("1.2.3 - 2.3", vec!["2.4.0"], false)]
+    #[case("1.2.3 - 2", vec!["2.9.9"], true)]
+    #[case("1.2.3 - 2", vec!["3.0.0"], false)]
+    // Partial lower bound is filled with zeros
+    #[case("1.2 - 2.3.4", vec!["1.2.0"], true)]
+    #[case("1.2 - 2.3.4", vec!["1.1.9"], false)]
     fn version_exists_hyphen_range(
         #[case] version_spec: &str,
         #[case] available: Vec<&str>,
@@ -531,12 +661,14 @@ mod tests {
 
     // version_exists tests - partial versions (normalized)
     #[rstest]
-    // 0.14 should be treated as exact 0.14.0 in npm
+    // 0.14 should be treated as 0.14.x in npm
     #[case("0.14", vec!["0.14.0"], true)]
-    #[case("0.14", vec!["0.14.1"], false)]
-    // 1 should be treated as exact 1.0.0
+    #[case("0.14", vec!["0.14.1"], true)]
+    #[case("0.14", vec!["0.15.0"], false)]
+    // 1 should be treated as 1.x
     #[case("1", vec!["1.0.0"], true)]
-    #[case("1", vec!["1.0.1"], false)]
+    #[case("1", vec!["1.0.1"], true)]
+    #[case("1", vec!["2.0.0"], false)]
     // ^0.14 should be treated as ^0.14.0
     #[case("^0.14", vec!["0.14.0", "0.14.5"], true)]
     #[case("^0.14", vec!["0.15.0"], false)]
@@ -578,20 +710,41 @@ mod tests {
     #[case("*", "999.0.0", CompareResult::Latest)]
     #[case("1.x", "1.9.9", CompareResult::Latest)]
     #[case("1.x", "2.0.0", CompareResult::Outdated)]
+    #[case("1", "1.9.9", CompareResult::Latest)]
+    #[case("1.2", "1.2.7", CompareResult::Latest)]
+    #[case("1.2", "1.3.0", CompareResult::Outdated)]
+    #[case("X", "5.0.0", CompareResult::Latest)]
     #[case("1.2.x", "1.2.9", CompareResult::Latest)]
     #[case("1.2.x", "1.3.0", CompareResult::Outdated)]
     // Hyphen ranges
     #[case("1.0.0 - 2.0.0", "1.5.0", CompareResult::Latest)]
     #[case("1.0.0 - 2.0.0", "2.0.0", CompareResult::Latest)]
     #[case("1.0.0 - 2.0.0", "2.5.0", CompareResult::Outdated)]
+    #[case("1.0.0 - 2.3", "2.3.5", CompareResult::Latest)]
+    #[case("1.0.0 - 2.3", "2.4.0", CompareResult::Outdated)]
+    #[case("3.0.0 - 4.0.0", "2.0.0", CompareResult::Newer)]
     // AND ranges
     #[case(">=1.0.0 <2.0.0", "1.5.0", CompareResult::Latest)]
     #[case(">=1.0.0 <2.0.0", "2.0.0", CompareResult::Outdated)]
     #[case(">=1.0.0 <2.0.0", "0.9.0", CompareResult::Newer)]
+    #[case("<2.0.0 >=1.2.3", "1.0.0", CompareResult::Newer)]
+    #[case(">=1.0.0 <2.0.0 >1.2.0", "1.1.0", CompareResult::Newer)]
+    #[case(">=1.0.0 <2.0.0 >1.2.0", "2.1.0", CompareResult::Outdated)]
+    #[case(">=1.2.3 <=1.4.0", "1.4.0", CompareResult::Latest)]
+    #[case(">=1.2.3 <=1.4.0", "1.5.0", CompareResult::Outdated)]
+    #[case(">= 1.2.3 < 2.0.0", "1.9.0", CompareResult::Latest)]
+    // Open-ended floors accept any newer release
+    #[case(">=16.8.0", "18.3.1", CompareResult::Latest)]
+    #[case(">16.8.0", "18.3.1", CompareResult::Latest)]
+    #[case(">=16.8.0", "16.8.0", CompareResult::Latest)]
+    #[case(">=16.8.0", "16.7.0", CompareResult::Newer)]
+    #[case("^16.8.0 || >=17", "18.3.1", CompareResult::Latest)]
     // OR ranges
     #[case("^1.0.0 || ^2.0.0", "1.5.0", CompareResult::Latest)]
     #[case("^1.0.0 || ^2.0.0", "2.5.0", CompareResult::Latest)]
     #[case("^1.0.0 || ^2.0.0", "3.0.0", CompareResult::Outdated)]
+    #[case("^2.0.0 || ^1.0.0", "3.0.0", CompareResult::Outdated)]
+    #[case("^1.0.0 || ^3.0.0", "2.0.0", CompareResult::Newer)]
     // Invalid versions
     #[case("invalid", "1.0.0", CompareResult::Invalid)]
     #[case("1.0.0", "invalid", CompareResult::Invalid)]
@@ -605,4 +758,28 @@ mod tests {
             expected
         );
     }
+
+    #[rstest]
+    #[case("~1.2.0", Some("1.2.9"))]
+    #[case("^1.2.0", Some("1.4.0"))]
+    #[case(">=1.0.0 <1.3.0", Some("1.2.9"))]
+    // Already on the newest version the range allows
+    #[case("~1.2.9", None)]
+    #[case("1.2.0", None)]
+    #[case("invalid", None)]
+    fn latest_in_range_returns_newest_allowed_version(
+        #[case] spec: &str,
+        #[case] expected: Option<&str>,
+    ) {
+        let available: Vec<String> = ["1.2.0", "1.2.9", "1.3.0-beta.1", "1.4.0", "2.0.0"]
+            .into_iter()
+            .map(String::from)
+            .collect();
+        assert_eq!(
+            NpmVersionMatcher
+                .latest_in_range(spec, &available)
+                .as_deref(),
+            expected
+        );
+    }
 }
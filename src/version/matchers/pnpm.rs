@@ -1,26 +1,85 @@
 //! pnpm catalog version matcher
 //!
 //! Uses the same version matching logic as npm since pnpm catalogs use npm registry.
+//! npm aliases (`npm:react@^18`) are matched by their trailing range, and
+//! `workspace:` entries never need an update.
 
+use crate::parser::pnpm_workspace::{is_registry_spec, split_npm_alias};
 use crate::parser::types::RegistryType;
 use crate::version::matcher::VersionMatcher;
-use crate::version::matchers::npm::{npm_compare_to_latest, npm_version_exists};
+use crate::version::matchers::npm::{
+    npm_compare_to_latest, npm_latest_in_range, npm_version_exists,
+};
 use crate::version::semver::CompareResult;
 
 /// pnpm catalog version matcher
 /// Uses the same logic as npm since pnpm catalogs use npm registry
 pub struct PnpmCatalogMatcher;
 
+/// Version range a catalog entry is matched by, or None for entries that
+/// don't refer to a registry version
+fn catalog_range(version_spec: &str) -> Option<&str> {
+    if !is_registry_spec(version_spec) {
+        return None;
+    }
+    Some(split_npm_alias(version_spec).map_or(version_spec, |(_, range)| range))
+}
+
 impl VersionMatcher for PnpmCatalogMatcher {
     fn registry_type(&self) -> RegistryType {
         RegistryType::PnpmCatalog
     }
 
     fn version_exists(&self, version_spec: &str, available_versions: &[String]) -> bool {
-        npm_version_exists(version_spec, available_versions)
+        catalog_range(version_spec)
+            .is_none_or(|range| npm_version_exists(range, available_versions))
     }
 
     fn compare_to_latest(&self, current_version: &str, latest_version: &str) -> CompareResult {
-        npm_compare_to_latest(current_version, latest_version)
+        match catalog_range(current_version) {
+            Some(range) => npm_compare_to_latest(range, latest_version),
+            // Workspace packages are always at their own version
+            None => CompareResult::Latest,
+        }
+    }
+
+    fn latest_in_range(&self, version_spec: &str, available_versions: &[String]) -> Option<String> {
+        npm_latest_in_range(catalog_range(version_spec)?, available_versions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("^18.2.0", "18.3.1", CompareResult::Latest)]
+    #[case("^17.0.2", "18.3.1", CompareResult::Outdated)]
+    #[case("npm:react@^17.0.2", "18.3.1", CompareResult::Outdated)]
+    #[case("npm:@types/node@^20.0.0", "20.11.0", CompareResult::Latest)]
+    #[case("workspace:*", "18.3.1", CompareResult::Latest)]
+    fn compare_to_latest_returns_expected(
+        #[case] current: &str,
+        #[case] latest: &str,
+        #[case] expected: CompareResult,
+    ) {
+        assert_eq!(
+            PnpmCatalogMatcher.compare_to_latest(current, latest),
+            expected
+        );
+    }
+
+    #[rstest]
+    #[case("^18.2.0", true)]
+    #[case("npm:react@^17.0.2", true)]
+    #[case("npm:react@^16.0.0", false)]
+    #[case("workspace:*", true)]
+    fn version_exists_returns_expected(#[case] version_spec: &str, #[case] expected: bool) {
+        let available = vec!["17.0.2".to_string(), "18.3.1".to_string()];
+        assert_eq!(
+            PnpmCatalogMatcher.version_exists(version_spec, &available),
+            expected
+        );
     }
 }
@@ -1,5 +1,9 @@
 //! PyPI version matcher using PEP 440 version specifiers
+//!
+//! Poetry constraints (`^1.2`, `~1.2`, `1.2.*`, bare `1.2`) are translated to
+//! their PEP 440 equivalents before matching.
 
+use std::borrow::Cow;
 use std::str::FromStr;
 
 use pep508_rs::pep440_rs::{Version, VersionSpecifiers};
@@ -18,6 +22,8 @@ impl VersionMatcher for PypiVersionMatcher {
     }
 
     fn version_exists(&self, version_spec: &str, available_versions: &[String]) -> bool {
+        let version_spec = &*poetry_to_pep440(version_spec);
+
         // Empty spec matches any version
         if version_spec.is_empty() {
             return !available_versions.is_empty();
@@ -42,6 +48,8 @@ impl VersionMatcher for PypiVersionMatcher {
     }
 
     fn compare_to_latest(&self, current_version: &str, latest_version: &str) -> CompareResult {
+        let current_version = &*poetry_to_pep440(current_version);
+
         // Empty spec is always satisfied by latest
         if current_version.is_empty() {
             return CompareResult::Latest;
@@ -89,6 +97,81 @@ impl VersionMatcher for PypiVersionMatcher {
     }
 }
 
+/// Translate a Poetry version constraint to PEP 440 specifiers
+///
+/// - `^1.2.3` -> `>=1.2.3,<2`, `^0.2.3` -> `>=0.2.3,<0.3`
+/// - `~1.2.3` -> `>=1.2.3,<1.3`, `~1` -> `>=1,<2`
+/// - `1.2.3` -> `==1.2.3`, `1.2.*` -> `==1.2.*`
+/// - `*` -> any version
+///
+/// PEP 440 specifiers are returned unchanged.
+fn poetry_to_pep440(spec: &str) -> Cow<'_, str> {
+    let is_poetry_clause = |clause: &str| {
+        clause == "*"
+            || clause.starts_with('^')
+            || (clause.starts_with('~') && !clause.starts_with("~="))
+            || clause.starts_with(|c: char| c.is_ascii_digit())
+    };
+
+    let clauses: Vec<&str> = spec.split(',').map(str::trim).collect();
+    if !clauses.iter().any(|clause| is_poetry_clause(clause)) {
+        return Cow::Borrowed(spec);
+    }
+
+    let translated: Vec<String> = clauses
+        .into_iter()
+        .filter(|clause| *clause != "*")
+        .map(|clause| {
+            if let Some(version) = clause.strip_prefix('^') {
+                bounded_range(version.trim(), caret_bump_index)
+            } else if let Some(version) = clause
+                .strip_prefix('~')
+                .filter(|_| !clause.starts_with("~="))
+            {
+                bounded_range(version.trim(), |parts| usize::from(parts.len() >= 2))
+            } else if clause.starts_with(|c: char| c.is_ascii_digit()) {
+                format!("=={}", clause)
+            } else {
+                clause.to_string()
+            }
+        })
+        .collect();
+
+    Cow::Owned(translated.join(","))
+}
+
+/// Build `>=version,<upper` where `upper` bumps the release segment chosen by `bump_index`
+///
+/// Versions whose release segment is not numeric are returned unchanged and
+/// fail to parse later.
+fn bounded_range(version: &str, bump_index: impl Fn(&[u64]) -> usize) -> String {
+    let Ok(parts) = version
+        .split('.')
+        .map(str::parse::<u64>)
+        .collect::<Result<Vec<_>, _>>()
+    else {
+        return version.to_string();
+    };
+
+    let index = bump_index(&parts);
+    let upper: Vec<String> = parts[..index]
+        .iter()
+        .map(u64::to_string)
+        .chain(std::iter::once((parts[index] + 1).to_string()))
+        .collect();
+
+    format!(">={},<{}", version, upper.join("."))
+}
+
+/// Index of the release segment bumped by a caret constraint: the first
+/// non-zero segment, or the last one when all segments are zero
+fn caret_bump_index(parts: &[u64]) -> usize {
+    parts
+        .iter()
+        .position(|part| *part != 0)
+        .unwrap_or(parts.len() - 1)
+}
+
 /// Extract the base version from a PEP 440 version specifier
 fn extract_base_version(spec: &str) -> Option<&str> {
     let spec = spec.trim();
@@ -248,6 +331,39 @@ mod tests {
         );
     }
 
+    #[rstest]
+    #[case("^2.31", ">=2.31,<3")]
+    #[case("^0.2.3", ">=0.2.3,<0.3")]
+    #[case("^0.0.3", ">=0.0.3,<0.0.4")]
+    #[case("^0.0", ">=0.0,<0.1")]
+    #[case("~1.2.3", ">=1.2.3,<1.3")]
+    #[case("~1", ">=1,<2")]
+    #[case("1.2.3", "==1.2.3")]
+    #[case("1.2.*", "==1.2.*")]
+    #[case("*", "")]
+    #[case(">=1.2,<1.5", ">=1.2,<1.5")]
+    #[case("~=1.4.2", "~=1.4.2")]
+    fn poetry_to_pep440_returns_expected(#[case] spec: &str, #[case] expected: &str) {
+        assert_eq!(poetry_to_pep440(spec), expected);
+    }
+
+    #[rstest]
+    #[case("^2.31", "2.32.3", CompareResult::Latest)]
+    #[case("^2.31", "3.0.0", CompareResult::Outdated)]
+    #[case("~0.27", "0.28.0", CompareResult::Outdated)]
+    #[case("6.1", "6.1", CompareResult::Latest)]
+    #[case("*", "6.1", CompareResult::Latest)]
+    fn compare_to_latest_handles_poetry_constraints(
+        #[case] current: &str,
+        #[case] latest: &str,
+        #[case] expected: CompareResult,
+    ) {
+        assert_eq!(
+            PypiVersionMatcher.compare_to_latest(current, latest),
+            expected
+        );
+    }
+
     #[test]
     fn registry_type_returns_pypi() {
         assert_eq!(PypiVersionMatcher.registry_type(), RegistryType::PyPI);
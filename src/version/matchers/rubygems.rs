@@ -0,0 +1,403 @@
+//! RubyGems version matcher
+//!
+//! Supports Gemfile version requirement specifications:
+//! - `1.2.3` / `= 1.2.3` - exact match
+//! - `~> 1.2.3` - pessimistic: >=1.2.3 <1.3 (`~> 1.2` means >=1.2 <2)
+//! - `>= 1.2.3`, `> 1.2.3`, `<= 1.2.3`, `< 1.2.3`, `!= 1.2.3` - comparison operators
+//! - `>= 1.0, < 2.0` - comma-separated requirements (all must match)
+//!
+//! Gem versions may have any number of segments (`6.1.7.6`) and string
+//! segments mark prereleases (`7.1.0.rc1`), so they are compared segment by
+//! segment instead of going through semver.
+
+use std::cmp::Ordering;
+
+use crate::parser::types::RegistryType;
+use crate::version::matcher::VersionMatcher;
+use crate::version::semver::CompareResult;
+
+pub struct RubyGemsVersionMatcher;
+
+/// A single segment of a gem version
+#[derive(Debug, Clone)]
+enum Segment {
+    Number(u64),
+    String(String),
+}
+
+/// A parsed gem version, compared the way `Gem::Version` does
+#[derive(Debug, Clone)]
+struct GemVersion {
+    segments: Vec<Segment>,
+}
+
+impl GemVersion {
+    fn parse(version: &str) -> Option<Self> {
+        let version = version.trim();
+        if version.is_empty() {
+            return None;
+        }
+
+        let mut segments = Vec::new();
+        for part in version.split(['.', '-']) {
+            if part.is_empty() || !part.chars().all(|c| c.is_ascii_alphanumeric()) {
+                return None;
+            }
+            // "rc1" is split into "rc" and 1, as Gem::Version does
+            let mut rest = part;
+            while !rest.is_empty() {
+                let is_digit = rest.starts_with(|c: char| c.is_ascii_digit());
+                let end = rest
+                    .find(|c: char| c.is_ascii_digit() != is_digit)
+                    .unwrap_or(rest.len());
+                let (token, tail) = rest.split_at(end);
+                segments.push(if is_digit {
+                    Segment::Number(token.parse().ok()?)
+                } else {
+                    Segment::String(token.to_string())
+                });
+                rest = tail;
+            }
+        }
+
+        if !matches!(segments.first(), Some(Segment::Number(_))) {
+            return None;
+        }
+
+        Some(Self { segments })
+    }
+
+    /// Leading numeric segments, used for pessimistic bounds
+    fn release_segments(&self) -> Vec<u64> {
+        self.segments
+            .iter()
+            .map_while(|s| match s {
+                Segment::Number(n) => Some(*n),
+                Segment::String(_) => None,
+            })
+            .collect()
+    }
+
+    /// The version without its prerelease segments (`7.1.0.rc1` -> 7.1.0)
+    fn release(&self) -> Self {
+        Self {
+            segments: self
+                .release_segments()
+                .into_iter()
+                .map(Segment::Number)
+                .collect(),
+        }
+    }
+
+    /// Upper bound for `~>`: drop the last segment and bump the new last one
+    ///
+    /// `~> 1.2.3` -> 1.3, `~> 1.2` -> 2, `~> 1` -> 2
+    fn pessimistic_upper(&self) -> Self {
+        let mut release = self.release_segments();
+        if release.len() > 1 {
+            release.pop();
+        }
+        if let Some(last) = release.last_mut() {
+            *last += 1;
+        }
+        Self {
+            segments: release.into_iter().map(Segment::Number).collect(),
+        }
+    }
+}
+
+impl Ord for GemVersion {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let len = self.segments.len().max(other.segments.len());
+        let zero = Segment::Number(0);
+        for i in 0..len {
+            let a = self.segments.get(i).unwrap_or(&zero);
+            let b = other.segments.get(i).unwrap_or(&zero);
+            let ordering = match (a, b) {
+                (Segment::Number(a), Segment::Number(b)) => a.cmp(b),
+                (Segment::String(a), Segment::String(b)) => a.cmp(b),
+                // Release segments sort after prerelease segments
+                (Segment::Number(_), Segment::String(_)) => Ordering::Greater,
+                (Segment::String(_), Segment::Number(_)) => Ordering::Less,
+            };
+            if ordering != Ordering::Equal {
+                return ordering;
+            }
+        }
+        Ordering::Equal
+    }
+}
+
+impl PartialOrd for GemVersion {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for GemVersion {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for GemVersion {}
+
+/// Represents a parsed gem version requirement
+#[derive(Debug)]
+enum VersionRequirement {
+    /// Pessimistic: ~> 1.2.3 means >=1.2.3 <1.3
+    Pessimistic(GemVersion),
+    /// Exact: = 1.2.3 or bare 1.2.3
+    Exact(GemVersion),
+    /// Not equal
+    NotEqual(GemVersion),
+    /// Greater than or equal
+    Gte(GemVersion),
+    /// Greater than
+    Gt(GemVersion),
+    /// Less than or equal
+    Lte(GemVersion),
+    /// Less than
+    Lt(GemVersion),
+}
+
+impl VersionRequirement {
+    /// Parse a single version requirement (not comma-separated)
+    fn parse(spec: &str) -> Option<Self> {
+        let spec = spec.trim();
+
+        if let Some(rest) = spec.strip_prefix("~>") {
+            GemVersion::parse(rest).map(VersionRequirement::Pessimistic)
+        } else if let Some(rest) = spec.strip_prefix(">=") {
+            GemVersion::parse(rest).map(VersionRequirement::Gte)
+        } else if let Some(rest) = spec.strip_prefix('>') {
+            GemVersion::parse(rest).map(VersionRequirement::Gt)
+        } else if let Some(rest) = spec.strip_prefix("<=") {
+            GemVersion::parse(rest).map(VersionRequirement::Lte)
+        } else if let Some(rest) = spec.strip_prefix('<') {
+            GemVersion::parse(rest).map(VersionRequirement::Lt)
+        } else if let Some(rest) = spec.strip_prefix("!=") {
+            GemVersion::parse(rest).map(VersionRequirement::NotEqual)
+        } else if let Some(rest) = spec.strip_prefix('=') {
+            GemVersion::parse(rest).map(VersionRequirement::Exact)
+        } else {
+            GemVersion::parse(spec).map(VersionRequirement::Exact)
+        }
+    }
+
+    /// Check if a version satisfies this requirement
+    fn satisfies(&self, version: &GemVersion) -> bool {
+        match self {
+            // Like Gem::Requirement, prereleases of the bound itself are
+            // outside it: `~> 7.0.1` rejects 7.1.0.rc1
+            VersionRequirement::Pessimistic(v) => {
+                version >= v && version.release() < v.pessimistic_upper()
+            }
+            VersionRequirement::Exact(v) => version == v,
+            VersionRequirement::NotEqual(v) => version != v,
+            VersionRequirement::Gte(v) => version >= v,
+            VersionRequirement::Gt(v) => version > v,
+            VersionRequirement::Lte(v) => version <= v,
+            VersionRequirement::Lt(v) => version < v,
+        }
+    }
+
+    /// Lower bound of this requirement, if it has one
+    fn lower_bound(&self) -> Option<&GemVersion> {
+        match self {
+            VersionRequirement::Pessimistic(v)
+            | VersionRequirement::Exact(v)
+            | VersionRequirement::Gte(v)
+            | VersionRequirement::Gt(v) => Some(v),
+            VersionRequirement::NotEqual(_)
+            | VersionRequirement::Lte(_)
+            | VersionRequirement::Lt(_) => None,
+        }
+    }
+
+    fn version(&self) -> &GemVersion {
+        match self {
+            VersionRequirement::Pessimistic(v)
+            | VersionRequirement::Exact(v)
+            | VersionRequirement::NotEqual(v)
+            | VersionRequirement::Gte(v)
+            | VersionRequirement::Gt(v)
+            | VersionRequirement::Lte(v)
+            | VersionRequirement::Lt(v) => v,
+        }
+    }
+}
+
+/// Represents a compound version specification (multiple requirements)
+#[derive(Debug)]
+struct VersionSpec {
+    /// All requirements must be satisfied (AND)
+    requirements: Vec<VersionRequirement>,
+}
+
+impl VersionSpec {
+    /// Parse a version specification (may be comma-separated)
+    fn parse(spec: &str) -> Option<Self> {
+        let spec = spec.trim();
+        if spec.is_empty() {
+            return None;
+        }
+
+        let requirements: Option<Vec<VersionRequirement>> =
+            spec.split(',').map(VersionRequirement::parse).collect();
+
+        requirements.map(|reqs| VersionSpec { requirements: reqs })
+    }
+
+    /// Check if a version satisfies all requirements
+    fn satisfies(&self, version: &GemVersion) -> bool {
+        self.requirements.iter().all(|req| req.satisfies(version))
+    }
+
+    /// Get the base version: the highest lower bound, falling back to the first requirement
+    fn base_version(&self) -> Option<&GemVersion> {
+        self.requirements
+            .iter()
+            .filter_map(VersionRequirement::lower_bound)
+            .max()
+            .or_else(|| self.requirements.first().map(VersionRequirement::version))
+    }
+}
+
+impl VersionMatcher for RubyGemsVersionMatcher {
+    fn registry_type(&self) -> RegistryType {
+        RegistryType::RubyGems
+    }
+
+    fn version_exists(&self, version_spec: &str, available_versions: &[String]) -> bool {
+        let Some(spec) = VersionSpec::parse(version_spec) else {
+            return false;
+        };
+
+        available_versions.iter().any(|v| {
+            GemVersion::parse(v)
+                .map(|ver| spec.satisfies(&ver))
+                .unwrap_or(false)
+        })
+    }
+
+    fn compare_to_latest(&self, current_version: &str, latest_version: &str) -> CompareResult {
+        let Some(spec) = VersionSpec::parse(current_version) else {
+            return CompareResult::Invalid;
+        };
+
+        let Some(latest) = GemVersion::parse(latest_version) else {
+            return CompareResult::Invalid;
+        };
+
+        if spec.satisfies(&latest) {
+            return CompareResult::Latest;
+        }
+
+        let Some(base) = spec.base_version() else {
+            return CompareResult::Invalid;
+        };
+
+        if *base < latest {
+            CompareResult::Outdated
+        } else {
+            CompareResult::Newer
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("1.0", "1.0.0", Ordering::Equal)]
+    #[case("6.1.7.6", "6.1.7", Ordering::Greater)]
+    #[case("7.1.0", "7.0.8", Ordering::Greater)]
+    #[case("1.10.0", "1.9.0", Ordering::Greater)]
+    #[case("7.1.0.rc1", "7.1.0", Ordering::Less)]
+    #[case("7.1.0.rc1", "7.0.8", Ordering::Greater)]
+    #[case("7.1.0.beta1", "7.1.0.rc1", Ordering::Less)]
+    #[case("7.1.0.rc1", "7.1.0.rc2", Ordering::Less)]
+    fn gem_version_ordering(#[case] a: &str, #[case] b: &str, #[case] expected: Ordering) {
+        let a = GemVersion::parse(a).unwrap();
+        let b = GemVersion::parse(b).unwrap();
+        assert_eq!(a.cmp(&b), expected);
+    }
+
+    #[rstest]
+    // Pessimistic with three segments: >=7.0.4 <7.1
+    #[case("~> 7.0.4", vec!["7.0.4", "7.0.8"], true)]
+    #[case("~> 7.0.4", vec!["7.1.0"], false)]
+    #[case("~> 7.0.4", vec!["7.0.3"], false)]
+    // Pessimistic with two segments: >=7.0 <8
+    #[case("~> 7.0", vec!["7.1.0"], true)]
+    #[case("~> 7.0", vec!["8.0.0"], false)]
+    // Prereleases of the upper bound are outside it
+    #[case("~> 7.0.1", vec!["7.1.0.rc1"], false)]
+    #[case("~> 7.0", vec!["8.0.0.beta1"], false)]
+    #[case("~> 7.0", vec!["7.2.0.rc1"], true)]
+    // Pessimistic with one segment: >=7 <8
+    #[case("~> 7", vec!["7.9.9"], true)]
+    #[case("~> 7", vec!["8.0"], false)]
+    // Exact
+    #[case("7.0.8", vec!["7.0.8"], true)]
+    #[case("= 7.0.8", vec!["7.0.8"], true)]
+    #[case("7.0.8", vec!["7.0.7", "7.1.0"], false)]
+    // Comparison operators
+    #[case(">= 6.1", vec!["7.0.0"], true)]
+    #[case(">= 6.1", vec!["6.0.9"], false)]
+    #[case("> 6.1", vec!["6.1.0"], false)]
+    #[case("< 7", vec!["6.1.7.6"], true)]
+    #[case("<= 7", vec!["7.0.1"], false)]
+    #[case("!= 7.0.8", vec!["7.0.8"], false)]
+    // Multiple requirements
+    #[case(">= 6.1, < 7.1", vec!["7.0.8"], true)]
+    #[case(">= 6.1, < 7.1", vec!["7.1.0"], false)]
+    #[case("~> 7.0, >= 7.0.4", vec!["7.0.3"], false)]
+    // Invalid
+    #[case("", vec!["1.0.0"], false)]
+    #[case("~> abc", vec!["1.0.0"], false)]
+    fn version_exists_returns_expected(
+        #[case] version_spec: &str,
+        #[case] available: Vec<&str>,
+        #[case] expected: bool,
+    ) {
+        let available: Vec<String> = available.into_iter().map(|s| s.to_string()).collect();
+        assert_eq!(
+            RubyGemsVersionMatcher.version_exists(version_spec, &available),
+            expected
+        );
+    }
+
+    #[rstest]
+    #[case("~> 7.0", "7.1.3", CompareResult::Latest)]
+    #[case("~> 7.0", "8.0.0", CompareResult::Outdated)]
+    #[case("~> 7.0.4", "7.1.3", CompareResult::Outdated)]
+    // The arity of the pessimistic version sets the bound
+    #[case("~> 7.0.1", "7.1.0", CompareResult::Outdated)]
+    #[case("~> 7.0.1", "7.0.9", CompareResult::Latest)]
+    #[case("~> 7.0", "7.9.9", CompareResult::Latest)]
+    #[case("~> 7", "8.0.0", CompareResult::Outdated)]
+    #[case("~> 7.0.1", "7.1.0.rc1", CompareResult::Outdated)]
+    #[case("~>7.0", "8.0.0", CompareResult::Outdated)]
+    #[case("7.0.8", "7.0.8", CompareResult::Latest)]
+    #[case("7.0.7", "7.0.8", CompareResult::Outdated)]
+    #[case("8.0.0", "7.1.3", CompareResult::Newer)]
+    #[case(">= 6.1", "7.1.3", CompareResult::Latest)]
+    #[case(">= 6.1, < 7", "7.1.3", CompareResult::Outdated)]
+    #[case("< 7", "7.1.3", CompareResult::Outdated)]
+    #[case("invalid", "7.1.3", CompareResult::Invalid)]
+    #[case("~> 7.0", "invalid", CompareResult::Invalid)]
+    fn compare_to_latest_returns_expected(
+        #[case] current: &str,
+        #[case] latest: &str,
+        #[case] expected: CompareResult,
+    ) {
+        assert_eq!(
+            RubyGemsVersionMatcher.compare_to_latest(current, latest),
+            expected
+        );
+    }
+}
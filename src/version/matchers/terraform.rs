@@ -0,0 +1,253 @@
+//! Terraform provider version matcher
+//!
+//! Supports Terraform version constraints:
+//! - `1.2.3` / `= 1.2.3` - exact match
+//! - `~> 1.2.3` - pessimistic: only the rightmost component may grow, >=1.2.3 <1.3.0
+//!   (`~> 1.2` means >=1.2.0 <2.0.0; `~> 1` means >=1.0.0)
+//! - `>= 1.2.3`, `> 1.2.3`, `<= 1.2.3`, `< 1.2.3`, `!= 1.2.3` - comparison operators
+//! - `>= 1.0, < 2.0` - comma-separated constraints (all must match)
+//!
+//! As in Terraform, pre-releases only match constraints that name them exactly.
+
+use semver::Version;
+
+use crate::parser::types::RegistryType;
+use crate::version::matcher::VersionMatcher;
+use crate::version::semver::{CompareResult, parse_version};
+
+pub struct TerraformVersionMatcher;
+
+/// Represents a single parsed Terraform version constraint
+#[derive(Debug)]
+enum VersionConstraint {
+    /// Pessimistic: `~> 1.2.3`, with the number of components that were given
+    Pessimistic(Version, usize),
+    /// Exact: `= 1.2.3` or bare `1.2.3`
+    Exact(Version),
+    /// Not equal
+    NotEqual(Version),
+    /// Greater than or equal
+    Gte(Version),
+    /// Greater than
+    Gt(Version),
+    /// Less than or equal
+    Lte(Version),
+    /// Less than
+    Lt(Version),
+}
+
+impl VersionConstraint {
+    /// Parse a single version constraint (not comma-separated)
+    fn parse(spec: &str) -> Option<Self> {
+        let spec = spec.trim();
+
+        if let Some(rest) = spec.strip_prefix("~>") {
+            let rest = rest.trim();
+            let components = rest.split(['-', '+']).next()?.split('.').count();
+            parse_version(rest).map(|v| VersionConstraint::Pessimistic(v, components))
+        } else if let Some(rest) = spec.strip_prefix(">=") {
+            parse_version(rest.trim()).map(VersionConstraint::Gte)
+        } else if let Some(rest) = spec.strip_prefix('>') {
+            parse_version(rest.trim()).map(VersionConstraint::Gt)
+        } else if let Some(rest) = spec.strip_prefix("<=") {
+            parse_version(rest.trim()).map(VersionConstraint::Lte)
+        } else if let Some(rest) = spec.strip_prefix('<') {
+            parse_version(rest.trim()).map(VersionConstraint::Lt)
+        } else if let Some(rest) = spec.strip_prefix("!=") {
+            parse_version(rest.trim()).map(VersionConstraint::NotEqual)
+        } else if let Some(rest) = spec.strip_prefix('=') {
+            parse_version(rest.trim()).map(VersionConstraint::Exact)
+        } else {
+            parse_version(spec).map(VersionConstraint::Exact)
+        }
+    }
+
+    fn version(&self) -> &Version {
+        match self {
+            VersionConstraint::Pessimistic(v, _)
+            | VersionConstraint::Exact(v)
+            | VersionConstraint::NotEqual(v)
+            | VersionConstraint::Gte(v)
+            | VersionConstraint::Gt(v)
+            | VersionConstraint::Lte(v)
+            | VersionConstraint::Lt(v) => v,
+        }
+    }
+
+    /// Exclusive upper bound of a pessimistic constraint: the component left
+    /// of the rightmost one is bumped (`~> 1.2.3` -> 1.3.0, `~> 1.2` -> 2.0.0)
+    ///
+    /// `~> 1` names a single component, which may grow freely.
+    fn pessimistic_upper(version: &Version, components: usize) -> Option<Version> {
+        match components {
+            0 | 1 => None,
+            2 => Some(Version::new(version.major + 1, 0, 0)),
+            _ => Some(Version::new(version.major, version.minor + 1, 0)),
+        }
+    }
+
+    /// Check if a version satisfies this constraint
+    fn satisfies(&self, version: &Version) -> bool {
+        // Pre-releases are only selected by an exact match
+        if !version.pre.is_empty() && !matches!(self, VersionConstraint::Exact(_)) {
+            return false;
+        }
+
+        match self {
+            VersionConstraint::Pessimistic(v, components) => {
+                version >= v
+                    && Self::pessimistic_upper(v, *components).is_none_or(|upper| *version < upper)
+            }
+            VersionConstraint::Exact(v) => version == v,
+            VersionConstraint::NotEqual(v) => version != v,
+            VersionConstraint::Gte(v) => version >= v,
+            VersionConstraint::Gt(v) => version > v,
+            VersionConstraint::Lte(v) => version <= v,
+            VersionConstraint::Lt(v) => version < v,
+        }
+    }
+
+    /// Whether a version that does not satisfy this constraint lies above
+    /// its range rather than below it
+    fn is_above(&self, version: &Version) -> bool {
+        match self {
+            VersionConstraint::Lt(_) | VersionConstraint::Lte(_) => true,
+            VersionConstraint::Gt(_) | VersionConstraint::Gte(_) => false,
+            VersionConstraint::Pessimistic(..)
+            | VersionConstraint::Exact(_)
+            | VersionConstraint::NotEqual(_) => version > self.version(),
+        }
+    }
+}
+
+/// Represents a compound version constraint (comma-separated, all must match)
+#[derive(Debug)]
+struct VersionSpec {
+    constraints: Vec<VersionConstraint>,
+}
+
+impl VersionSpec {
+    /// Parse a version constraint string (may be comma-separated)
+    fn parse(spec: &str) -> Option<Self> {
+        let spec = spec.trim();
+        if spec.is_empty() {
+            return None;
+        }
+
+        let constraints: Option<Vec<VersionConstraint>> =
+            spec.split(',').map(VersionConstraint::parse).collect();
+
+        constraints.map(|constraints| VersionSpec { constraints })
+    }
+
+    /// Check if a version satisfies all constraints
+    fn satisfies(&self, version: &Version) -> bool {
+        self.constraints.iter().all(|c| c.satisfies(version))
+    }
+
+    /// Whether a version lies above the range, i.e. exceeds the upper bound
+    /// of a constraint it does not satisfy
+    fn is_exceeded_by(&self, version: &Version) -> bool {
+        self.constraints
+            .iter()
+            .any(|c| !c.satisfies(version) && c.is_above(version))
+    }
+}
+
+impl VersionMatcher for TerraformVersionMatcher {
+    fn registry_type(&self) -> RegistryType {
+        RegistryType::TerraformRegistry
+    }
+
+    fn version_exists(&self, version_spec: &str, available_versions: &[String]) -> bool {
+        let Some(spec) = VersionSpec::parse(version_spec) else {
+            return false;
+        };
+
+        available_versions.iter().any(|v| {
+            Version::parse(v)
+                .map(|ver| spec.satisfies(&ver))
+                .unwrap_or(false)
+        })
+    }
+
+    fn compare_to_latest(&self, current_version: &str, latest_version: &str) -> CompareResult {
+        let Some(spec) = VersionSpec::parse(current_version) else {
+            return CompareResult::Invalid;
+        };
+
+        let Ok(latest) = Version::parse(latest_version) else {
+            return CompareResult::Invalid;
+        };
+
+        if spec.satisfies(&latest) {
+            return CompareResult::Latest;
+        }
+
+        if spec.is_exceeded_by(&latest) {
+            CompareResult::Outdated
+        } else {
+            CompareResult::Newer
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    // Pessimistic with three components: only the patch may grow
+    #[case("~> 5.0.1", vec!["5.0.9"], true)]
+    #[case("~> 5.0.1", vec!["5.0.0", "5.1.0"], false)]
+    // Pessimistic with two components: minor and patch may grow
+    #[case("~> 5.0", vec!["5.31.0"], true)]
+    #[case("~> 5.0", vec!["6.0.0"], false)]
+    // A single component only sets a lower bound
+    #[case("~> 5", vec!["7.2.0"], true)]
+    #[case("~> 5", vec!["4.9.0"], false)]
+    #[case("5.31.0", vec!["5.31.0"], true)]
+    #[case("= 5.31.0", vec!["5.31.1"], false)]
+    #[case(">= 3.1, < 4.0", vec!["3.6.2"], true)]
+    #[case(">= 3.1, < 4.0", vec!["4.0.0"], false)]
+    #[case("!= 3.6.2", vec!["3.6.2"], false)]
+    // Pre-releases only match exactly
+    #[case("~> 6.0", vec!["6.1.0-beta1"], false)]
+    #[case("6.1.0-beta1", vec!["6.1.0-beta1"], true)]
+    fn version_exists_returns_expected(
+        #[case] version_spec: &str,
+        #[case] available: Vec<&str>,
+        #[case] expected: bool,
+    ) {
+        let available: Vec<String> = available.into_iter().map(String::from).collect();
+        assert_eq!(
+            TerraformVersionMatcher.version_exists(version_spec, &available),
+            expected
+        );
+    }
+
+    #[rstest]
+    #[case("~> 5.0", "5.31.0", CompareResult::Latest)]
+    #[case("~> 5.0", "6.0.0", CompareResult::Outdated)]
+    #[case("~> 5.0.1", "5.0.9", CompareResult::Latest)]
+    #[case("~> 5.0.1", "5.1.0", CompareResult::Outdated)]
+    #[case("~> 5", "9.0.0", CompareResult::Latest)]
+    #[case("~> 6.0", "5.31.0", CompareResult::Newer)]
+    #[case("5.31.0", "5.31.0", CompareResult::Latest)]
+    #[case("5.30.0", "5.31.0", CompareResult::Outdated)]
+    #[case(">= 3.1, < 4.0", "3.6.2", CompareResult::Latest)]
+    #[case(">= 3.1, < 4.0", "4.1.0", CompareResult::Outdated)]
+    #[case(">= 3.1", "3.0.0", CompareResult::Newer)]
+    #[case("latest", "5.31.0", CompareResult::Invalid)]
+    fn compare_to_latest_returns_expected(
+        #[case] current: &str,
+        #[case] latest: &str,
+        #[case] expected: CompareResult,
+    ) {
+        assert_eq!(
+            TerraformVersionMatcher.compare_to_latest(current, latest),
+            expected
+        );
+    }
+}
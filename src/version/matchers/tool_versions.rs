@@ -0,0 +1,68 @@
+//! asdf `.tool-versions` version matcher
+//!
+//! Tools are pinned to a release (`nodejs 20.11.0`); mise also accepts a
+//! release series (`nodejs 20`). Versions are compared against the latest
+//! release only up to their own precision, so `nodejs 23` is latest while the
+//! newest release is `23.1.0`.
+
+use crate::parser::types::RegistryType;
+use crate::version::matcher::VersionMatcher;
+use crate::version::matchers::homebrew::{series_compare_to_latest, series_version_exists};
+use crate::version::semver::CompareResult;
+
+pub struct ToolVersionsMatcher;
+
+impl VersionMatcher for ToolVersionsMatcher {
+    fn registry_type(&self) -> RegistryType {
+        RegistryType::ToolVersions
+    }
+
+    fn version_exists(&self, version_spec: &str, available_versions: &[String]) -> bool {
+        series_version_exists(version_spec, available_versions)
+    }
+
+    fn compare_to_latest(&self, current_version: &str, latest_version: &str) -> CompareResult {
+        series_compare_to_latest(current_version, latest_version)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("20.11.0", vec!["18.19.0", "20.11.0", "23.1.0"], true)]
+    #[case("20", vec!["18.19.0", "20.11.0", "23.1.0"], true)]
+    #[case("20.11.1", vec!["18.19.0", "20.11.0", "23.1.0"], false)]
+    #[case("lts", vec!["20.11.0"], false)]
+    fn version_exists_returns_expected(
+        #[case] version_spec: &str,
+        #[case] available: Vec<&str>,
+        #[case] expected: bool,
+    ) {
+        let available: Vec<String> = available.into_iter().map(|s| s.to_string()).collect();
+        assert_eq!(
+            ToolVersionsMatcher.version_exists(version_spec, &available),
+            expected
+        );
+    }
+
+    #[rstest]
+    #[case("23.1.0", "23.1.0", CompareResult::Latest)]
+    #[case("23", "23.1.0", CompareResult::Latest)]
+    #[case("20.11.0", "23.1.0", CompareResult::Outdated)]
+    #[case("1.22.0", "1.22.3", CompareResult::Outdated)]
+    #[case("24.0.0", "23.1.0", CompareResult::Newer)]
+    #[case("system", "23.1.0", CompareResult::Invalid)]
+    fn compare_to_latest_returns_expected(
+        #[case] current: &str,
+        #[case] latest: &str,
+        #[case] expected: CompareResult,
+    ) {
+        assert_eq!(
+            ToolVersionsMatcher.compare_to_latest(current, latest),
+            expected
+        );
+    }
+}
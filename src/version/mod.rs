@@ -24,6 +24,7 @@
 //! - [`checker`]: Version comparison and status determination
 //! - [`matcher`]: Version matching trait and registry-specific implementations
 //! - [`registry`]: Registry trait for fetching versions from remote sources
+//! - [`rate_limit`]: Per-registry token bucket pacing registry requests
 //! - [`registries`]: Concrete registry implementations (npm, crates.io, etc.)
 //! - [`error`]: Error types for cache and registry operations
 //! - [`semver`]: Shared semver utilities
@@ -34,6 +35,7 @@ pub mod checker;
 pub mod error;
 pub mod matcher;
 pub mod matchers;
+pub mod rate_limit;
 pub mod registries;
 pub mod registry;
 pub mod semver;
@@ -0,0 +1,170 @@
+//! Request rate limiting for registries
+//!
+//! Each registry instance gets its own token bucket, so a burst of lookups
+//! against one host (e.g. opening a large Cargo.toml) is spread out instead
+//! of hammering it, while other registries are unaffected.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::sync::Mutex;
+use tokio::time::{Instant, sleep};
+
+use crate::parser::types::RegistryType;
+use crate::version::error::RegistryError;
+use crate::version::registry::Registry;
+use crate::version::types::PackageVersions;
+
+/// Token bucket allowing `requests_per_second` requests per second
+///
+/// The bucket holds up to one second's worth of tokens, so short bursts go
+/// out immediately and sustained traffic is paced at the configured rate.
+pub struct RateLimiter {
+    requests_per_second: f64,
+    bucket: Mutex<Bucket>,
+}
+
+struct Bucket {
+    tokens: f64,
+    last_refill: Instant,
+}
+
+impl RateLimiter {
+    /// Create a limiter with a full bucket. `requests_per_second` must be non-zero.
+    pub fn new(requests_per_second: u32) -> Self {
+        let requests_per_second = f64::from(requests_per_second.max(1));
+        Self {
+            requests_per_second,
+            bucket: Mutex::new(Bucket {
+                tokens: requests_per_second,
+                last_refill: Instant::now(),
+            }),
+        }
+    }
+
+    /// Wait until a request may be sent
+    ///
+    /// Waiters hold the bucket while sleeping, so they are served in the
+    /// order they arrived.
+    pub async fn acquire(&self) {
+        let mut bucket = self.bucket.lock().await;
+        self.refill(&mut bucket);
+
+        if bucket.tokens < 1.0 {
+            let missing = 1.0 - bucket.tokens;
+            sleep(Duration::from_secs_f64(missing / self.requests_per_second)).await;
+            self.refill(&mut bucket);
+        }
+
+        bucket.tokens = (bucket.tokens - 1.0).max(0.0);
+    }
+
+    fn refill(&self, bucket: &mut Bucket) {
+        let now = Instant::now();
+        let elapsed = now.duration_since(bucket.last_refill).as_secs_f64();
+        bucket.tokens =
+            (bucket.tokens + elapsed * self.requests_per_second).min(self.requests_per_second);
+        bucket.last_refill = now;
+    }
+}
+
+/// Registry wrapper that takes a token from a [`RateLimiter`] before each fetch
+///
+/// The limiter is shared by `Arc` so registries talking to the same host
+/// (npm and pnpm catalogs) draw from one bucket.
+pub struct RateLimitedRegistry {
+    inner: Arc<dyn Registry>,
+    limiter: Arc<RateLimiter>,
+}
+
+impl RateLimitedRegistry {
+    pub fn new(inner: Arc<dyn Registry>, limiter: Arc<RateLimiter>) -> Self {
+        Self { inner, limiter }
+    }
+}
+
+#[async_trait::async_trait]
+impl Registry for RateLimitedRegistry {
+    fn registry_type(&self) -> RegistryType {
+        self.inner.registry_type()
+    }
+
+    async fn fetch_all_versions(
+        &self,
+        package_name: &str,
+    ) -> Result<PackageVersions, RegistryError> {
+        self.limiter.acquire().await;
+        self.inner.fetch_all_versions(package_name).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex as StdMutex;
+
+    /// Registry that records when each request arrives
+    #[derive(Default)]
+    struct TimestampRegistry {
+        arrivals: StdMutex<Vec<Instant>>,
+    }
+
+    #[async_trait::async_trait]
+    impl Registry for TimestampRegistry {
+        fn registry_type(&self) -> RegistryType {
+            RegistryType::CratesIo
+        }
+
+        async fn fetch_all_versions(
+            &self,
+            _package_name: &str,
+        ) -> Result<PackageVersions, RegistryError> {
+            self.arrivals.lock().unwrap().push(Instant::now());
+            Ok(PackageVersions::new(vec!["1.0.0".to_string()]))
+        }
+    }
+
+    #[tokio::test]
+    async fn rate_limited_registry_keeps_requests_under_the_cap() {
+        const CAP: u32 = 10;
+        let inner = Arc::new(TimestampRegistry::default());
+        let registry = RateLimitedRegistry::new(inner.clone(), Arc::new(RateLimiter::new(CAP)));
+
+        let names: Vec<String> = (0..20).map(|i| format!("crate-{}", i)).collect();
+
+        let results =
+            futures::future::join_all(names.iter().map(|name| registry.fetch_all_versions(name)))
+                .await;
+
+        assert!(results.iter().all(Result::is_ok));
+        let arrivals = inner.arrivals.lock().unwrap();
+        assert_eq!(arrivals.len(), 20);
+
+        // A full bucket lets the first CAP requests through at once; every
+        // request after that has to wait for a token to be refilled
+        let first = arrivals[0];
+        for (i, arrival) in arrivals.iter().enumerate().skip(CAP as usize) {
+            let refilled = (i + 1 - CAP as usize) as f64 / f64::from(CAP);
+            let elapsed = arrival.duration_since(first).as_secs_f64();
+            assert!(
+                elapsed >= refilled * 0.95,
+                "request {} arrived after {:.3}s, expected at least {:.3}s",
+                i,
+                elapsed,
+                refilled
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn rate_limiter_does_not_delay_requests_within_the_burst() {
+        let limiter = RateLimiter::new(5);
+        let start = Instant::now();
+
+        for _ in 0..5 {
+            limiter.acquire().await;
+        }
+
+        assert!(start.elapsed() < Duration::from_millis(50));
+    }
+}
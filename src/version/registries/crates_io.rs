@@ -1,8 +1,11 @@
 //! crates.io registry API implementation
 
+use std::sync::Arc;
+
+use crate::netrc::Netrc;
 use crate::parser::types::RegistryType;
 use crate::version::error::RegistryError;
-use crate::version::registry::Registry;
+use crate::version::registry::{HttpSettings, Registry, http_client, send_with_retry};
 use crate::version::types::PackageVersions;
 use chrono::{DateTime, Utc};
 use serde::Deserialize;
@@ -29,19 +32,31 @@ struct CrateVersion {
 pub struct CratesIoRegistry {
     client: reqwest::Client,
     base_url: String,
+    /// Basic auth credentials, for private registries serving the crates.io API
+    netrc: Option<Arc<Netrc>>,
 }
 
 impl CratesIoRegistry {
     /// Creates a new CratesIoRegistry with a custom base URL
     pub fn new(base_url: &str) -> Self {
         Self {
-            client: reqwest::Client::builder()
-                .user_agent("version-lsp")
-                .build()
-                .expect("Failed to create HTTP client"),
+            client: http_client(&HttpSettings::default()),
             base_url: base_url.to_string(),
+            netrc: None,
         }
     }
+
+    /// Send requests with the given proxy and timeout settings
+    pub fn with_http_settings(mut self, settings: &HttpSettings) -> Self {
+        self.client = http_client(settings);
+        self
+    }
+
+    /// Send the `.netrc` login of the registry host as basic auth
+    pub fn with_netrc(mut self, netrc: Option<Arc<Netrc>>) -> Self {
+        self.netrc = netrc;
+        self
+    }
 }
 
 impl Default for CratesIoRegistry {
@@ -62,7 +77,15 @@ impl Registry for CratesIoRegistry {
     ) -> Result<PackageVersions, RegistryError> {
         let url = format!("{}/{}", self.base_url, package_name);
 
-        let response = self.client.get(&url).send().await?;
+        let mut request = self.client.get(&url);
+        if let Some(credentials) = self
+            .netrc
+            .as_deref()
+            .and_then(|netrc| netrc.credentials_for_url(&url))
+        {
+            request = request.basic_auth(&credentials.login, credentials.password.as_deref());
+        }
+        let response = send_with_retry(request).await?;
 
         let status = response.status();
 
@@ -83,24 +106,33 @@ impl Registry for CratesIoRegistry {
             RegistryError::InvalidResponse(e.to_string())
         })?;
 
-        // Filter out yanked versions and sort by created_at (oldest first, newest last)
-        let mut versions: Vec<(String, Option<DateTime<Utc>>)> = crate_info
+        // Sort by created_at (oldest first, newest last), keeping yanked versions apart
+        let mut versions: Vec<(String, bool, Option<DateTime<Utc>>)> = crate_info
             .versions
             .into_iter()
-            .filter(|v| !v.yanked)
             .map(|v| {
                 let timestamp = DateTime::parse_from_rfc3339(&v.created_at)
                     .ok()
                     .map(|dt| dt.with_timezone(&Utc));
-                (v.num, timestamp)
+                (v.num, v.yanked, timestamp)
             })
             .collect();
 
-        versions.sort_by_key(|(_, a)| *a);
+        versions.sort_by_key(|(_, _, a)| *a);
+
+        let published_at = versions
+            .iter()
+            .filter_map(|(v, _, ts)| ts.map(|ts| (v.clone(), ts.timestamp())))
+            .collect();
 
-        let versions: Vec<String> = versions.into_iter().map(|(v, _)| v).collect();
+        let (yanked, versions): (Vec<_>, Vec<_>) =
+            versions.into_iter().partition(|(_, yanked, _)| *yanked);
+        let versions: Vec<String> = versions.into_iter().map(|(v, _, _)| v).collect();
+        let yanked: Vec<String> = yanked.into_iter().map(|(v, _, _)| v).collect();
 
-        Ok(PackageVersions::new(versions))
+        Ok(PackageVersions::new(versions)
+            .with_yanked(yanked)
+            .with_published_at(published_at))
     }
 }
 
@@ -108,6 +140,7 @@ impl Registry for CratesIoRegistry {
 mod tests {
     use super::*;
     use mockito::Server;
+    use std::time::Duration;
 
     #[tokio::test]
     async fn fetch_all_versions_returns_versions_sorted_by_created_at() {
@@ -146,6 +179,7 @@ mod tests {
                 "1.0.2".to_string()
             ]
         );
+        assert_eq!(result.published_at.get("1.0.0"), Some(&1_577_836_800));
     }
 
     #[tokio::test]
@@ -167,6 +201,47 @@ mod tests {
         assert!(matches!(result, Err(RegistryError::NotFound(_))));
     }
 
+    #[tokio::test]
+    async fn fetch_all_versions_reports_network_error_through_unreachable_proxy() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/serde")
+            .with_status(200)
+            .expect(0)
+            .create_async()
+            .await;
+
+        let registry = CratesIoRegistry::new(&server.url()).with_http_settings(&HttpSettings {
+            proxy: Some("http://127.0.0.1:1".to_string()),
+            ..Default::default()
+        });
+        let result = registry.fetch_all_versions("serde").await;
+
+        mock.assert_async().await;
+        assert!(matches!(result, Err(RegistryError::Network(_))));
+    }
+
+    #[tokio::test]
+    async fn fetch_all_versions_reports_timeout_for_slow_response() {
+        let mut server = Server::new_async().await;
+        let _mock = server
+            .mock("GET", "/serde")
+            .with_status_code_from_request(|_| {
+                std::thread::sleep(Duration::from_millis(500));
+                200
+            })
+            .create_async()
+            .await;
+
+        let registry = CratesIoRegistry::new(&server.url()).with_http_settings(&HttpSettings {
+            timeout: Duration::from_millis(100),
+            ..Default::default()
+        });
+        let result = registry.fetch_all_versions("serde").await;
+
+        assert!(matches!(result, Err(RegistryError::Timeout)));
+    }
+
     #[tokio::test]
     async fn fetch_all_versions_excludes_yanked_versions() {
         let mut server = Server::new_async().await;
@@ -195,11 +270,12 @@ mod tests {
         let result = registry.fetch_all_versions("test-crate").await.unwrap();
 
         mock.assert_async().await;
-        // Yanked version 1.0.1 should be excluded
+        // Yanked version 1.0.1 should be excluded from the available versions
         assert_eq!(
             result.versions,
             vec!["1.0.0".to_string(), "1.0.2".to_string()]
         );
+        assert_eq!(result.yanked, vec!["1.0.1".to_string()]);
     }
 
     #[tokio::test]
@@ -6,7 +6,9 @@
 use crate::parser::types::RegistryType;
 use crate::version::error::RegistryError;
 use crate::version::matchers::docker::parse_docker_tag;
-use crate::version::registry::Registry;
+use crate::version::registry::{
+    HttpSettings, Registry, http_client, retry_after_secs, send_with_retry,
+};
 use crate::version::types::PackageVersions;
 use semver::Version;
 use serde::Deserialize;
@@ -57,10 +59,7 @@ impl DockerRegistry {
         ghcr_auth_url: &str,
     ) -> Self {
         Self {
-            client: reqwest::Client::builder()
-                .user_agent("version-lsp")
-                .build()
-                .expect("Failed to create HTTP client"),
+            client: http_client(&HttpSettings::default()),
             docker_hub_registry_url: docker_hub_registry_url.to_string(),
             docker_hub_auth_url: docker_hub_auth_url.to_string(),
             ghcr_registry_url: ghcr_registry_url.to_string(),
@@ -68,6 +67,12 @@ impl DockerRegistry {
         }
     }
 
+    /// Send requests with the given proxy and timeout settings
+    pub fn with_http_settings(mut self, settings: &HttpSettings) -> Self {
+        self.client = http_client(settings);
+        self
+    }
+
     /// Create a `DockerRegistry`, overriding any URL whose argument is `Some`
     /// and falling back to the hardcoded default for any `None`.
     pub fn with_overrides(
@@ -96,7 +101,7 @@ impl DockerRegistry {
             auth_url, service, repository
         );
 
-        let response = self.client.get(&url).send().await?;
+        let response = send_with_retry(self.client.get(&url)).await?;
         let status = response.status();
 
         if !status.is_success() {
@@ -124,12 +129,12 @@ impl DockerRegistry {
     ) -> Result<Vec<String>, RegistryError> {
         let url = format!("{}/v2/{}/tags/list", registry_url, repository);
 
-        let response = self
-            .client
-            .get(&url)
-            .header("Authorization", format!("Bearer {}", token))
-            .send()
-            .await?;
+        let response = send_with_retry(
+            self.client
+                .get(&url)
+                .header("Authorization", format!("Bearer {}", token)),
+        )
+        .await?;
 
         let status = response.status();
 
@@ -138,13 +143,8 @@ impl DockerRegistry {
         }
 
         if status == reqwest::StatusCode::TOO_MANY_REQUESTS {
-            let retry_after = response
-                .headers()
-                .get("retry-after")
-                .and_then(|v| v.to_str().ok())
-                .and_then(|v| v.parse().ok());
             return Err(RegistryError::RateLimited {
-                retry_after_secs: retry_after,
+                retry_after_secs: retry_after_secs(&response),
             });
         }
 
@@ -187,10 +187,7 @@ impl DockerRegistry {
 impl Default for DockerRegistry {
     fn default() -> Self {
         Self {
-            client: reqwest::Client::builder()
-                .user_agent("version-lsp")
-                .build()
-                .expect("Failed to create HTTP client"),
+            client: http_client(&HttpSettings::default()),
             docker_hub_registry_url: DOCKER_HUB_REGISTRY_URL.to_string(),
             docker_hub_auth_url: DOCKER_HUB_AUTH_URL.to_string(),
             ghcr_registry_url: GHCR_REGISTRY_URL.to_string(),
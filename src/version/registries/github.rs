@@ -2,15 +2,24 @@
 
 use crate::parser::types::RegistryType;
 use crate::version::error::RegistryError;
-use crate::version::registry::Registry;
+use crate::version::registry::{
+    HttpSettings, Registry, http_client, retry_after_secs, send_with_retry,
+};
 use crate::version::types::PackageVersions;
 use chrono::{DateTime, Utc};
 use serde::Deserialize;
-use tracing::warn;
+use serde::de::DeserializeOwned;
+use tracing::{debug, warn};
 
 /// Default base URL for GitHub API
 const DEFAULT_BASE_URL: &str = "https://api.github.com";
 
+/// Default maximum number of pages followed per listing
+const DEFAULT_MAX_PAGES: usize = 10;
+
+/// Environment variables holding a GitHub token, in lookup order
+const TOKEN_ENV_VARS: &[&str] = &["GITHUB_TOKEN", "GH_TOKEN"];
+
 /// Response from GitHub Releases API
 #[derive(Debug, Deserialize)]
 struct Release {
@@ -31,6 +40,12 @@ struct TagCommit {
     sha: String,
 }
 
+/// Response from GitHub Commits API
+#[derive(Debug, Deserialize)]
+struct Commit {
+    sha: String,
+}
+
 /// Trait for fetching commit SHA for a specific tag
 #[async_trait::async_trait]
 pub trait TagShaFetcher: Send + Sync {
@@ -46,48 +61,65 @@ pub trait TagShaFetcher: Send + Sync {
 pub struct GitHubRegistry {
     client: reqwest::Client,
     base_url: String,
+    /// Token sent as `Authorization: Bearer` to raise the API rate limit
+    token: Option<String>,
+    /// Maximum number of pages followed via the `Link` header
+    max_pages: usize,
 }
 
 impl GitHubRegistry {
     /// Creates a new GitHubRegistry with a custom base URL
     pub fn new(base_url: &str) -> Self {
         Self {
-            client: reqwest::Client::builder()
-                .user_agent("version-lsp")
-                .build()
-                .expect("Failed to create HTTP client"),
+            client: http_client(&HttpSettings::default()),
             base_url: base_url.to_string(),
+            token: None,
+            max_pages: DEFAULT_MAX_PAGES,
         }
     }
-}
 
-impl Default for GitHubRegistry {
-    fn default() -> Self {
-        // Allow overriding base URL for testing
-        let base_url =
-            std::env::var("GITHUB_API_BASE_URL").unwrap_or_else(|_| DEFAULT_BASE_URL.to_string());
-        Self::new(&base_url)
+    /// Send requests with the given proxy and timeout settings
+    pub fn with_http_settings(mut self, settings: &HttpSettings) -> Self {
+        self.client = http_client(settings);
+        self
     }
-}
 
-#[async_trait::async_trait]
-impl Registry for GitHubRegistry {
-    fn registry_type(&self) -> RegistryType {
-        RegistryType::GitHubActions
+    /// Limit how many pages of releases or tags are fetched
+    pub fn with_max_pages(mut self, max_pages: usize) -> Self {
+        self.max_pages = max_pages.max(1);
+        self
     }
 
-    async fn fetch_all_versions(
-        &self,
-        package_name: &str,
-    ) -> Result<PackageVersions, RegistryError> {
-        let url = format!("{}/repos/{}/releases", self.base_url, package_name);
+    /// Authenticate requests with `token`, if any
+    pub fn with_token(mut self, token: Option<String>) -> Self {
+        self.token = token;
+        self
+    }
+
+    /// Read a token from `GITHUB_TOKEN` or `GH_TOKEN`
+    pub fn token_from_env() -> Option<String> {
+        token_from_env_with(|name| std::env::var(name).ok())
+    }
 
-        let response = self
+    /// Build a GET request to the GitHub API
+    fn get(&self, url: &str) -> reqwest::RequestBuilder {
+        let request = self
             .client
-            .get(&url)
-            .header("Accept", "application/vnd.github+json")
-            .send()
-            .await?;
+            .get(url)
+            .header("Accept", "application/vnd.github+json");
+        match &self.token {
+            Some(token) => request.bearer_auth(token),
+            None => request,
+        }
+    }
+
+    /// Send a GET request, mapping error statuses to registry errors
+    async fn send(
+        &self,
+        url: &str,
+        package_name: &str,
+    ) -> Result<reqwest::Response, RegistryError> {
+        let response = send_with_retry(self.get(url)).await?;
 
         let status = response.status();
 
@@ -96,13 +128,8 @@ impl Registry for GitHubRegistry {
         }
 
         if status == reqwest::StatusCode::TOO_MANY_REQUESTS {
-            let retry_after = response
-                .headers()
-                .get("retry-after")
-                .and_then(|v| v.to_str().ok())
-                .and_then(|v| v.parse().ok());
             return Err(RegistryError::RateLimited {
-                retry_after_secs: retry_after,
+                retry_after_secs: retry_after_secs(&response),
             });
         }
 
@@ -114,11 +141,120 @@ impl Registry for GitHubRegistry {
             )));
         }
 
-        let releases: Vec<Release> = response.json().await.map_err(|e| {
-            warn!("Failed to parse GitHub releases response: {}", e);
+        Ok(response)
+    }
+
+    /// Fetch the SHA of the newest commit on `git_ref`, or on the default
+    /// branch when `git_ref` is `None`
+    pub async fn fetch_latest_commit(
+        &self,
+        package_name: &str,
+        git_ref: Option<&str>,
+    ) -> Result<String, RegistryError> {
+        let url = format!(
+            "{}/repos/{}/commits/{}",
+            self.base_url,
+            package_name,
+            git_ref.unwrap_or("HEAD")
+        );
+
+        let response = self.send(&url, package_name).await?;
+
+        let commit: Commit = response.json().await.map_err(|e| {
+            warn!("Failed to parse GitHub commit response: {}", e);
             RegistryError::InvalidResponse(e.to_string())
         })?;
 
+        Ok(commit.sha)
+    }
+
+    /// Fetch a paginated listing, following `Link: rel="next"` up to `max_pages`
+    async fn fetch_pages<T: DeserializeOwned>(
+        &self,
+        url: String,
+        package_name: &str,
+        kind: &str,
+    ) -> Result<Vec<T>, RegistryError> {
+        let mut items = Vec::new();
+        let mut next_url = Some(url);
+
+        for _ in 0..self.max_pages {
+            let Some(url) = next_url.take() else {
+                break;
+            };
+            let response = self.send(&url, package_name).await?;
+
+            next_url = response
+                .headers()
+                .get(reqwest::header::LINK)
+                .and_then(|v| v.to_str().ok())
+                .and_then(next_page_url);
+
+            let page: Vec<T> = response.json().await.map_err(|e| {
+                warn!("Failed to parse GitHub {} response: {}", kind, e);
+                RegistryError::InvalidResponse(e.to_string())
+            })?;
+            items.extend(page);
+        }
+
+        if next_url.is_some() {
+            debug!(
+                "Stopped after {} pages of {} for {}",
+                self.max_pages, kind, package_name
+            );
+        }
+
+        Ok(items)
+    }
+}
+
+/// URL of the `rel="next"` entry of a `Link` header
+fn next_page_url(link: &str) -> Option<String> {
+    link.split(',').find_map(|entry| {
+        let (url, params) = entry.split_once(';')?;
+        params
+            .split(';')
+            .any(|param| param.trim() == r#"rel="next""#)
+            .then(|| {
+                url.trim()
+                    .trim_start_matches('<')
+                    .trim_end_matches('>')
+                    .to_string()
+            })
+    })
+}
+
+impl Default for GitHubRegistry {
+    fn default() -> Self {
+        // Allow overriding base URL for testing
+        let base_url =
+            std::env::var("GITHUB_API_BASE_URL").unwrap_or_else(|_| DEFAULT_BASE_URL.to_string());
+        Self::new(&base_url).with_token(Self::token_from_env())
+    }
+}
+
+/// First non-empty token among [`TOKEN_ENV_VARS`]
+fn token_from_env_with(lookup: impl Fn(&str) -> Option<String>) -> Option<String> {
+    TOKEN_ENV_VARS
+        .iter()
+        .filter_map(|name| lookup(name))
+        .find(|token| !token.is_empty())
+}
+
+#[async_trait::async_trait]
+impl Registry for GitHubRegistry {
+    fn registry_type(&self) -> RegistryType {
+        RegistryType::GitHubActions
+    }
+
+    async fn fetch_all_versions(
+        &self,
+        package_name: &str,
+    ) -> Result<PackageVersions, RegistryError> {
+        let url = format!("{}/repos/{}/releases", self.base_url, package_name);
+
+        let releases: Vec<Release> = self.fetch_pages(url, package_name, "releases").await?;
+
         // Sort releases by published_at (oldest first, newest last)
         // Releases without published_at are placed at the beginning
         let mut releases_with_dates: Vec<(String, Option<DateTime<Utc>>)> = releases
@@ -134,12 +270,16 @@ impl Registry for GitHubRegistry {
 
         releases_with_dates.sort_by_key(|(_, a)| *a);
 
+        let published_at = releases_with_dates
+            .iter()
+            .filter_map(|(tag, ts)| ts.map(|ts| (tag.clone(), ts.timestamp())))
+            .collect();
         let versions = releases_with_dates
             .into_iter()
             .map(|(tag, _)| tag)
             .collect();
 
-        Ok(PackageVersions::new(versions))
+        Ok(PackageVersions::new(versions).with_published_at(published_at))
     }
 }
 
@@ -152,42 +292,7 @@ impl TagShaFetcher for GitHubRegistry {
     ) -> Result<String, RegistryError> {
         let url = format!("{}/repos/{}/tags", self.base_url, package_name);
 
-        let response = self
-            .client
-            .get(&url)
-            .header("Accept", "application/vnd.github+json")
-            .send()
-            .await?;
-
-        let status = response.status();
-
-        if status == reqwest::StatusCode::NOT_FOUND {
-            return Err(RegistryError::NotFound(package_name.to_string()));
-        }
-
-        if status == reqwest::StatusCode::TOO_MANY_REQUESTS {
-            let retry_after = response
-                .headers()
-                .get("retry-after")
-                .and_then(|v| v.to_str().ok())
-                .and_then(|v| v.parse().ok());
-            return Err(RegistryError::RateLimited {
-                retry_after_secs: retry_after,
-            });
-        }
-
-        if !status.is_success() {
-            warn!("GitHub API returned status {}: {}", status, url);
-            return Err(RegistryError::InvalidResponse(format!(
-                "Unexpected status: {}",
-                status
-            )));
-        }
-
-        let tags: Vec<Tag> = response.json().await.map_err(|e| {
-            warn!("Failed to parse GitHub tags response: {}", e);
-            RegistryError::InvalidResponse(e.to_string())
-        })?;
+        let tags: Vec<Tag> = self.fetch_pages(url, package_name, "tags").await?;
 
         // Find the tag with matching name
         tags.into_iter()
@@ -200,7 +305,146 @@ impl TagShaFetcher for GitHubRegistry {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use mockito::Server;
+    use mockito::{Matcher, Server};
+    use rstest::rstest;
+
+    #[tokio::test]
+    async fn fetch_all_versions_sends_token_as_bearer_header() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/repos/actions/checkout/releases")
+            .match_header("authorization", "Bearer ghp_test")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body("[]")
+            .create_async()
+            .await;
+
+        let registry = GitHubRegistry::new(&server.url()).with_token(Some("ghp_test".to_string()));
+        registry
+            .fetch_all_versions("actions/checkout")
+            .await
+            .unwrap();
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn fetch_all_versions_omits_authorization_without_token() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/repos/actions/checkout/releases")
+            .match_header("authorization", Matcher::Missing)
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body("[]")
+            .create_async()
+            .await;
+
+        let registry = GitHubRegistry::new(&server.url()).with_token(None);
+        registry
+            .fetch_all_versions("actions/checkout")
+            .await
+            .unwrap();
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn fetch_all_versions_follows_link_header_across_pages() {
+        let mut server = Server::new_async().await;
+        let page2_url = format!("{}/repositories/1/releases?page=2", server.url());
+
+        let page1 = server
+            .mock("GET", "/repos/actions/checkout/releases")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_header(
+                "link",
+                &format!(
+                    r#"<{}>; rel="next", <{}>; rel="last""#,
+                    page2_url, page2_url
+                ),
+            )
+            .with_body(r#"[{"tag_name": "v4.1.0", "published_at": "2024-01-15T00:00:00Z"}]"#)
+            .create_async()
+            .await;
+        let page2 = server
+            .mock("GET", "/repositories/1/releases?page=2")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"[{"tag_name": "v3.6.0", "published_at": "2023-12-01T00:00:00Z"}]"#)
+            .create_async()
+            .await;
+
+        let registry = GitHubRegistry::new(&server.url());
+        let result = registry
+            .fetch_all_versions("actions/checkout")
+            .await
+            .unwrap();
+
+        page1.assert_async().await;
+        page2.assert_async().await;
+        assert_eq!(result.versions, vec!["v3.6.0", "v4.1.0"]);
+    }
+
+    #[tokio::test]
+    async fn fetch_all_versions_stops_at_max_pages() {
+        let mut server = Server::new_async().await;
+        let next_url = format!("{}/repos/actions/checkout/releases", server.url());
+
+        // Every page links back to itself; only max_pages requests are made
+        let mock = server
+            .mock("GET", "/repos/actions/checkout/releases")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_header("link", &format!(r#"<{}>; rel="next""#, next_url))
+            .with_body(r#"[{"tag_name": "v4.0.0", "published_at": null}]"#)
+            .expect(3)
+            .create_async()
+            .await;
+
+        let registry = GitHubRegistry::new(&server.url()).with_max_pages(3);
+        let result = registry
+            .fetch_all_versions("actions/checkout")
+            .await
+            .unwrap();
+
+        mock.assert_async().await;
+        assert_eq!(result.versions.len(), 3);
+    }
+
+    #[rstest]
+    #[case(
+        r#"<https://api.github.com/repositories/1/releases?page=2>; rel="next", <https://api.github.com/repositories/1/releases?page=5>; rel="last""#,
+        Some("https://api.github.com/repositories/1/releases?page=2")
+    )]
+    #[case(
+        r#"<https://api.github.com/repositories/1/releases?page=1>; rel="prev""#,
+        None
+    )]
+    fn next_page_url_returns_expected(#[case] link: &str, #[case] expected: Option<&str>) {
+        assert_eq!(next_page_url(link).as_deref(), expected);
+    }
+
+    #[rstest]
+    #[case(Some("from-github"), Some("from-gh"), Some("from-github"))]
+    #[case(None, Some("from-gh"), Some("from-gh"))]
+    #[case(Some(""), Some("from-gh"), Some("from-gh"))]
+    #[case(None, None, None)]
+    fn token_from_env_with_prefers_github_token(
+        #[case] github_token: Option<&str>,
+        #[case] gh_token: Option<&str>,
+        #[case] expected: Option<&str>,
+    ) {
+        let token = token_from_env_with(|name| match name {
+            "GITHUB_TOKEN" => github_token.map(str::to_string),
+            "GH_TOKEN" => gh_token.map(str::to_string),
+            _ => None,
+        });
+
+        assert_eq!(token.as_deref(), expected);
+    }
 
     #[tokio::test]
     async fn fetch_all_versions_returns_releases_sorted_by_published_at() {
@@ -303,6 +547,45 @@ mod tests {
         assert!(result.is_empty());
     }
 
+    #[tokio::test]
+    async fn fetch_latest_commit_returns_sha_of_ref() {
+        let mut server = Server::new_async().await;
+
+        let mock = server
+            .mock("GET", "/repos/NixOS/nixpkgs/commits/nixos-24.05")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"sha": "9f4128e00b0ae8ec65918efeba59db998750ead6", "commit": {}}"#)
+            .create_async()
+            .await;
+
+        let registry = GitHubRegistry::new(&server.url());
+        let result = registry
+            .fetch_latest_commit("NixOS/nixpkgs", Some("nixos-24.05"))
+            .await
+            .unwrap();
+
+        mock.assert_async().await;
+        assert_eq!(result, "9f4128e00b0ae8ec65918efeba59db998750ead6");
+    }
+
+    #[tokio::test]
+    async fn fetch_latest_commit_returns_not_found_for_nonexistent_repo() {
+        let mut server = Server::new_async().await;
+
+        let mock = server
+            .mock("GET", "/repos/nonexistent/repo/commits/HEAD")
+            .with_status(404)
+            .create_async()
+            .await;
+
+        let registry = GitHubRegistry::new(&server.url());
+        let result = registry.fetch_latest_commit("nonexistent/repo", None).await;
+
+        mock.assert_async().await;
+        assert!(matches!(result, Err(RegistryError::NotFound(_))));
+    }
+
     #[tokio::test]
     async fn fetch_tag_sha_returns_sha_for_existing_tag() {
         let mut server = Server::new_async().await;
@@ -2,7 +2,7 @@
 
 use crate::parser::types::RegistryType;
 use crate::version::error::RegistryError;
-use crate::version::registry::Registry;
+use crate::version::registry::{HttpSettings, Registry, http_client, send_with_retry};
 use crate::version::types::PackageVersions;
 use semver::Version;
 use tracing::warn;
@@ -20,13 +20,16 @@ impl GoProxyRegistry {
     /// Creates a new GoProxyRegistry with a custom base URL
     pub fn new(base_url: &str) -> Self {
         Self {
-            client: reqwest::Client::builder()
-                .user_agent("version-lsp")
-                .build()
-                .expect("Failed to create HTTP client"),
+            client: http_client(&HttpSettings::default()),
             base_url: base_url.to_string(),
         }
     }
+
+    /// Send requests with the given proxy and timeout settings
+    pub fn with_http_settings(mut self, settings: &HttpSettings) -> Self {
+        self.client = http_client(settings);
+        self
+    }
 }
 
 impl Default for GoProxyRegistry {
@@ -50,7 +53,7 @@ impl Registry for GoProxyRegistry {
         let encoded_module = encode_module_path(package_name);
         let url = format!("{}/{}/@v/list", self.base_url, encoded_module);
 
-        let response = self.client.get(&url).send().await?;
+        let response = send_with_retry(self.client.get(&url)).await?;
 
         let status = response.status();
 
@@ -0,0 +1,147 @@
+//! Go release list implementation
+
+use crate::parser::types::RegistryType;
+use crate::version::error::RegistryError;
+use crate::version::registry::{HttpSettings, Registry, http_client, send_with_retry};
+use crate::version::semver::parse_version;
+use crate::version::types::PackageVersions;
+use serde::Deserialize;
+use tracing::warn;
+
+/// Default URL of the list of current Go releases
+const DEFAULT_RELEASES_URL: &str = "https://go.dev/dl/?mode=json";
+
+/// A release in the go.dev download list
+#[derive(Debug, Deserialize)]
+struct GoRelease {
+    /// Release name (e.g., "go1.22.0")
+    version: String,
+    stable: bool,
+}
+
+/// Registry implementation for the stable Go releases from go.dev
+///
+/// Serves the single package [`crate::parser::go_mod::GO_RELEASES_PACKAGE`],
+/// used to check `go` and `toolchain` directives of go.mod and Go pins of
+/// `.tool-versions` and mise.toml.
+pub struct GoReleaseRegistry {
+    client: reqwest::Client,
+    releases_url: String,
+}
+
+impl GoReleaseRegistry {
+    /// Creates a new GoReleaseRegistry with a custom release list URL
+    pub fn new(releases_url: &str) -> Self {
+        Self {
+            client: http_client(&HttpSettings::default()),
+            releases_url: releases_url.to_string(),
+        }
+    }
+
+    /// Send requests with the given proxy and timeout settings
+    pub fn with_http_settings(mut self, settings: &HttpSettings) -> Self {
+        self.client = http_client(settings);
+        self
+    }
+}
+
+impl Default for GoReleaseRegistry {
+    fn default() -> Self {
+        Self::new(DEFAULT_RELEASES_URL)
+    }
+}
+
+#[async_trait::async_trait]
+impl Registry for GoReleaseRegistry {
+    fn registry_type(&self) -> RegistryType {
+        RegistryType::GoRelease
+    }
+
+    /// Fetch the stable Go releases, without the `go` prefix (oldest first)
+    async fn fetch_all_versions(
+        &self,
+        _package_name: &str,
+    ) -> Result<PackageVersions, RegistryError> {
+        let response = send_with_retry(self.client.get(&self.releases_url)).await?;
+
+        let status = response.status();
+        if !status.is_success() {
+            warn!("Go release list returned status {}", status);
+            return Err(RegistryError::InvalidResponse(format!(
+                "Unexpected status: {}",
+                status
+            )));
+        }
+
+        let releases: Vec<GoRelease> = response.json().await.map_err(|e| {
+            warn!("Failed to parse Go release list: {}", e);
+            RegistryError::InvalidResponse(e.to_string())
+        })?;
+
+        let mut versions: Vec<String> = releases
+            .into_iter()
+            .filter(|release| release.stable)
+            .filter_map(|release| release.version.strip_prefix("go").map(str::to_string))
+            .collect();
+        versions.sort_by_key(|v| parse_version(v));
+
+        Ok(PackageVersions::new(versions))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::go_mod::GO_RELEASES_PACKAGE;
+    use mockito::Server;
+
+    #[tokio::test]
+    async fn fetch_all_versions_returns_stable_go_releases() {
+        let mut server = Server::new_async().await;
+
+        let mock = server
+            .mock("GET", "/dl/")
+            .match_query(mockito::Matcher::UrlEncoded("mode".into(), "json".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"[
+                    {"version": "go1.24rc1", "stable": false, "files": []},
+                    {"version": "go1.23.4", "stable": true, "files": []},
+                    {"version": "go1.22.10", "stable": true, "files": []}
+                ]"#,
+            )
+            .create_async()
+            .await;
+
+        let registry = GoReleaseRegistry::new(&format!("{}/dl/?mode=json", server.url()));
+        let result = registry
+            .fetch_all_versions(GO_RELEASES_PACKAGE)
+            .await
+            .unwrap();
+
+        mock.assert_async().await;
+        assert_eq!(
+            result.versions,
+            vec!["1.22.10".to_string(), "1.23.4".to_string()]
+        );
+    }
+
+    #[tokio::test]
+    async fn fetch_all_versions_returns_invalid_response_on_server_error() {
+        let mut server = Server::new_async().await;
+
+        let mock = server
+            .mock("GET", "/dl/")
+            .match_query(mockito::Matcher::Any)
+            .with_status(500)
+            .create_async()
+            .await;
+
+        let registry = GoReleaseRegistry::new(&format!("{}/dl/?mode=json", server.url()));
+        let result = registry.fetch_all_versions(GO_RELEASES_PACKAGE).await;
+
+        mock.assert_async().await;
+        assert!(matches!(result, Err(RegistryError::InvalidResponse(_))));
+    }
+}
@@ -0,0 +1,202 @@
+//! Homebrew formulae API implementation
+
+use std::collections::HashMap;
+
+use crate::parser::types::RegistryType;
+use crate::version::error::RegistryError;
+use crate::version::registry::{HttpSettings, Registry, http_client, send_with_retry};
+use crate::version::types::PackageVersions;
+use serde::Deserialize;
+use tracing::warn;
+
+/// Default base URL for the Homebrew formulae API
+const DEFAULT_BASE_URL: &str = "https://formulae.brew.sh/api/formula";
+
+/// Response from the `formula/{name}.json` endpoint
+#[derive(Debug, Deserialize)]
+struct FormulaResponse {
+    versions: FormulaVersions,
+    /// Versioned variants of the formula (e.g. `node@20`, `node@18`)
+    #[serde(default)]
+    versioned_formulae: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct FormulaVersions {
+    stable: Option<String>,
+}
+
+/// Registry implementation for the Homebrew formulae API
+///
+/// A formula only publishes its current stable version, so the versions of
+/// `node` are the `@` suffixes of its versioned formulae (`18`, `20`, ...)
+/// followed by the stable version, which is also the `latest` dist tag.
+pub struct HomebrewRegistry {
+    client: reqwest::Client,
+    base_url: String,
+}
+
+impl HomebrewRegistry {
+    /// Creates a new HomebrewRegistry with a custom base URL
+    pub fn new(base_url: &str) -> Self {
+        Self {
+            client: http_client(&HttpSettings::default()),
+            base_url: base_url.to_string(),
+        }
+    }
+
+    /// Send requests with the given proxy and timeout settings
+    pub fn with_http_settings(mut self, settings: &HttpSettings) -> Self {
+        self.client = http_client(settings);
+        self
+    }
+}
+
+impl Default for HomebrewRegistry {
+    fn default() -> Self {
+        Self::new(DEFAULT_BASE_URL)
+    }
+}
+
+#[async_trait::async_trait]
+impl Registry for HomebrewRegistry {
+    fn registry_type(&self) -> RegistryType {
+        RegistryType::Homebrew
+    }
+
+    async fn fetch_all_versions(
+        &self,
+        package_name: &str,
+    ) -> Result<PackageVersions, RegistryError> {
+        let url = format!("{}/{}.json", self.base_url, package_name);
+
+        let response = send_with_retry(self.client.get(&url)).await?;
+
+        let status = response.status();
+
+        if status == reqwest::StatusCode::NOT_FOUND {
+            return Err(RegistryError::NotFound(package_name.to_string()));
+        }
+
+        if !status.is_success() {
+            warn!("Homebrew API returned status {}: {}", status, url);
+            return Err(RegistryError::InvalidResponse(format!(
+                "Unexpected status: {}",
+                status
+            )));
+        }
+
+        let body: FormulaResponse = response.json().await.map_err(|e| {
+            warn!("Failed to parse Homebrew API response: {}", e);
+            RegistryError::InvalidResponse(e.to_string())
+        })?;
+
+        let Some(stable) = body.versions.stable else {
+            return Err(RegistryError::NotFound(package_name.to_string()));
+        };
+
+        // The API lists versioned formulae newest first; keep oldest first
+        let prefix = format!("{}@", package_name);
+        let mut versions: Vec<String> = body
+            .versioned_formulae
+            .iter()
+            .rev()
+            .filter_map(|formula| formula.strip_prefix(&prefix))
+            .map(str::to_string)
+            .collect();
+        versions.push(stable.clone());
+
+        Ok(PackageVersions::with_dist_tags(
+            versions,
+            HashMap::from([("latest".to_string(), stable)]),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::Server;
+
+    const NODE_RESPONSE: &str = r#"{
+        "name": "node",
+        "full_name": "node",
+        "versioned_formulae": ["node@22", "node@20", "node@18"],
+        "versions": {"stable": "23.1.0", "head": "HEAD", "bottle": true}
+    }"#;
+
+    #[tokio::test]
+    async fn fetch_all_versions_returns_versioned_formulae_and_stable() {
+        let mut server = Server::new_async().await;
+
+        let mock = server
+            .mock("GET", "/node.json")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(NODE_RESPONSE)
+            .create_async()
+            .await;
+
+        let registry = HomebrewRegistry::new(&server.url());
+        let result = registry.fetch_all_versions("node").await.unwrap();
+
+        mock.assert_async().await;
+        assert_eq!(result.versions, vec!["18", "20", "22", "23.1.0"]);
+        assert_eq!(result.resolve_dist_tag("latest"), Some("23.1.0"));
+    }
+
+    #[tokio::test]
+    async fn fetch_all_versions_returns_stable_without_versioned_formulae() {
+        let mut server = Server::new_async().await;
+
+        let mock = server
+            .mock("GET", "/jq.json")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"name": "jq", "versions": {"stable": "1.7.1", "head": "HEAD"}}"#)
+            .create_async()
+            .await;
+
+        let registry = HomebrewRegistry::new(&server.url());
+        let result = registry.fetch_all_versions("jq").await.unwrap();
+
+        mock.assert_async().await;
+        assert_eq!(result.versions, vec!["1.7.1"]);
+    }
+
+    #[tokio::test]
+    async fn fetch_all_versions_returns_not_found_for_404() {
+        let mut server = Server::new_async().await;
+
+        let mock = server
+            .mock("GET", "/missing.json")
+            .with_status(404)
+            .create_async()
+            .await;
+
+        let registry = HomebrewRegistry::new(&server.url());
+        let result = registry.fetch_all_versions("missing").await;
+
+        mock.assert_async().await;
+        assert!(matches!(result, Err(RegistryError::NotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn fetch_all_versions_returns_error_for_invalid_json() {
+        let mut server = Server::new_async().await;
+
+        let mock = server
+            .mock("GET", "/node.json")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body("not json")
+            .create_async()
+            .await;
+
+        let registry = HomebrewRegistry::new(&server.url());
+        let result = registry.fetch_all_versions("node").await;
+
+        mock.assert_async().await;
+        assert!(matches!(result, Err(RegistryError::InvalidResponse(_))));
+    }
+}
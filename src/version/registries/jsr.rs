@@ -4,7 +4,7 @@ use std::collections::HashMap;
 
 use crate::parser::types::RegistryType;
 use crate::version::error::RegistryError;
-use crate::version::registry::Registry;
+use crate::version::registry::{HttpSettings, Registry, http_client, send_with_retry};
 use crate::version::types::PackageVersions;
 use chrono::{DateTime, Utc};
 use serde::Deserialize;
@@ -41,13 +41,16 @@ impl JsrRegistry {
     /// Creates a new JsrRegistry with a custom base URL
     pub fn new(base_url: &str) -> Self {
         Self {
-            client: reqwest::Client::builder()
-                .user_agent("version-lsp")
-                .build()
-                .expect("Failed to create HTTP client"),
+            client: http_client(&HttpSettings::default()),
             base_url: base_url.to_string(),
         }
     }
+
+    /// Send requests with the given proxy and timeout settings
+    pub fn with_http_settings(mut self, settings: &HttpSettings) -> Self {
+        self.client = http_client(settings);
+        self
+    }
 }
 
 impl Default for JsrRegistry {
@@ -69,12 +72,8 @@ impl Registry for JsrRegistry {
         // JSR API URL: https://jsr.io/@scope/package/meta.json
         let url = format!("{}/{}/meta.json", self.base_url, package_name);
 
-        let response = self
-            .client
-            .get(&url)
-            .header("Accept", "application/json")
-            .send()
-            .await?;
+        let response =
+            send_with_retry(self.client.get(&url).header("Accept", "application/json")).await?;
 
         let status = response.status();
 
@@ -111,9 +110,13 @@ impl Registry for JsrRegistry {
 
         versions.sort_by_key(|(_, a)| *a);
 
+        let published_at = versions
+            .iter()
+            .filter_map(|(v, ts)| ts.map(|ts| (v.clone(), ts.timestamp())))
+            .collect();
         let versions: Vec<String> = versions.into_iter().map(|(v, _)| v).collect();
 
-        Ok(PackageVersions::new(versions))
+        Ok(PackageVersions::new(versions).with_published_at(published_at))
     }
 }
 
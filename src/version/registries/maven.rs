@@ -0,0 +1,228 @@
+//! Maven repository (Maven Central) implementation
+
+use std::collections::HashMap;
+
+use crate::parser::types::RegistryType;
+use crate::version::error::RegistryError;
+use crate::version::registry::{HttpSettings, Registry, http_client, send_with_retry};
+use crate::version::types::PackageVersions;
+use tracing::warn;
+
+/// Default base URL of Maven Central
+const DEFAULT_BASE_URL: &str = "https://repo1.maven.org/maven2";
+
+/// Registry implementation for Maven repositories
+///
+/// Package names are `group:artifact` coordinates such as
+/// `com.google.guava:guava`. Versions are read from the artifact's
+/// `maven-metadata.xml`, whose `<release>` becomes the `latest` dist tag.
+pub struct MavenRegistry {
+    client: reqwest::Client,
+    base_url: String,
+}
+
+impl MavenRegistry {
+    /// Creates a new MavenRegistry with a custom base URL
+    pub fn new(base_url: &str) -> Self {
+        Self {
+            client: http_client(&HttpSettings::default()),
+            base_url: base_url.to_string(),
+        }
+    }
+
+    /// Send requests with the given proxy and timeout settings
+    pub fn with_http_settings(mut self, settings: &HttpSettings) -> Self {
+        self.client = http_client(settings);
+        self
+    }
+}
+
+impl Default for MavenRegistry {
+    fn default() -> Self {
+        Self::new(DEFAULT_BASE_URL)
+    }
+}
+
+/// Text of every `<tag>` element in `xml`, in document order
+///
+/// maven-metadata.xml is flat and attribute-free, so plain scanning suffices.
+fn element_texts<'a>(xml: &'a str, tag: &str) -> Vec<&'a str> {
+    let open = format!("<{}>", tag);
+    let close = format!("</{}>", tag);
+    xml.match_indices(&open)
+        .filter_map(|(i, _)| {
+            let start = i + open.len();
+            let end = start + xml[start..].find(&close)?;
+            Some(xml[start..end].trim())
+        })
+        .filter(|text| !text.is_empty())
+        .collect()
+}
+
+#[async_trait::async_trait]
+impl Registry for MavenRegistry {
+    fn registry_type(&self) -> RegistryType {
+        RegistryType::Maven
+    }
+
+    async fn fetch_all_versions(
+        &self,
+        package_name: &str,
+    ) -> Result<PackageVersions, RegistryError> {
+        let Some((group, artifact)) = package_name.split_once(':') else {
+            return Err(RegistryError::NotFound(package_name.to_string()));
+        };
+        if group.is_empty() || artifact.is_empty() || artifact.contains(':') {
+            return Err(RegistryError::NotFound(package_name.to_string()));
+        }
+
+        let url = format!(
+            "{}/{}/{}/maven-metadata.xml",
+            self.base_url,
+            group.replace('.', "/"),
+            artifact
+        );
+
+        let response = send_with_retry(self.client.get(&url)).await?;
+
+        let status = response.status();
+
+        if status == reqwest::StatusCode::NOT_FOUND {
+            return Err(RegistryError::NotFound(package_name.to_string()));
+        }
+
+        if !status.is_success() {
+            warn!("Maven repository returned status {}: {}", status, url);
+            return Err(RegistryError::InvalidResponse(format!(
+                "Unexpected status: {}",
+                status
+            )));
+        }
+
+        let body = response.text().await.map_err(|e| {
+            warn!("Failed to read maven-metadata.xml: {}", e);
+            RegistryError::InvalidResponse(e.to_string())
+        })?;
+
+        // Only versions listed under <versioning><versions> are releases
+        let versions_section = body
+            .split_once("<versions>")
+            .and_then(|(_, rest)| rest.split_once("</versions>"))
+            .map(|(section, _)| section)
+            .ok_or_else(|| {
+                warn!("maven-metadata.xml without <versions>: {}", url);
+                RegistryError::InvalidResponse("Missing <versions> element".to_string())
+            })?;
+
+        // Versions are listed in the order they were deployed, oldest first
+        let versions: Vec<String> = element_texts(versions_section, "version")
+            .into_iter()
+            .map(String::from)
+            .collect();
+
+        let dist_tags = element_texts(&body, "release")
+            .first()
+            .map(|release| HashMap::from([("latest".to_string(), release.to_string())]))
+            .unwrap_or_default();
+
+        Ok(PackageVersions::with_dist_tags(versions, dist_tags))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::Server;
+
+    #[tokio::test]
+    async fn fetch_all_versions_reads_maven_metadata() {
+        let mut server = Server::new_async().await;
+
+        let mock = server
+            .mock("GET", "/com/google/guava/guava/maven-metadata.xml")
+            .with_status(200)
+            .with_header("content-type", "text/xml")
+            .with_body(
+                r#"<?xml version="1.0" encoding="UTF-8"?>
+<metadata>
+  <groupId>com.google.guava</groupId>
+  <artifactId>guava</artifactId>
+  <versioning>
+    <latest>33.0.0-jre</latest>
+    <release>33.0.0-jre</release>
+    <versions>
+      <version>32.1.3-android</version>
+      <version>32.1.3-jre</version>
+      <version>33.0.0-android</version>
+      <version>33.0.0-jre</version>
+    </versions>
+    <lastUpdated>20231219000000</lastUpdated>
+  </versioning>
+</metadata>"#,
+            )
+            .create_async()
+            .await;
+
+        let registry = MavenRegistry::new(&server.url());
+        let result = registry
+            .fetch_all_versions("com.google.guava:guava")
+            .await
+            .unwrap();
+
+        mock.assert_async().await;
+        assert_eq!(
+            result.versions,
+            vec![
+                "32.1.3-android",
+                "32.1.3-jre",
+                "33.0.0-android",
+                "33.0.0-jre"
+            ]
+        );
+        assert_eq!(result.resolve_dist_tag("latest"), Some("33.0.0-jre"));
+    }
+
+    #[tokio::test]
+    async fn fetch_all_versions_returns_not_found_for_nonexistent_artifact() {
+        let mut server = Server::new_async().await;
+
+        let mock = server
+            .mock("GET", "/org/example/missing/maven-metadata.xml")
+            .with_status(404)
+            .create_async()
+            .await;
+
+        let registry = MavenRegistry::new(&server.url());
+        let result = registry.fetch_all_versions("org.example:missing").await;
+
+        mock.assert_async().await;
+        assert!(matches!(result, Err(RegistryError::NotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn fetch_all_versions_returns_not_found_for_name_without_group() {
+        let registry = MavenRegistry::new("http://127.0.0.1:1");
+        let result = registry.fetch_all_versions("guava").await;
+
+        assert!(matches!(result, Err(RegistryError::NotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn fetch_all_versions_returns_error_for_metadata_without_versions() {
+        let mut server = Server::new_async().await;
+
+        let mock = server
+            .mock("GET", "/org/example/lib/maven-metadata.xml")
+            .with_status(200)
+            .with_header("content-type", "text/xml")
+            .with_body("<metadata></metadata>")
+            .create_async()
+            .await;
+
+        let registry = MavenRegistry::new(&server.url());
+        let result = registry.fetch_all_versions("org.example:lib").await;
+
+        mock.assert_async().await;
+        assert!(matches!(result, Err(RegistryError::InvalidResponse(_))));
+    }
+}
@@ -4,14 +4,30 @@ pub mod crates_io;
 pub mod docker;
 pub mod github;
 pub mod go_proxy;
+pub mod go_release;
+pub mod homebrew;
 pub mod jsr;
+pub mod maven;
+pub mod nix_flake;
 pub mod npm;
+pub mod packagist;
 pub mod pypi;
+pub mod rubygems;
+pub mod terraform;
+pub mod tool_versions;
 
 pub use crates_io::CratesIoRegistry;
 pub use docker::DockerRegistry;
 pub use github::GitHubRegistry;
 pub use go_proxy::GoProxyRegistry;
+pub use go_release::GoReleaseRegistry;
+pub use homebrew::HomebrewRegistry;
 pub use jsr::JsrRegistry;
+pub use maven::MavenRegistry;
+pub use nix_flake::NixFlakeRegistry;
 pub use npm::NpmRegistry;
+pub use packagist::PackagistRegistry;
 pub use pypi::PypiRegistry;
+pub use rubygems::RubyGemsRegistry;
+pub use terraform::TerraformRegistry;
+pub use tool_versions::ToolVersionsRegistry;
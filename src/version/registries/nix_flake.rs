@@ -0,0 +1,114 @@
+//! Nix flake input registry backed by the GitHub API
+
+use std::collections::HashMap;
+
+use crate::parser::flake_lock::SHORT_REV_LEN;
+use crate::parser::types::RegistryType;
+use crate::version::error::RegistryError;
+use crate::version::registries::github::GitHubRegistry;
+use crate::version::registry::Registry;
+use crate::version::types::PackageVersions;
+
+/// Registry implementation for GitHub flake inputs
+///
+/// Inputs are named like their flake reference (`owner/repo` or
+/// `owner/repo/ref`). The only version is the short rev of the newest commit
+/// on `ref`, or on the default branch when the input does not name one. Revs
+/// are not semver, so it is also returned as the `latest` dist tag.
+pub struct NixFlakeRegistry {
+    github: GitHubRegistry,
+}
+
+impl NixFlakeRegistry {
+    /// Creates a new NixFlakeRegistry fetching commits through `github`
+    pub fn new(github: GitHubRegistry) -> Self {
+        Self { github }
+    }
+}
+
+impl Default for NixFlakeRegistry {
+    fn default() -> Self {
+        Self::new(GitHubRegistry::default())
+    }
+}
+
+/// Split an input name into the repository and the optional ref
+///
+/// Refs may contain slashes (`release/24.05`), so everything after
+/// `owner/repo` belongs to the ref.
+fn split_input_name(package_name: &str) -> Option<(&str, Option<&str>)> {
+    let mut separators = package_name.match_indices('/').map(|(i, _)| i);
+    // Skip the separator between owner and repo
+    separators.next()?;
+    match separators.next() {
+        Some(repo_end) => Some((
+            &package_name[..repo_end],
+            Some(&package_name[repo_end + 1..]).filter(|r| !r.is_empty()),
+        )),
+        None => Some((package_name, None)),
+    }
+}
+
+#[async_trait::async_trait]
+impl Registry for NixFlakeRegistry {
+    fn registry_type(&self) -> RegistryType {
+        RegistryType::NixFlake
+    }
+
+    async fn fetch_all_versions(
+        &self,
+        package_name: &str,
+    ) -> Result<PackageVersions, RegistryError> {
+        let Some((repo, git_ref)) = split_input_name(package_name) else {
+            return Err(RegistryError::NotFound(package_name.to_string()));
+        };
+
+        let sha = self.github.fetch_latest_commit(repo, git_ref).await?;
+        let short_rev = sha.get(..SHORT_REV_LEN).unwrap_or(&sha).to_string();
+
+        let dist_tags = HashMap::from([("latest".to_string(), short_rev.clone())]);
+        Ok(PackageVersions::with_dist_tags(vec![short_rev], dist_tags))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::Server;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("NixOS/nixpkgs", Some(("NixOS/nixpkgs", None)))]
+    #[case("NixOS/nixpkgs/nixos-24.05", Some(("NixOS/nixpkgs", Some("nixos-24.05"))))]
+    #[case("owner/repo/release/24.05", Some(("owner/repo", Some("release/24.05"))))]
+    #[case("nixpkgs", None)]
+    fn split_input_name_returns_expected(
+        #[case] package_name: &str,
+        #[case] expected: Option<(&str, Option<&str>)>,
+    ) {
+        assert_eq!(split_input_name(package_name), expected);
+    }
+
+    #[tokio::test]
+    async fn fetch_all_versions_returns_short_rev_of_branch_head() {
+        let mut server = Server::new_async().await;
+
+        let mock = server
+            .mock("GET", "/repos/NixOS/nixpkgs/commits/nixos-24.05")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"sha": "9f4128e00b0ae8ec65918efeba59db998750ead6"}"#)
+            .create_async()
+            .await;
+
+        let registry = NixFlakeRegistry::new(GitHubRegistry::new(&server.url()));
+        let result = registry
+            .fetch_all_versions("NixOS/nixpkgs/nixos-24.05")
+            .await
+            .unwrap();
+
+        mock.assert_async().await;
+        assert_eq!(result.versions, vec!["9f4128e"]);
+        assert_eq!(result.resolve_dist_tag("latest"), Some("9f4128e"));
+    }
+}
@@ -0,0 +1,227 @@
+//! Packagist (Composer) registry API implementation
+
+use std::collections::HashMap;
+
+use crate::parser::types::RegistryType;
+use crate::version::error::RegistryError;
+use crate::version::registry::{HttpSettings, Registry, http_client, send_with_retry};
+use crate::version::types::PackageVersions;
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use tracing::warn;
+
+/// Default base URL for the Packagist metadata API
+const DEFAULT_BASE_URL: &str = "https://repo.packagist.org/p2";
+
+/// Response from the Packagist `p2/{vendor}/{package}.json` endpoint
+#[derive(Debug, Deserialize)]
+struct PackagistResponse {
+    packages: HashMap<String, Vec<PackagistVersion>>,
+}
+
+/// Version information from Packagist
+///
+/// The p2 metadata is minified: fields equal to the previous entry are
+/// omitted, so `time` may be missing.
+#[derive(Debug, Deserialize)]
+struct PackagistVersion {
+    version: String,
+    #[serde(default)]
+    time: Option<String>,
+}
+
+/// Registry implementation for the Packagist API
+pub struct PackagistRegistry {
+    client: reqwest::Client,
+    base_url: String,
+}
+
+impl PackagistRegistry {
+    /// Creates a new PackagistRegistry with a custom base URL
+    pub fn new(base_url: &str) -> Self {
+        Self {
+            client: http_client(&HttpSettings::default()),
+            base_url: base_url.to_string(),
+        }
+    }
+
+    /// Send requests with the given proxy and timeout settings
+    pub fn with_http_settings(mut self, settings: &HttpSettings) -> Self {
+        self.client = http_client(settings);
+        self
+    }
+}
+
+impl Default for PackagistRegistry {
+    fn default() -> Self {
+        Self::new(DEFAULT_BASE_URL)
+    }
+}
+
+#[async_trait::async_trait]
+impl Registry for PackagistRegistry {
+    fn registry_type(&self) -> RegistryType {
+        RegistryType::Composer
+    }
+
+    async fn fetch_all_versions(
+        &self,
+        package_name: &str,
+    ) -> Result<PackageVersions, RegistryError> {
+        let url = format!("{}/{}.json", self.base_url, package_name);
+
+        let response = send_with_retry(self.client.get(&url)).await?;
+
+        let status = response.status();
+
+        if status == reqwest::StatusCode::NOT_FOUND {
+            return Err(RegistryError::NotFound(package_name.to_string()));
+        }
+
+        if !status.is_success() {
+            warn!("Packagist registry returned status {}: {}", status, url);
+            return Err(RegistryError::InvalidResponse(format!(
+                "Unexpected status: {}",
+                status
+            )));
+        }
+
+        let mut body: PackagistResponse = response.json().await.map_err(|e| {
+            warn!("Failed to parse Packagist registry response: {}", e);
+            RegistryError::InvalidResponse(e.to_string())
+        })?;
+
+        let Some(releases) = body.packages.remove(package_name) else {
+            return Err(RegistryError::NotFound(package_name.to_string()));
+        };
+
+        // Tags are often `v`-prefixed (v7.0.0); keep the bare version, which is
+        // what constraints in composer.json are written against.
+        // Sort by release time (oldest first, newest last)
+        let mut versions: Vec<(String, Option<DateTime<Utc>>)> = releases
+            .into_iter()
+            .map(|v| {
+                let timestamp = v
+                    .time
+                    .as_deref()
+                    .and_then(|time| DateTime::parse_from_rfc3339(time).ok())
+                    .map(|dt| dt.with_timezone(&Utc));
+                let version = v
+                    .version
+                    .strip_prefix('v')
+                    .map(str::to_string)
+                    .unwrap_or(v.version);
+                (version, timestamp)
+            })
+            .collect();
+
+        versions.sort_by_key(|(_, a)| *a);
+
+        let versions: Vec<String> = versions.into_iter().map(|(v, _)| v).collect();
+
+        Ok(PackageVersions::new(versions))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::Server;
+
+    const MONOLOG_RESPONSE: &str = r#"{
+        "packages": {
+            "monolog/monolog": [
+                {"name": "monolog/monolog", "version": "3.5.0", "time": "2023-10-27T15:32:31+00:00"},
+                {"version": "2.9.2", "time": "2023-10-27T15:25:26+00:00"},
+                {"version": "3.4.0", "time": "2023-06-21T08:46:11+00:00"}
+            ]
+        },
+        "minified": "composer/2.0"
+    }"#;
+
+    #[tokio::test]
+    async fn fetch_all_versions_returns_versions_sorted_by_time() {
+        let mut server = Server::new_async().await;
+
+        let mock = server
+            .mock("GET", "/monolog/monolog.json")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(MONOLOG_RESPONSE)
+            .create_async()
+            .await;
+
+        let registry = PackagistRegistry::new(&server.url());
+        let result = registry
+            .fetch_all_versions("monolog/monolog")
+            .await
+            .unwrap();
+
+        mock.assert_async().await;
+        // 2.9.2 is a backport released after 3.4.0
+        assert_eq!(result.versions, vec!["3.4.0", "2.9.2", "3.5.0"]);
+    }
+
+    #[tokio::test]
+    async fn fetch_all_versions_strips_v_prefix() {
+        let mut server = Server::new_async().await;
+
+        let mock = server
+            .mock("GET", "/symfony/console.json")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"{"packages": {"symfony/console": [
+                    {"version": "v7.0.1", "time": "2023-12-01T14:56:37+00:00"},
+                    {"version": "v6.4.1", "time": "2023-12-01T14:54:06+00:00"}
+                ]}}"#,
+            )
+            .create_async()
+            .await;
+
+        let registry = PackagistRegistry::new(&server.url());
+        let result = registry
+            .fetch_all_versions("symfony/console")
+            .await
+            .unwrap();
+
+        mock.assert_async().await;
+        assert_eq!(result.versions, vec!["6.4.1", "7.0.1"]);
+    }
+
+    #[tokio::test]
+    async fn fetch_all_versions_returns_not_found_for_404() {
+        let mut server = Server::new_async().await;
+
+        let mock = server
+            .mock("GET", "/acme/missing.json")
+            .with_status(404)
+            .create_async()
+            .await;
+
+        let registry = PackagistRegistry::new(&server.url());
+        let result = registry.fetch_all_versions("acme/missing").await;
+
+        mock.assert_async().await;
+        assert!(matches!(result, Err(RegistryError::NotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn fetch_all_versions_returns_error_for_invalid_json() {
+        let mut server = Server::new_async().await;
+
+        let mock = server
+            .mock("GET", "/monolog/monolog.json")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body("not json")
+            .create_async()
+            .await;
+
+        let registry = PackagistRegistry::new(&server.url());
+        let result = registry.fetch_all_versions("monolog/monolog").await;
+
+        mock.assert_async().await;
+        assert!(matches!(result, Err(RegistryError::InvalidResponse(_))));
+    }
+}
@@ -3,13 +3,14 @@
 use std::collections::HashMap;
 
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use reqwest::Client;
 use serde::Deserialize;
 use tracing::debug;
 
 use crate::parser::types::RegistryType;
 use crate::version::error::RegistryError;
-use crate::version::registry::Registry;
+use crate::version::registry::{HttpSettings, Registry, http_client, send_with_retry};
 use crate::version::types::PackageVersions;
 
 const DEFAULT_PYPI_REGISTRY: &str = "https://pypi.org";
@@ -29,10 +30,16 @@ impl Default for PypiRegistry {
 impl PypiRegistry {
     pub fn new(base_url: String) -> Self {
         Self {
-            client: Client::new(),
+            client: http_client(&HttpSettings::default()),
             base_url,
         }
     }
+
+    /// Send requests with the given proxy and timeout settings
+    pub fn with_http_settings(mut self, settings: &HttpSettings) -> Self {
+        self.client = http_client(settings);
+        self
+    }
 }
 
 /// PyPI JSON API response structure
@@ -49,11 +56,32 @@ struct PypiInfo {
     version: String,
 }
 
-/// File information (not used currently but needed for deserialization)
+/// Distribution file information for a release
 #[derive(Debug, Deserialize)]
 struct PypiFile {
-    // We don't need any fields, just need the structure to exist
-    // The releases keys are what we care about
+    /// Whether this file has been yanked (PEP 592)
+    #[serde(default)]
+    yanked: bool,
+    /// Upload timestamp (ISO 8601)
+    #[serde(default)]
+    upload_time_iso_8601: Option<String>,
+}
+
+/// Returns true if every file of a release is yanked
+///
+/// Releases without any files are kept, since there is nothing to be yanked.
+fn is_yanked(files: &[PypiFile]) -> bool {
+    !files.is_empty() && files.iter().all(|f| f.yanked)
+}
+
+/// Earliest upload time among a release's files
+fn upload_time(files: &[PypiFile]) -> Option<DateTime<Utc>> {
+    files
+        .iter()
+        .filter_map(|f| f.upload_time_iso_8601.as_deref())
+        .filter_map(|ts| DateTime::parse_from_rfc3339(ts).ok())
+        .map(|dt| dt.with_timezone(&Utc))
+        .min()
 }
 
 #[async_trait]
@@ -69,7 +97,7 @@ impl Registry for PypiRegistry {
         let url = format!("{}/pypi/{}/json", self.base_url, package_name);
         debug!("Fetching PyPI package: {}", url);
 
-        let response = self.client.get(&url).send().await?;
+        let response = send_with_retry(self.client.get(&url)).await?;
 
         if response.status() == reqwest::StatusCode::NOT_FOUND {
             return Err(RegistryError::NotFound(package_name.to_string()));
@@ -87,8 +115,21 @@ impl Registry for PypiRegistry {
             .await
             .map_err(|e| RegistryError::InvalidResponse(e.to_string()))?;
 
-        // Extract versions from releases keys
-        let versions: Vec<String> = pypi_response.releases.keys().cloned().collect();
+        // Sort non-yanked releases by upload date (oldest first, newest last)
+        // Releases without timestamps are placed at the beginning
+        let mut versions: Vec<(String, Option<DateTime<Utc>>)> = pypi_response
+            .releases
+            .into_iter()
+            .filter(|(_, files)| !is_yanked(files))
+            .map(|(v, files)| {
+                let timestamp = upload_time(&files);
+                (v, timestamp)
+            })
+            .collect();
+
+        versions.sort_by_key(|(_, a)| *a);
+
+        let versions: Vec<String> = versions.into_iter().map(|(v, _)| v).collect();
 
         // Create dist-tags with "latest" pointing to info.version
         let mut dist_tags = HashMap::new();
@@ -140,6 +181,97 @@ mod tests {
         assert_eq!(result.dist_tags.get("latest"), Some(&"2.32.5".to_string()));
     }
 
+    #[tokio::test]
+    async fn fetch_all_versions_returns_versions_sorted_by_upload_time() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/pypi/requests/json")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"{
+                    "info": {"version": "2.32.0"},
+                    "releases": {
+                        "2.32.0": [
+                            {"yanked": false, "upload_time_iso_8601": "2024-05-20T12:00:00.000000Z"}
+                        ],
+                        "2.28.2": [
+                            {"yanked": false, "upload_time_iso_8601": "2024-06-01T09:00:00.000000Z"}
+                        ],
+                        "2.31.0": [
+                            {"yanked": false, "upload_time_iso_8601": "2023-05-22T15:00:00.000000Z"},
+                            {"yanked": false, "upload_time_iso_8601": "2023-05-22T14:00:00.000000Z"}
+                        ]
+                    }
+                }"#,
+            )
+            .create_async()
+            .await;
+
+        let registry = PypiRegistry::new(server.url());
+        let result = registry.fetch_all_versions("requests").await.unwrap();
+
+        mock.assert_async().await;
+
+        // 2.28.2 is a backport uploaded after 2.32.0
+        assert_eq!(result.versions, vec!["2.31.0", "2.32.0", "2.28.2"]);
+    }
+
+    #[tokio::test]
+    async fn fetch_all_versions_excludes_yanked_releases() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/pypi/requests/json")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"{
+                    "info": {"version": "2.32.3"},
+                    "releases": {
+                        "2.32.0": [
+                            {"yanked": true, "upload_time_iso_8601": "2024-05-20T12:00:00Z"},
+                            {"yanked": true, "upload_time_iso_8601": "2024-05-20T12:00:01Z"}
+                        ],
+                        "2.32.1": [
+                            {"yanked": true, "upload_time_iso_8601": "2024-05-21T12:00:00Z"},
+                            {"yanked": false, "upload_time_iso_8601": "2024-05-21T12:00:01Z"}
+                        ],
+                        "2.32.3": [
+                            {"yanked": false, "upload_time_iso_8601": "2024-05-29T12:00:00Z"}
+                        ]
+                    }
+                }"#,
+            )
+            .create_async()
+            .await;
+
+        let registry = PypiRegistry::new(server.url());
+        let result = registry.fetch_all_versions("requests").await.unwrap();
+
+        mock.assert_async().await;
+
+        assert_eq!(result.versions, vec!["2.32.1", "2.32.3"]);
+    }
+
+    #[tokio::test]
+    async fn fetch_all_versions_handles_empty_releases() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/pypi/placeholder/json")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"info": {"version": "0.0.0"}, "releases": {}}"#)
+            .create_async()
+            .await;
+
+        let registry = PypiRegistry::new(server.url());
+        let result = registry.fetch_all_versions("placeholder").await.unwrap();
+
+        mock.assert_async().await;
+
+        assert!(result.versions.is_empty());
+    }
+
     #[tokio::test]
     async fn fetch_all_versions_returns_not_found_for_missing_package() {
         let mut server = Server::new_async().await;
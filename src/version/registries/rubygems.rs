@@ -0,0 +1,201 @@
+//! RubyGems registry API implementation
+
+use crate::parser::types::RegistryType;
+use crate::version::error::RegistryError;
+use crate::version::registry::{HttpSettings, Registry, http_client, send_with_retry};
+use crate::version::types::PackageVersions;
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use tracing::warn;
+
+/// Default base URL for the RubyGems versions API
+const DEFAULT_BASE_URL: &str = "https://rubygems.org/api/v1/versions";
+
+/// Version information from RubyGems
+#[derive(Debug, Deserialize)]
+struct GemVersion {
+    number: String,
+    created_at: String,
+    #[serde(default)]
+    prerelease: bool,
+}
+
+/// Registry implementation for the RubyGems API
+pub struct RubyGemsRegistry {
+    client: reqwest::Client,
+    base_url: String,
+    include_prereleases: bool,
+}
+
+impl RubyGemsRegistry {
+    /// Creates a new RubyGemsRegistry with a custom base URL
+    pub fn new(base_url: &str) -> Self {
+        Self {
+            client: http_client(&HttpSettings::default()),
+            base_url: base_url.to_string(),
+            include_prereleases: false,
+        }
+    }
+
+    /// Send requests with the given proxy and timeout settings
+    pub fn with_http_settings(mut self, settings: &HttpSettings) -> Self {
+        self.client = http_client(settings);
+        self
+    }
+
+    /// Keep prerelease versions (excluded by default)
+    pub fn with_prereleases(mut self, include_prereleases: bool) -> Self {
+        self.include_prereleases = include_prereleases;
+        self
+    }
+}
+
+impl Default for RubyGemsRegistry {
+    fn default() -> Self {
+        Self::new(DEFAULT_BASE_URL)
+    }
+}
+
+#[async_trait::async_trait]
+impl Registry for RubyGemsRegistry {
+    fn registry_type(&self) -> RegistryType {
+        RegistryType::RubyGems
+    }
+
+    async fn fetch_all_versions(
+        &self,
+        package_name: &str,
+    ) -> Result<PackageVersions, RegistryError> {
+        let url = format!("{}/{}.json", self.base_url, package_name);
+
+        let response = send_with_retry(self.client.get(&url)).await?;
+
+        let status = response.status();
+
+        if status == reqwest::StatusCode::NOT_FOUND {
+            return Err(RegistryError::NotFound(package_name.to_string()));
+        }
+
+        if !status.is_success() {
+            warn!("RubyGems registry returned status {}: {}", status, url);
+            return Err(RegistryError::InvalidResponse(format!(
+                "Unexpected status: {}",
+                status
+            )));
+        }
+
+        let gem_versions: Vec<GemVersion> = response.json().await.map_err(|e| {
+            warn!("Failed to parse RubyGems registry response: {}", e);
+            RegistryError::InvalidResponse(e.to_string())
+        })?;
+
+        // Filter out prereleases and sort by created_at (oldest first, newest last)
+        let mut versions: Vec<(String, Option<DateTime<Utc>>)> = gem_versions
+            .into_iter()
+            .filter(|v| self.include_prereleases || !v.prerelease)
+            .map(|v| {
+                let timestamp = DateTime::parse_from_rfc3339(&v.created_at)
+                    .ok()
+                    .map(|dt| dt.with_timezone(&Utc));
+                (v.number, timestamp)
+            })
+            .collect();
+
+        versions.sort_by_key(|(_, a)| *a);
+
+        let versions: Vec<String> = versions.into_iter().map(|(v, _)| v).collect();
+
+        Ok(PackageVersions::new(versions))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::Server;
+
+    const RAILS_RESPONSE: &str = r#"[
+        {"number": "7.1.0", "created_at": "2023-10-05T10:00:00.000Z", "prerelease": false},
+        {"number": "7.1.0.rc1", "created_at": "2023-09-13T10:00:00.000Z", "prerelease": true},
+        {"number": "7.0.8", "created_at": "2023-09-09T10:00:00.000Z", "prerelease": false},
+        {"number": "6.1.7.6", "created_at": "2023-10-10T10:00:00.000Z", "prerelease": false}
+    ]"#;
+
+    #[tokio::test]
+    async fn fetch_all_versions_returns_versions_sorted_by_created_at() {
+        let mut server = Server::new_async().await;
+
+        let mock = server
+            .mock("GET", "/rails.json")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(RAILS_RESPONSE)
+            .create_async()
+            .await;
+
+        let registry = RubyGemsRegistry::new(&server.url());
+        let result = registry.fetch_all_versions("rails").await.unwrap();
+
+        mock.assert_async().await;
+        // 6.1.7.6 is a backport released after 7.1.0; prereleases are excluded
+        assert_eq!(result.versions, vec!["7.0.8", "7.1.0", "6.1.7.6"]);
+    }
+
+    #[tokio::test]
+    async fn fetch_all_versions_includes_prereleases_when_enabled() {
+        let mut server = Server::new_async().await;
+
+        let mock = server
+            .mock("GET", "/rails.json")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(RAILS_RESPONSE)
+            .create_async()
+            .await;
+
+        let registry = RubyGemsRegistry::new(&server.url()).with_prereleases(true);
+        let result = registry.fetch_all_versions("rails").await.unwrap();
+
+        mock.assert_async().await;
+        assert_eq!(
+            result.versions,
+            vec!["7.0.8", "7.1.0.rc1", "7.1.0", "6.1.7.6"]
+        );
+    }
+
+    #[tokio::test]
+    async fn fetch_all_versions_returns_not_found_for_404() {
+        let mut server = Server::new_async().await;
+
+        let mock = server
+            .mock("GET", "/nonexistent-gem.json")
+            .with_status(404)
+            .create_async()
+            .await;
+
+        let registry = RubyGemsRegistry::new(&server.url());
+        let result = registry.fetch_all_versions("nonexistent-gem").await;
+
+        mock.assert_async().await;
+        assert!(matches!(result, Err(RegistryError::NotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn fetch_all_versions_returns_error_for_invalid_json() {
+        let mut server = Server::new_async().await;
+
+        let mock = server
+            .mock("GET", "/rails.json")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body("not json")
+            .create_async()
+            .await;
+
+        let registry = RubyGemsRegistry::new(&server.url());
+        let result = registry.fetch_all_versions("rails").await;
+
+        mock.assert_async().await;
+        assert!(matches!(result, Err(RegistryError::InvalidResponse(_))));
+    }
+}
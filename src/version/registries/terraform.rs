@@ -0,0 +1,205 @@
+//! Terraform Registry provider API implementation
+
+use std::collections::HashMap;
+
+use crate::parser::types::RegistryType;
+use crate::version::error::RegistryError;
+use crate::version::registry::{HttpSettings, Registry, http_client, send_with_retry};
+use crate::version::types::PackageVersions;
+use semver::Version;
+use serde::Deserialize;
+use tracing::warn;
+
+/// Default base URL for the Terraform Registry provider API
+const DEFAULT_BASE_URL: &str = "https://registry.terraform.io/v1/providers";
+
+/// Response from the provider versions endpoint
+#[derive(Debug, Deserialize)]
+struct ProviderVersionsResponse {
+    versions: Vec<ProviderVersion>,
+}
+
+/// Version information of a provider release
+#[derive(Debug, Deserialize)]
+struct ProviderVersion {
+    version: String,
+}
+
+/// Registry implementation for the Terraform Registry provider API
+///
+/// Package names are provider `namespace/type` pairs such as `hashicorp/aws`.
+pub struct TerraformRegistry {
+    client: reqwest::Client,
+    base_url: String,
+}
+
+impl TerraformRegistry {
+    /// Creates a new TerraformRegistry with a custom base URL
+    pub fn new(base_url: &str) -> Self {
+        Self {
+            client: http_client(&HttpSettings::default()),
+            base_url: base_url.to_string(),
+        }
+    }
+
+    /// Send requests with the given proxy and timeout settings
+    pub fn with_http_settings(mut self, settings: &HttpSettings) -> Self {
+        self.client = http_client(settings);
+        self
+    }
+}
+
+impl Default for TerraformRegistry {
+    fn default() -> Self {
+        Self::new(DEFAULT_BASE_URL)
+    }
+}
+
+#[async_trait::async_trait]
+impl Registry for TerraformRegistry {
+    fn registry_type(&self) -> RegistryType {
+        RegistryType::TerraformRegistry
+    }
+
+    async fn fetch_all_versions(
+        &self,
+        package_name: &str,
+    ) -> Result<PackageVersions, RegistryError> {
+        let Some((namespace, provider_type)) = package_name.split_once('/') else {
+            return Err(RegistryError::NotFound(package_name.to_string()));
+        };
+        if namespace.is_empty() || provider_type.is_empty() || provider_type.contains('/') {
+            return Err(RegistryError::NotFound(package_name.to_string()));
+        }
+
+        let url = format!("{}/{}/{}/versions", self.base_url, namespace, provider_type);
+
+        let response = send_with_retry(self.client.get(&url)).await?;
+
+        let status = response.status();
+
+        if status == reqwest::StatusCode::NOT_FOUND {
+            return Err(RegistryError::NotFound(package_name.to_string()));
+        }
+
+        if !status.is_success() {
+            warn!("Terraform Registry returned status {}: {}", status, url);
+            return Err(RegistryError::InvalidResponse(format!(
+                "Unexpected status: {}",
+                status
+            )));
+        }
+
+        let provider: ProviderVersionsResponse = response.json().await.map_err(|e| {
+            warn!("Failed to parse Terraform Registry response: {}", e);
+            RegistryError::InvalidResponse(e.to_string())
+        })?;
+
+        // The API lists versions in no particular order; sort to keep oldest first
+        let mut versions: Vec<Version> = provider
+            .versions
+            .iter()
+            .filter_map(|v| Version::parse(&v.version).ok())
+            .collect();
+        versions.sort();
+
+        // Pre-releases are published too, but never chosen as the latest
+        let dist_tags = versions
+            .iter()
+            .rev()
+            .find(|v| v.pre.is_empty())
+            .map(|latest| HashMap::from([("latest".to_string(), latest.to_string())]))
+            .unwrap_or_default();
+
+        Ok(PackageVersions::with_dist_tags(
+            versions.iter().map(Version::to_string).collect(),
+            dist_tags,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::Server;
+
+    #[tokio::test]
+    async fn fetch_all_versions_returns_versions_oldest_first() {
+        let mut server = Server::new_async().await;
+
+        let mock = server
+            .mock("GET", "/hashicorp/aws/versions")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                r#"{
+                    "id": "hashicorp/aws",
+                    "versions": [
+                        {"version": "5.31.0", "protocols": ["5.0"], "platforms": []},
+                        {"version": "6.0.0-beta1", "protocols": ["5.0"], "platforms": []},
+                        {"version": "4.67.0", "protocols": ["5.0"], "platforms": []},
+                        {"version": "5.0.0", "protocols": ["5.0"], "platforms": []}
+                    ],
+                    "warnings": null
+                }"#,
+            )
+            .create_async()
+            .await;
+
+        let registry = TerraformRegistry::new(&server.url());
+        let result = registry.fetch_all_versions("hashicorp/aws").await.unwrap();
+
+        mock.assert_async().await;
+        assert_eq!(
+            result.versions,
+            vec!["4.67.0", "5.0.0", "5.31.0", "6.0.0-beta1"]
+        );
+        assert_eq!(result.resolve_dist_tag("latest"), Some("5.31.0"));
+    }
+
+    #[tokio::test]
+    async fn fetch_all_versions_returns_not_found_for_nonexistent_provider() {
+        let mut server = Server::new_async().await;
+
+        let mock = server
+            .mock("GET", "/hashicorp/nonexistent/versions")
+            .with_status(404)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"errors": ["Not Found"]}"#)
+            .create_async()
+            .await;
+
+        let registry = TerraformRegistry::new(&server.url());
+        let result = registry.fetch_all_versions("hashicorp/nonexistent").await;
+
+        mock.assert_async().await;
+        assert!(matches!(result, Err(RegistryError::NotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn fetch_all_versions_returns_not_found_for_name_without_namespace() {
+        let registry = TerraformRegistry::new("http://127.0.0.1:1");
+        let result = registry.fetch_all_versions("aws").await;
+
+        assert!(matches!(result, Err(RegistryError::NotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn fetch_all_versions_returns_error_for_invalid_json() {
+        let mut server = Server::new_async().await;
+
+        let mock = server
+            .mock("GET", "/hashicorp/aws/versions")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body("not json")
+            .create_async()
+            .await;
+
+        let registry = TerraformRegistry::new(&server.url());
+        let result = registry.fetch_all_versions("hashicorp/aws").await;
+
+        mock.assert_async().await;
+        assert!(matches!(result, Err(RegistryError::InvalidResponse(_))));
+    }
+}
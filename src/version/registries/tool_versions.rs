@@ -0,0 +1,168 @@
+//! Tool release feeds for asdf `.tool-versions`
+
+use std::collections::HashMap;
+
+use crate::parser::tool_versions::NODEJS_PACKAGE;
+use crate::parser::types::RegistryType;
+use crate::version::error::RegistryError;
+use crate::version::registry::{HttpSettings, Registry, http_client, send_with_retry};
+use crate::version::types::PackageVersions;
+use semver::Version;
+use serde::Deserialize;
+use tracing::warn;
+
+/// Default base URL for the Node.js release index
+const DEFAULT_BASE_URL: &str = "https://nodejs.org/dist";
+
+/// Entry of the Node.js `index.json` release list
+#[derive(Debug, Deserialize)]
+struct NodeRelease {
+    /// Release tag, e.g. `v20.11.0`
+    version: String,
+}
+
+/// Registry implementation for the release feeds of `.tool-versions` tools
+///
+/// Only `nodejs` is served here; its versions come from the Node.js
+/// `index.json` release list. Go releases are fetched through the Go release
+/// registry, shared with the `go` directive of go.mod.
+pub struct ToolVersionsRegistry {
+    client: reqwest::Client,
+    base_url: String,
+}
+
+impl ToolVersionsRegistry {
+    /// Creates a new ToolVersionsRegistry with a custom Node.js dist base URL
+    pub fn new(base_url: &str) -> Self {
+        Self {
+            client: http_client(&HttpSettings::default()),
+            base_url: base_url.to_string(),
+        }
+    }
+
+    /// Send requests with the given proxy and timeout settings
+    pub fn with_http_settings(mut self, settings: &HttpSettings) -> Self {
+        self.client = http_client(settings);
+        self
+    }
+}
+
+impl Default for ToolVersionsRegistry {
+    fn default() -> Self {
+        Self::new(DEFAULT_BASE_URL)
+    }
+}
+
+#[async_trait::async_trait]
+impl Registry for ToolVersionsRegistry {
+    fn registry_type(&self) -> RegistryType {
+        RegistryType::ToolVersions
+    }
+
+    async fn fetch_all_versions(
+        &self,
+        package_name: &str,
+    ) -> Result<PackageVersions, RegistryError> {
+        if package_name != NODEJS_PACKAGE {
+            return Err(RegistryError::NotFound(package_name.to_string()));
+        }
+
+        let url = format!("{}/index.json", self.base_url);
+
+        let response = send_with_retry(self.client.get(&url)).await?;
+
+        let status = response.status();
+
+        if status == reqwest::StatusCode::NOT_FOUND {
+            return Err(RegistryError::NotFound(package_name.to_string()));
+        }
+
+        if !status.is_success() {
+            warn!("Node.js release index returned status {}: {}", status, url);
+            return Err(RegistryError::InvalidResponse(format!(
+                "Unexpected status: {}",
+                status
+            )));
+        }
+
+        let releases: Vec<NodeRelease> = response.json().await.map_err(|e| {
+            warn!("Failed to parse Node.js release index: {}", e);
+            RegistryError::InvalidResponse(e.to_string())
+        })?;
+
+        // The index lists releases newest first; sort to keep oldest first
+        let mut versions: Vec<Version> = releases
+            .iter()
+            .filter_map(|release| Version::parse(release.version.trim_start_matches('v')).ok())
+            .collect();
+        versions.sort();
+
+        let Some(latest) = versions.last().map(Version::to_string) else {
+            return Err(RegistryError::NotFound(package_name.to_string()));
+        };
+
+        Ok(PackageVersions::with_dist_tags(
+            versions.iter().map(Version::to_string).collect(),
+            HashMap::from([("latest".to_string(), latest)]),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::Server;
+
+    const INDEX_RESPONSE: &str = r#"[
+        {"version": "v23.1.0", "date": "2024-10-24", "lts": false},
+        {"version": "v22.11.0", "date": "2024-10-29", "lts": "Jod"},
+        {"version": "v20.11.0", "date": "2024-01-09", "lts": "Iron"}
+    ]"#;
+
+    #[tokio::test]
+    async fn fetch_all_versions_returns_node_releases_oldest_first() {
+        let mut server = Server::new_async().await;
+
+        let mock = server
+            .mock("GET", "/index.json")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(INDEX_RESPONSE)
+            .create_async()
+            .await;
+
+        let registry = ToolVersionsRegistry::new(&server.url());
+        let result = registry.fetch_all_versions("nodejs").await.unwrap();
+
+        mock.assert_async().await;
+        assert_eq!(result.versions, vec!["20.11.0", "22.11.0", "23.1.0"]);
+        assert_eq!(result.resolve_dist_tag("latest"), Some("23.1.0"));
+    }
+
+    #[tokio::test]
+    async fn fetch_all_versions_returns_not_found_for_unknown_tool() {
+        let registry = ToolVersionsRegistry::new("http://127.0.0.1:1");
+        let result = registry.fetch_all_versions("python").await;
+
+        assert!(matches!(result, Err(RegistryError::NotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn fetch_all_versions_returns_error_for_invalid_json() {
+        let mut server = Server::new_async().await;
+
+        let mock = server
+            .mock("GET", "/index.json")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body("not json")
+            .create_async()
+            .await;
+
+        let registry = ToolVersionsRegistry::new(&server.url());
+        let result = registry.fetch_all_versions("nodejs").await;
+
+        mock.assert_async().await;
+        assert!(matches!(result, Err(RegistryError::InvalidResponse(_))));
+    }
+}
@@ -1,8 +1,12 @@
 //! Registry trait for fetching package versions from various sources
 
+use std::time::Duration;
+
 #[cfg(test)]
 use mockall::automock;
+use tracing::{debug, warn};
 
+use crate::config::{CONNECT_TIMEOUT_MS, DEFAULT_REQUEST_TIMEOUT_MS, DEFAULT_USER_AGENT};
 use crate::parser::types::RegistryType;
 use crate::version::error::RegistryError;
 use crate::version::types::PackageVersions;
@@ -27,3 +31,305 @@ pub trait Registry: Send + Sync {
         package_name: &str,
     ) -> Result<PackageVersions, RegistryError>;
 }
+
+/// Environment variables holding the proxy for each scheme, in lookup order
+const HTTP_PROXY_ENV_VARS: [&str; 4] = ["HTTP_PROXY", "http_proxy", "ALL_PROXY", "all_proxy"];
+const HTTPS_PROXY_ENV_VARS: [&str; 4] = ["HTTPS_PROXY", "https_proxy", "ALL_PROXY", "all_proxy"];
+
+/// Network settings shared by the HTTP clients of all registries
+#[derive(Debug, Clone, PartialEq)]
+pub struct HttpSettings {
+    /// Proxy URL for every request
+    pub proxy: Option<String>,
+    /// Comma-separated hosts that bypass the proxy, like `NO_PROXY`
+    pub no_proxy: Option<String>,
+    /// Limit for a whole request, from connecting to reading the body
+    pub timeout: Duration,
+    /// User-Agent header sent with every request
+    pub user_agent: String,
+}
+
+impl Default for HttpSettings {
+    fn default() -> Self {
+        Self {
+            proxy: None,
+            no_proxy: None,
+            timeout: Duration::from_millis(DEFAULT_REQUEST_TIMEOUT_MS),
+            user_agent: DEFAULT_USER_AGENT.to_string(),
+        }
+    }
+}
+
+/// Build the HTTP client registries send requests with
+///
+/// Without overrides, `HTTP_PROXY`, `HTTPS_PROXY`, `ALL_PROXY` and `NO_PROXY`
+/// are honored. `proxy` routes every request through the given URL instead.
+/// `no_proxy` is a comma-separated host list like `NO_PROXY` that replaces
+/// it, for the configured proxy or the one from the environment. An invalid
+/// proxy URL is logged and ignored.
+///
+/// Requests fail with a timeout error after `timeout`, and connecting alone
+/// may take at most [`CONNECT_TIMEOUT_MS`] of it. Every request carries
+/// `user_agent` as its `User-Agent` header.
+pub(crate) fn http_client(settings: &HttpSettings) -> reqwest::Client {
+    let mut builder = reqwest::Client::builder()
+        .user_agent(settings.user_agent.as_str())
+        .timeout(settings.timeout)
+        .connect_timeout(
+            settings
+                .timeout
+                .min(Duration::from_millis(CONNECT_TIMEOUT_MS)),
+        );
+
+    let (proxy, no_proxy) = (settings.proxy.as_deref(), settings.no_proxy.as_deref());
+    let proxies = match (proxy, no_proxy) {
+        (Some(proxy), _) => vec![reqwest::Proxy::all(proxy)],
+        (None, Some(_)) => {
+            let from_env = |names: &[&str]| {
+                names
+                    .iter()
+                    .find_map(|name| std::env::var(name).ok().filter(|v| !v.is_empty()))
+            };
+            let http = from_env(&HTTP_PROXY_ENV_VARS).map(reqwest::Proxy::http);
+            let https = from_env(&HTTPS_PROXY_ENV_VARS).map(reqwest::Proxy::https);
+            http.into_iter().chain(https).collect()
+        }
+        (None, None) => Vec::new(),
+    };
+
+    let no_proxy = no_proxy.map_or_else(reqwest::NoProxy::from_env, reqwest::NoProxy::from_string);
+    match proxies.into_iter().collect::<Result<Vec<_>, _>>() {
+        Ok(proxies) => {
+            for proxy in proxies {
+                builder = builder.proxy(proxy.no_proxy(no_proxy.clone()));
+            }
+        }
+        Err(e) => warn!("Ignoring invalid proxy URL: {}", e),
+    }
+
+    builder.build().expect("Failed to create HTTP client")
+}
+
+/// Maximum number of retries after a 429 or 503 response
+const MAX_RETRIES: u32 = 3;
+
+/// Longest `Retry-After` (seconds) waited for; longer waits fail fast instead
+/// of stalling the fetch
+const MAX_RETRY_AFTER_SECS: u64 = 10;
+
+/// First backoff delay when the response has no `Retry-After`; doubles per retry
+const BASE_BACKOFF_MS: u64 = 500;
+
+/// Send a request, retrying with backoff on 429 Too Many Requests and
+/// 503 Service Unavailable
+///
+/// `Retry-After` (in seconds) is honored when present. The last response is
+/// returned as-is once retries are exhausted, so callers still see the status.
+pub(crate) async fn send_with_retry(
+    request: reqwest::RequestBuilder,
+) -> Result<reqwest::Response, reqwest::Error> {
+    for attempt in 0..MAX_RETRIES {
+        // Requests with streaming bodies cannot be cloned, so are sent once
+        let Some(retryable) = request.try_clone() else {
+            break;
+        };
+        let response = retryable.send().await?;
+
+        let status = response.status();
+        if status != reqwest::StatusCode::TOO_MANY_REQUESTS
+            && status != reqwest::StatusCode::SERVICE_UNAVAILABLE
+        {
+            return Ok(response);
+        }
+
+        let delay = match retry_after_secs(&response) {
+            Some(secs) if secs > MAX_RETRY_AFTER_SECS => return Ok(response),
+            Some(secs) => Duration::from_secs(secs),
+            None => Duration::from_millis(BASE_BACKOFF_MS << attempt),
+        };
+        debug!(
+            "{} returned {}, retrying in {:?}",
+            response.url(),
+            status,
+            delay
+        );
+        tokio::time::sleep(delay).await;
+    }
+
+    request.send().await
+}
+
+/// `Retry-After` header of a response, in seconds
+pub(crate) fn retry_after_secs(response: &reqwest::Response) -> Option<u64> {
+    response
+        .headers()
+        .get(reqwest::header::RETRY_AFTER)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.trim().parse().ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::Server;
+
+    /// A local port nothing listens on
+    const UNREACHABLE_PROXY: &str = "http://127.0.0.1:1";
+
+    fn proxied(proxy: &str, no_proxy: Option<&str>) -> HttpSettings {
+        HttpSettings {
+            proxy: Some(proxy.to_string()),
+            no_proxy: no_proxy.map(str::to_string),
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn http_client_sends_requests_through_configured_proxy() {
+        let mut server = Server::new_async().await;
+        let direct = server
+            .mock("GET", "/lodash")
+            .with_status(200)
+            .expect(0)
+            .create_async()
+            .await;
+
+        let client = http_client(&proxied(UNREACHABLE_PROXY, None));
+        let result = client.get(format!("{}/lodash", server.url())).send().await;
+
+        direct.assert_async().await;
+        let error = result.unwrap_err();
+        assert!(error.is_connect() || error.is_timeout(), "{:?}", error);
+    }
+
+    #[tokio::test]
+    async fn http_client_sends_configured_user_agent() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/serde")
+            .match_header("user-agent", "acme-ci (ops@example.com)")
+            .with_status(200)
+            .create_async()
+            .await;
+
+        let client = http_client(&HttpSettings {
+            user_agent: "acme-ci (ops@example.com)".to_string(),
+            ..Default::default()
+        });
+        let response = client
+            .get(format!("{}/serde", server.url()))
+            .send()
+            .await
+            .unwrap();
+
+        mock.assert_async().await;
+        assert_eq!(response.status(), reqwest::StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn http_client_bypasses_proxy_for_no_proxy_hosts() {
+        let mut server = Server::new_async().await;
+        let direct = server
+            .mock("GET", "/lodash")
+            .with_status(200)
+            .create_async()
+            .await;
+
+        let client = http_client(&proxied(UNREACHABLE_PROXY, Some("127.0.0.1")));
+        let response = client
+            .get(format!("{}/lodash", server.url()))
+            .send()
+            .await
+            .unwrap();
+
+        direct.assert_async().await;
+        assert_eq!(response.status(), reqwest::StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn http_client_ignores_invalid_proxy_url() {
+        let mut server = Server::new_async().await;
+        let direct = server
+            .mock("GET", "/lodash")
+            .with_status(200)
+            .create_async()
+            .await;
+
+        let client = http_client(&proxied("not a url", Some("127.0.0.1")));
+        let response = client
+            .get(format!("{}/lodash", server.url()))
+            .send()
+            .await
+            .unwrap();
+
+        direct.assert_async().await;
+        assert_eq!(response.status(), reqwest::StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn send_with_retry_retries_after_429() {
+        let mut server = Server::new_async().await;
+        let limited = server
+            .mock("GET", "/lodash")
+            .with_status(429)
+            .with_header("retry-after", "0")
+            .create_async()
+            .await;
+        let ok = server
+            .mock("GET", "/lodash")
+            .with_status(200)
+            .with_body("ok")
+            .create_async()
+            .await;
+
+        let client = reqwest::Client::new();
+        let response = send_with_retry(client.get(format!("{}/lodash", server.url())))
+            .await
+            .unwrap();
+
+        limited.assert_async().await;
+        ok.assert_async().await;
+        assert_eq!(response.status(), reqwest::StatusCode::OK);
+        assert_eq!(response.text().await.unwrap(), "ok");
+    }
+
+    #[tokio::test]
+    async fn send_with_retry_gives_up_after_max_retries() {
+        let mut server = Server::new_async().await;
+        let unavailable = server
+            .mock("GET", "/lodash")
+            .with_status(503)
+            .with_header("retry-after", "0")
+            .expect(MAX_RETRIES as usize + 1)
+            .create_async()
+            .await;
+
+        let client = reqwest::Client::new();
+        let response = send_with_retry(client.get(format!("{}/lodash", server.url())))
+            .await
+            .unwrap();
+
+        unavailable.assert_async().await;
+        assert_eq!(response.status(), reqwest::StatusCode::SERVICE_UNAVAILABLE);
+    }
+
+    #[tokio::test]
+    async fn send_with_retry_does_not_wait_for_long_retry_after() {
+        let mut server = Server::new_async().await;
+        let limited = server
+            .mock("GET", "/lodash")
+            .with_status(429)
+            .with_header("retry-after", "3600")
+            .expect(1)
+            .create_async()
+            .await;
+
+        let client = reqwest::Client::new();
+        let response = send_with_retry(client.get(format!("{}/lodash", server.url())))
+            .await
+            .unwrap();
+
+        limited.assert_async().await;
+        assert_eq!(response.status(), reqwest::StatusCode::TOO_MANY_REQUESTS);
+    }
+}
@@ -33,8 +33,8 @@ pub fn parse_version(version: &str) -> Option<Version> {
         .trim_start_matches('<')
         .trim_start_matches('=')
         .trim_start_matches('^')
-        .trim_start_matches('~')
-        .trim_start_matches('v');
+        .trim_start_matches('~');
+    let stripped = strip_v_prefix(stripped);
 
     let parts: Vec<&str> = stripped.split('.').collect();
     let normalized = match parts.len() {
@@ -45,6 +45,13 @@ pub fn parse_version(version: &str) -> Option<Version> {
     Version::parse(&normalized).ok()
 }
 
+/// Strip a leading `v` or `V` from a version (e.g. Go's `v0.14.0`)
+///
+/// Only used before parsing; the original string is kept for display.
+pub fn strip_v_prefix(version: &str) -> &str {
+    version.strip_prefix(['v', 'V']).unwrap_or(version)
+}
+
 /// Calculate the latest patch version within the same major.minor
 ///
 /// Returns the latest patch version if a newer patch exists,
@@ -186,6 +193,7 @@ mod tests {
     #[case("<1.2.3", Some(Version::new(1, 2, 3)))] // lt prefix
     #[case("=1.2.3", Some(Version::new(1, 2, 3)))] // eq prefix
     #[case("v1.2.3", Some(Version::new(1, 2, 3)))] // v prefix
+    #[case("V1.2.3", Some(Version::new(1, 2, 3)))] // uppercase v prefix
     #[case("~=1.2.3", Some(Version::new(1, 2, 3)))] // PyPI compatible release
     #[case("==1.2.3", Some(Version::new(1, 2, 3)))] // PyPI exact pin
     #[case("!=1.2.3", Some(Version::new(1, 2, 3)))] // PyPI not-equal
@@ -196,6 +204,16 @@ mod tests {
         assert_eq!(parse_version(input), expected);
     }
 
+    #[rstest]
+    #[case("v0.14.0", "0.14.0")]
+    #[case("V0.14.0", "0.14.0")]
+    #[case("0.14.0", "0.14.0")]
+    #[case("vv1.0.0", "v1.0.0")]
+    #[case("", "")]
+    fn test_strip_v_prefix(#[case] input: &str, #[case] expected: &str) {
+        assert_eq!(strip_v_prefix(input), expected);
+    }
+
     #[rstest]
     #[case("1.2.3", &["1.2.3", "1.2.5", "1.3.0", "2.0.0"], Some("1.2.5".to_string()))]
     #[case("^1.2.3", &["1.2.3", "1.2.5", "1.3.0", "2.0.0"], Some("1.2.5".to_string()))] // caret prefix
@@ -9,6 +9,10 @@ pub struct PackageVersions {
     pub versions: Vec<String>,
     /// Dist tags mapping tag names to versions (e.g., "latest" -> "4.17.21")
     pub dist_tags: HashMap<String, String>,
+    /// Versions withdrawn from the registry (e.g., yanked crates), not listed in `versions`
+    pub yanked: Vec<String>,
+    /// Publish time (Unix seconds) of each version, when the registry reports it
+    pub published_at: HashMap<String, i64>,
 }
 
 impl PackageVersions {
@@ -17,6 +21,8 @@ impl PackageVersions {
         Self {
             versions,
             dist_tags: HashMap::new(),
+            yanked: Vec::new(),
+            published_at: HashMap::new(),
         }
     }
 
@@ -25,9 +31,23 @@ impl PackageVersions {
         Self {
             versions,
             dist_tags,
+            yanked: Vec::new(),
+            published_at: HashMap::new(),
         }
     }
 
+    /// Attach the versions withdrawn from the registry
+    pub fn with_yanked(mut self, yanked: Vec<String>) -> Self {
+        self.yanked = yanked;
+        self
+    }
+
+    /// Attach the publish time (Unix seconds) of each version
+    pub fn with_published_at(mut self, published_at: HashMap<String, i64>) -> Self {
+        self.published_at = published_at;
+        self
+    }
+
     /// Returns the latest (first) version, if any
     pub fn latest(&self) -> Option<&str> {
         self.versions.first().map(|s| s.as_str())
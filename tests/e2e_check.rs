@@ -0,0 +1,180 @@
+//! `version-lsp check` E2E tests
+
+mod helper;
+
+use std::collections::HashMap;
+
+use tower_lsp::lsp_types::DiagnosticSeverity;
+
+use helper::{MockRegistry, create_test_cache, create_test_resolver};
+use version_lsp::config::{DiagnosticSummary, LspConfig};
+use version_lsp::lsp::check::{check_files, exceeds_threshold, load_config, render_json};
+use version_lsp::lsp::resolver::PackageResolver;
+use version_lsp::parser::types::RegistryType;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn check_reports_outdated_dependencies_as_json() {
+    // 1. Setup real Cache with test data (oldest first, newest last)
+    let (temp_dir, cache) = create_test_cache(
+        RegistryType::CratesIo,
+        &[
+            ("serde", vec!["1.0.100", "1.0.210"]),
+            ("tokio", vec!["0.2.25", "1.40.0"]),
+        ],
+    );
+
+    // 2. Setup mock Registry and resolver
+    let registry = MockRegistry::new(RegistryType::CratesIo)
+        .with_versions("serde", vec!["1.0.100", "1.0.210"])
+        .with_versions("tokio", vec!["0.2.25", "1.40.0"]);
+
+    let resolvers: HashMap<RegistryType, PackageResolver> = HashMap::from([(
+        RegistryType::CratesIo,
+        create_test_resolver(RegistryType::CratesIo, registry),
+    )]);
+
+    // 3. Write the fixture: serde is up to date, tokio is a major behind
+    let manifest = temp_dir.path().join("Cargo.toml");
+    std::fs::write(
+        &manifest,
+        r#"[package]
+name = "demo"
+version = "0.1.0"
+
+[dependencies]
+serde = "1.0.100"
+tokio = "0.2"
+"#,
+    )
+    .unwrap();
+
+    // 4. Check the file
+    let reports = check_files(
+        &*cache,
+        &resolvers,
+        &LspConfig::default(),
+        std::slice::from_ref(&manifest),
+    )
+    .await
+    .unwrap();
+
+    // 5. Verify JSON output and exit status
+    let json: serde_json::Value = serde_json::from_str(&render_json(&reports).unwrap()).unwrap();
+    assert_eq!(
+        json,
+        serde_json::json!([{
+            "path": manifest.display().to_string(),
+            "line": 7,
+            "column": 10,
+            "severity": "warning",
+            "code": "outdated",
+            "message": "Update available: 0.2 -> 1.40.0",
+        }])
+    );
+    assert!(exceeds_threshold(
+        &reports,
+        Some(DiagnosticSeverity::WARNING)
+    ));
+    assert!(!exceeds_threshold(
+        &reports,
+        Some(DiagnosticSeverity::ERROR)
+    ));
+    assert!(!exceeds_threshold(&reports, None));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn check_summarizes_updates_like_the_language_server() {
+    let (temp_dir, cache) = create_test_cache(
+        RegistryType::CratesIo,
+        &[
+            ("serde", vec!["1.0.100", "1.0.210"]),
+            ("tokio", vec!["0.2.25", "1.40.0"]),
+        ],
+    );
+
+    let registry = MockRegistry::new(RegistryType::CratesIo)
+        .with_versions("serde", vec!["1.0.100", "1.0.210"])
+        .with_versions("tokio", vec!["0.2.25", "1.40.0"]);
+
+    let resolvers: HashMap<RegistryType, PackageResolver> = HashMap::from([(
+        RegistryType::CratesIo,
+        create_test_resolver(RegistryType::CratesIo, registry),
+    )]);
+
+    let manifest = temp_dir.path().join("Cargo.toml");
+    std::fs::write(
+        &manifest,
+        r#"[package]
+name = "demo"
+version = "0.1.0"
+
+[dependencies]
+serde = "=1.0.100"
+tokio = "0.2"
+"#,
+    )
+    .unwrap();
+
+    let config = LspConfig {
+        summarize_diagnostics: DiagnosticSummary::SummaryOnly,
+        ..LspConfig::default()
+    };
+    let reports = check_files(
+        &*cache,
+        &resolvers,
+        &config,
+        std::slice::from_ref(&manifest),
+    )
+    .await
+    .unwrap();
+
+    let messages: Vec<&str> = reports[0]
+        .diagnostics
+        .iter()
+        .map(|d| d.message.as_str())
+        .collect();
+    assert_eq!(messages, vec!["2 dependencies have updates available"]);
+}
+
+#[test]
+fn load_config_reads_the_language_server_settings() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let path = temp_dir.path().join("version-lsp.json");
+    std::fs::write(
+        &path,
+        r#"{ "ignore": ["tokio"], "summarizeDiagnostics": "summary" }"#,
+    )
+    .unwrap();
+
+    let config = load_config(Some(&path)).unwrap();
+
+    assert_eq!(config.ignore, vec!["tokio".to_string()]);
+    assert_eq!(config.summarize_diagnostics, DiagnosticSummary::Summary);
+    assert_eq!(load_config(None).unwrap(), LspConfig::default());
+}
+
+#[test]
+fn load_config_rejects_invalid_settings() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let path = temp_dir.path().join("version-lsp.json");
+    std::fs::write(&path, r#"{ "summarizeDiagnostics": 1 }"#).unwrap();
+
+    assert!(load_config(Some(&path)).is_err());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn check_rejects_unsupported_files() {
+    let (temp_dir, cache) = create_test_cache(RegistryType::CratesIo, &[]);
+    let path = temp_dir.path().join("notes.txt");
+    std::fs::write(&path, "hello").unwrap();
+
+    let result = check_files(
+        &*cache,
+        &HashMap::new(),
+        &LspConfig::default(),
+        std::slice::from_ref(&path),
+    )
+    .await;
+
+    assert!(result.is_err());
+}
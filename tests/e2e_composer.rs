@@ -0,0 +1,90 @@
+//! Composer (composer.json) E2E tests
+
+mod helper;
+
+use std::collections::HashMap;
+
+use tower::Service;
+use tower_lsp::LspService;
+use tower_lsp::lsp_types::*;
+
+use helper::{
+    MockRegistry, create_did_open_notification, create_initialize_request,
+    create_initialized_notification, create_test_cache, create_test_resolver,
+    spawn_notification_collector, wait_for_notification,
+};
+use version_lsp::lsp::backend::Backend;
+use version_lsp::lsp::resolver::PackageResolver;
+use version_lsp::parser::types::RegistryType;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn publishes_outdated_version_warning() {
+    // 1. Setup real Cache with test data (oldest first, newest last)
+    let (_temp_dir, cache) = create_test_cache(
+        RegistryType::Composer,
+        &[
+            ("monolog/monolog", vec!["2.9.2", "3.4.0", "3.5.0"]),
+            ("symfony/console", vec!["6.4.1", "7.0.1", "7.1.0"]),
+        ],
+    );
+
+    // 2. Setup mock Registry and resolver
+    let registry = MockRegistry::new(RegistryType::Composer)
+        .with_versions("monolog/monolog", vec!["2.9.2", "3.4.0", "3.5.0"])
+        .with_versions("symfony/console", vec!["6.4.1", "7.0.1", "7.1.0"]);
+
+    let resolvers: HashMap<RegistryType, PackageResolver> = HashMap::from([(
+        RegistryType::Composer,
+        create_test_resolver(RegistryType::Composer, registry),
+    )]);
+
+    // 3. Create LspService
+    let (mut service, socket) =
+        LspService::build(|client| Backend::build(client, cache.clone(), resolvers)).finish();
+
+    let mut notification_rx = spawn_notification_collector(socket);
+
+    // 4. Initialize
+    service.call(create_initialize_request(1)).await.unwrap();
+    service
+        .call(create_initialized_notification())
+        .await
+        .unwrap();
+
+    // 5. didOpen: ^2.9 stops before 3.0 -> outdated. Composer's ~7.0 means
+    // >=7.0 <8.0, so symfony/console is up to date; php is a platform requirement.
+    let composer_json = r#"{
+  "require": {
+    "php": ">=8.1",
+    "monolog/monolog": "^2.9",
+    "symfony/console": "~7.0"
+  }
+}"#;
+
+    service
+        .call(create_did_open_notification(
+            "file:///test/composer.json",
+            composer_json,
+        ))
+        .await
+        .unwrap();
+
+    // 6. Receive publishDiagnostics notification
+    let notification =
+        wait_for_notification(&mut notification_rx, "textDocument/publishDiagnostics")
+            .await
+            .expect("Expected publishDiagnostics notification");
+
+    let params: PublishDiagnosticsParams =
+        serde_json::from_value(notification.params().unwrap().clone()).unwrap();
+    assert_eq!(params.diagnostics.len(), 1);
+    assert_eq!(
+        params.diagnostics[0].severity,
+        Some(DiagnosticSeverity::WARNING)
+    );
+    assert_eq!(
+        params.diagnostics[0].message,
+        "Update available: ^2.9 -> 3.5.0"
+    );
+    assert_eq!(params.diagnostics[0].range.start, Position::new(3, 24));
+}
@@ -3,19 +3,27 @@
 mod helper;
 
 use std::collections::HashMap;
+use std::sync::Arc;
 
 use tower::Service;
 use tower_lsp::LspService;
 use tower_lsp::lsp_types::*;
 
 use helper::{
-    MockRegistry, create_did_open_notification, create_initialize_request,
-    create_initialized_notification, create_test_cache, create_test_resolver,
-    spawn_notification_collector, wait_for_notification,
+    MockRegistry, create_code_lens_request, create_did_open_notification,
+    create_execute_command_request, create_initialize_request,
+    create_initialize_request_with_options, create_initialized_notification, create_test_cache,
+    create_test_cache_with_refresh_interval, create_test_resolver, spawn_notification_collector,
+    wait_for_notification,
 };
-use version_lsp::lsp::backend::Backend;
+use version_lsp::config::LspConfig;
+use version_lsp::lsp::backend::{Backend, UPDATE_ALL_COMMAND, UPDATE_PACKAGE_COMMAND};
+use version_lsp::lsp::check::check_files;
 use version_lsp::lsp::resolver::PackageResolver;
+use version_lsp::parser::cargo_toml::CargoTomlParser;
 use version_lsp::parser::types::RegistryType;
+use version_lsp::version::matchers::CratesVersionMatcher;
+use version_lsp::version::registries::CratesIoRegistry;
 
 #[tokio::test(flavor = "multi_thread")]
 async fn publishes_outdated_version_warning() {
@@ -331,3 +339,414 @@ prost = "0.13"
         "Update available: 0.13 -> 0.14.1"
     );
 }
+
+/// Stale cache entries are served immediately, then revalidated in the background
+#[tokio::test(flavor = "multi_thread")]
+async fn stale_versions_are_served_then_revalidated() {
+    // 1. Setup Cache with a short refresh interval and let the entry go stale
+    let (_temp_dir, cache) = create_test_cache_with_refresh_interval(
+        RegistryType::CratesIo,
+        &[("serde", vec!["1.0.0"])],
+        50,
+    );
+    std::thread::sleep(std::time::Duration::from_millis(100));
+
+    // 2. Registry now knows about a newer release
+    let registry =
+        MockRegistry::new(RegistryType::CratesIo).with_versions("serde", vec!["1.0.0", "2.0.0"]);
+
+    let resolvers: HashMap<RegistryType, PackageResolver> = HashMap::from([(
+        RegistryType::CratesIo,
+        create_test_resolver(RegistryType::CratesIo, registry),
+    )]);
+
+    // 3. Create LspService
+    let (mut service, socket) =
+        LspService::build(|client| Backend::build(client, cache.clone(), resolvers)).finish();
+
+    let mut notification_rx = spawn_notification_collector(socket);
+
+    // 4. Initialize
+    service.call(create_initialize_request(1)).await.unwrap();
+
+    // 5. didOpen before `initialized`, which starts the background refresh of
+    // stale entries, so the first check is guaranteed to see the stale cache
+    let cargo_toml = r#"[dependencies]
+serde = "1.0.0"
+"#;
+
+    service
+        .call(create_did_open_notification(
+            "file:///test/Cargo.toml",
+            cargo_toml,
+        ))
+        .await
+        .unwrap();
+
+    // 6. First publish uses the stale cache: 1.0.0 is the latest known version
+    let notification =
+        wait_for_notification(&mut notification_rx, "textDocument/publishDiagnostics")
+            .await
+            .expect("Expected publishDiagnostics notification");
+    let params: PublishDiagnosticsParams =
+        serde_json::from_value(notification.params().unwrap().clone()).unwrap();
+    assert!(params.diagnostics.is_empty());
+
+    service
+        .call(create_initialized_notification())
+        .await
+        .unwrap();
+
+    // 7. Republish after revalidation sees 2.0.0
+    let notification =
+        wait_for_notification(&mut notification_rx, "textDocument/publishDiagnostics")
+            .await
+            .expect("Expected republished publishDiagnostics notification");
+    let params: PublishDiagnosticsParams =
+        serde_json::from_value(notification.params().unwrap().clone()).unwrap();
+    assert_eq!(params.diagnostics.len(), 1);
+    assert_eq!(
+        params.diagnostics[0].message,
+        "Update available: 1.0.0 -> 2.0.0"
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn update_all_command_edits_only_outdated_dependencies() {
+    let versions = [
+        ("serde", vec!["1.0.100", "1.1.0"]),
+        ("tokio", vec!["0.2.25", "1.40.0"]),
+        ("anyhow", vec!["1.0.0", "1.0.86"]),
+    ];
+    let (_temp_dir, cache) = create_test_cache(RegistryType::CratesIo, &versions);
+
+    let registry = versions.iter().fold(
+        MockRegistry::new(RegistryType::CratesIo),
+        |registry, (name, versions)| registry.with_versions(name, versions.clone()),
+    );
+
+    let resolvers: HashMap<RegistryType, PackageResolver> = HashMap::from([(
+        RegistryType::CratesIo,
+        create_test_resolver(RegistryType::CratesIo, registry),
+    )]);
+
+    let (mut service, socket) =
+        LspService::build(|client| Backend::build(client, cache.clone(), resolvers)).finish();
+
+    let mut notification_rx = spawn_notification_collector(socket);
+
+    service.call(create_initialize_request(1)).await.unwrap();
+    service
+        .call(create_initialized_notification())
+        .await
+        .unwrap();
+
+    // serde and tokio are outdated, anyhow is current
+    let uri = "file:///test/Cargo.toml";
+    let cargo_toml = r#"[package]
+name = "test-project"
+version = "0.1.0"
+
+[dependencies]
+serde = "~1.0.100"
+tokio = "0.2"
+anyhow = "1.0"
+"#;
+
+    service
+        .call(create_did_open_notification(uri, cargo_toml))
+        .await
+        .unwrap();
+
+    wait_for_notification(&mut notification_rx, "textDocument/publishDiagnostics")
+        .await
+        .expect("Expected publishDiagnostics notification");
+
+    let response = service
+        .call(create_execute_command_request(
+            2,
+            UPDATE_ALL_COMMAND,
+            vec![serde_json::Value::String(uri.to_string())],
+        ))
+        .await
+        .unwrap()
+        .expect("Expected executeCommand response");
+    // The edit is only applied through workspace/applyEdit, never returned
+    assert_eq!(response.result(), Some(&serde_json::Value::Null));
+
+    let request = wait_for_notification(&mut notification_rx, "workspace/applyEdit")
+        .await
+        .expect("Expected workspace/applyEdit request");
+    let params: ApplyWorkspaceEditParams =
+        serde_json::from_value(request.params().unwrap().clone()).unwrap();
+
+    let changes = params.edit.changes.expect("Expected changes");
+    let edits = changes.get(&uri.parse::<Url>().unwrap()).unwrap();
+    assert_eq!(
+        edits,
+        &vec![
+            TextEdit {
+                range: Range {
+                    start: Position::new(5, 9),
+                    end: Position::new(5, 17),
+                },
+                new_text: "~1.1.0".to_string(),
+            },
+            TextEdit {
+                range: Range {
+                    start: Position::new(6, 9),
+                    end: Position::new(6, 12),
+                },
+                new_text: "1.40.0".to_string(),
+            },
+        ]
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn code_lens_shows_latest_version_on_each_dependency_line() {
+    let versions = [
+        ("serde", vec!["1.0.100", "1.1.0"]),
+        ("tokio", vec!["0.2.25", "1.40.0"]),
+        ("anyhow", vec!["1.0.0", "1.0.86"]),
+    ];
+    let (_temp_dir, cache) = create_test_cache(RegistryType::CratesIo, &versions);
+
+    let registry = versions.iter().fold(
+        MockRegistry::new(RegistryType::CratesIo),
+        |registry, (name, versions)| registry.with_versions(name, versions.clone()),
+    );
+
+    let resolvers: HashMap<RegistryType, PackageResolver> = HashMap::from([(
+        RegistryType::CratesIo,
+        create_test_resolver(RegistryType::CratesIo, registry),
+    )]);
+
+    let (mut service, socket) =
+        LspService::build(|client| Backend::build(client, cache.clone(), resolvers)).finish();
+
+    let mut notification_rx = spawn_notification_collector(socket);
+
+    service.call(create_initialize_request(1)).await.unwrap();
+    service
+        .call(create_initialized_notification())
+        .await
+        .unwrap();
+
+    let uri = "file:///test/Cargo.toml";
+    let cargo_toml = r#"[package]
+name = "test-project"
+version = "0.1.0"
+
+[dependencies]
+serde = "~1.0.100"
+tokio = "0.2"
+anyhow = "1.0"
+"#;
+
+    service
+        .call(create_did_open_notification(uri, cargo_toml))
+        .await
+        .unwrap();
+
+    wait_for_notification(&mut notification_rx, "textDocument/publishDiagnostics")
+        .await
+        .expect("Expected publishDiagnostics notification");
+
+    let response = service
+        .call(create_code_lens_request(2, uri))
+        .await
+        .unwrap()
+        .expect("Expected codeLens response");
+    let lenses: Option<Vec<CodeLens>> =
+        serde_json::from_value(response.result().unwrap().clone()).unwrap();
+    let lenses = lenses.expect("Expected code lenses");
+
+    // One lens per dependency, on the dependency's line
+    let summary: Vec<_> = lenses
+        .iter()
+        .map(|lens| {
+            let command = lens.command.as_ref().expect("Expected resolved lens");
+            (
+                lens.range.start.line,
+                command.title.as_str(),
+                command.command.as_str(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        summary,
+        vec![
+            (5, "latest 1.1.0", UPDATE_PACKAGE_COMMAND),
+            (6, "latest 1.40.0", UPDATE_PACKAGE_COMMAND),
+            (7, "latest 1.0.86", ""),
+        ]
+    );
+
+    // Clicking the tokio lens updates only tokio
+    let command = lenses[1].command.clone().unwrap();
+    let response = service
+        .call(create_execute_command_request(
+            3,
+            &command.command,
+            command.arguments.unwrap(),
+        ))
+        .await
+        .unwrap()
+        .expect("Expected executeCommand response");
+    assert_eq!(response.result(), Some(&serde_json::Value::Null));
+
+    let request = wait_for_notification(&mut notification_rx, "workspace/applyEdit")
+        .await
+        .expect("Expected workspace/applyEdit request");
+    let params: ApplyWorkspaceEditParams =
+        serde_json::from_value(request.params().unwrap().clone()).unwrap();
+
+    let changes = params.edit.changes.expect("Expected changes");
+    let edits = changes.get(&uri.parse::<Url>().unwrap()).unwrap();
+    assert_eq!(
+        edits,
+        &vec![TextEdit {
+            range: Range {
+                start: Position::new(6, 9),
+                end: Position::new(6, 12),
+            },
+            new_text: "1.40.0".to_string(),
+        }]
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn reports_yanked_current_version() {
+    // 1. Mock crates.io: 1.0.1 has been yanked
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("GET", "/foo")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            r#"{
+                "versions": [
+                    {"num": "1.0.2", "yanked": false, "created_at": "2024-03-01T00:00:00.000Z"},
+                    {"num": "1.0.1", "yanked": true, "created_at": "2024-02-01T00:00:00.000Z"},
+                    {"num": "1.0.0", "yanked": false, "created_at": "2024-01-01T00:00:00.000Z"}
+                ]
+            }"#,
+        )
+        .create_async()
+        .await;
+
+    // 2. Start from an empty cache so the crate is fetched from the mock server
+    let (temp_dir, cache) = create_test_cache(RegistryType::CratesIo, &[]);
+    let resolvers: HashMap<RegistryType, PackageResolver> = HashMap::from([(
+        RegistryType::CratesIo,
+        PackageResolver::new(
+            Arc::new(CargoTomlParser::new()),
+            Arc::new(CratesVersionMatcher),
+            Arc::new(CratesIoRegistry::new(&server.url())),
+        ),
+    )]);
+
+    // 3. Pin the yanked version
+    let manifest = temp_dir.path().join("Cargo.toml");
+    std::fs::write(
+        &manifest,
+        r#"[package]
+name = "test-project"
+version = "0.1.0"
+
+[dependencies]
+foo = "=1.0.1"
+"#,
+    )
+    .unwrap();
+
+    // 4. Check the file
+    let reports = check_files(
+        &*cache,
+        &resolvers,
+        &LspConfig::default(),
+        std::slice::from_ref(&manifest),
+    )
+    .await
+    .unwrap();
+
+    // 5. Verify the yanked diagnostic instead of "not found"
+    mock.assert_async().await;
+    let diagnostics = &reports[0].diagnostics;
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].severity, Some(DiagnosticSeverity::ERROR));
+    assert_eq!(
+        diagnostics[0].code,
+        Some(NumberOrString::String("yanked".to_string()))
+    );
+    assert_eq!(diagnostics[0].message, "Version =1.0.1 has been yanked");
+    assert_eq!(diagnostics[0].range.start, Position::new(5, 7));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn publishes_diagnostics_with_in_memory_cache() {
+    // 1. Mock crates.io
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("GET", "/serde")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            r#"{
+                "versions": [
+                    {"num": "1.1.0", "yanked": false, "created_at": "2024-03-01T00:00:00.000Z"},
+                    {"num": "1.0.0", "yanked": false, "created_at": "2024-01-01T00:00:00.000Z"}
+                ]
+            }"#,
+        )
+        .create_async()
+        .await;
+
+    // 2. Use the production backend, which opens its cache on initialize
+    let (mut service, socket) = LspService::new(Backend::new);
+    let mut notification_rx = spawn_notification_collector(socket);
+
+    service
+        .call(create_initialize_request_with_options(
+            1,
+            serde_json::json!({
+                "cachePath": ":memory:",
+                "registries": { "crates": { "url": server.url() } }
+            }),
+        ))
+        .await
+        .unwrap();
+    service
+        .call(create_initialized_notification())
+        .await
+        .unwrap();
+
+    // 3. didOpen: serde is not cached yet, so it is fetched in the background
+    service
+        .call(create_did_open_notification(
+            "file:///test/Cargo.toml",
+            "[dependencies]\nserde = \"~1.0.0\"\n",
+        ))
+        .await
+        .unwrap();
+
+    // 4. Diagnostics are republished once the fetched versions are cached
+    let diagnostics = loop {
+        let notification =
+            wait_for_notification(&mut notification_rx, "textDocument/publishDiagnostics")
+                .await
+                .expect("Expected diagnostics after the fetch");
+        let params: PublishDiagnosticsParams =
+            serde_json::from_value(notification.params().unwrap().clone()).unwrap();
+        if !params.diagnostics.is_empty() {
+            break params.diagnostics;
+        }
+    };
+
+    mock.assert_async().await;
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].message, "Update available: ~1.0.0 -> 1.1.0");
+    // `:memory:` is not opened as a file in the working directory
+    assert!(!std::path::Path::new(":memory:").exists());
+}
@@ -1,4 +1,4 @@
-//! Docker (compose.yaml) E2E tests
+//! Docker (compose.yaml, Dockerfile) E2E tests
 
 mod helper;
 
@@ -245,3 +245,60 @@ async fn handles_suffixed_tag_comparison() {
         "Update available: 1.25-alpine -> 1.27-alpine"
     );
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn publishes_outdated_version_warning_for_dockerfile() {
+    let (_temp_dir, cache) = create_test_cache(
+        RegistryType::Docker,
+        &[("library/node", vec!["18.19.0", "20.11.0", "22.2.0"])],
+    );
+
+    let registry = MockRegistry::new(RegistryType::Docker)
+        .with_versions("library/node", vec!["18.19.0", "20.11.0", "22.2.0"]);
+
+    let resolvers: HashMap<RegistryType, PackageResolver> = HashMap::from([(
+        RegistryType::Docker,
+        create_test_resolver(RegistryType::Docker, registry),
+    )]);
+
+    let (mut service, socket) =
+        LspService::build(|client| Backend::build(client, cache.clone(), resolvers)).finish();
+
+    let mut notification_rx = spawn_notification_collector(socket);
+
+    service.call(create_initialize_request(1)).await.unwrap();
+    service
+        .call(create_initialized_notification())
+        .await
+        .unwrap();
+
+    // The digest-pinned stage is not compared
+    let dockerfile = r#"FROM node:18.19.0 AS build
+RUN npm ci
+
+FROM node:18.19.0@sha256:abc123
+COPY --from=build /app /app
+"#;
+
+    service
+        .call(create_did_open_notification(
+            "file:///test/Dockerfile",
+            dockerfile,
+        ))
+        .await
+        .unwrap();
+
+    let notification =
+        wait_for_notification(&mut notification_rx, "textDocument/publishDiagnostics")
+            .await
+            .expect("Expected publishDiagnostics notification");
+
+    let params: PublishDiagnosticsParams =
+        serde_json::from_value(notification.params().unwrap().clone()).unwrap();
+    assert_eq!(params.diagnostics.len(), 1);
+    assert_eq!(params.diagnostics[0].range.start, Position::new(0, 10));
+    assert_eq!(
+        params.diagnostics[0].message,
+        "Update available: 18.19.0 -> 22.2.0"
+    );
+}
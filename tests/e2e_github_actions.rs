@@ -93,6 +93,75 @@ jobs:
     );
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn did_open_compares_docker_steps_with_the_docker_matcher() {
+    let (_temp_dir, cache) = create_test_cache(
+        RegistryType::Docker,
+        &[("library/node", vec!["18-alpine", "18", "20-alpine", "20"])],
+    );
+
+    let resolvers: HashMap<RegistryType, PackageResolver> = HashMap::from([
+        (
+            RegistryType::GitHubActions,
+            create_test_resolver(
+                RegistryType::GitHubActions,
+                MockRegistry::new(RegistryType::GitHubActions),
+            ),
+        ),
+        (
+            RegistryType::Docker,
+            create_test_resolver(
+                RegistryType::Docker,
+                MockRegistry::new(RegistryType::Docker)
+                    .with_versions("library/node", vec!["18-alpine", "18", "20-alpine", "20"]),
+            ),
+        ),
+    ]);
+
+    let (mut service, socket) =
+        LspService::build(|client| Backend::build(client, cache.clone(), resolvers)).finish();
+
+    let mut notification_rx = spawn_notification_collector(socket);
+
+    service.call(create_initialize_request(1)).await.unwrap();
+    service
+        .call(create_initialized_notification())
+        .await
+        .unwrap();
+
+    let workflow_content = r#"
+name: CI
+on: push
+jobs:
+  build:
+    runs-on: ubuntu-latest
+    steps:
+      - uses: docker://node:18-alpine
+"#;
+
+    service
+        .call(create_did_open_notification(
+            "file:///test/.github/workflows/ci.yml",
+            workflow_content,
+        ))
+        .await
+        .unwrap();
+
+    let notification =
+        wait_for_notification(&mut notification_rx, "textDocument/publishDiagnostics")
+            .await
+            .expect("Expected publishDiagnostics notification");
+
+    // The tag suffix is kept, as for images in compose files
+    let params: PublishDiagnosticsParams =
+        serde_json::from_value(notification.params().unwrap().clone()).unwrap();
+    assert_eq!(params.diagnostics.len(), 1);
+    assert_eq!(
+        params.diagnostics[0].message,
+        "Update available: 18-alpine -> 20-alpine"
+    );
+}
+
 #[tokio::test(flavor = "multi_thread")]
 async fn did_open_no_diagnostics_for_latest_version() {
     // 1. Setup real Cache with test data (oldest first, newest last)
@@ -314,6 +383,67 @@ jobs:
     );
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn did_open_publishes_warning_for_eol_node_runtime() {
+    // 1. Setup real Cache (no packages needed for runs.using)
+    let (_temp_dir, cache) = create_test_cache(RegistryType::GitHubActions, &[]);
+
+    // 2. Setup mock Registry and resolver
+    let registry = MockRegistry::new(RegistryType::GitHubActions);
+
+    let resolvers: HashMap<RegistryType, PackageResolver> = HashMap::from([(
+        RegistryType::GitHubActions,
+        create_test_resolver(RegistryType::GitHubActions, registry),
+    )]);
+
+    // 3. Create LspService
+    let (mut service, socket) =
+        LspService::build(|client| Backend::build(client, cache.clone(), resolvers)).finish();
+
+    let mut notification_rx = spawn_notification_collector(socket);
+
+    // 4. Initialize
+    service.call(create_initialize_request(1)).await.unwrap();
+    service
+        .call(create_initialized_notification())
+        .await
+        .unwrap();
+
+    // 5. didOpen an action metadata file running on node16
+    let action_content = r#"name: My Action
+description: Does things
+runs:
+  using: node16
+  main: dist/index.js
+"#;
+
+    service
+        .call(create_did_open_notification(
+            "file:///test/.github/actions/my-action/action.yml",
+            action_content,
+        ))
+        .await
+        .unwrap();
+
+    // 6. Receive publishDiagnostics notification
+    let notification =
+        wait_for_notification(&mut notification_rx, "textDocument/publishDiagnostics")
+            .await
+            .expect("Expected publishDiagnostics notification");
+
+    let params: PublishDiagnosticsParams =
+        serde_json::from_value(notification.params().unwrap().clone()).unwrap();
+    assert_eq!(params.diagnostics.len(), 1);
+    assert_eq!(
+        params.diagnostics[0].severity,
+        Some(DiagnosticSeverity::WARNING)
+    );
+    assert_eq!(
+        params.diagnostics[0].message,
+        "Runtime node16 is end-of-life; use node20 or later"
+    );
+    assert_eq!(params.diagnostics[0].range.start, Position::new(3, 9));
+}
 #[tokio::test(flavor = "multi_thread")]
 async fn code_action_returns_bump_actions_for_version_tag() {
     // Pattern 3: Version tag only → Returns version bump code actions
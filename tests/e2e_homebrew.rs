@@ -0,0 +1,87 @@
+//! Homebrew (Brewfile) E2E tests
+
+mod helper;
+
+use std::collections::HashMap;
+
+use tower::Service;
+use tower_lsp::LspService;
+use tower_lsp::lsp_types::*;
+
+use helper::{
+    MockRegistry, create_did_open_notification, create_initialize_request,
+    create_initialized_notification, create_test_cache, create_test_resolver,
+    spawn_notification_collector, wait_for_notification,
+};
+use version_lsp::lsp::backend::Backend;
+use version_lsp::lsp::resolver::PackageResolver;
+use version_lsp::parser::types::RegistryType;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn publishes_outdated_versioned_formula_warning() {
+    // 1. Setup real Cache with test data (versioned formulae, then stable)
+    let (_temp_dir, cache) = create_test_cache(
+        RegistryType::Homebrew,
+        &[
+            ("node", vec!["18", "20", "22", "23.1.0"]),
+            ("python", vec!["3.11", "3.12", "3.13.1"]),
+        ],
+    );
+
+    // 2. Setup mock Registry and resolver
+    let registry = MockRegistry::new(RegistryType::Homebrew)
+        .with_versions("node", vec!["18", "20", "22", "23.1.0"])
+        .with_versions("python", vec!["3.11", "3.12", "3.13.1"]);
+
+    let resolvers: HashMap<RegistryType, PackageResolver> = HashMap::from([(
+        RegistryType::Homebrew,
+        create_test_resolver(RegistryType::Homebrew, registry),
+    )]);
+
+    // 3. Create LspService
+    let (mut service, socket) =
+        LspService::build(|client| Backend::build(client, cache.clone(), resolvers)).finish();
+
+    let mut notification_rx = spawn_notification_collector(socket);
+
+    // 4. Initialize
+    service.call(create_initialize_request(1)).await.unwrap();
+    service
+        .call(create_initialized_notification())
+        .await
+        .unwrap();
+
+    // 5. didOpen: node@18 is behind 23.x -> outdated. python@3.13 matches the
+    // stable series, and the unpinned git formula is not checked.
+    let brewfile = r#"brew "git"
+brew "node@18"
+brew "python@3.13"
+"#;
+
+    service
+        .call(create_did_open_notification(
+            "file:///test/Brewfile",
+            brewfile,
+        ))
+        .await
+        .unwrap();
+
+    // 6. Receive publishDiagnostics notification
+    let notification =
+        wait_for_notification(&mut notification_rx, "textDocument/publishDiagnostics")
+            .await
+            .expect("Expected publishDiagnostics notification");
+
+    let params: PublishDiagnosticsParams =
+        serde_json::from_value(notification.params().unwrap().clone()).unwrap();
+    assert_eq!(params.diagnostics.len(), 1);
+    assert_eq!(
+        params.diagnostics[0].severity,
+        Some(DiagnosticSeverity::WARNING)
+    );
+    assert_eq!(
+        params.diagnostics[0].message,
+        "Update available: 18 -> 23.1.0"
+    );
+    assert_eq!(params.diagnostics[0].range.start, Position::new(1, 11));
+}
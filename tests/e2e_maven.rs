@@ -0,0 +1,96 @@
+//! Gradle version catalog (libs.versions.toml) E2E tests
+
+mod helper;
+
+use std::collections::HashMap;
+
+use tower::Service;
+use tower_lsp::LspService;
+use tower_lsp::lsp_types::*;
+
+use helper::{
+    MockRegistry, create_did_open_notification, create_initialize_request,
+    create_initialized_notification, create_test_cache, create_test_resolver,
+    spawn_notification_collector, wait_for_notification,
+};
+use version_lsp::lsp::backend::Backend;
+use version_lsp::lsp::resolver::PackageResolver;
+use version_lsp::parser::types::RegistryType;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn publishes_outdated_library_warning_on_versions_alias() {
+    // 1. Setup real Cache with test data
+    let (_temp_dir, cache) = create_test_cache(
+        RegistryType::Maven,
+        &[
+            (
+                "org.jetbrains.kotlin:kotlin-stdlib",
+                vec!["1.9.21", "1.9.22", "2.0.0"],
+            ),
+            ("com.squareup.okhttp3:okhttp", vec!["4.11.0", "4.12.0"]),
+        ],
+    );
+
+    // 2. Setup mock Registry and resolver
+    let registry = MockRegistry::new(RegistryType::Maven)
+        .with_versions(
+            "org.jetbrains.kotlin:kotlin-stdlib",
+            vec!["1.9.21", "1.9.22", "2.0.0"],
+        )
+        .with_versions("com.squareup.okhttp3:okhttp", vec!["4.11.0", "4.12.0"]);
+
+    let resolvers: HashMap<RegistryType, PackageResolver> = HashMap::from([(
+        RegistryType::Maven,
+        create_test_resolver(RegistryType::Maven, registry),
+    )]);
+
+    // 3. Create LspService
+    let (mut service, socket) =
+        LspService::build(|client| Backend::build(client, cache.clone(), resolvers)).finish();
+
+    let mut notification_rx = spawn_notification_collector(socket);
+
+    // 4. Initialize
+    service.call(create_initialize_request(1)).await.unwrap();
+    service
+        .call(create_initialized_notification())
+        .await
+        .unwrap();
+
+    // 5. didOpen: the kotlin alias is outdated and reported on [versions],
+    // okhttp's inline version is up to date
+    let libs_versions_toml = r#"[versions]
+kotlin = "1.9.22"
+
+[libraries]
+kotlin-stdlib = { module = "org.jetbrains.kotlin:kotlin-stdlib", version.ref = "kotlin" }
+okhttp = "com.squareup.okhttp3:okhttp:4.12.0"
+"#;
+
+    service
+        .call(create_did_open_notification(
+            "file:///test/gradle/libs.versions.toml",
+            libs_versions_toml,
+        ))
+        .await
+        .unwrap();
+
+    // 6. Receive publishDiagnostics notification
+    let notification =
+        wait_for_notification(&mut notification_rx, "textDocument/publishDiagnostics")
+            .await
+            .expect("Expected publishDiagnostics notification");
+
+    let params: PublishDiagnosticsParams =
+        serde_json::from_value(notification.params().unwrap().clone()).unwrap();
+    assert_eq!(params.diagnostics.len(), 1);
+    assert_eq!(
+        params.diagnostics[0].severity,
+        Some(DiagnosticSeverity::WARNING)
+    );
+    assert_eq!(
+        params.diagnostics[0].message,
+        "Update available: 1.9.22 -> 2.0.0"
+    );
+    assert_eq!(params.diagnostics[0].range.start, Position::new(1, 10));
+}
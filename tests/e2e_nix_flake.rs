@@ -0,0 +1,136 @@
+//! Nix flake.lock E2E tests
+
+mod helper;
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use tower::Service;
+use tower_lsp::LspService;
+use tower_lsp::lsp_types::*;
+
+use helper::{
+    create_did_open_notification, create_initialize_request, create_initialized_notification,
+    create_test_cache, spawn_notification_collector, wait_for_notification,
+};
+use version_lsp::lsp::backend::Backend;
+use version_lsp::lsp::resolver::PackageResolver;
+use version_lsp::parser::flake_lock::FlakeLockParser;
+use version_lsp::parser::types::RegistryType;
+use version_lsp::version::matchers::NixFlakeMatcher;
+use version_lsp::version::registries::github::GitHubRegistry;
+use version_lsp::version::registries::nix_flake::NixFlakeRegistry;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn publishes_outdated_flake_input_warning() {
+    // 1. Mock the GitHub API: the head of each input's branch
+    let mut server = mockito::Server::new_async().await;
+    let nixpkgs_mock = server
+        .mock("GET", "/repos/NixOS/nixpkgs/commits/nixos-24.05")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"sha": "9f4128e00b0ae8ec65918efeba59db998750ead6"}"#)
+        .create_async()
+        .await;
+    let flake_utils_mock = server
+        .mock("GET", "/repos/numtide/flake-utils/commits/HEAD")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"sha": "b1d9ab70662946ef0850d488da1c9019f3a9752a"}"#)
+        .create_async()
+        .await;
+
+    // 2. Start from an empty cache so the inputs are fetched through the
+    // real registry, the way the editor sees them
+    let (_temp_dir, cache) = create_test_cache(RegistryType::NixFlake, &[]);
+    let resolvers: HashMap<RegistryType, PackageResolver> = HashMap::from([(
+        RegistryType::NixFlake,
+        PackageResolver::new(
+            Arc::new(FlakeLockParser::new()),
+            Arc::new(NixFlakeMatcher),
+            Arc::new(NixFlakeRegistry::new(GitHubRegistry::new(&server.url()))),
+        ),
+    )]);
+
+    // 3. Create LspService
+    let (mut service, socket) =
+        LspService::build(|client| Backend::build(client, cache.clone(), resolvers)).finish();
+
+    let mut notification_rx = spawn_notification_collector(socket);
+
+    // 4. Initialize
+    service.call(create_initialize_request(1)).await.unwrap();
+    service
+        .call(create_initialized_notification())
+        .await
+        .unwrap();
+
+    // 5. didOpen: nixpkgs is locked behind its branch head -> outdated.
+    // flake-utils is locked at the head of its default branch.
+    let flake_lock = r#"{
+  "nodes": {
+    "flake-utils": {
+      "locked": {
+        "lastModified": 1710146030,
+        "owner": "numtide",
+        "repo": "flake-utils",
+        "rev": "b1d9ab70662946ef0850d488da1c9019f3a9752a",
+        "type": "github"
+      },
+      "original": { "owner": "numtide", "repo": "flake-utils", "type": "github" }
+    },
+    "nixpkgs": {
+      "locked": {
+        "lastModified": 1717144377,
+        "owner": "NixOS",
+        "repo": "nixpkgs",
+        "rev": "805a384895c696f802a9bf5bf4720f37385df547",
+        "type": "github"
+      },
+      "original": { "owner": "NixOS", "ref": "nixos-24.05", "repo": "nixpkgs", "type": "github" }
+    },
+    "root": { "inputs": { "flake-utils": "flake-utils", "nixpkgs": "nixpkgs" } }
+  },
+  "root": "root",
+  "version": 7
+}
+"#;
+
+    service
+        .call(create_did_open_notification(
+            "file:///test/flake.lock",
+            flake_lock,
+        ))
+        .await
+        .unwrap();
+
+    // 6. Nothing is cached yet, so the first publish is empty
+    let notification =
+        wait_for_notification(&mut notification_rx, "textDocument/publishDiagnostics")
+            .await
+            .expect("Expected publishDiagnostics notification");
+    let params: PublishDiagnosticsParams =
+        serde_json::from_value(notification.params().unwrap().clone()).unwrap();
+    assert!(params.diagnostics.is_empty());
+
+    // 7. Republish once the branch heads have been fetched
+    let notification =
+        wait_for_notification(&mut notification_rx, "textDocument/publishDiagnostics")
+            .await
+            .expect("Expected republished publishDiagnostics notification");
+    nixpkgs_mock.assert_async().await;
+    flake_utils_mock.assert_async().await;
+
+    let params: PublishDiagnosticsParams =
+        serde_json::from_value(notification.params().unwrap().clone()).unwrap();
+    assert_eq!(params.diagnostics.len(), 1);
+    assert_eq!(
+        params.diagnostics[0].severity,
+        Some(DiagnosticSeverity::WARNING)
+    );
+    assert_eq!(
+        params.diagnostics[0].message,
+        "Update available: 805a384 -> 9f4128e (locked 2024-05-31)"
+    );
+    assert_eq!(params.diagnostics[0].range.start, Position::new(17, 16));
+}
@@ -3,19 +3,27 @@
 mod helper;
 
 use std::collections::HashMap;
+use std::time::Duration;
 
+use futures::{SinkExt, StreamExt};
+use tokio::time::timeout;
 use tower::Service;
 use tower_lsp::LspService;
+use tower_lsp::jsonrpc::Response;
 use tower_lsp::lsp_types::*;
 
 use helper::{
-    MockRegistry, create_did_open_notification, create_initialize_request,
-    create_initialized_notification, create_test_cache, create_test_resolver,
-    spawn_notification_collector, wait_for_notification,
+    MockRegistry, create_completion_request, create_did_change_configuration_notification,
+    create_did_open_notification, create_did_save_notification, create_execute_command_request,
+    create_hover_request, create_incremental_did_change_notification, create_initialize_request,
+    create_initialize_request_with_capabilities, create_initialize_request_with_options,
+    create_initialized_notification, create_inlay_hint_request, create_test_cache,
+    create_test_resolver, spawn_notification_collector, wait_for_notification,
 };
-use version_lsp::lsp::backend::Backend;
+use version_lsp::lsp::backend::{Backend, REFRESH_COMMAND};
 use version_lsp::lsp::resolver::PackageResolver;
 use version_lsp::parser::types::RegistryType;
+use version_lsp::version::checker::VersionStorer;
 
 #[tokio::test(flavor = "multi_thread")]
 async fn publishes_outdated_version_warning() {
@@ -254,3 +262,1046 @@ async fn caret_range_is_latest_when_satisfied() {
         serde_json::from_value(notification.params().unwrap().clone()).unwrap();
     assert!(params.diagnostics.is_empty());
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn hover_shows_latest_version_and_status() {
+    let (_temp_dir, cache) = create_test_cache(
+        RegistryType::Npm,
+        &[("lodash", vec!["4.17.19", "4.17.20", "4.17.21"])],
+    );
+
+    let registry = MockRegistry::new(RegistryType::Npm)
+        .with_versions("lodash", vec!["4.17.19", "4.17.20", "4.17.21"]);
+
+    let resolvers: HashMap<RegistryType, PackageResolver> = HashMap::from([(
+        RegistryType::Npm,
+        create_test_resolver(RegistryType::Npm, registry),
+    )]);
+
+    let (mut service, socket) =
+        LspService::build(|client| Backend::build(client, cache.clone(), resolvers)).finish();
+
+    let mut notification_rx = spawn_notification_collector(socket);
+
+    service.call(create_initialize_request(1)).await.unwrap();
+    service
+        .call(create_initialized_notification())
+        .await
+        .unwrap();
+
+    let uri = "file:///test/package.json";
+    let package_json = r#"{
+  "name": "test-project",
+  "dependencies": {
+    "lodash": "^4.17.0"
+  }
+}"#;
+
+    service
+        .call(create_did_open_notification(uri, package_json))
+        .await
+        .unwrap();
+
+    wait_for_notification(&mut notification_rx, "textDocument/publishDiagnostics")
+        .await
+        .expect("Expected publishDiagnostics notification");
+
+    // "^4.17.0" starts at line 3, column 15 (after the opening quote)
+    let response = service
+        .call(create_hover_request(2, uri, 3, 17))
+        .await
+        .unwrap()
+        .expect("Expected hover response");
+    let hover: Option<Hover> = serde_json::from_value(response.result().unwrap().clone()).unwrap();
+    let hover = hover.expect("Expected hover content");
+
+    let HoverContents::Markup(contents) = hover.contents else {
+        panic!("Expected markup hover contents");
+    };
+    assert_eq!(
+        contents.value,
+        "**lodash** current: ^4.17.0 · latest: 4.17.21 (up to date)"
+    );
+    assert_eq!(
+        hover.range,
+        Some(Range {
+            start: Position::new(3, 15),
+            end: Position::new(3, 22),
+        })
+    );
+
+    // Hovering the package name yields nothing
+    let response = service
+        .call(create_hover_request(3, uri, 3, 6))
+        .await
+        .unwrap()
+        .expect("Expected hover response");
+    let hover: Option<Hover> = serde_json::from_value(response.result().unwrap().clone()).unwrap();
+    assert!(hover.is_none());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn completion_offers_cached_versions_newest_first() {
+    let (_temp_dir, cache) = create_test_cache(
+        RegistryType::Npm,
+        &[("lodash", vec!["4.17.19", "4.17.20", "4.17.21"])],
+    );
+
+    let registry = MockRegistry::new(RegistryType::Npm)
+        .with_versions("lodash", vec!["4.17.19", "4.17.20", "4.17.21"]);
+
+    let resolvers: HashMap<RegistryType, PackageResolver> = HashMap::from([(
+        RegistryType::Npm,
+        create_test_resolver(RegistryType::Npm, registry),
+    )]);
+
+    let (mut service, socket) =
+        LspService::build(|client| Backend::build(client, cache.clone(), resolvers)).finish();
+
+    let mut notification_rx = spawn_notification_collector(socket);
+
+    service.call(create_initialize_request(1)).await.unwrap();
+    service
+        .call(create_initialized_notification())
+        .await
+        .unwrap();
+
+    let uri = "file:///test/package.json";
+    let package_json = r#"{
+  "name": "test-project",
+  "dependencies": {
+    "lodash": "^4.17"
+  }
+}"#;
+
+    service
+        .call(create_did_open_notification(uri, package_json))
+        .await
+        .unwrap();
+
+    wait_for_notification(&mut notification_rx, "textDocument/publishDiagnostics")
+        .await
+        .expect("Expected publishDiagnostics notification");
+
+    // Cursor right after "^4.17" (line 3, columns 15..20)
+    let response = service
+        .call(create_completion_request(2, uri, 3, 20))
+        .await
+        .unwrap()
+        .expect("Expected completion response");
+    let completion: Option<CompletionResponse> =
+        serde_json::from_value(response.result().unwrap().clone()).unwrap();
+    let Some(CompletionResponse::Array(items)) = completion else {
+        panic!("Expected completion items");
+    };
+
+    let labels: Vec<_> = items.iter().map(|item| item.label.as_str()).collect();
+    assert_eq!(labels, vec!["^4.17.21", "^4.17.20", "^4.17.19"]);
+    assert_eq!(
+        items[0].text_edit,
+        Some(CompletionTextEdit::Edit(TextEdit {
+            range: Range {
+                start: Position::new(3, 15),
+                end: Position::new(3, 20),
+            },
+            new_text: "^4.17.21".to_string(),
+        }))
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn refresh_command_refetches_document_packages() {
+    // 1. The cache predates the 4.17.21 release
+    let (_temp_dir, cache) =
+        create_test_cache(RegistryType::Npm, &[("lodash", vec!["4.17.19", "4.17.20"])]);
+
+    let registry = MockRegistry::new(RegistryType::Npm)
+        .with_versions("lodash", vec!["4.17.19", "4.17.20", "4.17.21"]);
+
+    let resolvers: HashMap<RegistryType, PackageResolver> = HashMap::from([(
+        RegistryType::Npm,
+        create_test_resolver(RegistryType::Npm, registry),
+    )]);
+
+    let (mut service, socket) =
+        LspService::build(|client| Backend::build(client, cache.clone(), resolvers)).finish();
+
+    let mut notification_rx = spawn_notification_collector(socket);
+
+    service.call(create_initialize_request(1)).await.unwrap();
+    service
+        .call(create_initialized_notification())
+        .await
+        .unwrap();
+
+    let uri = "file:///test/package.json";
+    let package_json = r#"{
+  "name": "test-project",
+  "dependencies": {
+    "lodash": "4.17.20"
+  }
+}"#;
+
+    service
+        .call(create_did_open_notification(uri, package_json))
+        .await
+        .unwrap();
+
+    // 2. Up to date according to the cache
+    let notification =
+        wait_for_notification(&mut notification_rx, "textDocument/publishDiagnostics")
+            .await
+            .expect("Expected publishDiagnostics notification");
+    let params: PublishDiagnosticsParams =
+        serde_json::from_value(notification.params().unwrap().clone()).unwrap();
+    assert!(params.diagnostics.is_empty());
+
+    // 3. Refresh the document
+    service
+        .call(create_execute_command_request(
+            2,
+            REFRESH_COMMAND,
+            vec![serde_json::json!(uri)],
+        ))
+        .await
+        .unwrap();
+
+    // 4. Diagnostics are republished from the versions fetched again
+    let mut diagnostics = Vec::new();
+    while diagnostics.is_empty() {
+        let notification =
+            wait_for_notification(&mut notification_rx, "textDocument/publishDiagnostics")
+                .await
+                .expect("Expected diagnostics after the refetch");
+        let params: PublishDiagnosticsParams =
+            serde_json::from_value(notification.params().unwrap().clone()).unwrap();
+        diagnostics = params.diagnostics;
+    }
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(
+        diagnostics[0].message,
+        "Update available: 4.17.20 -> 4.17.21"
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn incremental_change_to_latest_clears_diagnostics() {
+    // 1. Setup real Cache with test data (oldest first, newest last)
+    let (_temp_dir, cache) = create_test_cache(
+        RegistryType::Npm,
+        &[("lodash", vec!["4.17.19", "4.17.20", "4.17.21"])],
+    );
+
+    // 2. Setup mock Registry and resolver
+    let registry = MockRegistry::new(RegistryType::Npm)
+        .with_versions("lodash", vec!["4.17.19", "4.17.20", "4.17.21"]);
+
+    let resolvers: HashMap<RegistryType, PackageResolver> = HashMap::from([(
+        RegistryType::Npm,
+        create_test_resolver(RegistryType::Npm, registry),
+    )]);
+
+    // 3. Create LspService
+    let (mut service, socket) =
+        LspService::build(|client| Backend::build(client, cache.clone(), resolvers)).finish();
+
+    let mut notification_rx = spawn_notification_collector(socket);
+
+    // 4. Initialize
+    service.call(create_initialize_request(1)).await.unwrap();
+    service
+        .call(create_initialized_notification())
+        .await
+        .unwrap();
+
+    // 5. didOpen with outdated version
+    let uri = "file:///test/package.json";
+    let package_json = r#"{
+  "name": "test-project",
+  "dependencies": {
+    "lodash": "4.17.20"
+  }
+}"#;
+
+    service
+        .call(create_did_open_notification(uri, package_json))
+        .await
+        .unwrap();
+
+    let notification =
+        wait_for_notification(&mut notification_rx, "textDocument/publishDiagnostics")
+            .await
+            .expect("Expected publishDiagnostics notification");
+    let params: PublishDiagnosticsParams =
+        serde_json::from_value(notification.params().unwrap().clone()).unwrap();
+    assert_eq!(params.diagnostics.len(), 1);
+
+    // 6. Type the latest version in two quick edits: "4.17.20" -> "4.17.2" -> "4.17.21"
+    service
+        .call(create_incremental_did_change_notification(
+            uri,
+            Range::new(Position::new(3, 15), Position::new(3, 22)),
+            "4.17.2",
+            2,
+        ))
+        .await
+        .unwrap();
+    service
+        .call(create_incremental_did_change_notification(
+            uri,
+            Range::new(Position::new(3, 21), Position::new(3, 21)),
+            "1",
+            3,
+        ))
+        .await
+        .unwrap();
+
+    // 7. The debounced republish only reflects the final text
+    let notification =
+        wait_for_notification(&mut notification_rx, "textDocument/publishDiagnostics")
+            .await
+            .expect("Expected publishDiagnostics notification after didChange");
+    let params: PublishDiagnosticsParams =
+        serde_json::from_value(notification.params().unwrap().clone()).unwrap();
+    assert!(params.diagnostics.is_empty());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn inlay_hint_shows_latest_version_after_outdated_dependency() {
+    let (_temp_dir, cache) = create_test_cache(
+        RegistryType::Npm,
+        &[
+            ("lodash", vec!["4.17.19", "4.17.20", "4.17.21"]),
+            ("react", vec!["18.2.0", "18.3.1"]),
+        ],
+    );
+
+    let registry = MockRegistry::new(RegistryType::Npm)
+        .with_versions("lodash", vec!["4.17.19", "4.17.20", "4.17.21"])
+        .with_versions("react", vec!["18.2.0", "18.3.1"]);
+
+    let resolvers: HashMap<RegistryType, PackageResolver> = HashMap::from([(
+        RegistryType::Npm,
+        create_test_resolver(RegistryType::Npm, registry),
+    )]);
+
+    let (mut service, socket) =
+        LspService::build(|client| Backend::build(client, cache.clone(), resolvers)).finish();
+
+    let mut notification_rx = spawn_notification_collector(socket);
+
+    service.call(create_initialize_request(1)).await.unwrap();
+    service
+        .call(create_initialized_notification())
+        .await
+        .unwrap();
+
+    let uri = "file:///test/package.json";
+    let package_json = r#"{
+  "name": "test-project",
+  "dependencies": {
+    "lodash": "4.17.20",
+    "react": "^18.2.0"
+  }
+}"#;
+
+    service
+        .call(create_did_open_notification(uri, package_json))
+        .await
+        .unwrap();
+
+    wait_for_notification(&mut notification_rx, "textDocument/publishDiagnostics")
+        .await
+        .expect("Expected publishDiagnostics notification");
+
+    let response = service
+        .call(create_inlay_hint_request(2, uri, 0, 7))
+        .await
+        .unwrap()
+        .expect("Expected inlayHint response");
+    let hints: Option<Vec<InlayHint>> =
+        serde_json::from_value(response.result().unwrap().clone()).unwrap();
+    let hints = hints.expect("Expected inlay hints");
+
+    // Only the outdated lodash gets a hint, right after "4.17.20" (line 3, columns 15..22)
+    assert_eq!(hints.len(), 1);
+    assert_eq!(hints[0].position, Position::new(3, 22));
+    let InlayHintLabel::String(label) = &hints[0].label else {
+        panic!("Expected string inlay hint label");
+    };
+    assert_eq!(label, " → 4.17.21");
+    assert!(hints[0].tooltip.is_some());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn initialization_options_disable_diagnostics() {
+    let (_temp_dir, cache) = create_test_cache(
+        RegistryType::Npm,
+        &[("lodash", vec!["4.17.19", "4.17.20", "4.17.21"])],
+    );
+    let registry = MockRegistry::new(RegistryType::Npm)
+        .with_versions("lodash", vec!["4.17.19", "4.17.20", "4.17.21"]);
+    let resolvers: HashMap<RegistryType, PackageResolver> = HashMap::from([(
+        RegistryType::Npm,
+        create_test_resolver(RegistryType::Npm, registry),
+    )]);
+
+    let (mut service, socket) =
+        LspService::build(|client| Backend::build(client, cache.clone(), resolvers)).finish();
+    let mut notification_rx = spawn_notification_collector(socket);
+
+    service
+        .call(create_initialize_request_with_options(
+            1,
+            serde_json::json!({
+                "diagnosticsEnabled": false,
+                "unknownOption": true
+            }),
+        ))
+        .await
+        .unwrap();
+    service
+        .call(create_initialized_notification())
+        .await
+        .unwrap();
+
+    let package_json = r#"{
+  "dependencies": {
+    "lodash": "4.17.20"
+  }
+}"#;
+    service
+        .call(create_did_open_notification(
+            "file:///test/package.json",
+            package_json,
+        ))
+        .await
+        .unwrap();
+
+    let notification =
+        wait_for_notification(&mut notification_rx, "textDocument/publishDiagnostics")
+            .await
+            .expect("Expected publishDiagnostics notification");
+    let params: PublishDiagnosticsParams =
+        serde_json::from_value(notification.params().unwrap().clone()).unwrap();
+    assert!(params.diagnostics.is_empty());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn did_change_configuration_clears_diagnostics_when_disabled() {
+    let (_temp_dir, cache) = create_test_cache(
+        RegistryType::Npm,
+        &[("lodash", vec!["4.17.19", "4.17.20", "4.17.21"])],
+    );
+    let registry = MockRegistry::new(RegistryType::Npm)
+        .with_versions("lodash", vec!["4.17.19", "4.17.20", "4.17.21"]);
+    let resolvers: HashMap<RegistryType, PackageResolver> = HashMap::from([(
+        RegistryType::Npm,
+        create_test_resolver(RegistryType::Npm, registry),
+    )]);
+
+    let (mut service, socket) =
+        LspService::build(|client| Backend::build(client, cache.clone(), resolvers)).finish();
+    let mut notification_rx = spawn_notification_collector(socket);
+
+    service.call(create_initialize_request(1)).await.unwrap();
+    service
+        .call(create_initialized_notification())
+        .await
+        .unwrap();
+
+    let package_json = r#"{
+  "dependencies": {
+    "lodash": "4.17.20"
+  }
+}"#;
+    service
+        .call(create_did_open_notification(
+            "file:///test/package.json",
+            package_json,
+        ))
+        .await
+        .unwrap();
+
+    let notification =
+        wait_for_notification(&mut notification_rx, "textDocument/publishDiagnostics")
+            .await
+            .expect("Expected publishDiagnostics notification");
+    let params: PublishDiagnosticsParams =
+        serde_json::from_value(notification.params().unwrap().clone()).unwrap();
+    assert_eq!(params.diagnostics.len(), 1);
+
+    // Settings pushed by the editor take effect without a restart
+    service
+        .call(create_did_change_configuration_notification(
+            serde_json::json!({ "version-lsp": { "diagnosticsEnabled": false } }),
+        ))
+        .await
+        .unwrap();
+
+    let notification =
+        wait_for_notification(&mut notification_rx, "textDocument/publishDiagnostics")
+            .await
+            .expect("Expected publishDiagnostics notification after configuration change");
+    let params: PublishDiagnosticsParams =
+        serde_json::from_value(notification.params().unwrap().clone()).unwrap();
+    assert_eq!(params.uri.as_str(), "file:///test/package.json");
+    assert!(params.diagnostics.is_empty());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn initialization_options_disable_registry() {
+    let (_temp_dir, cache) = create_test_cache(
+        RegistryType::Npm,
+        &[("lodash", vec!["4.17.19", "4.17.20", "4.17.21"])],
+    );
+    let registry = MockRegistry::new(RegistryType::Npm)
+        .with_versions("lodash", vec!["4.17.19", "4.17.20", "4.17.21"]);
+    let resolvers: HashMap<RegistryType, PackageResolver> = HashMap::from([(
+        RegistryType::Npm,
+        create_test_resolver(RegistryType::Npm, registry),
+    )]);
+
+    let (mut service, socket) =
+        LspService::build(|client| Backend::build(client, cache.clone(), resolvers)).finish();
+    let mut notification_rx = spawn_notification_collector(socket);
+
+    service
+        .call(create_initialize_request_with_options(
+            1,
+            serde_json::json!({
+                "registries": { "npm": { "enabled": false } }
+            }),
+        ))
+        .await
+        .unwrap();
+    service
+        .call(create_initialized_notification())
+        .await
+        .unwrap();
+
+    let package_json = r#"{
+  "dependencies": {
+    "lodash": "4.17.20"
+  }
+}"#;
+    service
+        .call(create_did_open_notification(
+            "file:///test/package.json",
+            package_json,
+        ))
+        .await
+        .unwrap();
+
+    let notification =
+        wait_for_notification(&mut notification_rx, "textDocument/publishDiagnostics").await;
+    assert!(notification.is_none());
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn initialization_options_route_fetches_to_registry_mirror() {
+    // 1. Mock an npm mirror
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("GET", "/lodash")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            r#"{
+                "name": "lodash",
+                "versions": { "4.17.20": {}, "4.17.21": {} },
+                "time": {
+                    "4.17.20": "2020-08-13T16:53:54.152Z",
+                    "4.17.21": "2021-02-20T15:42:16.891Z"
+                }
+            }"#,
+        )
+        .create_async()
+        .await;
+
+    // 2. Use the production backend, which builds its resolvers from the options
+    let (mut service, socket) = LspService::new(Backend::new);
+    let mut notification_rx = spawn_notification_collector(socket);
+
+    service
+        .call(create_initialize_request_with_options(
+            1,
+            serde_json::json!({
+                "cachePath": ":memory:",
+                "registries": { "npm": { "url": server.url() } }
+            }),
+        ))
+        .await
+        .unwrap();
+    service
+        .call(create_initialized_notification())
+        .await
+        .unwrap();
+
+    // 3. didOpen: lodash is fetched from the mirror in the background
+    service
+        .call(create_did_open_notification(
+            "file:///test/package.json",
+            r#"{ "dependencies": { "lodash": "4.17.20" } }"#,
+        ))
+        .await
+        .unwrap();
+
+    // 4. Diagnostics are republished once the mirror's versions are cached
+    let diagnostics = loop {
+        let notification =
+            wait_for_notification(&mut notification_rx, "textDocument/publishDiagnostics")
+                .await
+                .expect("Expected diagnostics after the fetch");
+        let params: PublishDiagnosticsParams =
+            serde_json::from_value(notification.params().unwrap().clone()).unwrap();
+        if !params.diagnostics.is_empty() {
+            break params.diagnostics;
+        }
+    };
+
+    mock.assert_async().await;
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(
+        diagnostics[0].message,
+        "Update available: 4.17.20 -> 4.17.21"
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn reports_work_done_progress_while_fetching_packages() {
+    // 1. Nothing is cached, so all three packages are fetched on open
+    let (_temp_dir, cache) = create_test_cache(RegistryType::Npm, &[]);
+    let registry = MockRegistry::new(RegistryType::Npm)
+        .with_versions("lodash", vec!["4.17.20", "4.17.21"])
+        .with_versions("react", vec!["18.2.0", "19.0.0"])
+        .with_versions("vue", vec!["3.4.0", "3.5.0"]);
+    let resolvers: HashMap<RegistryType, PackageResolver> = HashMap::from([(
+        RegistryType::Npm,
+        create_test_resolver(RegistryType::Npm, registry),
+    )]);
+
+    let (mut service, socket) =
+        LspService::build(|client| Backend::build(client, cache.clone(), resolvers)).finish();
+
+    // 2. Act as the client: accept progress tokens and record the events
+    let (mut requests, mut responses) = socket.split();
+    let client = tokio::spawn(async move {
+        let mut events = Vec::new();
+        while let Ok(Some(request)) = timeout(Duration::from_secs(5), requests.next()).await {
+            match request.method() {
+                "window/workDoneProgress/create" => {
+                    let id = request.id().cloned().unwrap();
+                    responses
+                        .send(Response::from_ok(id, serde_json::Value::Null))
+                        .await
+                        .unwrap();
+                }
+                "$/progress" => {
+                    let params: ProgressParams =
+                        serde_json::from_value(request.params().unwrap().clone()).unwrap();
+                    let ProgressParamsValue::WorkDone(progress) = params.value;
+                    let end = matches!(progress, WorkDoneProgress::End(_));
+                    events.push(match progress {
+                        WorkDoneProgress::Begin(begin) => format!("begin {:?}", begin.message),
+                        WorkDoneProgress::Report(report) => format!("report {:?}", report.message),
+                        WorkDoneProgress::End(_) => "end".to_string(),
+                    });
+                    if end {
+                        break;
+                    }
+                }
+                "textDocument/publishDiagnostics" => {
+                    let params: PublishDiagnosticsParams =
+                        serde_json::from_value(request.params().unwrap().clone()).unwrap();
+                    events.push(format!("publish {}", params.diagnostics.len()));
+                }
+                _ => {}
+            }
+        }
+        events
+    });
+
+    service
+        .call(create_initialize_request_with_capabilities(
+            1,
+            ClientCapabilities {
+                window: Some(WindowClientCapabilities {
+                    work_done_progress: Some(true),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            },
+        ))
+        .await
+        .unwrap();
+    service
+        .call(create_initialized_notification())
+        .await
+        .unwrap();
+
+    let package_json = r#"{
+  "dependencies": {
+    "lodash": "4.17.20",
+    "react": "18.2.0",
+    "vue": "3.4.0"
+  }
+}"#;
+    service
+        .call(create_did_open_notification(
+            "file:///test/package.json",
+            package_json,
+        ))
+        .await
+        .unwrap();
+
+    // 3. Progress begins after the empty first publish and ends once the
+    // fetched versions are published
+    let events = client.await.unwrap();
+    assert_eq!(events.first().map(String::as_str), Some("publish 0"));
+    assert_eq!(
+        events.get(1).map(String::as_str),
+        Some(r#"begin Some("Checking dependencies (0/3)")"#)
+    );
+    assert!(events.contains(&r#"report Some("Checking dependencies (3/3)")"#.to_string()));
+    assert_eq!(
+        events[events.len() - 2..],
+        ["publish 3".to_string(), "end".to_string()]
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn ignore_setting_suppresses_diagnostics_for_matching_packages() {
+    let (_temp_dir, cache) = create_test_cache(
+        RegistryType::Npm,
+        &[
+            ("lodash", vec!["4.17.19", "4.17.20", "4.17.21"]),
+            ("axios", vec!["1.6.0", "1.7.0"]),
+        ],
+    );
+    let registry = MockRegistry::new(RegistryType::Npm)
+        .with_versions("lodash", vec!["4.17.19", "4.17.20", "4.17.21"])
+        .with_versions("axios", vec!["1.6.0", "1.7.0"]);
+    let resolvers: HashMap<RegistryType, PackageResolver> = HashMap::from([(
+        RegistryType::Npm,
+        create_test_resolver(RegistryType::Npm, registry),
+    )]);
+
+    let (mut service, socket) =
+        LspService::build(|client| Backend::build(client, cache.clone(), resolvers)).finish();
+    let mut notification_rx = spawn_notification_collector(socket);
+
+    service
+        .call(create_initialize_request_with_options(
+            1,
+            serde_json::json!({ "ignore": ["lodash"] }),
+        ))
+        .await
+        .unwrap();
+    service
+        .call(create_initialized_notification())
+        .await
+        .unwrap();
+
+    let package_json = r#"{
+  "dependencies": {
+    "lodash": "4.17.20",
+    "axios": "1.6.0"
+  }
+}"#;
+    service
+        .call(create_did_open_notification(
+            "file:///test/package.json",
+            package_json,
+        ))
+        .await
+        .unwrap();
+
+    let notification =
+        wait_for_notification(&mut notification_rx, "textDocument/publishDiagnostics")
+            .await
+            .expect("Expected publishDiagnostics notification");
+    let params: PublishDiagnosticsParams =
+        serde_json::from_value(notification.params().unwrap().clone()).unwrap();
+    assert_eq!(params.diagnostics.len(), 1);
+    assert_eq!(params.diagnostics[0].range.start.line, 3);
+    assert!(params.diagnostics[0].message.contains("1.7.0"));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn summarize_diagnostics_adds_summary_of_reported_updates() {
+    let (_temp_dir, cache) = create_test_cache(
+        RegistryType::Npm,
+        &[
+            ("lodash", vec!["4.17.20", "4.17.21"]),
+            ("axios", vec!["1.6.0", "1.7.0"]),
+            ("react", vec!["17.0.2", "18.3.1"]),
+            ("vue", vec!["3.3.0", "3.4.0"]),
+        ],
+    );
+    let registry = MockRegistry::new(RegistryType::Npm)
+        .with_versions("lodash", vec!["4.17.20", "4.17.21"])
+        .with_versions("axios", vec!["1.6.0", "1.7.0"])
+        .with_versions("react", vec!["17.0.2", "18.3.1"])
+        .with_versions("vue", vec!["3.3.0", "3.4.0"]);
+    let resolvers: HashMap<RegistryType, PackageResolver> = HashMap::from([(
+        RegistryType::Npm,
+        create_test_resolver(RegistryType::Npm, registry),
+    )]);
+
+    let (mut service, socket) =
+        LspService::build(|client| Backend::build(client, cache.clone(), resolvers)).finish();
+    let mut notification_rx = spawn_notification_collector(socket);
+
+    service
+        .call(create_initialize_request_with_options(
+            1,
+            serde_json::json!({ "summarizeDiagnostics": "summary", "ignore": ["vue"] }),
+        ))
+        .await
+        .unwrap();
+    service
+        .call(create_initialized_notification())
+        .await
+        .unwrap();
+
+    let package_json = r#"{
+  "dependencies": {
+    "lodash": "4.17.20",
+    "axios": "1.6.0",
+    "react": "17.0.2",
+    "vue": "3.3.0"
+  }
+}"#;
+    service
+        .call(create_did_open_notification(
+            "file:///test/package.json",
+            package_json,
+        ))
+        .await
+        .unwrap();
+
+    let notification =
+        wait_for_notification(&mut notification_rx, "textDocument/publishDiagnostics")
+            .await
+            .expect("Expected publishDiagnostics notification");
+    let params: PublishDiagnosticsParams =
+        serde_json::from_value(notification.params().unwrap().clone()).unwrap();
+
+    // The summary comes first, followed by the three per-line warnings
+    assert_eq!(params.diagnostics.len(), 4);
+    let summary = &params.diagnostics[0];
+    assert_eq!(summary.message, "3 dependencies have updates available");
+    assert_eq!(summary.severity, Some(DiagnosticSeverity::INFORMATION));
+    assert_eq!(summary.range.start.line, 0);
+    let lines: Vec<u32> = params.diagnostics[1..]
+        .iter()
+        .map(|d| d.range.start.line)
+        .collect();
+    assert_eq!(lines, vec![2, 3, 4]);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn concurrently_opened_documents_both_publish_diagnostics() {
+    // lodash is cached; axios is missing, so the second document's background
+    // fetch writes to the cache while the first document reads from it.
+    let (_temp_dir, cache) = create_test_cache(
+        RegistryType::Npm,
+        &[("lodash", vec!["4.17.19", "4.17.20", "4.17.21"])],
+    );
+    let registry = MockRegistry::new(RegistryType::Npm)
+        .with_versions("lodash", vec!["4.17.19", "4.17.20", "4.17.21"])
+        .with_versions("axios", vec!["1.6.0", "1.7.0"]);
+    let resolvers: HashMap<RegistryType, PackageResolver> = HashMap::from([(
+        RegistryType::Npm,
+        create_test_resolver(RegistryType::Npm, registry),
+    )]);
+
+    let (mut service, socket) =
+        LspService::build(|client| Backend::build(client, cache.clone(), resolvers)).finish();
+    let mut notification_rx = spawn_notification_collector(socket);
+
+    service.call(create_initialize_request(1)).await.unwrap();
+    service
+        .call(create_initialized_notification())
+        .await
+        .unwrap();
+
+    let first = service.call(create_did_open_notification(
+        "file:///first/package.json",
+        r#"{"dependencies": {"lodash": "4.17.20"}}"#,
+    ));
+    let second = service.call(create_did_open_notification(
+        "file:///second/package.json",
+        r#"{"dependencies": {"lodash": "4.17.19", "axios": "1.6.0"}}"#,
+    ));
+    let (first, second) = tokio::join!(first, second);
+    first.unwrap();
+    second.unwrap();
+
+    // Keep the latest diagnostics per document until the fetched axios shows up
+    let mut published: HashMap<Url, Vec<Diagnostic>> = HashMap::new();
+    while published.values().map(Vec::len).sum::<usize>() < 3 {
+        let notification =
+            wait_for_notification(&mut notification_rx, "textDocument/publishDiagnostics")
+                .await
+                .expect("Expected publishDiagnostics for both documents");
+        let params: PublishDiagnosticsParams =
+            serde_json::from_value(notification.params().unwrap().clone()).unwrap();
+        published.insert(params.uri, params.diagnostics);
+    }
+
+    let first = &published[&Url::parse("file:///first/package.json").unwrap()];
+    assert_eq!(first.len(), 1);
+    assert_eq!(first[0].message, "Update available: 4.17.20 -> 4.17.21");
+
+    let second = &published[&Url::parse("file:///second/package.json").unwrap()];
+    let mut messages: Vec<&str> = second.iter().map(|d| d.message.as_str()).collect();
+    messages.sort();
+    assert_eq!(
+        messages,
+        vec![
+            "Update available: 1.6.0 -> 1.7.0",
+            "Update available: 4.17.19 -> 4.17.21"
+        ]
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn did_save_republishes_diagnostics_from_updated_cache() {
+    let (_temp_dir, cache) = create_test_cache(
+        RegistryType::Npm,
+        &[("lodash", vec!["4.17.19", "4.17.20", "4.17.21"])],
+    );
+    let registry = MockRegistry::new(RegistryType::Npm)
+        .with_versions("lodash", vec!["4.17.19", "4.17.20", "4.17.21"]);
+    let resolvers: HashMap<RegistryType, PackageResolver> = HashMap::from([(
+        RegistryType::Npm,
+        create_test_resolver(RegistryType::Npm, registry),
+    )]);
+
+    let (mut service, socket) =
+        LspService::build(|client| Backend::build(client, cache.clone(), resolvers)).finish();
+    let mut notification_rx = spawn_notification_collector(socket);
+
+    service.call(create_initialize_request(1)).await.unwrap();
+    service
+        .call(create_initialized_notification())
+        .await
+        .unwrap();
+
+    let uri = "file:///test/package.json";
+    service
+        .call(create_did_open_notification(
+            uri,
+            r#"{"dependencies": {"lodash": "4.17.21"}}"#,
+        ))
+        .await
+        .unwrap();
+
+    // 1. Up to date when opened
+    let notification =
+        wait_for_notification(&mut notification_rx, "textDocument/publishDiagnostics")
+            .await
+            .expect("Expected publishDiagnostics notification");
+    let params: PublishDiagnosticsParams =
+        serde_json::from_value(notification.params().unwrap().clone()).unwrap();
+    assert!(params.diagnostics.is_empty());
+
+    // 2. The cache learns about a newer release while the document is open
+    cache
+        .replace_versions(
+            RegistryType::Npm,
+            "lodash",
+            vec![
+                "4.17.19".to_string(),
+                "4.17.20".to_string(),
+                "4.17.21".to_string(),
+                "4.17.22".to_string(),
+            ],
+        )
+        .unwrap();
+
+    // 3. Saving rechecks the document against the updated cache
+    service
+        .call(create_did_save_notification(uri))
+        .await
+        .unwrap();
+
+    let notification =
+        wait_for_notification(&mut notification_rx, "textDocument/publishDiagnostics")
+            .await
+            .expect("Expected publishDiagnostics after save");
+    let params: PublishDiagnosticsParams =
+        serde_json::from_value(notification.params().unwrap().clone()).unwrap();
+    assert_eq!(params.diagnostics.len(), 1);
+    assert_eq!(
+        params.diagnostics[0].message,
+        "Update available: 4.17.21 -> 4.17.22"
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn update_on_save_applies_update_all_edit() {
+    let (_temp_dir, cache) = create_test_cache(
+        RegistryType::Npm,
+        &[("lodash", vec!["4.17.19", "4.17.20", "4.17.21"])],
+    );
+    let registry = MockRegistry::new(RegistryType::Npm)
+        .with_versions("lodash", vec!["4.17.19", "4.17.20", "4.17.21"]);
+    let resolvers: HashMap<RegistryType, PackageResolver> = HashMap::from([(
+        RegistryType::Npm,
+        create_test_resolver(RegistryType::Npm, registry),
+    )]);
+
+    let (mut service, socket) =
+        LspService::build(|client| Backend::build(client, cache.clone(), resolvers)).finish();
+    let mut notification_rx = spawn_notification_collector(socket);
+
+    service
+        .call(create_initialize_request_with_options(
+            1,
+            serde_json::json!({ "updateOnSave": true }),
+        ))
+        .await
+        .unwrap();
+    service
+        .call(create_initialized_notification())
+        .await
+        .unwrap();
+
+    let uri = "file:///test/package.json";
+    service
+        .call(create_did_open_notification(
+            uri,
+            r#"{"dependencies": {"lodash": "4.17.20"}}"#,
+        ))
+        .await
+        .unwrap();
+    wait_for_notification(&mut notification_rx, "textDocument/publishDiagnostics")
+        .await
+        .expect("Expected publishDiagnostics notification");
+
+    service
+        .call(create_did_save_notification(uri))
+        .await
+        .unwrap();
+
+    let request = wait_for_notification(&mut notification_rx, "workspace/applyEdit")
+        .await
+        .expect("Expected workspace/applyEdit request");
+    let params: ApplyWorkspaceEditParams =
+        serde_json::from_value(request.params().unwrap().clone()).unwrap();
+    let changes = params.edit.changes.expect("Expected changes");
+    let edits = changes.get(&uri.parse::<Url>().unwrap()).unwrap();
+    assert_eq!(
+        edits,
+        &vec![TextEdit {
+            range: Range {
+                start: Position::new(0, 29),
+                end: Position::new(0, 36),
+            },
+            new_text: "4.17.21".to_string(),
+        }]
+    );
+}
@@ -0,0 +1,89 @@
+//! RubyGems (Gemfile) E2E tests
+
+mod helper;
+
+use std::collections::HashMap;
+
+use tower::Service;
+use tower_lsp::LspService;
+use tower_lsp::lsp_types::*;
+
+use helper::{
+    MockRegistry, create_did_open_notification, create_initialize_request,
+    create_initialized_notification, create_test_cache, create_test_resolver,
+    spawn_notification_collector, wait_for_notification,
+};
+use version_lsp::lsp::backend::Backend;
+use version_lsp::lsp::resolver::PackageResolver;
+use version_lsp::parser::types::RegistryType;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn publishes_outdated_version_warning() {
+    // 1. Setup real Cache with test data (oldest first, newest last)
+    let (_temp_dir, cache) = create_test_cache(
+        RegistryType::RubyGems,
+        &[
+            ("rails", vec!["6.1.7", "7.0.8", "7.1.3"]),
+            ("pg", vec!["1.4.0", "1.5.4"]),
+        ],
+    );
+
+    // 2. Setup mock Registry and resolver
+    let registry = MockRegistry::new(RegistryType::RubyGems)
+        .with_versions("rails", vec!["6.1.7", "7.0.8", "7.1.3"])
+        .with_versions("pg", vec!["1.4.0", "1.5.4"]);
+
+    let resolvers: HashMap<RegistryType, PackageResolver> = HashMap::from([(
+        RegistryType::RubyGems,
+        create_test_resolver(RegistryType::RubyGems, registry),
+    )]);
+
+    // 3. Create LspService
+    let (mut service, socket) =
+        LspService::build(|client| Backend::build(client, cache.clone(), resolvers)).finish();
+
+    let mut notification_rx = spawn_notification_collector(socket);
+
+    // 4. Initialize
+    service.call(create_initialize_request(1)).await.unwrap();
+    service
+        .call(create_initialized_notification())
+        .await
+        .unwrap();
+
+    // 5. didOpen: ~> 7.0.4 means >=7.0.4 <7.1, so 7.1.3 is outside the range -> outdated.
+    // ~> 1.1 means >=1.1 <2, so pg is up to date; gems without a version are skipped.
+    let gemfile = r#"source "https://rubygems.org"
+
+gem "rails", "~> 7.0.4"
+gem "pg", "~> 1.1"
+gem "bootsnap", require: false
+"#;
+
+    service
+        .call(create_did_open_notification(
+            "file:///test/Gemfile",
+            gemfile,
+        ))
+        .await
+        .unwrap();
+
+    // 6. Receive publishDiagnostics notification
+    let notification =
+        wait_for_notification(&mut notification_rx, "textDocument/publishDiagnostics")
+            .await
+            .expect("Expected publishDiagnostics notification");
+
+    let params: PublishDiagnosticsParams =
+        serde_json::from_value(notification.params().unwrap().clone()).unwrap();
+    assert_eq!(params.diagnostics.len(), 1);
+    assert_eq!(
+        params.diagnostics[0].severity,
+        Some(DiagnosticSeverity::WARNING)
+    );
+    assert_eq!(
+        params.diagnostics[0].message,
+        "Update available: ~> 7.0.4 -> 7.1.3"
+    );
+    assert_eq!(params.diagnostics[0].range.start, Position::new(2, 14));
+}
@@ -0,0 +1,93 @@
+//! Terraform (required_providers) E2E tests
+
+mod helper;
+
+use std::collections::HashMap;
+
+use tower::Service;
+use tower_lsp::LspService;
+use tower_lsp::lsp_types::*;
+
+use helper::{
+    MockRegistry, create_did_open_notification, create_initialize_request,
+    create_initialized_notification, create_test_cache, create_test_resolver,
+    spawn_notification_collector, wait_for_notification,
+};
+use version_lsp::lsp::backend::Backend;
+use version_lsp::lsp::resolver::PackageResolver;
+use version_lsp::parser::types::RegistryType;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn publishes_outdated_provider_warning() {
+    // 1. Setup real Cache with test data
+    let (_temp_dir, cache) = create_test_cache(
+        RegistryType::TerraformRegistry,
+        &[
+            ("hashicorp/aws", vec!["4.67.0", "5.0.0", "5.31.0", "6.2.0"]),
+            ("hashicorp/random", vec!["3.1.0", "3.6.2"]),
+        ],
+    );
+
+    // 2. Setup mock Registry and resolver
+    let registry = MockRegistry::new(RegistryType::TerraformRegistry)
+        .with_versions("hashicorp/aws", vec!["4.67.0", "5.0.0", "5.31.0", "6.2.0"])
+        .with_versions("hashicorp/random", vec!["3.1.0", "3.6.2"]);
+
+    let resolvers: HashMap<RegistryType, PackageResolver> = HashMap::from([(
+        RegistryType::TerraformRegistry,
+        create_test_resolver(RegistryType::TerraformRegistry, registry),
+    )]);
+
+    // 3. Create LspService
+    let (mut service, socket) =
+        LspService::build(|client| Backend::build(client, cache.clone(), resolvers)).finish();
+
+    let mut notification_rx = spawn_notification_collector(socket);
+
+    // 4. Initialize
+    service.call(create_initialize_request(1)).await.unwrap();
+    service
+        .call(create_initialized_notification())
+        .await
+        .unwrap();
+
+    // 5. didOpen: `~> 5.0` stops below 6.0.0 -> outdated. `>= 3.1` allows
+    // 3.6.2 and is up to date.
+    let versions_tf = r#"terraform {
+  required_providers {
+    aws = {
+      source  = "hashicorp/aws"
+      version = "~> 5.0"
+    }
+    random = { source = "hashicorp/random", version = ">= 3.1" }
+  }
+}
+"#;
+
+    service
+        .call(create_did_open_notification(
+            "file:///test/versions.tf",
+            versions_tf,
+        ))
+        .await
+        .unwrap();
+
+    // 6. Receive publishDiagnostics notification
+    let notification =
+        wait_for_notification(&mut notification_rx, "textDocument/publishDiagnostics")
+            .await
+            .expect("Expected publishDiagnostics notification");
+
+    let params: PublishDiagnosticsParams =
+        serde_json::from_value(notification.params().unwrap().clone()).unwrap();
+    assert_eq!(params.diagnostics.len(), 1);
+    assert_eq!(
+        params.diagnostics[0].severity,
+        Some(DiagnosticSeverity::WARNING)
+    );
+    assert_eq!(
+        params.diagnostics[0].message,
+        "Update available: ~> 5.0 -> 6.2.0"
+    );
+    assert_eq!(params.diagnostics[0].range.start, Position::new(4, 17));
+}
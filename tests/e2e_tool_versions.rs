@@ -0,0 +1,185 @@
+//! asdf (.tool-versions) and mise.toml E2E tests
+
+mod helper;
+
+use std::collections::HashMap;
+
+use tower::Service;
+use tower_lsp::LspService;
+use tower_lsp::lsp_types::*;
+
+use helper::{
+    MockRegistry, create_did_open_notification, create_initialize_request,
+    create_initialized_notification, create_test_cache, create_test_resolver,
+    spawn_notification_collector, wait_for_notification,
+};
+use version_lsp::lsp::backend::Backend;
+use version_lsp::lsp::resolver::PackageResolver;
+use version_lsp::parser::types::RegistryType;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn publishes_outdated_nodejs_warning() {
+    // 1. Setup real Cache with test data
+    let (_temp_dir, cache) = create_test_cache(
+        RegistryType::ToolVersions,
+        &[("nodejs", vec!["18.19.0", "20.11.0", "23.1.0"])],
+    );
+
+    // 2. Setup mock Registry and resolver
+    let registry = MockRegistry::new(RegistryType::ToolVersions)
+        .with_versions("nodejs", vec!["18.19.0", "20.11.0", "23.1.0"]);
+
+    let resolvers: HashMap<RegistryType, PackageResolver> = HashMap::from([(
+        RegistryType::ToolVersions,
+        create_test_resolver(RegistryType::ToolVersions, registry),
+    )]);
+
+    // 3. Create LspService
+    let (mut service, socket) =
+        LspService::build(|client| Backend::build(client, cache.clone(), resolvers)).finish();
+
+    let mut notification_rx = spawn_notification_collector(socket);
+
+    // 4. Initialize
+    service.call(create_initialize_request(1)).await.unwrap();
+    service
+        .call(create_initialized_notification())
+        .await
+        .unwrap();
+
+    // 5. didOpen: nodejs 20.11.0 is behind 23.1.0 -> outdated. python has no
+    // release feed and is not checked.
+    let tool_versions = "python 3.12.1\nnodejs 20.11.0\n";
+
+    service
+        .call(create_did_open_notification(
+            "file:///test/.tool-versions",
+            tool_versions,
+        ))
+        .await
+        .unwrap();
+
+    // 6. Receive publishDiagnostics notification
+    let notification =
+        wait_for_notification(&mut notification_rx, "textDocument/publishDiagnostics")
+            .await
+            .expect("Expected publishDiagnostics notification");
+
+    let params: PublishDiagnosticsParams =
+        serde_json::from_value(notification.params().unwrap().clone()).unwrap();
+    assert_eq!(params.diagnostics.len(), 1);
+    assert_eq!(
+        params.diagnostics[0].severity,
+        Some(DiagnosticSeverity::WARNING)
+    );
+    assert_eq!(
+        params.diagnostics[0].message,
+        "Update available: 20.11.0 -> 23.1.0"
+    );
+    assert_eq!(params.diagnostics[0].range.start, Position::new(1, 7));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn publishes_outdated_golang_warning_from_go_releases() {
+    let (_temp_dir, cache) =
+        create_test_cache(RegistryType::GoRelease, &[("go", vec!["1.22.0", "1.23.4"])]);
+
+    let tool_registry = MockRegistry::new(RegistryType::ToolVersions);
+    let go_registry =
+        MockRegistry::new(RegistryType::GoRelease).with_versions("go", vec!["1.22.0", "1.23.4"]);
+
+    let resolvers: HashMap<RegistryType, PackageResolver> = HashMap::from([
+        (
+            RegistryType::ToolVersions,
+            create_test_resolver(RegistryType::ToolVersions, tool_registry),
+        ),
+        (
+            RegistryType::GoRelease,
+            create_test_resolver(RegistryType::GoRelease, go_registry),
+        ),
+    ]);
+
+    let (mut service, socket) =
+        LspService::build(|client| Backend::build(client, cache.clone(), resolvers)).finish();
+
+    let mut notification_rx = spawn_notification_collector(socket);
+
+    service.call(create_initialize_request(1)).await.unwrap();
+    service
+        .call(create_initialized_notification())
+        .await
+        .unwrap();
+
+    // golang releases are listed without a `v` prefix, unlike Go modules
+    service
+        .call(create_did_open_notification(
+            "file:///test/.tool-versions",
+            "golang 1.22.0\n",
+        ))
+        .await
+        .unwrap();
+
+    let notification =
+        wait_for_notification(&mut notification_rx, "textDocument/publishDiagnostics")
+            .await
+            .expect("Expected publishDiagnostics notification");
+
+    let params: PublishDiagnosticsParams =
+        serde_json::from_value(notification.params().unwrap().clone()).unwrap();
+    assert_eq!(params.diagnostics.len(), 1);
+    assert_eq!(
+        params.diagnostics[0].message,
+        "Update available: 1.22.0 -> 1.23.4"
+    );
+    assert_eq!(params.diagnostics[0].range.start, Position::new(0, 7));
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn publishes_outdated_nodejs_warning_for_mise_environment_config() {
+    let (_temp_dir, cache) = create_test_cache(
+        RegistryType::ToolVersions,
+        &[("nodejs", vec!["18.19.0", "20.11.0", "23.1.0"])],
+    );
+
+    let registry = MockRegistry::new(RegistryType::ToolVersions)
+        .with_versions("nodejs", vec!["18.19.0", "20.11.0", "23.1.0"]);
+
+    let resolvers: HashMap<RegistryType, PackageResolver> = HashMap::from([(
+        RegistryType::ToolVersions,
+        create_test_resolver(RegistryType::ToolVersions, registry),
+    )]);
+
+    let (mut service, socket) =
+        LspService::build(|client| Backend::build(client, cache.clone(), resolvers)).finish();
+
+    let mut notification_rx = spawn_notification_collector(socket);
+
+    service.call(create_initialize_request(1)).await.unwrap();
+    service
+        .call(create_initialized_notification())
+        .await
+        .unwrap();
+
+    // The file name, not the content, marks this as a mise config
+    service
+        .call(create_did_open_notification(
+            "file:///test/mise.local.toml",
+            "[tools]\nnode = \"20.11.0\"\n",
+        ))
+        .await
+        .unwrap();
+
+    let notification =
+        wait_for_notification(&mut notification_rx, "textDocument/publishDiagnostics")
+            .await
+            .expect("Expected publishDiagnostics notification");
+
+    let params: PublishDiagnosticsParams =
+        serde_json::from_value(notification.params().unwrap().clone()).unwrap();
+    assert_eq!(params.diagnostics.len(), 1);
+    assert_eq!(
+        params.diagnostics[0].message,
+        "Update available: 20.11.0 -> 23.1.0"
+    );
+    assert_eq!(params.diagnostics[0].range.start, Position::new(1, 8));
+}
@@ -10,6 +10,7 @@ use tower_lsp::jsonrpc::Request;
 use tower_lsp::lsp_types::*;
 
 /// Create an LSP initialize request
+#[allow(dead_code)]
 pub fn create_initialize_request(id: i64) -> Request {
     Request::build("initialize")
         .id(id)
@@ -17,14 +18,57 @@ pub fn create_initialize_request(id: i64) -> Request {
         .finish()
 }
 
+/// Create an LSP initialize request carrying `initializationOptions`
+#[allow(dead_code)]
+pub fn create_initialize_request_with_options(id: i64, options: serde_json::Value) -> Request {
+    Request::build("initialize")
+        .id(id)
+        .params(
+            serde_json::to_value(InitializeParams {
+                initialization_options: Some(options),
+                ..Default::default()
+            })
+            .unwrap(),
+        )
+        .finish()
+}
+
+/// Create an LSP initialize request announcing the given client capabilities
+#[allow(dead_code)]
+pub fn create_initialize_request_with_capabilities(
+    id: i64,
+    capabilities: ClientCapabilities,
+) -> Request {
+    Request::build("initialize")
+        .id(id)
+        .params(
+            serde_json::to_value(InitializeParams {
+                capabilities,
+                ..Default::default()
+            })
+            .unwrap(),
+        )
+        .finish()
+}
+
 /// Create an LSP initialized notification
+#[allow(dead_code)]
 pub fn create_initialized_notification() -> Request {
     Request::build("initialized")
         .params(serde_json::to_value(InitializedParams {}).unwrap())
         .finish()
 }
 
+/// Create an LSP workspace/didChangeConfiguration notification pushing `settings`
+#[allow(dead_code)]
+pub fn create_did_change_configuration_notification(settings: serde_json::Value) -> Request {
+    Request::build("workspace/didChangeConfiguration")
+        .params(serde_json::to_value(DidChangeConfigurationParams { settings }).unwrap())
+        .finish()
+}
+
 /// Create an LSP didOpen notification
+#[allow(dead_code)]
 pub fn create_did_open_notification(uri: &str, content: &str) -> Request {
     Request::build("textDocument/didOpen")
         .params(
@@ -41,6 +85,22 @@ pub fn create_did_open_notification(uri: &str, content: &str) -> Request {
         .finish()
 }
 
+/// Create an LSP didSave notification (without the saved text)
+#[allow(dead_code)]
+pub fn create_did_save_notification(uri: &str) -> Request {
+    Request::build("textDocument/didSave")
+        .params(
+            serde_json::to_value(DidSaveTextDocumentParams {
+                text_document: TextDocumentIdentifier {
+                    uri: uri.parse().unwrap(),
+                },
+                text: None,
+            })
+            .unwrap(),
+        )
+        .finish()
+}
+
 /// Create an LSP didChange notification
 #[allow(dead_code)]
 pub fn create_did_change_notification(uri: &str, content: &str, version: i32) -> Request {
@@ -62,7 +122,34 @@ pub fn create_did_change_notification(uri: &str, content: &str, version: i32) ->
         .finish()
 }
 
+/// Create an LSP didChange notification replacing `range` with `text`
+#[allow(dead_code)]
+pub fn create_incremental_did_change_notification(
+    uri: &str,
+    range: Range,
+    text: &str,
+    version: i32,
+) -> Request {
+    Request::build("textDocument/didChange")
+        .params(
+            serde_json::to_value(DidChangeTextDocumentParams {
+                text_document: VersionedTextDocumentIdentifier {
+                    uri: uri.parse().unwrap(),
+                    version,
+                },
+                content_changes: vec![TextDocumentContentChangeEvent {
+                    range: Some(range),
+                    range_length: None,
+                    text: text.to_string(),
+                }],
+            })
+            .unwrap(),
+        )
+        .finish()
+}
+
 /// Collect notifications in background and return a receiver
+#[allow(dead_code)]
 pub fn spawn_notification_collector(mut socket: ClientSocket) -> mpsc::Receiver<Request> {
     let (tx, rx) = mpsc::channel(100);
 
@@ -78,6 +165,7 @@ pub fn spawn_notification_collector(mut socket: ClientSocket) -> mpsc::Receiver<
 }
 
 /// Wait for a notification with the specified method name from the receiver
+#[allow(dead_code)]
 pub async fn wait_for_notification(
     rx: &mut mpsc::Receiver<Request>,
     method: &str,
@@ -97,6 +185,84 @@ pub async fn wait_for_notification(
     }
 }
 
+/// Create an LSP hover request
+#[allow(dead_code)]
+pub fn create_hover_request(id: i64, uri: &str, line: u32, character: u32) -> Request {
+    Request::build("textDocument/hover")
+        .id(id)
+        .params(
+            serde_json::to_value(HoverParams {
+                text_document_position_params: TextDocumentPositionParams {
+                    text_document: TextDocumentIdentifier {
+                        uri: uri.parse().unwrap(),
+                    },
+                    position: Position { line, character },
+                },
+                work_done_progress_params: Default::default(),
+            })
+            .unwrap(),
+        )
+        .finish()
+}
+
+/// Create an LSP completion request at the given position
+#[allow(dead_code)]
+pub fn create_completion_request(id: i64, uri: &str, line: u32, character: u32) -> Request {
+    Request::build("textDocument/completion")
+        .id(id)
+        .params(
+            serde_json::to_value(CompletionParams {
+                text_document_position: TextDocumentPositionParams {
+                    text_document: TextDocumentIdentifier {
+                        uri: uri.parse().unwrap(),
+                    },
+                    position: Position { line, character },
+                },
+                work_done_progress_params: Default::default(),
+                partial_result_params: Default::default(),
+                context: None,
+            })
+            .unwrap(),
+        )
+        .finish()
+}
+
+/// Create an LSP inlayHint request covering `start_line..end_line`
+#[allow(dead_code)]
+pub fn create_inlay_hint_request(id: i64, uri: &str, start_line: u32, end_line: u32) -> Request {
+    Request::build("textDocument/inlayHint")
+        .id(id)
+        .params(
+            serde_json::to_value(InlayHintParams {
+                text_document: TextDocumentIdentifier {
+                    uri: uri.parse().unwrap(),
+                },
+                range: Range::new(Position::new(start_line, 0), Position::new(end_line, 0)),
+                work_done_progress_params: Default::default(),
+            })
+            .unwrap(),
+        )
+        .finish()
+}
+
+/// Create an LSP codeLens request
+#[allow(dead_code)]
+pub fn create_code_lens_request(id: i64, uri: &str) -> Request {
+    Request::build("textDocument/codeLens")
+        .id(id)
+        .params(
+            serde_json::to_value(CodeLensParams {
+                text_document: TextDocumentIdentifier {
+                    uri: uri.parse().unwrap(),
+                },
+                work_done_progress_params: Default::default(),
+                partial_result_params: Default::default(),
+            })
+            .unwrap(),
+        )
+        .finish()
+}
+
 /// Create an LSP codeAction request
 #[allow(dead_code)]
 pub fn create_code_action_request(id: i64, uri: &str, line: u32, character: u32) -> Request {
@@ -123,3 +289,23 @@ pub fn create_code_action_request(id: i64, uri: &str, line: u32, character: u32)
         )
         .finish()
 }
+
+/// Create an LSP workspace/executeCommand request
+#[allow(dead_code)]
+pub fn create_execute_command_request(
+    id: i64,
+    command: &str,
+    arguments: Vec<serde_json::Value>,
+) -> Request {
+    Request::build("workspace/executeCommand")
+        .id(id)
+        .params(
+            serde_json::to_value(ExecuteCommandParams {
+                command: command.to_string(),
+                arguments,
+                work_done_progress_params: Default::default(),
+            })
+            .unwrap(),
+        )
+        .finish()
+}
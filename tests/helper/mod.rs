@@ -3,5 +3,6 @@
 pub mod lsp;
 pub mod registry;
 
+#[allow(unused_imports)]
 pub use lsp::*;
 pub use registry::*;
@@ -7,32 +7,44 @@ use async_trait::async_trait;
 use tempfile::TempDir;
 
 use version_lsp::lsp::resolver::PackageResolver;
+use version_lsp::parser::brewfile::BrewfileParser;
 use version_lsp::parser::cargo_toml::CargoTomlParser;
-use version_lsp::parser::compose::ComposeParser;
+use version_lsp::parser::composer_json::ComposerJsonParser;
 use version_lsp::parser::deno_json::DenoJsonParser;
+use version_lsp::parser::docker::DockerParser;
+use version_lsp::parser::flake_lock::FlakeLockParser;
+use version_lsp::parser::gemfile::GemfileParser;
 use version_lsp::parser::github_actions::GitHubActionsParser;
 use version_lsp::parser::go_mod::GoModParser;
+use version_lsp::parser::gradle_versions_toml::GradleVersionsTomlParser;
+use version_lsp::parser::mise_toml::MiseTomlParser;
 use version_lsp::parser::package_json::PackageJsonParser;
 use version_lsp::parser::pnpm_workspace::PnpmWorkspaceParser;
 use version_lsp::parser::pyproject_toml::PyprojectTomlParser;
-use version_lsp::parser::types::RegistryType;
+use version_lsp::parser::terraform::TerraformParser;
+use version_lsp::parser::tool_versions::ToolVersionsParser;
+use version_lsp::parser::types::{RegistryType, is_mise_toml_file};
 use version_lsp::version::cache::Cache;
 use version_lsp::version::checker::VersionStorer;
 use version_lsp::version::error::RegistryError;
 use version_lsp::version::matchers::{
-    CratesVersionMatcher, DockerVersionMatcher, GitHubActionsMatcher, GoVersionMatcher,
-    JsrVersionMatcher, NpmVersionMatcher, PnpmCatalogMatcher, PypiVersionMatcher,
+    ComposerVersionMatcher, CratesVersionMatcher, DockerVersionMatcher, GitHubActionsMatcher,
+    GoReleaseMatcher, GoVersionMatcher, HomebrewVersionMatcher, JsrVersionMatcher,
+    MavenVersionMatcher, NixFlakeMatcher, NpmVersionMatcher, PnpmCatalogMatcher,
+    PypiVersionMatcher, RubyGemsVersionMatcher, TerraformVersionMatcher, ToolVersionsMatcher,
 };
 use version_lsp::version::registries::github::GitHubRegistry;
 use version_lsp::version::registry::Registry;
 use version_lsp::version::types::PackageVersions;
 
 /// Mock registry for testing
+#[allow(dead_code)]
 pub struct MockRegistry {
     registry_type: RegistryType,
     versions: HashMap<String, Vec<String>>,
 }
 
+#[allow(dead_code)]
 impl MockRegistry {
     pub fn new(registry_type: RegistryType) -> Self {
         Self {
@@ -68,6 +80,7 @@ impl Registry for MockRegistry {
 }
 
 /// Create a test resolver for the given registry type with a mock registry
+#[allow(dead_code)]
 pub fn create_test_resolver(
     registry_type: RegistryType,
     mock_registry: MockRegistry,
@@ -96,6 +109,11 @@ pub fn create_test_resolver(
             Arc::new(GoVersionMatcher),
             Arc::new(mock_registry),
         ),
+        RegistryType::GoRelease => PackageResolver::new(
+            Arc::new(GoModParser::new()),
+            Arc::new(GoReleaseMatcher),
+            Arc::new(mock_registry),
+        ),
         RegistryType::PnpmCatalog => PackageResolver::new(
             Arc::new(PnpmWorkspaceParser),
             Arc::new(PnpmCatalogMatcher),
@@ -111,11 +129,47 @@ pub fn create_test_resolver(
             Arc::new(PypiVersionMatcher),
             Arc::new(mock_registry),
         ),
+        RegistryType::RubyGems => PackageResolver::new(
+            Arc::new(GemfileParser::new()),
+            Arc::new(RubyGemsVersionMatcher),
+            Arc::new(mock_registry),
+        ),
+        RegistryType::Composer => PackageResolver::new(
+            Arc::new(ComposerJsonParser::new()),
+            Arc::new(ComposerVersionMatcher),
+            Arc::new(mock_registry),
+        ),
+        RegistryType::Homebrew => PackageResolver::new(
+            Arc::new(BrewfileParser::new()),
+            Arc::new(HomebrewVersionMatcher),
+            Arc::new(mock_registry),
+        ),
+        RegistryType::NixFlake => PackageResolver::new(
+            Arc::new(FlakeLockParser::new()),
+            Arc::new(NixFlakeMatcher),
+            Arc::new(mock_registry),
+        ),
+        RegistryType::ToolVersions => PackageResolver::new(
+            Arc::new(ToolVersionsParser::new()),
+            Arc::new(ToolVersionsMatcher),
+            Arc::new(mock_registry),
+        )
+        .with_file_parser(is_mise_toml_file, Arc::new(MiseTomlParser::new())),
         RegistryType::Docker => PackageResolver::new(
-            Arc::new(ComposeParser::new()),
+            Arc::new(DockerParser::new()),
             Arc::new(DockerVersionMatcher),
             Arc::new(mock_registry),
         ),
+        RegistryType::TerraformRegistry => PackageResolver::new(
+            Arc::new(TerraformParser::new()),
+            Arc::new(TerraformVersionMatcher),
+            Arc::new(mock_registry),
+        ),
+        RegistryType::Maven => PackageResolver::new(
+            Arc::new(GradleVersionsTomlParser::new()),
+            Arc::new(MavenVersionMatcher),
+            Arc::new(mock_registry),
+        ),
     }
 }
 
@@ -123,10 +177,19 @@ pub fn create_test_resolver(
 pub fn create_test_cache(
     registry_type: RegistryType,
     versions: &[(&str, Vec<&str>)],
+) -> (TempDir, Arc<Cache>) {
+    create_test_cache_with_refresh_interval(registry_type, versions, 86400000)
+}
+
+/// Create a test cache with pre-populated versions and a custom refresh interval (ms)
+pub fn create_test_cache_with_refresh_interval(
+    registry_type: RegistryType,
+    versions: &[(&str, Vec<&str>)],
+    refresh_interval: i64,
 ) -> (TempDir, Arc<Cache>) {
     let temp_dir = TempDir::new().unwrap();
     let db_path = temp_dir.path().join("test.db");
-    let cache = Cache::new(&db_path, 86400000, false).unwrap();
+    let cache = Cache::new(&db_path, refresh_interval, false).unwrap();
 
     for (package_name, package_versions) in versions {
         cache